@@ -0,0 +1,372 @@
+// Procedural macros for `src/35-procedural-macros.rs`.
+//
+// A `proc-macro` crate is a compiler plugin: its public functions receive the
+// tokens of the item they're attached to and return new tokens. Real projects
+// parse those tokens with `syn` and generate code with `quote`; this crate
+// uses only the built-in `proc_macro` API, so every step is visible:
+//
+// 1. walk the input `TokenStream` to find the pieces we need (names, fields,
+//    attributes),
+// 2. build the output code, either as a `String` that is parsed back into a
+//    `TokenStream`, or token by token when the output must keep the input's
+//    spans (see `#[log_call]`).
+//
+// Errors are reported by expanding to `compile_error!("...")`, which the
+// compiler shows like any other error. Giving those tokens the span of the
+// offending input token makes the error point at it.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+// -------------------------------------------------------------------------
+// 1. `#[derive(Summary)]`
+// -------------------------------------------------------------------------
+// Implements the traits lesson's `Summary` trait for a struct with named fields:
+//
+//   #[derive(Summary)]
+//   struct NewsArticle {
+//       #[summary] headline: String,          // Included in `summarize()`
+//       #[summary(author)] author: String,    // Used by `author_info()`
+//       content: String,                      // Ignored
+//   }
+//
+// The generated impl names `Summary` without a path, so the trait must be in
+// scope where the derive is used (just like a hand-written `impl`).
+
+struct Field {
+    name: String,
+    in_summary: bool,
+    is_author: bool,
+}
+
+// A message and the input token it is about.
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: impl Into<String>) -> Self {
+        Error {
+            span,
+            message: message.into(),
+        }
+    }
+
+    // For problems with the input as a whole rather than one token.
+    fn at_call_site(message: impl Into<String>) -> Self {
+        Error::new(Span::call_site(), message)
+    }
+}
+
+#[proc_macro_derive(Summary, attributes(summary))]
+pub fn derive_summary(input: TokenStream) -> TokenStream {
+    match parse_struct(input) {
+        Ok((name, fields)) => expand_summary(&name, &fields),
+        Err(error) => compile_error(&error.message, error.span),
+    }
+}
+
+fn expand_summary(name: &str, fields: &[Field]) -> TokenStream {
+    let summary_fields: Vec<&Field> = fields.iter().filter(|f| f.in_summary).collect();
+
+    // With no `#[summary]` fields, summarize every field.
+    let summarized: Vec<&Field> = if summary_fields.is_empty() {
+        fields.iter().collect()
+    } else {
+        summary_fields
+    };
+    let format_string = summarized
+        .iter()
+        .map(|f| format!("{}: {{}}", f.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let format_args: String = summarized
+        .iter()
+        .map(|f| format!(", self.{}", f.name))
+        .collect();
+
+    let author_info = match fields.iter().find(|f| f.is_author) {
+        Some(author) => format!("format!(\"Author: {{}}\", self.{})", author.name),
+        None => String::from("String::from(\"Author: unknown\")"),
+    };
+
+    let code = format!(
+        "impl Summary for {name} {{
+            fn summarize(&self) -> String {{
+                format!(\"{name} {{{{ {format_string} }}}}\"{format_args})
+            }}
+            fn author_info(&self) -> String {{
+                {author_info}
+            }}
+        }}"
+    );
+    parse_generated(&code, "#[derive(Summary)]")
+}
+
+// Extracts the struct name and its fields from the derive input.
+fn parse_struct(input: TokenStream) -> Result<(String, Vec<Field>), Error> {
+    let mut tokens = input.into_iter();
+    let mut name = None;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident.to_string() == "struct" => {
+                name = match tokens.next() {
+                    Some(TokenTree::Ident(ident)) => Some(ident.to_string()),
+                    Some(other) => return Err(Error::new(other.span(), "expected a struct name")),
+                    None => return Err(Error::at_call_site("expected a struct name")),
+                };
+                break;
+            }
+            TokenTree::Ident(ident) if matches!(ident.to_string().as_str(), "enum" | "union") => {
+                return Err(Error::new(
+                    ident.span(),
+                    "`#[derive(Summary)]` only supports structs with named fields",
+                ));
+            }
+            _ => {} // Outer attributes (`#[...]`) and visibility (`pub`, `pub(crate)`)
+        }
+    }
+    let name = name.ok_or_else(|| Error::at_call_site("expected a struct"))?;
+
+    let only_named_fields = "`#[derive(Summary)]` only supports structs with named fields";
+    match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+            Ok((name, parse_fields(group.stream())?))
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => Err(Error::new(
+            punct.span(),
+            "`#[derive(Summary)]` doesn't support generic structs",
+        )),
+        Some(other) => Err(Error::new(other.span(), only_named_fields)), // `(...)` or `;`
+        None => Err(Error::at_call_site(only_named_fields)),
+    }
+}
+
+// Fields look like `#[attr] pub name: Type,`. Types can contain commas inside
+// `<...>` (e.g. `HashMap<K, V>`), and `<` / `>` aren't token groups, so we
+// track the angle-bracket depth to find the commas that separate fields.
+// The `>` of an arrow (`fn(u8) -> u8`) doesn't close anything.
+fn parse_fields(body: TokenStream) -> Result<Vec<Field>, Error> {
+    let mut fields = Vec::new();
+    let mut current: Vec<TokenTree> = Vec::new();
+    let mut depth = 0;
+
+    for token in body {
+        let after_dash = matches!(current.last(), Some(TokenTree::Punct(p)) if p.as_char() == '-');
+        match &token {
+            TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+            TokenTree::Punct(p) if p.as_char() == '>' && !after_dash => depth -= 1,
+            TokenTree::Punct(p) if p.as_char() == ',' && depth == 0 => {
+                fields.push(parse_field(std::mem::take(&mut current))?);
+                continue;
+            }
+            _ => {}
+        }
+        current.push(token);
+    }
+    if !current.is_empty() {
+        fields.push(parse_field(current)?); // No trailing comma after the last field
+    }
+    Ok(fields)
+}
+
+fn parse_field(tokens: Vec<TokenTree>) -> Result<Field, Error> {
+    let mut in_summary = false;
+    let mut is_author = false;
+    let mut name = None;
+
+    let mut iter = tokens.into_iter();
+    while let Some(token) = iter.next() {
+        match token {
+            // An attribute: `#` followed by a `[...]` group.
+            TokenTree::Punct(p) if p.as_char() == '#' => {
+                let Some(TokenTree::Group(attr)) = iter.next() else {
+                    return Err(Error::new(p.span(), "expected `[...]` after `#`"));
+                };
+                let attr: Vec<TokenTree> = attr.stream().into_iter().collect();
+                match attr.as_slice() {
+                    [TokenTree::Ident(id)] if id.to_string() == "summary" => in_summary = true,
+                    [TokenTree::Ident(id), TokenTree::Group(args)]
+                        if id.to_string() == "summary" =>
+                    {
+                        match args.stream().to_string().as_str() {
+                            "author" => is_author = true,
+                            other => {
+                                return Err(Error::new(
+                                    args.span(),
+                                    format!(
+                                        "unknown option `{}`, expected `#[summary]` or `#[summary(author)]`",
+                                        other
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+                    _ => {} // Some other attribute, e.g. a doc comment
+                }
+            }
+            TokenTree::Ident(id) if id.to_string() == "pub" => {
+                // `pub(crate)` and friends: skip the parenthesized group too.
+                if let Some(TokenTree::Group(_)) = iter.as_slice().first() {
+                    iter.next();
+                }
+            }
+            TokenTree::Ident(id) => {
+                name = Some(id.to_string());
+                break; // The rest is `: Type`, which we don't need
+            }
+            other => {
+                return Err(Error::new(
+                    other.span(),
+                    format!("unexpected `{}` in struct fields", other),
+                ));
+            }
+        }
+    }
+
+    let name = name.ok_or_else(|| Error::at_call_site("expected a field name"))?;
+    Ok(Field {
+        name,
+        in_summary,
+        is_author,
+    })
+}
+
+// -------------------------------------------------------------------------
+// 2. `#[log_call]`: an Attribute Macro
+// -------------------------------------------------------------------------
+// Attribute macros receive two streams: the attribute's own arguments
+// (`#[log_call(prefix)]` -> `prefix`) and the item it's attached to. This one
+// inserts a `println!` at the start of a function's body:
+//
+//   #[log_call]         fn add(a: i32, b: i32) -> i32 { a + b }
+//   // expands to:      fn add(a: i32, b: i32) -> i32 { println!("[call] add"); a + b }
+//
+// The label ends up inside the `println!` format string, so only a plain
+// identifier is accepted: arbitrary tokens such as `"{}"` or `a b` would
+// change what the generated code means, or stop it from parsing.
+//
+// The output is built from tokens, not from a string: the signature and the
+// body are the input's own tokens, spans included, so an error inside the
+// function is still reported at the line that caused it. Only the new
+// `println!` gets the call-site span.
+
+#[proc_macro_attribute]
+pub fn log_call(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args: Vec<TokenTree> = args.into_iter().collect();
+    let label = match args.as_slice() {
+        [] => String::from("call"),
+        [TokenTree::Ident(label)] => label.to_string(),
+        // Point at the first token that doesn't fit: a non-identifier label,
+        // or whatever follows the identifier.
+        [TokenTree::Ident(_), extra, ..] | [extra, ..] => {
+            return compile_error(
+                "`#[log_call]` takes at most one identifier as its label, e.g. `#[log_call(audit)]`",
+                extra.span(),
+            );
+        }
+    };
+
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+
+    // The function name is the identifier right after `fn`.
+    let fn_name = tokens.windows(2).find_map(|pair| match pair {
+        [TokenTree::Ident(kw), TokenTree::Ident(name)] if kw.to_string() == "fn" => {
+            Some(name.to_string())
+        }
+        _ => None,
+    });
+    let Some(fn_name) = fn_name else {
+        return compile_error(
+            "`#[log_call]` can only be used on functions",
+            Span::call_site(),
+        );
+    };
+
+    // The body is the last `{ ... }` group of the item.
+    let body = match tokens.pop() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => body,
+        other => {
+            let span = other.map_or_else(Span::call_site, |token| token.span()); // E.g. the `;`
+            return compile_error("`#[log_call]` needs a function with a body", span);
+        }
+    };
+
+    // `{ ::std::println!("[label] name"); <original body> }`
+    let mut new_body = println_tokens(&format!("[{}] {}", label, fn_name));
+    new_body.extend(body.stream());
+    let mut new_body = Group::new(Delimiter::Brace, new_body);
+    new_body.set_span(body.span());
+
+    tokens.push(TokenTree::Group(new_body));
+    tokens.into_iter().collect()
+}
+
+// `::std::println!("<message>");`, with the message as a string literal so
+// its contents can't change the generated code. (It is still a format string,
+// which is why `log_call` only accepts identifiers in it.)
+fn println_tokens(message: &str) -> TokenStream {
+    let mut tokens = std_macro_path("println", Span::call_site());
+    tokens.push(TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        TokenTree::Literal(Literal::string(message)).into(),
+    )));
+    tokens.push(TokenTree::Punct(Punct::new(';', Spacing::Alone)));
+    tokens.into_iter().collect()
+}
+
+// Turns code generated as a string back into tokens. `#[derive(Summary)]`
+// only pastes identifiers into its template, so this should always succeed;
+// if it doesn't, that's a bug in the macro, and it's reported as a compile
+// error instead of a panic inside the compiler. Every parsed token gets the
+// call-site span, so this is only for code the macro writes itself: input
+// tokens formatted into the string would lose their spans.
+fn parse_generated(code: &str, macro_name: &str) -> TokenStream {
+    code.parse().unwrap_or_else(|error| {
+        compile_error(
+            &format!(
+                "`{}` generated invalid code ({}): {}",
+                macro_name, error, code
+            ),
+            Span::call_site(),
+        )
+    })
+}
+
+// Builds `::std::compile_error!("message");` token by token. Unlike parsing
+// a string, this can't fail, whatever the message contains. The compiler
+// reports the error at the span of these tokens, so `span` should be the
+// input token the message is about.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut tokens = std_macro_path("compile_error", span);
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(literal).into());
+    args.set_span(span);
+    tokens.push(TokenTree::Group(args));
+    tokens.push(with_span(Punct::new(';', Spacing::Alone), span));
+    tokens.into_iter().collect()
+}
+
+// `::std::<name>!`, every token with `span`.
+fn std_macro_path(name: &str, span: Span) -> Vec<TokenTree> {
+    let path_separator = || {
+        [
+            with_span(Punct::new(':', Spacing::Joint), span),
+            with_span(Punct::new(':', Spacing::Alone), span),
+        ]
+    };
+    let mut tokens = Vec::new();
+    tokens.extend(path_separator());
+    tokens.push(TokenTree::Ident(Ident::new("std", span)));
+    tokens.extend(path_separator());
+    tokens.push(TokenTree::Ident(Ident::new(name, span)));
+    tokens.push(with_span(Punct::new('!', Spacing::Alone), span));
+    tokens
+}
+
+fn with_span(mut punct: Punct, span: Span) -> TokenTree {
+    punct.set_span(span);
+    TokenTree::Punct(punct)
+}
@@ -0,0 +1,16 @@
+// The macros report misuse with `compile_error!` (see the "Errors From a
+// Macro" section of 35-procedural-macros.rs). This harness applies them to
+// the inputs they reject, kept in tests/compile_fail/, and checks with
+// `trybuild` that each one fails with the error in its `.stderr` file.
+//
+//     cargo test -p lesson-derive --test compile_fail
+//
+// Write the expected output with
+// `TRYBUILD=overwrite cargo test -p lesson-derive --test compile_fail`
+// and review the `.stderr` diff like any other change.
+
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,17 @@
+// 35-procedural-macros.rs, section 2: `#[derive(Summary)]` only supports
+// structs with named fields.
+
+use lesson_derive::Summary;
+
+trait Summary {
+    fn summarize(&self) -> String;
+    fn author_info(&self) -> String;
+}
+
+#[derive(Summary)]
+enum Status {
+    Active,
+    Inactive,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// `#[log_call]` keeps the function body's own tokens, so an error inside the
+// body is reported at the line that caused it, not at the attribute.
+
+use lesson_derive::log_call;
+
+#[log_call]
+fn add(a: i32, b: i32) -> String {
+    let sum: String = a + b;
+    sum
+}
+
+fn main() {
+    add(1, 2);
+}
@@ -0,0 +1,14 @@
+// 35-procedural-macros.rs, section 2: the label is pasted into a format
+// string, so `#[log_call]` only takes an identifier. A string literal could
+// carry `{}` placeholders or quotes into the generated `println!`.
+
+use lesson_derive::log_call;
+
+#[log_call("{}")]
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn main() {
+    add(1, 2);
+}
@@ -0,0 +1,13 @@
+// 35-procedural-macros.rs, section 2: `#[log_call]` takes at most one
+// identifier.
+
+use lesson_derive::log_call;
+
+#[log_call(audit, trace)]
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn main() {
+    add(1, 2);
+}
@@ -0,0 +1,18 @@
+// 35-procedural-macros.rs, section 2: the macro only sees tokens, so it can't
+// check that a field implements `Display`. The generated `format!` fails
+// instead, and since that code was written by the macro, the error points at
+// the derive.
+
+use lesson_derive::Summary;
+
+trait Summary {
+    fn summarize(&self) -> String;
+    fn author_info(&self) -> String;
+}
+
+#[derive(Summary)]
+struct Point {
+    x: Vec<i32>,
+}
+
+fn main() {}
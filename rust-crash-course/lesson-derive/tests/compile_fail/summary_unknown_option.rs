@@ -0,0 +1,17 @@
+// 35-procedural-macros.rs, section 2: `#[summary]` only takes `author` as an
+// option. The error points at the option.
+
+use lesson_derive::Summary;
+
+trait Summary {
+    fn summarize(&self) -> String;
+    fn author_info(&self) -> String;
+}
+
+#[derive(Summary)]
+struct Labelled {
+    #[summary(title)]
+    label: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// Build script for `36-ffi-with-build-rs.rs`.
+//
+// Cargo compiles and runs this file *before* compiling the lesson. It uses the
+// `cc` crate to compile `c/textutil.c` with the platform's C compiler into a
+// static library, and tells Cargo to link it (`cargo:rustc-link-lib=static=textutil`
+// is printed by `compile` for us).
+
+fn main() {
+    cc::Build::new()
+        .file("c/textutil.c")
+        .warnings(true)
+        .compile("textutil");
+
+    // Only rerun this script when the C code changes, not on every build.
+    println!("cargo:rerun-if-changed=c/textutil.c");
+    println!("cargo:rerun-if-changed=c/textutil.h");
+}
@@ -0,0 +1,83 @@
+#include "textutil.h"
+
+#include <ctype.h>
+#include <stdlib.h>
+#include <string.h>
+
+size_t tu_count_words(const char *text) {
+    size_t count = 0;
+    int in_word = 0;
+    for (const char *p = text; *p != '\0'; p++) {
+        if (isspace((unsigned char)*p)) {
+            in_word = 0;
+        } else if (!in_word) {
+            in_word = 1;
+            count++;
+        }
+    }
+    return count;
+}
+
+uint32_t tu_checksum(const uint8_t *data, size_t len) {
+    uint32_t sum = 0;
+    for (size_t i = 0; i < len; i++) {
+        sum += data[i];
+    }
+    return sum;
+}
+
+static int parse_number(const char **cursor, uint32_t *out) {
+    const char *p = *cursor;
+    uint64_t value = 0;
+    if (!isdigit((unsigned char)*p)) {
+        return -1;
+    }
+    while (isdigit((unsigned char)*p)) {
+        value = value * 10 + (uint64_t)(*p - '0');
+        if (value > UINT32_MAX) {
+            return -1;
+        }
+        p++;
+    }
+    *out = (uint32_t)value;
+    *cursor = p;
+    return 0;
+}
+
+int tu_parse_version(const char *text, tu_version *out) {
+    const char *p = text;
+    tu_version v;
+    if (parse_number(&p, &v.major) != 0 || *p++ != '.') return -1;
+    if (parse_number(&p, &v.minor) != 0 || *p++ != '.') return -1;
+    if (parse_number(&p, &v.patch) != 0 || *p != '\0') return -1;
+    *out = v;
+    return 0;
+}
+
+char *tu_reverse_words(const char *text) {
+    size_t len = strlen(text);
+    char *result = malloc(len + 1);
+    if (result == NULL) {
+        return NULL;
+    }
+
+    /* Walk the input backwards, copying each word to the output. */
+    size_t written = 0;
+    size_t end = len;
+    while (end > 0) {
+        while (end > 0 && isspace((unsigned char)text[end - 1])) end--;
+        size_t start = end;
+        while (start > 0 && !isspace((unsigned char)text[start - 1])) start--;
+        if (start == end) break;
+        if (written > 0) result[written++] = ' ';
+        memcpy(result + written, text + start, end - start);
+        written += end - start;
+        end = start;
+    }
+    result[written] = '\0';
+    return result;
+}
+
+void tu_free(char *text) {
+    free(text);
+}
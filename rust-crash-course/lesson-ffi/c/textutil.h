@@ -0,0 +1,31 @@
+/* A tiny C library used by the FFI lesson (src/36-ffi-with-build-rs.rs). */
+#ifndef TEXTUTIL_H
+#define TEXTUTIL_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/* Mirrored in Rust as `#[repr(C)] struct Version`. */
+typedef struct {
+    uint32_t major;
+    uint32_t minor;
+    uint32_t patch;
+} tu_version;
+
+/* Counts whitespace-separated words in a NUL-terminated string. */
+size_t tu_count_words(const char *text);
+
+/* Adds up `len` bytes starting at `data` (a pointer + length pair, not a C string). */
+uint32_t tu_checksum(const uint8_t *data, size_t len);
+
+/* Parses "MAJOR.MINOR.PATCH" into `*out`. Returns 0 on success, -1 on bad input. */
+int tu_parse_version(const char *text, tu_version *out);
+
+/* Returns a newly allocated copy of `text` with its words in reverse order,
+ * or NULL if allocation fails. The caller must release it with tu_free. */
+char *tu_reverse_words(const char *text);
+
+/* Frees a string returned by this library. */
+void tu_free(char *text);
+
+#endif
@@ -1,5 +1,10 @@
 // This file covers the fundamental concepts of variables, ownership, and borrowing in Rust.
 
+#[path = "lessons/testing.rs"]
+mod testing;
+
+use testing::{DropTracker, Tracked};
+
 fn main() {
     // -------------------------------------------------------------------------
     // 1. Variables and Ownership
@@ -83,10 +88,10 @@ fn main() {
     let mut s4 = String::from("hello");
     let r3 = &mut s4; // r3 is a mutable reference to s4
     r3.push_str(", rust!"); // change the value that r3 refers to.
-    println!("s4: {}", s4); // s4 has been changed.
+    println!("r3: {}", r3); // Last use of r3: the mutable borrow ends here
 
-    // let r4 = &s4; // This would cause a compile-time error: cannot borrow `s4` as immutable because it is also borrowed as mutable
-    println!("r3: {}", r3);
+    // let r4 = &s4; // This would cause a compile-time error (while r3 is still in use): cannot borrow `s4` as immutable because it is also borrowed as mutable
+    println!("s4: {}", s4); // s4 has been changed.
 
     // -------------------------------------------------------------------------
     // 5. Scope and Variable Validity
@@ -101,6 +106,16 @@ fn main() {
         println!("s5: {}", s5);
     } // s5 goes out of scope here, and the memory for the String is dropped
 
+    // The same rule, checked: a `Tracked` value (from lessons/testing.rs)
+    // records its tag when it is dropped, so we can see exactly when that is.
+    let tracker = DropTracker::new();
+    {
+        let _first = tracker.track("first");
+        let _second = tracker.track("second");
+        assert!(tracker.dropped().is_empty()); // Both still in scope
+    } // Both dropped here, in reverse order of declaration
+    assert_eq!(tracker.take(), ["second", "first"]);
+
     // println!("s5: {}", s5); // This would be an error: `s5` is not in scope
 
     // This Example is borrowing, not ownership transfer will see again on point 12
@@ -131,6 +146,15 @@ fn main() {
     }
     println!("s6: {}", s6);
 
+    // A value moved out of the block is not dropped at the end of the block.
+    let outer;
+    {
+        let inner = tracker.track("inner");
+        outer = inner;
+    } // Nothing to drop: `inner` no longer owns anything
+    assert!(tracker.dropped().is_empty());
+    println!("outer now owns '{}'", outer.tag()); // Dropped at the end of main
+
     // -------------------------------------------------------------------------
     // 7. Rust's Memory Management and "Garbage Collection" (No Traditional GC)
     // -------------------------------------------------------------------------
@@ -140,6 +164,7 @@ fn main() {
     // of a runtime garbage collector. Memory is automatically deallocated when
     // the owner of the data goes out of scope. This is often referred to as
     // "RAII" (Resource Acquisition Is Initialization).  Rust's memory management is deterministic.
+    // The `Drop` trait lets your own types hook into this; see 37-drop-and-raii.rs.
 
     // -------------------------------------------------------------------------
     // 8. Integer vs. String: Stack vs. Heap
@@ -261,6 +286,20 @@ fn main() {
     take_ownership(another_string); // Ownership of another_string moves to the function
     // println!("another_string after function call: {}", another_string); // Error: another_string is no longer valid
 
+    // Checked with the drop tracker from section 5:
+    fn borrow_tracked(tracked: &Tracked) -> &'static str {
+        tracked.tag()
+    } // Only a reference goes out of scope: nothing is dropped
+    fn take_tracked(tracked: Tracked) {
+        println!("Function got ownership: {}", tracked.tag());
+    } // Dropped here
+
+    let owned = tracker.track("owned");
+    assert_eq!(borrow_tracked(&owned), "owned");
+    assert!(tracker.dropped().is_empty());
+    take_tracked(owned);
+    assert_eq!(tracker.take(), ["owned"]);
+
     // -------------------------------------------------------------------------
     // 14. Mutable and Immutable References: Rules and Use Cases
     // -------------------------------------------------------------------------
@@ -377,7 +416,7 @@ fn main() {
     // any existing immutable references to the same data are no longer in scope.
     // The scope is determined by the curly braces `{}`.
 
-    let data3 = vec![5, 6, 7];
+    let mut data3 = vec![5, 6, 7];
     {
         let immutable_r = &data3[0];
         println!("Inside scope: {}", immutable_r);
@@ -207,6 +207,7 @@ fn main() {
         username: String::from("Amal"),
         ..user3 // Copy remaining fields from user3
     };
+    // With `..Default::default()` as the base: see 66-default-and-struct-update.rs.
 
     println!("User4 username: {}, email: {}", user4.username, user4.email);
 
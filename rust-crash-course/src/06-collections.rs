@@ -30,6 +30,8 @@ fn main() {
     v.push(6);
     v.push(7);
     println!("Vector after pushes: {:?}", v);
+    // `push` and `pop` make a `Vec` a stack; for a queue (removing from the
+    // front), see `VecDeque` in 53-stacks-and-queues.rs.
 
     // c. Accessing Elements
     // Access by index (returns a reference, panics if index is out of bounds)
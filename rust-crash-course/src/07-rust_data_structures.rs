@@ -3,8 +3,33 @@
 // It covers their creation, common manipulations, typical use cases, and how iterators
 // interact with them, highlighting differences in memory allocation, mutability, and ownership.
 
+// Lookups in this file handle the missing case (see 5.l). Two checks keep
+// it that way: clippy's `get_unwrap` lint (denied in Cargo.toml) and
+// tests/no_get_unwrap.rs, which also covers the lessons cargo doesn't build.
+// A demo that panics on purpose opts out with `#[allow(clippy::get_unwrap)]`.
+// The examples spell each operation out (a `vec!` where an array would do,
+// `push` right after `Vec::new()`, `.iter().count()`, `is_empty()` on a
+// fixed-size array), which clippy would shorten.
+#![allow(
+    clippy::const_is_empty,
+    clippy::iter_count,
+    clippy::useless_vec,
+    clippy::vec_init_then_push
+)]
+
 use std::collections::HashMap; // Required for HashMap
 
+#[path = "lessons/money.rs"]
+mod money;
+#[path = "lessons/safety.rs"]
+mod safety;
+#[path = "lessons/strings.rs"]
+mod strings;
+
+use money::Money;
+use safety::{LookupError, get_key_or_err, get_or_err};
+use strings::{slice_chars, truncate_at_char_boundary};
+
 fn main() {
     // -------------------------------------------------------------------------
     // Introduction to Data Structures & Iterators in Rust
@@ -265,6 +290,7 @@ fn main() {
 
     // l. Inserting Custom Structs
     #[derive(Debug)] // Required for printing with `{:?}`
+    #[allow(dead_code)] // The fields are only printed, which doesn't count as a read
     struct Item {
         id: u32,
         name: String,
@@ -347,6 +373,64 @@ fn main() {
     // Note: Slicing must be on valid UTF-8 character boundaries.
     // `&sentence[0..1]` would panic for "Здравствуйте" because 'З' is 2 bytes.
 
+    // Safe alternatives to byte slicing:
+    // - `str::get(range)` returns `None` instead of panicking on a bad boundary.
+    // - Count in *characters* and translate to byte offsets with `char_indices()`.
+    // - Step a byte limit back to the nearest boundary with `is_char_boundary()`.
+    let russian = "Здравствуйте";
+    println!("russian.get(0..1): {:?}", russian.get(0..1)); // None, no panic
+    println!("russian.get(0..2): {:?}", russian.get(0..2)); // Some("З")
+
+    // The second and third approaches are packaged in `lessons/strings.rs`
+    // (included at the top of this file): `slice_chars` takes a range of
+    // *character* positions, and `truncate_at_char_boundary` cuts at most
+    // `max_bytes` bytes without splitting a character.
+    println!(
+        "slice_chars(russian, 0..1): '{}'",
+        slice_chars(russian, 0..1) // 'З'
+    );
+    println!(
+        "slice_chars(russian, 0..5): '{}'",
+        slice_chars(russian, 0..5) // 'Здрав'
+    );
+    println!(
+        "truncate_at_char_boundary(russian, 5): '{}'",
+        truncate_at_char_boundary(russian, 5) // 'Зд' (5 bytes would split 'р')
+    );
+    assert_eq!(slice_chars(russian, 0..1), "З");
+    assert_eq!(slice_chars(russian, 6..100), "твуйте"); // Out-of-range end is clamped
+    #[allow(clippy::reversed_empty_ranges)] // Reversed on purpose
+    let reversed = slice_chars("Rust", 3..1);
+    assert_eq!(reversed, ""); // A reversed range gives an empty slice
+    assert_eq!(truncate_at_char_boundary(russian, 5), "Зд");
+    assert_eq!(truncate_at_char_boundary("short", 100), "short");
+
+    let emoji = "héllo 👋🏽!";
+    assert_eq!(slice_chars(emoji, 6..7), "👋"); // The hand alone, without its skin tone
+    assert_eq!(truncate_at_char_boundary(emoji, 9), "héllo "); // 9 bytes lands inside '👋'
+    println!(
+        "'{}': {} bytes, {} chars",
+        emoji,
+        emoji.len(),
+        emoji.chars().count() // 9: '👋🏽' is two chars (hand + skin-tone modifier)
+    );
+
+    // What a user sees as one "character" is a *grapheme cluster*, which can
+    // span several `char`s. The standard library doesn't segment graphemes;
+    // the `unicode-segmentation` crate does. It's an optional dependency, so
+    // this part runs with `cargo run --features ecosystem --bin 07-rust_data_structures`.
+    #[cfg(feature = "ecosystem")]
+    {
+        use strings::grapheme_count;
+
+        println!("'{}': {} graphemes", emoji, grapheme_count(emoji));
+        assert_eq!(grapheme_count(emoji), 8); // '👋🏽' counts once
+        assert_eq!(grapheme_count(russian), russian.chars().count()); // One char each
+    }
+
+    // For UTF-8 encoding, boundaries and safe truncation in more depth, see
+    // 42-string-internals.rs.
+
     // g. Mutability
     // `String` is mutable if declared with `mut`. `&str` is immutable.
 
@@ -397,28 +481,27 @@ fn main() {
     println!("Hash map after inserts: {:?}", user_ages);
 
     // c. Accessing Values (safely with `get`, unsafely with `[]`)
-    let alice_age = user_ages.get(&String::from("Alice")); // Returns `Option<&V>` (safe read)
+    // A `HashMap<String, V>` can be queried with a plain `&str`: `String`
+    // implements `Borrow<str>`, so no temporary `String` has to be allocated.
+    let alice_age = user_ages.get("Alice"); // Returns `Option<&V>` (safe read)
     match alice_age {
         Some(age) => println!("Alice's age (safe): {}", age),
         None => println!("Alice not found."),
     }
 
     // Unsafely reading values: Panics if key is not found
-    // let charlie_age = user_ages[&String::from("Charlie")]; // This would panic!
+    // let charlie_age = user_ages["Charlie"]; // This would panic!
     // println!("Charlie's age (unsafe): {}", charlie_age);
 
     // d. Checking Existence of Keys
-    println!(
-        "Does map contain 'Bob'? {}",
-        user_ages.contains_key(&String::from("Bob"))
-    );
+    println!("Does map contain 'Bob'? {}", user_ages.contains_key("Bob"));
     println!(
         "Does map contain 'Charlie'? {}",
-        user_ages.contains_key(&String::from("Charlie"))
+        user_ages.contains_key("Charlie")
     );
 
     // e. Removing Keys and Values
-    let removed_bob_age = user_ages.remove(&String::from("Bob")); // Returns `Option<V>`
+    let removed_bob_age = user_ages.remove("Bob"); // Returns `Option<V>`
     println!("Removed Bob's age: {:?}", removed_bob_age);
     println!("Map after removing Bob: {:?}", user_ages);
 
@@ -474,21 +557,28 @@ fn main() {
         *count += 1; // Dereference the mutable reference to increment the count
     }
     println!("Word counts: {:?}", word_counts);
+    // The order is arbitrary; for the same count sorted by word (and range
+    // queries over the keys), see 54-btreemap-and-btreeset.rs.
 
     // j. Inserting Custom Structs
     #[derive(Debug)] // Required for printing with `{:?}`
+    #[allow(dead_code)] // `order_id` is only printed
     struct Order {
         order_id: u32,
         customer_name: String,
-        total_amount: f64,
+        // `Money` (lessons/money.rs) stores whole cents, not an `f64`: money
+        // must add up exactly (see the floating-point and money lesson for
+        // why 0.1 + 0.2 != 0.3).
+        total: Money,
     }
+
     let mut orders: HashMap<u32, Order> = HashMap::new();
     orders.insert(
         1,
         Order {
             order_id: 1,
             customer_name: String::from("Bob"),
-            total_amount: 150.75,
+            total: Money::new(150, 75),
         },
     );
     orders.insert(
@@ -496,18 +586,58 @@ fn main() {
         Order {
             order_id: 2,
             customer_name: String::from("Charlie"),
-            total_amount: 200.00,
+            total: Money::new(200, 0),
         },
     );
-    println!(
-        "Order with ID 1: Customer '{}', Total ${}",
-        orders.get(&1).unwrap().customer_name, // `.unwrap()` is unsafe here if key might be absent
-        orders.get(&1).unwrap().total_amount
-    );
+    // Look the order up once and handle the missing case instead of calling
+    // `.unwrap()` (which would panic if the key were absent).
+    match orders.get(&1) {
+        Some(order) => println!(
+            "Order with ID 1: Customer '{}', Total {}",
+            order.customer_name, order.total
+        ),
+        None => println!("Order with ID 1 not found."),
+    }
 
     // k. Testing for Empty
     println!("Is user_ages map empty? {}", user_ages.is_empty());
 
+    // l. Panic-free Lookups (`get_or_err`)
+    // Indexing with `[]` and `.get(..).unwrap()` both panic on a missing key or
+    // an out-of-bounds index. In real code, turn a missing value into an error
+    // so the caller can decide what to do, and `?` can propagate it.
+    // `lessons/safety.rs` (included at the top of this file) has the helpers:
+    // `get_or_err` for slices and `get_key_or_err` for hash maps, both
+    // returning a `LookupError`.
+    fn describe_order(orders: &HashMap<u32, Order>, id: u32) -> Result<String, LookupError> {
+        let order = get_key_or_err(orders, &id)?; // `?` returns early with the error
+        Ok(format!("{} owes {}", order.customer_name, order.total))
+    }
+
+    let scores = [90, 85, 77];
+    println!("get_or_err(&scores, 1): {:?}", get_or_err(&scores, 1)); // Ok(85)
+    println!("get_or_err(&scores, 5): {:?}", get_or_err(&scores, 5)); // Err(IndexOutOfBounds { .. })
+    println!("describe_order(2): {:?}", describe_order(&orders, 2)); // Ok("Charlie owes $200.00")
+    println!("describe_order(9): {:?}", describe_order(&orders, 9)); // Err(KeyNotFound("9"))
+    assert_eq!(get_or_err(&scores, 1), Ok(&85));
+    assert_eq!(
+        get_or_err(&scores, 5),
+        Err(LookupError::IndexOutOfBounds { index: 5, len: 3 })
+    );
+    assert_eq!(
+        describe_order(&orders, 9),
+        Err(LookupError::KeyNotFound(String::from("9")))
+    );
+    if let Err(error) = get_or_err(&scores, 5) {
+        println!("Error: {}", error); // index 5 is out of bounds for length 3
+    }
+
+    // The designated demo block: the panic is the point, so the lint is allowed.
+    #[allow(clippy::get_unwrap)]
+    let lookup_panicked = std::panic::catch_unwind(|| orders.get(&9).unwrap().total).is_err();
+    println!("orders.get(&9).unwrap() panicked: {}", lookup_panicked); // true
+    assert!(lookup_panicked);
+
     // -------------------------------------------------------------------------
     // 6. Iterators: General Concepts & Advanced Usage
     // -------------------------------------------------------------------------
@@ -567,7 +697,7 @@ fn main() {
     // consumed it.
 
     println!("\nIterator Consumption: Single Use vs. Re-creation");
-    let mut numbers_for_iter = vec![10, 20, 30];
+    let numbers_for_iter = vec![10, 20, 30];
     let mut iter1 = numbers_for_iter.iter();
     println!("First item from iter1: {:?}", iter1.next()); // Some(10)
     println!("Second item from iter1: {:?}", iter1.next()); // Some(20)
@@ -598,7 +728,8 @@ fn main() {
     // `zip()`: Combines two iterators into a new iterator of pairs. Stops when either iterator is exhausted.
     let names = vec!["Alice", "Bob", "Charlie"];
     let ages = vec![30, 25, 35];
-    let name_age_pairs: Vec<(&str, &i32)> = names.iter().zip(ages.iter()).collect();
+    // `names.iter()` yields `&&str`; `.copied()` turns each into a `&str`.
+    let name_age_pairs: Vec<(&str, &i32)> = names.iter().copied().zip(ages.iter()).collect();
     println!("Name-Age pairs: {:?}", name_age_pairs);
 
     println!("\n--- Iterator Adapter: enumerate() ---");
@@ -228,6 +228,8 @@ fn main() {
     let some_vec_option = Some(vec![1, 2]);
     let actual_vec = some_vec_option.unwrap_or_default();
     println!("Actual vec (from Some): {:?}", actual_vec);
+    // Deriving and implementing `Default` for your own types: see
+    // 66-default-and-struct-update.rs.
 
     // -------------------------------------------------------------------------
     // 7. Unwrap with Functions (`unwrap_or_else`) Execute Function To get Value
@@ -373,6 +375,8 @@ fn main() {
     let word2 = "rhythm";
     println!("First vowel in '{}': {:?}", word1, find_first_vowel(word1)); // Some('e')
     println!("First vowel in '{}': {:?}", word2, find_first_vowel(word2)); // None
+    // Only ASCII vowels count here: for "élan" this returns Some('a'). The character
+    // classification lesson generalizes this to accented letters and other classes.
 
     // -------------------------------------------------------------------------
     // 11. Additional `Option` Methods and Patterns
@@ -417,6 +421,9 @@ fn main() {
     let result_and_none = user_id.and(no_auth_token); // None
     println!("Result of and with None: {:?}", result_and_none);
 
+    // For more combinators (`ok_or`, `zip`, `transpose`, `flatten`, `?` on
+    // `Option`), see 50-option-result-combinators.rs.
+
     // -------------------------------------------------------------------------
     // Conclusion: The Power of `Option<T>`
     // -------------------------------------------------------------------------
@@ -54,6 +54,9 @@ fn main() {
             }
         }
     }
+    // To iterate it backwards or ask for its length, see 41-iterator-traits.rs.
+    // Why `Item` is an associated type rather than `Iterator<T>`: see
+    // 65-associated-types-vs-generics.rs.
 
     println!("\n--- Custom Iterator Example ---");
     let mut counter = Counter::new(); // Create a new Counter instance
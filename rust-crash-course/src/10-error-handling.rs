@@ -313,6 +313,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Clean up files created by do_something_risky
     let _ = fs::remove_file("data_50.txt"); // Remove the file created by the successful call.
 
+    // The `thiserror` crate can derive `Display`, `Error` and the `From` impl
+    // above, and `anyhow` adds context to errors in application code: see
+    // 48-thiserror-anyhow.rs (needs `--features ecosystem`).
+
     // -------------------------------------------------------------------------
     // 9. Main Function Returning Result (`fn main() -> Result<(), E>`)
     // -------------------------------------------------------------------------
@@ -325,6 +329,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // `Box<dyn std::error::Error>` is a common choice for the error type `E`
     // because it can represent any type that implements the `Error` trait,
     // making it flexible for various error origins.
+    // For what the `Error` trait provides (`source()` chains, downcasting,
+    // backtraces), see 49-error-trait-and-sources.rs.
 
     // Print a header for the main function returning `Result` explanation.
     println!("\n--- Main function returning Result ---");
@@ -981,6 +981,7 @@ fn main() {
     //   short, lowercase letters (e.g., `'a'`, `'b'`).
     // - The `'static` lifetime is a special lifetime that means "lives for
     //   the entire duration of the program." String literals have this lifetime.
+    // - For `for<'a>` bounds, `T: 'static` and variance, see 45-advanced-lifetimes.rs.
 
     // Announce the completion of lifetime examples.
     println!("\n--- End of Lifetimes Examples ---");
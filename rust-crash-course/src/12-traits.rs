@@ -319,6 +319,8 @@ fn main() {
     println!("\n--- Newtype Pattern ---");
     let my_vec = MyVec(vec![1, 2, 3]);
     println!("Debug output of MyVec: {:?}", my_vec); // Uses our custom Debug implementation.
+    // For unit newtypes, forwarding impls, `Deref` and `#[repr(transparent)]`,
+    // see 47-newtypes.rs.
 
     // -------------------------------------------------------------------------
     // 12. Orphan Rule (Coherence) - Explained in previous section
@@ -637,6 +639,8 @@ fn main() {
     // -------------------------------------------------------------------------
     // Trait objects provide dynamic dispatch, allowing different concrete types
     // to be treated uniformly at runtime as long as they implement the same trait.
+    // (Which traits can be used as `dyn`, and what a trait object looks like in
+    // memory: sections 18 and 19 below.)
     println!("\n--- Trait Objects (`dyn Trait`) Revisited ---");
     let mut displayable_items: Vec<Box<dyn Display>> = Vec::new();
     displayable_items.push(Box::new(Person {
@@ -649,5 +653,185 @@ fn main() {
         println!("Displayable item: {}", item); // Dynamically calls Display::fmt for each type
     }
 
+    // -------------------------------------------------------------------------
+    // 18. Object Safety: Which Traits Can Be `dyn`
+    // -------------------------------------------------------------------------
+    // A `dyn Trait` value is used through a table of function pointers (the
+    // "vtable", see 19), one entry per method. So a trait can only be made
+    // into a trait object if every method can have such an entry. Rust calls
+    // this being "dyn compatible" (older docs say "object safe"). Roughly,
+    // each method must:
+    // - take `self` by reference (`&self`, `&mut self`, `self: Box<Self>`...),
+    //   so the caller doesn't need to know the concrete type's size;
+    // - not return or take `Self` by value, for the same reason;
+    // - have no generic type parameters: the vtable would need one entry per
+    //   type the method might ever be called with.
+    // And the trait itself must not require `Self: Sized`.
+
+    println!("\n--- Object Safety (dyn Compatibility) ---");
+
+    // `Clone` breaks the rules: `clone` returns `Self`, and `Clone: Sized`.
+    /*
+    let items: Vec<Box<dyn Clone>> = Vec::new();
+    // error[E0038]: the trait `Clone` is not dyn compatible
+    // note: the trait is not dyn compatible because it requires `Self: Sized`
+    */
+    // A generic method breaks them too:
+    /*
+    trait Exporter {
+        fn export<W: std::io::Write>(&self, out: W);
+    }
+    fn save(exporter: &dyn Exporter) {}
+    // error[E0038]: the trait `Exporter` is not dyn compatible
+    // ...because method `export` has generic type parameters
+    */
+
+    // The escape hatch: `where Self: Sized` on a method takes it out of the
+    // vtable. The trait stays dyn compatible; the method just can't be called
+    // on a `dyn Shape`, only on concrete types.
+    trait Shape {
+        fn area(&self) -> f64;
+        fn name(&self) -> String;
+
+        // Returns `Self`: fine, because it's excluded from `dyn Shape`.
+        fn scaled(&self, factor: f64) -> Self
+        where
+            Self: Sized;
+
+        // Generic: fine for the same reason. A default body works too.
+        fn describe_with<F: Fn(f64) -> String>(&self, format_area: F) -> String
+        where
+            Self: Sized,
+        {
+            format!("{} with area {}", self.name(), format_area(self.area()))
+        }
+
+        // The usual way to clone trait objects: a method returning a boxed
+        // copy, which *is* dyn compatible (`Box<dyn Shape>` has a known size).
+        fn clone_box(&self) -> Box<dyn Shape>;
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Circle {
+        radius: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Rectangle {
+        width: f64,
+        height: f64,
+    }
+
+    impl Shape for Circle {
+        fn area(&self) -> f64 {
+            std::f64::consts::PI * self.radius * self.radius
+        }
+        fn name(&self) -> String {
+            format!("Circle(r={})", self.radius)
+        }
+        fn scaled(&self, factor: f64) -> Self {
+            Circle {
+                radius: self.radius * factor,
+            }
+        }
+        fn clone_box(&self) -> Box<dyn Shape> {
+            Box::new(self.clone())
+        }
+    }
+
+    impl Shape for Rectangle {
+        fn area(&self) -> f64 {
+            self.width * self.height
+        }
+        fn name(&self) -> String {
+            format!("Rectangle({}x{})", self.width, self.height)
+        }
+        fn scaled(&self, factor: f64) -> Self {
+            Rectangle {
+                width: self.width * factor,
+                height: self.height * factor,
+            }
+        }
+        fn clone_box(&self) -> Box<dyn Shape> {
+            Box::new(self.clone())
+        }
+    }
+
+    // With `clone_box`, `Box<dyn Shape>` itself can implement `Clone`.
+    impl Clone for Box<dyn Shape> {
+        fn clone(&self) -> Self {
+            self.clone_box()
+        }
+    }
+
+    // On a concrete type, every method is available.
+    let rect = Rectangle {
+        width: 2.0,
+        height: 3.0,
+    };
+    let bigger = rect.scaled(2.0);
+    assert_eq!(bigger.area(), 24.0);
+    println!("{}", bigger.describe_with(|a| format!("{:.1}", a)));
+
+    // Through `dyn Shape`, only the vtable methods are.
+    let shapes: Vec<Box<dyn Shape>> =
+        vec![Box::new(Circle { radius: 1.0 }), Box::new(rect.clone())];
+    /*
+    shapes[0].scaled(2.0);
+    // error: the `scaled` method cannot be invoked on a trait object
+    */
+    let copies = shapes.clone(); // Uses our `Clone for Box<dyn Shape>`
+    for (original, copy) in shapes.iter().zip(&copies) {
+        println!("{} == {}", original.name(), copy.name());
+        assert_eq!(original.area(), copy.area());
+    }
+
+    // -------------------------------------------------------------------------
+    // 19. Inside a `dyn Trait`: Fat Pointers and Vtables
+    // -------------------------------------------------------------------------
+    // A reference to a concrete type is one address. A reference to a
+    // `dyn Trait` is two: the address of the data, plus the address of the
+    // vtable for the concrete type behind it. (Slices are fat pointers too:
+    // address plus length.) Conceptually, the vtable for `Circle as Shape` is
+    // a static table like:
+    //     [drop_in_place::<Circle>, size_of::<Circle>(), align_of::<Circle>(),
+    //      <Circle as Shape>::area, <Circle as Shape>::name, <Circle as Shape>::clone_box]
+    // There's one per (type, trait) pair, built at compile time. Calling
+    // `shape.area()` loads the function pointer from it and calls it: an
+    // indirect call the compiler usually can't inline, unlike a generic call.
+
+    println!("\n--- Fat Pointers and Vtables ---");
+
+    use std::mem::{size_of, size_of_val};
+
+    let word = size_of::<usize>();
+    println!("&Circle:          {} bytes", size_of::<&Circle>());
+    println!("&dyn Shape:       {} bytes", size_of::<&dyn Shape>());
+    println!("Box<dyn Shape>:   {} bytes", size_of::<Box<dyn Shape>>());
+    println!("&[u8], &str:      {} bytes", size_of::<&[u8]>());
+    assert_eq!(size_of::<&Circle>(), word); // Thin: just the address
+    assert_eq!(size_of::<&dyn Shape>(), 2 * word); // Data + vtable
+    assert_eq!(size_of::<Box<dyn Shape>>(), 2 * word);
+    assert_eq!(size_of::<&[u8]>(), 2 * word); // Data + length
+    assert_eq!(size_of::<&str>(), 2 * word);
+
+    // `size_of::<dyn Shape>()` doesn't compile (no size known), but
+    // `size_of_val` reads it from the vtable at runtime.
+    let circle = Circle { radius: 1.0 };
+    let as_dyn: &dyn Shape = &circle;
+    assert_eq!(size_of_val(as_dyn), size_of::<Circle>()); // 8: one f64
+    assert_eq!(size_of_val(&*shapes[1]), size_of::<Rectangle>()); // 16: two f64s
+
+    // The data half is the same address as the concrete value: making a
+    // trait object copies nothing, it just attaches the vtable pointer.
+    let data_address = as_dyn as *const dyn Shape as *const ();
+    assert_eq!(data_address, &circle as *const Circle as *const ());
+    println!(
+        "Trait object points at the original value: {:?}",
+        data_address
+    );
+    // (Generic functions are the opposite trade-off: one compiled copy per
+    // type, direct calls, bigger binaries. 14-generics.rs covers those.)
+
     println!("\n--- End of Traits Examples ---");
 }
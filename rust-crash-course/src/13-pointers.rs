@@ -11,7 +11,9 @@
 // often in `unsafe` blocks. References are a type of smart pointer
 // managed by the borrow checker.
 
-fn main() {
+// The basics (references, raw pointers, function pointers). The `main` of the
+// second part below calls this first.
+fn pointer_basics() {
     println!("--- Rust Pointers: Understanding Memory Addresses ---");
 
     // -------------------------------------------------------------------------
@@ -38,8 +40,8 @@ fn main() {
     // Mutable reference: `&mut T`
     // You can have *only one* mutable reference to a piece of data at a time
     // within a given scope. This prevents data races.
+    println!("Original mutable value (y): {}", y); // Read `y` before the `&mut` borrow starts
     let r_mut = &mut y;
-    println!("Original mutable value (y): {}", y);
     println!("Mutable reference (r_mut): {}", r_mut);
     *r_mut = 30; // Dereferencing to modify the original value
     println!("Modified value via mutable reference (y): {}", y);
@@ -162,6 +164,7 @@ fn main() {
     // - `Arc<T>`: Atomic reference counting, for safe shared ownership across threads.
     // - `RefCell<T>`: Interior mutability (allowing mutable borrows through an immutable reference).
     // - `Cow<'a, T>`: Clone-on-write, for efficient handling of owned or borrowed data.
+    //   (See 56-cow-clone-on-write.rs.)
 
     println!("\n--- 5. Smart Pointers (Brief Mention) ---");
 
@@ -173,7 +176,7 @@ fn main() {
     use std::rc::Rc;
     let rc_data = Rc::new("shared data".to_string());
     let rc_clone1 = Rc::clone(&rc_data);
-    let rc_clone2 = Rc::clone(&rc_data);
+    let _rc_clone2 = Rc::clone(&rc_data); // Unused, but counted while alive
     println!(
         "Rc data: {}, {} (count: {})",
         rc_data,
@@ -189,15 +192,24 @@ fn main() {
 
 // This file delves into advanced Rust pointer concepts, covering the stack vs. heap,
 // various smart pointers like Box, Rc, and Cell/RefCell, and their implications
-// for ownership, borrowing, and mutability.
+// for ownership, borrowing, and mutability. It ends with a doubly linked list
+// that uses `Rc<RefCell<T>>` and `Weak` together without leaking.
 
 use std::cell::Cell;
 use std::cell::RefCell;
+use std::marker::PhantomData;
 use std::ops::Deref;
 use std::rc::{Rc, Weak};
 
+#[path = "lessons/testing.rs"]
+mod testing;
+
+use testing::{DropTracker, Tracked};
+
 fn main() {
-    println!("--- Advanced Rust Pointers and Memory Management ---");
+    pointer_basics();
+
+    println!("\n--- Advanced Rust Pointers and Memory Management ---");
 
     // -------------------------------------------------------------------------
     // 0. Stack vs. Heap: Where Data Lives
@@ -238,6 +250,7 @@ fn main() {
     // It's used when you have data whose size isn't known at compile time,
     // or when you want to own a value and only have a pointer to it (e.g., recursive data structures).
     // When a `Box` goes out of scope, its destructor is called, and the heap memory is freed.
+    // For recursive data structures built with `Box`, see 51-recursive-types-box.rs.
 
     println!("\n--- 1. Box<T>: Heap Allocation ---");
 
@@ -344,6 +357,7 @@ fn main() {
     // Rc is single-threaded explanation:
     // `Rc` is *not* safe for use across multiple threads because its reference count
     // is not atomic. In a multi-threaded context, you would use `Arc<T>` (Atomic Reference Counted).
+    // (`Rc<RefCell<T>>` vs `Arc<Mutex<T>>` across threads: see 57-shared-state-arc-mutex.rs.)
 
     // Create a vector of string objects
     let shared_vec = Rc::new(vec![
@@ -357,13 +371,13 @@ fn main() {
         Rc::strong_count(&shared_vec)
     );
 
-    let consumer1 = Rc::clone(&shared_vec);
+    let _consumer1 = Rc::clone(&shared_vec); // Kept alive (unlike `_`) so it's counted
     println!(
         "Strong count after consumer1 clone: {}",
         Rc::strong_count(&shared_vec)
     );
 
-    let consumer2 = Rc::clone(&shared_vec);
+    let _consumer2 = Rc::clone(&shared_vec);
     println!(
         "Strong count after consumer2 clone: {}",
         Rc::strong_count(&shared_vec)
@@ -496,6 +510,8 @@ fn main() {
     // RefCell is only allowed in single-threaded environments:
     // Like `Rc`, `RefCell` is not thread-safe. For multi-threaded interior mutability,
     // you would use `Mutex<T>` or `RwLock<T>`.
+    // (Choosing between `Cell`, `RefCell`, `Mutex` and `RwLock`, and how each one
+    // fails when misused: see 58-interior-mutability-guide.rs.)
 
     println!("\n--- 6.2 RefCell<T> ---");
 
@@ -505,6 +521,7 @@ fn main() {
     // Get an immutable reference to the vector
     let borrowed_immut = my_vec.borrow();
     println!("Immutably borrowed vector: {:?}", *borrowed_immut);
+    drop(borrowed_immut); // The shared borrow must end before `borrow_mut`, or it panics
 
     // Get a mutable reference to the vector
     let mut borrowed_mut = my_vec.borrow_mut();
@@ -531,9 +548,10 @@ fn main() {
     println!(
         "Attempting to get a mutable borrow while an immutable one is active would cause a runtime panic. (Example commented out)"
     );
+    drop(r1); // End the immutable borrow, so the `borrow_mut` below succeeds
 
     // Get a mutable reference to the vector
-    let mut_vec_ref = data_vec.borrow_mut();
+    let mut mut_vec_ref = data_vec.borrow_mut();
     println!("Mutably borrowed: {:?}", *mut_vec_ref);
 
     // Push a new value to the vector
@@ -572,7 +590,307 @@ fn main() {
     );
 
     // -------------------------------------------------------------------------
-    // 8. Learning Pointers in Rust is a Must!
+    // 8. Observing Drops: A Drop Tracker
+    // -------------------------------------------------------------------------
+    // Comments like "the value is dropped here" are claims we can check.
+    // A `DropTracker` hands out `Tracked` values that write their tag into a
+    // shared log when they are dropped, so the drop order can be asserted.
+    // The log itself is an `Rc<RefCell<Vec<_>>>`, the pattern from section 7:
+    // every `Tracked` holds a clone of the tracker's `Rc` and pushes its tag
+    // in `Drop`. The tracker lives in lessons/testing.rs so other lessons can
+    // assert drop order the same way. `cargo run --bin 13-pointers` runs
+    // these asserts.
+
+    println!("\n--- 8. Observing Drops: A Drop Tracker ---");
+
+    let tracker = DropTracker::new();
+
+    // a. Scope: locals are dropped at the end of their block, in reverse declaration order.
+    {
+        let _first = tracker.track("first");
+        let _second = tracker.track("second");
+        assert!(tracker.dropped().is_empty()); // Nothing dropped inside the block
+    }
+    assert_eq!(tracker.dropped(), ["second", "first"]);
+    println!("Scope drop order: {:?}", tracker.dropped());
+
+    // b. Box<T>: dropping the Box drops the value it owns on the heap.
+    let boxed = Box::new(tracker.track("boxed"));
+    println!("Box holds '{}'", boxed.tag());
+    drop(boxed);
+    assert_eq!(tracker.dropped().last(), Some(&"boxed"));
+
+    // c. Rc<T>: the value is dropped only when the last strong reference goes away.
+    let shared = Rc::new(tracker.track("shared"));
+    let shared_clone = Rc::clone(&shared);
+    drop(shared);
+    assert_eq!(tracker.dropped().last(), Some(&"boxed")); // Still alive: one clone remains
+    drop(shared_clone);
+    assert_eq!(tracker.dropped().last(), Some(&"shared")); // Strong count hit zero
+    assert_eq!(tracker.dropped(), ["second", "first", "boxed", "shared"]);
+    println!("Full drop log: {:?}", tracker.dropped());
+
+    // -------------------------------------------------------------------------
+    // 9. A Doubly Linked List: `Rc<RefCell<T>>` Forward, `Weak` Back
+    // -------------------------------------------------------------------------
+    // Each node is shared: the previous node points at it *and* it points back.
+    // If both directions were `Rc`, every pair of neighbours would be a
+    // reference cycle and no node would ever be freed. So ownership flows one
+    // way: `next` (and the list's `head`/`tail`) are strong `Rc`s, `prev` is a
+    // `Weak` that doesn't keep its target alive. `RefCell` lets us relink
+    // nodes through those shared pointers.
+
+    println!("\n--- 9. A Doubly Linked List ---");
+
+    type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+
+    struct Node<T> {
+        value: T,
+        next: Link<T>,
+        prev: Option<Weak<RefCell<Node<T>>>>,
+    }
+
+    struct DoublyLinkedList<T> {
+        head: Link<T>,
+        tail: Link<T>,
+        len: usize,
+    }
+
+    impl<T> DoublyLinkedList<T> {
+        fn new() -> Self {
+            DoublyLinkedList {
+                head: None,
+                tail: None,
+                len: 0,
+            }
+        }
+
+        fn new_node(value: T) -> Rc<RefCell<Node<T>>> {
+            Rc::new(RefCell::new(Node {
+                value,
+                next: None,
+                prev: None,
+            }))
+        }
+
+        fn push_back(&mut self, value: T) {
+            let node = Self::new_node(value);
+            match self.tail.take() {
+                Some(old_tail) => {
+                    node.borrow_mut().prev = Some(Rc::downgrade(&old_tail)); // Weak: back
+                    old_tail.borrow_mut().next = Some(Rc::clone(&node)); // Strong: forward
+                }
+                None => self.head = Some(Rc::clone(&node)),
+            }
+            self.tail = Some(node);
+            self.len += 1;
+        }
+
+        fn push_front(&mut self, value: T) {
+            let node = Self::new_node(value);
+            match self.head.take() {
+                Some(old_head) => {
+                    old_head.borrow_mut().prev = Some(Rc::downgrade(&node));
+                    node.borrow_mut().next = Some(old_head);
+                }
+                None => self.tail = Some(Rc::clone(&node)),
+            }
+            self.head = Some(node);
+            self.len += 1;
+        }
+
+        fn pop_front(&mut self) -> Option<T> {
+            self.head.take().map(|old_head| {
+                match old_head.borrow_mut().next.take() {
+                    Some(new_head) => {
+                        new_head.borrow_mut().prev = None;
+                        self.head = Some(new_head);
+                    }
+                    None => self.tail = None, // It was the only node
+                }
+                self.len -= 1;
+                Self::into_value(old_head)
+            })
+        }
+
+        fn pop_back(&mut self) -> Option<T> {
+            self.tail.take().map(|old_tail| {
+                let prev = old_tail.borrow_mut().prev.take();
+                match prev.and_then(|weak| weak.upgrade()) {
+                    Some(new_tail) => {
+                        new_tail.borrow_mut().next = None; // Releases the other strong ref
+                        self.tail = Some(new_tail);
+                    }
+                    None => self.head = None,
+                }
+                self.len -= 1;
+                Self::into_value(old_tail)
+            })
+        }
+
+        // A node that has been unlinked has no other strong owner, so the
+        // value can be moved out of the `Rc<RefCell<_>>`.
+        fn into_value(node: Rc<RefCell<Node<T>>>) -> T {
+            match Rc::try_unwrap(node) {
+                Ok(cell) => cell.into_inner().value,
+                Err(_) => panic!("an unlinked node is still shared"),
+            }
+        }
+
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        // A `&T` can't outlive the `RefCell` borrow it came from, so these
+        // iterators hand out clones of the values instead of references.
+        // They still borrow the list: an iterator holds a strong pointer to
+        // its next node, which would make `into_value` panic if that node
+        // were popped while the iterator is alive.
+        fn iter(&self) -> Iter<'_, T> {
+            Iter {
+                next: self.head.clone(),
+                _list: PhantomData,
+            }
+        }
+
+        fn iter_rev(&self) -> IterRev<'_, T> {
+            IterRev {
+                next: self.tail.clone(),
+                _list: PhantomData,
+            }
+        }
+    }
+
+    // The default drop would be recursive (each node drops the next), so a
+    // long list could overflow the stack. Popping one node at a time can't.
+    impl<T> Drop for DoublyLinkedList<T> {
+        fn drop(&mut self) {
+            while self.pop_front().is_some() {}
+        }
+    }
+
+    struct Iter<'a, T> {
+        next: Link<T>,
+        _list: PhantomData<&'a DoublyLinkedList<T>>,
+    }
+
+    impl<T: Clone> Iterator for Iter<'_, T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            self.next.take().map(|node| {
+                let node = node.borrow();
+                self.next = node.next.clone();
+                node.value.clone()
+            })
+        }
+    }
+
+    // Walks backwards through the `Weak` links: each step must `upgrade`.
+    struct IterRev<'a, T> {
+        next: Link<T>,
+        _list: PhantomData<&'a DoublyLinkedList<T>>,
+    }
+
+    impl<T: Clone> Iterator for IterRev<'_, T> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<T> {
+            self.next.take().map(|node| {
+                let node = node.borrow();
+                self.next = node.prev.as_ref().and_then(Weak::upgrade);
+                node.value.clone()
+            })
+        }
+    }
+
+    let mut list = DoublyLinkedList::new();
+    list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    println!("Forward: {:?}", list.iter().collect::<Vec<_>>());
+    println!("Backward: {:?}", list.iter_rev().collect::<Vec<_>>());
+    assert_eq!(list.iter().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(list.iter_rev().collect::<Vec<_>>(), [3, 2, 1]);
+    assert_eq!(list.len(), 3);
+
+    // The middle node: one strong owner (node 1's `next`), one weak (node 3's `prev`).
+    let middle = list.head.as_ref().unwrap().borrow().next.clone().unwrap();
+    assert_eq!(Rc::strong_count(&middle), 2); // `next` + our `middle` clone
+    assert_eq!(Rc::weak_count(&middle), 1);
+    let middle_weak = Rc::downgrade(&middle);
+    drop(middle);
+
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.iter_rev().collect::<Vec<_>>(), [2]);
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(
+        (list.pop_front(), list.pop_back(), list.len()),
+        (None, None, 0)
+    );
+    assert!(middle_weak.upgrade().is_none()); // The node was freed, not leaked
+
+    // Popping has to wait until the iterator is gone:
+    /*
+    let mut it = list.iter();
+    list.pop_front();
+    // error[E0502]: cannot borrow `list` as mutable because it is also borrowed as immutable
+    it.next();
+    */
+    list.push_back(4);
+    list.push_back(5);
+    let mut it = list.iter();
+    assert_eq!(it.next(), Some(4)); // `it` now holds a strong pointer to node 5
+    drop(it);
+    assert_eq!(list.pop_back(), Some(5)); // Node 5 is unshared again
+    assert_eq!(list.pop_front(), Some(4));
+
+    // Leak check with the drop tracker from section 8: every value is dropped
+    // exactly once when the list goes away, front to back.
+    let tracker = DropTracker::new();
+    {
+        let mut tracked_list = DoublyLinkedList::new();
+        tracked_list.push_back(tracker.track("node b"));
+        tracked_list.push_front(tracker.track("node a"));
+        tracked_list.push_back(tracker.track("node c"));
+        assert!(tracker.dropped().is_empty());
+    }
+    assert_eq!(tracker.dropped(), ["node a", "node b", "node c"]);
+
+    // A long list is dropped without recursion.
+    let mut long = DoublyLinkedList::new();
+    for i in 0..200_000 {
+        long.push_back(i);
+    }
+    assert_eq!(long.iter_rev().next(), Some(199_999));
+    drop(long);
+
+    // What `Weak` prevents: two nodes holding *strong* pointers to each other.
+    // Dropping our handles leaves each count at 1, so neither is ever freed.
+    struct StrongNode {
+        _tracked: Tracked,
+        other: RefCell<Option<Rc<StrongNode>>>,
+    }
+
+    let a = Rc::new(StrongNode {
+        _tracked: tracker.track("cycle a"),
+        other: RefCell::new(None),
+    });
+    let b = Rc::new(StrongNode {
+        _tracked: tracker.track("cycle b"),
+        other: RefCell::new(Some(Rc::clone(&a))),
+    });
+    *a.other.borrow_mut() = Some(Rc::clone(&b)); // a -> b -> a
+    let a_weak = Rc::downgrade(&a);
+    drop(a);
+    drop(b);
+    assert!(a_weak.upgrade().is_some()); // Still alive: leaked
+    assert!(!tracker.dropped().contains(&"cycle a"));
+    println!("Leaked by the cycle: 'cycle a' and 'cycle b' were never dropped");
+
+    // -------------------------------------------------------------------------
+    // 10. Learning Pointers in Rust is a Must!
     // -------------------------------------------------------------------------
     // While Rust aims to minimize the direct use of raw pointers, understanding
     // how references, smart pointers, and their underlying memory models work
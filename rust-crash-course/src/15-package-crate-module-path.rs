@@ -158,7 +158,7 @@ fn main() {
     println!("Value after subtract via public method: {}", calc.value);
 
     // This would be a compile-time error because `subtract` is private:
-    // calc.subtract(1.0); // error[E0616]: method `subtract` is private
+    // calc.subtract(1.0); // error[E0624]: method `subtract` is private
 
     // -------------------------------------------------------------------------
     // 4. Paths: Referring to Items in the Module Tree
@@ -41,217 +41,11 @@ fn main() {
 */
 
 // To run async code, you need an asynchronous runtime.
-// The most popular one in Rust is `tokio`. Add this to your `Cargo.toml`:
-// [dependencies]
-// tokio = { version = "1", features = ["full"] } // "full" for convenience, narrow down features for production
-
-#[tokio::main] // This macro transforms `main` into an async function and sets up the tokio runtime
-async fn main() {
-    println!("--- Rust Asynchronous Programming: Non-Blocking Concurrency ---");
-
-    // -------------------------------------------------------------------------
-    // 1. What is Asynchronous Programming? Futures and Non-Blocking I/O
-    // -------------------------------------------------------------------------
-    // Asynchronous programming in Rust is built around the concept of `Futures`.
-    // A `Future` is a trait that represents an asynchronous computation that
-    // may complete at some point in the future. It's similar to a "promise"
-    // in JavaScript or a "Task" in C#.
-    //
-    // When you call an `async` function, it doesn't immediately execute its
-    // entire body. Instead, it returns a `Future`. This `Future` can then be
-    // "polled" by an asynchronous runtime (like Tokio, async-std, etc.) to
-    // check its progress. The runtime manages the execution of multiple futures
-    // concurrently on a limited number of threads.
-
-    println!("\n--- 1. What is Asynchronous Programming? Futures & Non-Blocking I/O ---");
-    println!(
-        "`async` functions return `Future`s, which represent a value that will be available later."
-    );
-    println!("An async runtime executes and polls these futures.");
-
-    // -------------------------------------------------------------------------
-    // 2. The `async`/`await` Keywords: Syntactic Sugar for Futures
-    // -------------------------------------------------------------------------
-    // The `async` and `await` keywords provide ergonomic syntax for writing
-    // asynchronous code, making it look and feel more like synchronous code.
-    //
-    // - `async fn`: Marks a function as asynchronous. It returns a `Future`.
-    //   The code inside an `async fn` can contain `await` expressions.
-    // - `.await`: Pauses the execution of the current `async` function until
-    //   the `Future` it's `await`ing completes. While paused, the runtime can
-    //   switch to execute other pending futures.
-
-    println!("\n--- 2. The `async`/`await` Keywords ---");
-
-    // An `async` function. Notice the `async` keyword before `fn`.
-    async fn fetch_data_async(id: u32) -> String {
-        println!("[Task {}] Fetching data asynchronously...", id);
-        // Simulate a non-blocking I/O operation (e.g., network request)
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await; // `.await` here!
-        println!("[Task {}] Data fetched asynchronously!", id);
-        format!("Asynchronous Data from Task {}", id)
-    }
-
-    // Call the async function. It returns a Future.
-    let future1 = fetch_data_async(1);
-    let future2 = fetch_data_async(2);
-
-    println!("Futures created, but not yet executed.");
-    println!("This line runs immediately after `Workspace_data_async` returns Futures.");
-
-    // `await` the futures to get their results.
-    // The `await!` points tell the runtime where it can switch tasks.
-    let data1 = future1.await; // Program pauses here, allowing other futures to run
-    let data2 = future2.await; // Program pauses here, allowing other futures to run
-
-    println!("Result from Task 1: {}", data1);
-    println!("Result from Task 2: {}", data2);
-    println!("All asynchronous operations finished.");
-
-    // Notice how "[Task 1] Fetching..." and "[Task 2] Fetching..." print
-    // almost simultaneously, then after a 2-second delay, both "Data fetched!"
-    // messages appear, demonstrating concurrent execution.
-
-    // -------------------------------------------------------------------------
-    // 3. Spawning Tasks: Running Futures Concurrently
-    // -------------------------------------------------------------------------
-    // To run multiple `Future`s truly concurrently (in parallel if multiple
-    // CPU cores are available, or interleaved if on a single core), you need
-    // to "spawn" them onto the async runtime. The `tokio::spawn` function
-    // takes a `Future` and schedules it for execution. It returns a `JoinHandle`.
-
-    println!("\n--- 3. Spawning Tasks: Running Futures Concurrently ---");
-
-    async fn background_task(name: &str, delay_secs: u64) -> String {
-        println!("[{}] Starting...", name);
-        tokio::time::sleep(tokio::time::Duration::from_secs(delay_secs)).await;
-        println!("[{}] Finished!", name);
-        format!("Result from {}", name)
-    }
-
-    let handle1 = tokio::spawn(background_task("Task A", 3)); // Spawn a task
-    let handle2 = tokio::spawn(background_task("Task B", 1)); // Spawn another task
-    let handle3 = tokio::spawn(background_task("Task C", 2)); // Spawn a third task
-
-    println!("Main function continues while tasks are running in background.");
-
-    // `await`ing the `JoinHandle` blocks the current async function until
-    // the spawned task completes.
-    let result_a = handle1.await.expect("Task A failed");
-    let result_b = handle2.await.expect("Task B failed");
-    let result_c = handle3.await.expect("Task C failed");
-
-    println!(
-        "Collected results: {}, {}, {}",
-        result_a, result_b, result_c
-    );
-    println!("All spawned tasks completed.");
-
-    // Observe the output: "Task B Finished!" will likely appear before "Task A Finished!"
-    // even though Task A was spawned first, because Task B has a shorter delay.
-    // This highlights the non-blocking, concurrent nature.
-
-    // -------------------------------------------------------------------------
-    // 4. Asynchronous I/O Operations
-    // -------------------------------------------------------------------------
-    // The power of async programming comes from its use with I/O-bound operations.
-    // Asynchronous runtimes provide their own versions of I/O primitives that
-    // are non-blocking. For example, `tokio::fs` for file operations, `tokio::net`
-    // for network operations, `tokio::io` for general I/O traits.
-
-    println!("\n--- 4. Asynchronous I/O Operations ---");
-
-    use tokio::fs; // For asynchronous file operations
-    use tokio::io::{self, AsyncReadExt, AsyncWriteExt}; // For async I/O traits
-
-    let file_path = "async_example.txt";
-    let content = "Hello from async Rust!";
-
-    async fn write_and_read_file(path: &str, data: &str) -> io::Result<String> {
-        println!("Writing to file: {}", path);
-        let mut file = fs::File::create(path).await?; // Create file asynchronously
-        file.write_all(data.as_bytes()).await?; // Write asynchronously
-        println!("Finished writing to file.");
-
-        println!("Reading from file: {}", path);
-        let mut file = fs::File::open(path).await?; // Open file asynchronously
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer).await?; // Read asynchronously
-        println!("Finished reading from file.");
-
-        Ok(String::from_utf8_lossy(&buffer).into_owned())
-    }
-
-    // Spawn the file operation as a task
-    let file_handle = tokio::spawn(write_and_read_file(file_path, content));
-
-    // Do other work while file I/O is happening
-    println!("Performing other tasks while file I/O is in progress...");
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    println!("Other tasks continue...");
-
-    // Await the file operation result
-    match file_handle.await {
-        Ok(Ok(read_content)) => println!("Content read from file: '{}'", read_content),
-        Ok(Err(e)) => eprintln!("File operation error: {}", e),
-        Err(e) => eprintln!("Task join error: {}", e), // Error from `tokio::spawn` itself
-    }
-
-    // Clean up the created file (synchronously for simplicity here)
-    if let Err(e) = std::fs::remove_file(file_path) {
-        eprintln!("Failed to clean up file {}: {}", file_path, e);
-    }
-
-    // -------------------------------------------------------------------------
-    // 5. Channels for Async Communication (Brief Mention)
-    // -------------------------------------------------------------------------
-    // When you have multiple async tasks, you often need them to communicate.
-    // Asynchronous channels (e.g., `tokio::sync::mpsc` for multi-producer, single-consumer)
-    // are used for safe, non-blocking communication between tasks.
-
-    println!("\n--- 5. Channels for Async Communication ---");
-
-    use tokio::sync::mpsc;
-
-    async fn producer(sender: mpsc::Sender<String>) {
-        for i in 0..3 {
-            let msg = format!("Message {}", i);
-            println!("[Producer] Sending: {}", msg);
-            sender.send(msg).await.expect("Failed to send message"); // Non-blocking send
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-        }
-    }
-
-    async fn consumer(mut receiver: mpsc::Receiver<String>) {
-        while let Some(msg) = receiver.recv().await {
-            // Non-blocking receive
-            println!("[Consumer] Received: {}", msg);
-        }
-        println!("[Consumer] Channel closed.");
-    }
-
-    let (tx, rx) = mpsc::channel(10); // Create an async channel with a buffer of 10
-    tokio::spawn(producer(tx));
-    tokio::spawn(consumer(rx))
-        .await
-        .expect("Consumer task failed"); // Await consumer to finish
-
-    println!("\n--- End of Asynchronous Programming Examples ---");
-}
-
-///////////////////////////////////////////////////////////////////////////////////////////////////////////////
-///////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
-// This file covers fundamental Rust Asynchronous Programming concepts, explaining
-// why it's necessary for concurrent operations, how it differs from traditional
-// multi-threading, and how to use async/await for non-blocking I/O.
-// Rust supports async/await like many other modern languages, providing a
-// powerful way to write concurrent code without the overhead of traditional threads.
-
-// To run async code, you need an asynchronous runtime.
-// The most popular one in Rust is `tokio`. Add this to your `Cargo.toml`:
+// The most popular one in Rust is `tokio`. In your own project, add it to `Cargo.toml`:
 // [dependencies]
 // tokio = { version = "1", features = ["full"] } // "full" for convenience, narrow down features for production
+// Here it's an optional dependency behind the `ecosystem` feature:
+//     cargo run --features ecosystem --bin 16-asynchronous
 
 // Import required headers for async operations
 use std::future::Future;
@@ -259,6 +53,23 @@ use tokio::fs; // For asynchronous file operations
 use tokio::io::{self, AsyncReadExt, AsyncWriteExt}; // For async I/O traits
 use tokio::sync::mpsc; // For asynchronous channels // Required for `impl Future` examples
 
+#[path = "lessons/async_compat.rs"]
+mod async_compat;
+#[path = "lessons/async_guard.rs"]
+mod async_guard;
+#[path = "lessons/async_iter.rs"]
+mod async_iter;
+#[path = "lessons/error.rs"]
+mod error;
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use async_compat::run_blocking;
+use async_guard::CleanupGuard;
+use async_iter::map_concurrent;
+use error::MultiError;
+use fmt::human_duration;
+
 #[tokio::main] // This macro transforms `main` into an async function and sets up the tokio runtime
 async fn main() {
     println!("--- Rust Asynchronous Programming: Non-Blocking Concurrency ---");
@@ -357,9 +168,18 @@ async fn main() {
     // CPU cores are available, or interleaved if on a single core), you need
     // to "spawn" them onto the async runtime. The `tokio::spawn` function
     // takes a `Future` and schedules it for execution. It returns a `JoinHandle`.
+    //
+    // When you spawn a *group* of tasks ("fan-out") and want all their results
+    // ("fan-in"), `tokio::task::JoinSet` owns the handles for you. Its
+    // `join_next()` returns whichever task finishes next, so results are
+    // collected as they complete instead of awaiting handles one by one.
+    // (`join!`, `try_join!`, and `JoinSet` panics and aborts: see
+    // 62-join-and-joinset.rs.)
 
     println!("\n--- 3. Spawning Tasks: Running Futures Concurrently ---");
 
+    use tokio::task::JoinSet;
+
     async fn background_task(name: &str, delay_secs: u64) -> String {
         println!("[{}] Starting...", name);
         tokio::time::sleep(tokio::time::Duration::from_secs(delay_secs)).await;
@@ -367,27 +187,104 @@ async fn main() {
         format!("Result from {}", name)
     }
 
-    let handle1 = tokio::spawn(background_task("Task A", 3)); // Spawn a task
-    let handle2 = tokio::spawn(background_task("Task B", 1)); // Spawn another task
-    let handle3 = tokio::spawn(background_task("Task C", 2)); // Spawn a third task
+    let mut task_set = JoinSet::new();
+    task_set.spawn(background_task("Task A", 3)); // Fan out: spawn each task into the set
+    task_set.spawn(background_task("Task B", 1));
+    task_set.spawn(background_task("Task C", 2));
 
     println!("Main function continues while tasks are running in background.");
 
-    // `await`ing the `JoinHandle` blocks the current async function until
-    // the spawned task completes.
-    let result_a = handle1.await.expect("Task A failed");
-    let result_b = handle2.await.expect("Task B failed");
-    let result_c = handle3.await.expect("Task C failed");
+    // Fan in: `join_next()` returns `None` once every task has been collected.
+    let mut collected_results = Vec::new();
+    while let Some(joined) = task_set.join_next().await {
+        let result = joined.expect("Task panicked or was cancelled"); // `Err` is a `JoinError`
+        println!("Collected: {}", result);
+        collected_results.push(result);
+    }
+    println!("Completion order: {:?}", collected_results); // B, C, A
+    println!("All spawned tasks completed.");
 
-    println!(
-        "Collected results: {}, {}, {}",
-        result_a, result_b, result_c
+    // Observe the output: "Task B" is collected before "Task A" even though
+    // Task A was spawned first, because Task B has a shorter delay.
+    // The *set* of results doesn't depend on that order, though:
+    collected_results.sort();
+    assert_eq!(
+        collected_results,
+        [
+            "Result from Task A",
+            "Result from Task B",
+            "Result from Task C"
+        ]
     );
-    println!("All spawned tasks completed.");
 
-    // Observe the output: "Task B Finished!" will likely appear before "Task A Finished!"
-    // even though Task A was spawned first, because Task B has a shorter delay.
-    // This highlights the non-blocking, concurrent nature.
+    // 3.1 Aggregating Partial Failures (`MultiError`)
+    // With `?`, the first failed task would hide all the others. For fan-out
+    // work it's often better to keep every success and report every failure
+    // together in one error value: `MultiError`, from `lessons/error.rs`
+    // (included at the top of this file).
+
+    println!("\n--- 3.1 Aggregating Partial Failures ---");
+
+    async fn check_service(
+        name: &'static str,
+        delay_ms: u64,
+        healthy: bool,
+    ) -> Result<String, String> {
+        tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+        if healthy {
+            Ok(format!("{} is up", name))
+        } else {
+            Err(format!("{} is down", name))
+        }
+    }
+
+    async fn check_all(
+        services: &[(&'static str, u64, bool)],
+    ) -> (Vec<String>, Result<(), MultiError>) {
+        let mut set = JoinSet::new();
+        for &(name, delay_ms, healthy) in services {
+            set.spawn(check_service(name, delay_ms, healthy));
+        }
+
+        let mut successes = Vec::new();
+        let mut errors = MultiError::new();
+        while let Some(joined) = set.join_next().await {
+            match joined {
+                Ok(Ok(status)) => successes.push(status),
+                Ok(Err(error)) => errors.push(error),
+                // The task itself panicked or was cancelled
+                Err(join_error) => errors.push(format!("task did not complete: {}", join_error)),
+            }
+        }
+
+        // Sort so the report is the same no matter which task finished first.
+        successes.sort();
+        (successes, errors.sorted().into_result())
+    }
+
+    let (healthy, outcome) = check_all(&[
+        ("db", 300, true),
+        ("cache", 100, false),
+        ("queue", 200, false),
+    ])
+    .await;
+    println!("Healthy services: {:?}", healthy);
+    if let Err(multi_error) = &outcome {
+        println!("{}", multi_error);
+    }
+
+    // Same services with the opposite timings give an identical report.
+    let (healthy_reversed, outcome_reversed) = check_all(&[
+        ("db", 100, true),
+        ("cache", 300, false),
+        ("queue", 200, false),
+    ])
+    .await;
+    assert_eq!(healthy, ["db is up"]);
+    assert_eq!(healthy, healthy_reversed);
+    let errors = outcome.expect_err("two services are down");
+    assert_eq!(errors.errors(), ["cache is down", "queue is down"]);
+    assert_eq!(errors, outcome_reversed.expect_err("two services are down"));
 
     // -------------------------------------------------------------------------
     // 4. Asynchronous Functions Don't *Have* to Use `async fn`
@@ -400,6 +297,7 @@ async fn main() {
     println!("\n--- 4. Asynchronous Functions Don't *Have* to Use `async fn` ---");
 
     // Change function signature: This function returns an `impl Future` directly.
+    #[allow(clippy::manual_async_fn)] // Spelled out on purpose: this is what `async fn` means
     fn manual_async_function(value: u32) -> impl Future<Output = String> {
         // Add function code into async block
         async move {
@@ -548,6 +446,323 @@ async fn main() {
         .await
         .expect("Consumer task failed"); // Await consumer to finish
 
+    // -------------------------------------------------------------------------
+    // 8. Backpressure with Bounded Channels
+    // -------------------------------------------------------------------------
+    // `mpsc::channel(capacity)` is *bounded*: once `capacity` messages are
+    // queued, `send().await` waits until the consumer makes room. This is
+    // backpressure: a fast producer is slowed down to the pace of a slow
+    // consumer instead of growing the queue (and memory use) without limit.
+    // `try_send` never waits; it returns `TrySendError::Full` immediately so
+    // the producer can drop, retry, or report the message instead.
+
+    println!("\n--- 8. Backpressure with Bounded Channels ---");
+
+    use tokio::sync::mpsc::error::TrySendError;
+    use tokio::time::{Duration, Instant};
+
+    // a. `try_send`: fail fast when the buffer is full
+    let (fast_tx, mut fast_rx) = mpsc::channel::<u32>(2); // Room for only 2 messages
+    fast_tx.try_send(1).expect("buffer has room");
+    fast_tx.try_send(2).expect("buffer has room");
+    match fast_tx.try_send(3) {
+        Err(TrySendError::Full(rejected)) => {
+            println!("try_send({}) rejected: channel is full", rejected)
+        }
+        Err(TrySendError::Closed(_)) => println!("try_send failed: channel is closed"),
+        Ok(()) => println!("try_send(3) accepted"),
+    }
+    assert!(matches!(fast_tx.try_send(3), Err(TrySendError::Full(3))));
+    while fast_rx.try_recv().is_ok() {} // Drain the buffer
+
+    // b. `send().await`: the producer is paused while the buffer is full.
+    // This part runs with time *paused*, as in 60-select-and-cancellation.rs:
+    // sleeps complete instantly in virtual time, so the asserts can check
+    // exact durations. A paused clock needs a current-thread runtime, so the
+    // demo gets its own, started on a blocking thread (section 10).
+    fn sparkline(samples: &[usize], max: usize) -> String {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        samples
+            .iter()
+            .map(|&sample| BARS[sample.min(max) * (BARS.len() - 1) / max.max(1)])
+            .collect()
+    }
+
+    const CAPACITY: usize = 4;
+
+    // Returns the producer's total wait, the whole run's duration, and the
+    // queue depth sampled every 100ms.
+    async fn backpressure_demo() -> (Duration, Duration, Vec<usize>) {
+        let start = Instant::now();
+        let (slow_tx, mut slow_rx) = mpsc::channel::<u32>(CAPACITY);
+        let depth_probe = slow_tx.clone(); // Extra sender used only to read the queue depth
+
+        let producer_handle = tokio::spawn(async move {
+            let mut waited = Duration::ZERO;
+            for i in 0..10 {
+                let started = Instant::now();
+                slow_tx.send(i).await.expect("Consumer dropped"); // Waits while the buffer is full
+                waited += started.elapsed();
+            }
+            waited // Total time the producer spent blocked by backpressure
+        });
+
+        let consumer_handle = tokio::spawn(async move {
+            while let Some(i) = slow_rx.recv().await {
+                tokio::time::sleep(Duration::from_millis(100)).await; // A deliberately slow consumer
+                println!("[Slow Consumer] Processed {}", i);
+            }
+        });
+
+        // Sample the queue depth (messages currently buffered) halfway between
+        // the consumer's steps, until the producer is done *and* the consumer
+        // has drained the buffer.
+        let mut depth_samples = Vec::new();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        loop {
+            let depth = depth_probe.max_capacity() - depth_probe.capacity();
+            depth_samples.push(depth);
+            if producer_handle.is_finished() && depth == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        drop(depth_probe); // Last sender gone: the consumer's loop ends after its current message
+
+        let producer_waited = producer_handle.await.expect("Producer task failed");
+        consumer_handle.await.expect("Consumer task failed");
+        (producer_waited, start.elapsed(), depth_samples)
+    }
+
+    let (producer_waited, total, depth_samples) = tokio::task::spawn_blocking(|| {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .expect("Failed to build a paused runtime")
+            .block_on(backpressure_demo())
+    })
+    .await
+    .expect("Backpressure demo panicked");
+
+    println!(
+        "Producer spent {} waiting for buffer space; the run took {}.",
+        human_duration(producer_waited),
+        human_duration(total)
+    );
+    println!(
+        "Queue depth over time: {}",
+        sparkline(&depth_samples, CAPACITY)
+    );
+
+    // 10 messages, 4 slots, 100ms per message. The first 5 go out at once (4
+    // buffered, 1 taken by the consumer); each later one waits for the
+    // consumer to take the next message, 100ms apart, so the producer is
+    // done at 500ms after waiting 500ms in total. The buffer stays full
+    // until then, and drains one slot per 100ms afterwards.
+    assert_eq!(producer_waited, Duration::from_millis(500));
+    assert_eq!(total, Duration::from_millis(1000)); // 10 messages x 100ms
+    assert_eq!(depth_samples, [4, 4, 4, 4, 4, 4, 3, 2, 1, 0]);
+
+    // -------------------------------------------------------------------------
+    // 9. Cancellation-Safe Cleanup with Drop Guards
+    // -------------------------------------------------------------------------
+    // A task can be cancelled at any `.await`: `JoinHandle::abort()`, a lost
+    // `select!` branch, or a timeout all simply *drop* the future. Code after
+    // that `.await` never runs, so "cleanup at the end of the function" is
+    // skipped. Dropping the future still drops its local variables, though,
+    // so cleanup placed in a `Drop` impl (an RAII guard) always runs.
+    // Semaphore permits already work this way: the permit is released when dropped.
+    // (`select!`, `CancellationToken`, and cancellation-unsafe code: see
+    // 60-select-and-cancellation.rs.)
+
+    println!("\n--- 9. Cancellation-Safe Cleanup with Drop Guards ---");
+
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tokio::sync::Semaphore;
+
+    // `CleanupGuard` (from `lessons/async_guard.rs`, included at the top of
+    // this file) removes a temporary file in its `Drop` impl.
+    let semaphore = Arc::new(Semaphore::new(1));
+    let cleaned_up = Arc::new(AtomicBool::new(false));
+    let temp_path = std::env::temp_dir().join("async_guard_example.tmp");
+
+    let guarded_task = tokio::spawn({
+        let semaphore = Arc::clone(&semaphore);
+        let cleaned_up = Arc::clone(&cleaned_up);
+        let temp_path = temp_path.clone();
+        async move {
+            let _permit = semaphore.acquire_owned().await.expect("Semaphore closed");
+            std::fs::write(&temp_path, "work in progress").expect("Failed to write temp file");
+            let _guard = CleanupGuard::new(temp_path, cleaned_up);
+            println!("[Guarded Task] Holding a permit and a temp file, working...");
+            tokio::time::sleep(Duration::from_secs(10)).await; // Cancelled while waiting here
+            println!("[Guarded Task] Finished."); // Never printed: the task is aborted
+        }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await; // Let the task reach its `.await`
+    assert_eq!(semaphore.available_permits(), 0); // The task holds the only permit
+
+    guarded_task.abort(); // Cancel the task: its future is dropped at the pending `.await`
+    let join_error = guarded_task
+        .await
+        .expect_err("Task should have been cancelled");
+    assert!(join_error.is_cancelled());
+
+    // Both resources were released even though the task never reached its end.
+    assert!(cleaned_up.load(Ordering::SeqCst));
+    assert!(!temp_path.exists());
+    assert_eq!(semaphore.available_permits(), 1);
+    println!("Aborted task released its permit and removed its temp file.");
+
+    // -------------------------------------------------------------------------
+    // 10. Offloading Blocking and CPU-Heavy Work (`spawn_blocking`)
+    // -------------------------------------------------------------------------
+    // The runtime has a small, fixed pool of worker threads and relies on tasks
+    // reaching an `.await` quickly so others get a turn. A task that computes
+    // for a long time (hashing, image rendering, compression) or calls a
+    // blocking API never yields: it holds its worker hostage and every other
+    // task scheduled there is *starved*.
+    //
+    // `tokio::task::spawn_blocking` runs a closure on a separate pool of
+    // threads meant for blocking work, and returns a `JoinHandle` to `.await`.
+    // (The same stall with `std::thread::sleep` on a single-threaded runtime,
+    // and `block_in_place` as the alternative: see 63-blocking-in-async.rs.)
+
+    println!("\n--- 10. Offloading Blocking and CPU-Heavy Work (`spawn_blocking`) ---");
+
+    // Simulates CPU-bound work: spins for `duration` without ever yielding.
+    fn cpu_heavy_checksum(duration: Duration) -> u64 {
+        let started = std::time::Instant::now();
+        let mut checksum: u64 = 0;
+        while started.elapsed() < duration {
+            checksum = checksum.wrapping_mul(6364136223846793005).wrapping_add(1);
+        }
+        checksum
+    }
+
+    // `run_blocking` (from `lessons/async_compat.rs`, included at the top of
+    // this file) wraps `spawn_blocking` and re-raises a panic from the closure.
+
+    // Ticks every 10ms for `duration` and returns the longest gap between ticks.
+    // On a healthy runtime the gap stays close to 10ms.
+    async fn heartbeat(duration: Duration) -> Duration {
+        let deadline = Instant::now() + duration;
+        let mut last_tick = Instant::now();
+        let mut longest_gap = Duration::ZERO;
+        while Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            longest_gap = longest_gap.max(last_tick.elapsed());
+            last_tick = Instant::now();
+        }
+        longest_gap
+    }
+
+    // One CPU-heavy job per worker thread is enough to occupy the whole runtime.
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let work_time = Duration::from_millis(500);
+
+    // a. CPU work directly inside async tasks: the heartbeat cannot run.
+    let heartbeat_handle = tokio::spawn(heartbeat(Duration::from_millis(800)));
+    let mut cpu_tasks = JoinSet::new();
+    for _ in 0..workers {
+        cpu_tasks.spawn(async move { cpu_heavy_checksum(work_time) });
+    }
+    while cpu_tasks.join_next().await.is_some() {}
+    let starved_gap = heartbeat_handle.await.expect("Heartbeat task failed");
+    println!(
+        "Longest heartbeat gap with inline CPU work: {}",
+        human_duration(starved_gap)
+    );
+
+    // b. The same work offloaded with `run_blocking`: the workers stay free.
+    let heartbeat_handle = tokio::spawn(heartbeat(Duration::from_millis(800)));
+    let mut cpu_tasks = JoinSet::new();
+    for _ in 0..workers {
+        cpu_tasks.spawn(run_blocking(move || cpu_heavy_checksum(work_time)));
+    }
+    while cpu_tasks.join_next().await.is_some() {}
+    let healthy_gap = heartbeat_handle.await.expect("Heartbeat task failed");
+    println!(
+        "Longest heartbeat gap with spawn_blocking: {}",
+        human_duration(healthy_gap)
+    );
+
+    // Real time, so the exact gaps vary with the machine and its load; only
+    // their order is certain. (Typically ~500ms starved vs ~10ms healthy.)
+    assert!(healthy_gap < starved_gap);
+
+    // c. `run_blocking` returns the closure's result...
+    let answer = run_blocking(|| 6 * 7).await;
+    assert_eq!(answer, 42);
+
+    // ...and propagates its panic to whoever awaits it. Here that is a spawned
+    // task, so the panic surfaces as a `JoinError` we can inspect.
+    // (The "hash input was empty" panic message printed to stderr is expected.)
+    let panicking = tokio::spawn(run_blocking(|| -> u64 { panic!("hash input was empty") }));
+    let join_error = panicking.await.expect_err("Panic should propagate");
+    assert!(join_error.is_panic());
+    let payload = join_error.into_panic();
+    assert_eq!(
+        payload.downcast_ref::<&str>(),
+        Some(&"hash input was empty")
+    );
+    println!("run_blocking returned {} and propagated the panic.", answer);
+
+    // -------------------------------------------------------------------------
+    // 11. Bounded Parallelism: `map_concurrent`
+    // -------------------------------------------------------------------------
+    // Spawning one task per item is fine for ten items, but not for ten
+    // thousand URLs or ports: you would open every connection at once.
+    // A `Semaphore` with `limit` permits caps how many items are in flight.
+    // Each task holds a permit while it runs and releases it (on drop) when done.
+    // (The `futures` crate offers the same idea as `stream::iter(..).buffer_unordered(limit)`.)
+
+    println!("\n--- 11. Bounded Parallelism: `map_concurrent` ---");
+
+    use std::sync::atomic::AtomicUsize;
+
+    // `map_concurrent` (from `lessons/async_iter.rs`, included at the top of
+    // this file) spawns each call into a `JoinSet` once it gets a permit, and
+    // puts the results back in input order.
+
+    // Count how many calls are running at once and remember the peak (high-water mark).
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let high_water_mark = Arc::new(AtomicUsize::new(0));
+
+    let started = Instant::now();
+    let page_ids: Vec<u32> = (1..=10).collect();
+    let page_sizes = map_concurrent(page_ids, 3, |page_id| {
+        let in_flight = Arc::clone(&in_flight);
+        let high_water_mark = Arc::clone(&high_water_mark);
+        async move {
+            let running = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            high_water_mark.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await; // Simulated request
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            page_id * 100 // Simulated response size
+        }
+    })
+    .await;
+
+    let peak = high_water_mark.load(Ordering::SeqCst);
+    println!("Page sizes: {:?}", page_sizes);
+    println!(
+        "10 requests with limit 3 took {} (peak concurrency: {})",
+        human_duration(started.elapsed()),
+        peak
+    );
+    assert_eq!(
+        page_sizes,
+        (1..=10).map(|id| id * 100).collect::<Vec<u32>>()
+    );
+    // Exactly 3: the ceiling was never exceeded, *and* the calls really
+    // overlapped (a sequential loop would also pass `peak <= 3`, with 1).
+    assert_eq!(peak, 3);
+    assert_eq!(in_flight.load(Ordering::SeqCst), 0); // Every call finished
+
     println!("\n--- End of Asynchronous Programming Examples ---");
     println!(
         "Asynchronous programming is a big topic with many nuances, but these fundamentals provide a strong starting point."
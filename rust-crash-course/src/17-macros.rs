@@ -115,6 +115,8 @@ macro_rules! create_map {
 
 // Example (Conceptual) 4.1: Custom `derive` macro for a `Builder` pattern.
 // This code cannot be directly run here as it requires a separate `proc-macro` crate.
+// For working macros you can build and run, see the `lesson-derive` crate in
+// this workspace and `35-procedural-macros.rs`.
 /*
 // In a `my_builder_macro` crate (type: `proc-macro`):
 use proc_macro::TokenStream;
@@ -0,0 +1,211 @@
+// This file puts lifetimes in structs to real use: a zero-copy parser for a
+// small `key = value` configuration format. The parsed struct holds `&'a str`
+// slices that point straight into the input text, so parsing allocates almost
+// nothing. We compare it with an owned parser and count heap allocations.
+
+// -------------------------------------------------------------------------
+// 0. Counting Allocations
+// -------------------------------------------------------------------------
+// To *measure* the difference between the two parsers, we install a global
+// allocator that forwards to the system allocator and counts every `alloc` call.
+// It lives in `lessons/alloc.rs` so other lessons can reuse it.
+// `#[global_allocator]` must be a top-level `static`, so it lives outside `main`.
+
+#[path = "lessons/alloc.rs"]
+mod alloc;
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use alloc::measure_allocations;
+use fmt::human_bytes;
+
+#[global_allocator]
+static GLOBAL: alloc::CountingAllocator = alloc::CountingAllocator;
+
+fn main() {
+    println!("--- Zero-Copy Parsing with Lifetimes ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Format
+    // -------------------------------------------------------------------------
+    // One `key = value` pair per line. Blank lines and lines starting with `#`
+    // are ignored. Whitespace around keys and values is trimmed.
+
+    println!("\n--- 1. The Format ---");
+
+    let input = "\
+# server settings
+host = localhost
+port = 8080
+
+name = Learning Rust
+";
+    println!("Input:\n{}", input);
+
+    #[derive(Debug, PartialEq)]
+    enum ParseError {
+        MissingEquals { line: usize },
+        EmptyKey { line: usize },
+    }
+
+    // -------------------------------------------------------------------------
+    // 2. The Borrowed (Zero-Copy) Parser
+    // -------------------------------------------------------------------------
+    // `BorrowedConfig<'a>` says: "I contain references that are valid for `'a`,
+    // the lifetime of the input text". Every key and value is a sub-slice of
+    // `input`, found with `split_once` and `trim`, both of which return slices
+    // of the original string instead of new `String`s.
+
+    println!("\n--- 2. The Borrowed (Zero-Copy) Parser ---");
+
+    #[derive(Debug)]
+    struct BorrowedConfig<'a> {
+        entries: Vec<(&'a str, &'a str)>,
+    }
+
+    impl<'a> BorrowedConfig<'a> {
+        // The returned config borrows from `input`, so it can't outlive it.
+        fn parse(input: &'a str) -> Result<BorrowedConfig<'a>, ParseError> {
+            let mut entries = Vec::new();
+            for (index, raw_line) in input.lines().enumerate() {
+                let line = raw_line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let (key, value) = line
+                    .split_once('=')
+                    .ok_or(ParseError::MissingEquals { line: index + 1 })?;
+                let key = key.trim();
+                if key.is_empty() {
+                    return Err(ParseError::EmptyKey { line: index + 1 });
+                }
+                entries.push((key, value.trim()));
+            }
+            Ok(BorrowedConfig { entries })
+        }
+
+        // The returned `&'a str` borrows from the *input*, not from `self`,
+        // so it stays usable even after the config itself is dropped.
+        fn get(&self, key: &str) -> Option<&'a str> {
+            self.entries
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, value)| *value)
+        }
+    }
+
+    let borrowed = BorrowedConfig::parse(input).expect("valid config");
+    println!("Borrowed config: {:?}", borrowed);
+    println!("host = {:?}", borrowed.get("host")); // Some("localhost")
+    assert_eq!(borrowed.get("port"), Some("8080"));
+    assert_eq!(borrowed.get("name"), Some("Learning Rust"));
+    assert_eq!(borrowed.get("missing"), None);
+
+    // Proof that nothing was copied: the value's address lies inside `input`.
+    let host = borrowed.get("host").expect("the input sets host");
+    let input_range = input.as_ptr() as usize..input.as_ptr() as usize + input.len();
+    assert!(input_range.contains(&(host.as_ptr() as usize)));
+    println!("`host` points into the input buffer: no copy was made.");
+
+    // Errors report the offending line.
+    assert_eq!(
+        BorrowedConfig::parse("a = 1\nnot a pair").unwrap_err(),
+        ParseError::MissingEquals { line: 2 }
+    );
+    assert_eq!(
+        BorrowedConfig::parse(" = 1").unwrap_err(),
+        ParseError::EmptyKey { line: 1 }
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. What the Borrow Checker Guarantees
+    // -------------------------------------------------------------------------
+    // Because `BorrowedConfig<'a>` borrows `input`, the compiler will not let
+    // the input be dropped or modified while the config is still in use.
+
+    println!("\n--- 3. What the Borrow Checker Guarantees ---");
+
+    /*
+    let config;
+    {
+        let text = String::from("key = value");
+        config = BorrowedConfig::parse(&text).unwrap();
+    } // `text` is dropped here...
+    println!("{:?}", config.get("key")); // ...error[E0597]: `text` does not live long enough
+    */
+
+    // `get` returns `&'a str`, tied to the input rather than to the config,
+    // so a looked-up value may outlive the `BorrowedConfig` it came from.
+    let port = {
+        let short_lived = BorrowedConfig::parse(input).expect("valid config");
+        short_lived.get("port").expect("the input sets port")
+    }; // `short_lived` is dropped here, but `input` is still alive
+    println!("port (outlived its config): {}", port);
+
+    // -------------------------------------------------------------------------
+    // 4. The Owned Parser
+    // -------------------------------------------------------------------------
+    // The owned version copies every key and value into a fresh `String`.
+    // It has no lifetime parameter and can outlive the input (e.g. be stored
+    // in a long-lived struct or sent to another thread), at the cost of
+    // one or two allocations per entry.
+
+    println!("\n--- 4. The Owned Parser ---");
+
+    #[derive(Debug)]
+    struct OwnedConfig {
+        entries: Vec<(String, String)>,
+    }
+
+    impl OwnedConfig {
+        fn parse(input: &str) -> Result<OwnedConfig, ParseError> {
+            // Reuse the borrowed parser, then copy each slice into an owned `String`.
+            let borrowed = BorrowedConfig::parse(input)?;
+            let entries = borrowed
+                .entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect();
+            Ok(OwnedConfig { entries })
+        }
+    }
+
+    let owned = {
+        let temporary_input = String::from(input);
+        OwnedConfig::parse(&temporary_input).expect("valid config")
+    }; // `temporary_input` is gone, but `owned` doesn't borrow from it
+    println!("Owned config: {:?}", owned);
+    assert_eq!(owned.entries.len(), borrowed.entries.len());
+
+    // -------------------------------------------------------------------------
+    // 5. Measuring Allocations
+    // -------------------------------------------------------------------------
+    // The borrowed parser only allocates for its `Vec` (which grows a few
+    // times). The owned parser additionally allocates two `String`s per entry.
+
+    println!("\n--- 5. Measuring Allocations ---");
+
+    let big_input: String = (0..1000)
+        .map(|i| format!("key{} = value{}\n", i, i))
+        .collect();
+
+    let (borrowed_big, borrowed_allocs) =
+        measure_allocations(|| BorrowedConfig::parse(&big_input).expect("valid config"));
+    let (owned_big, owned_allocs) =
+        measure_allocations(|| OwnedConfig::parse(&big_input).expect("valid config"));
+
+    println!(
+        "Parsing {} entries: borrowed = {} allocations ({}), owned = {} allocations ({})",
+        borrowed_big.entries.len(),
+        borrowed_allocs.count,
+        human_bytes(borrowed_allocs.bytes),
+        owned_allocs.count,
+        human_bytes(owned_allocs.bytes)
+    );
+    assert_eq!(owned_big.entries.len(), 1000);
+    assert!(borrowed_allocs.count < 20); // Only `Vec` growth
+    assert!(owned_allocs.count >= 2000); // At least one `String` per key and per value
+    assert!(owned_allocs.bytes > borrowed_allocs.bytes);
+
+    println!("\n--- End of Zero-Copy Parsing Examples ---");
+}
@@ -0,0 +1,226 @@
+// This file covers self-referential structs: structs where one field borrows
+// from another field of the same struct (e.g. a `String` plus a `&str` slice
+// into it). It explains why safe Rust rejects them, and demonstrates three
+// sound alternatives: indices, an owning-reference wrapper, and `Pin`.
+
+use std::marker::PhantomPinned;
+use std::ops::{Deref, Range};
+use std::pin::Pin;
+
+fn main() {
+    println!("--- Self-Referential Structs: Pitfalls and Workarounds ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Attempt That Doesn't Compile
+    // -------------------------------------------------------------------------
+    // We'd like to read a document once and keep both the text and a slice
+    // pointing at its title (the first line) in one struct:
+
+    /*
+    struct Document<'a> {
+        text: String,
+        title: &'a str, // Wants to borrow from `text`, a field of the same struct
+    }
+
+    fn load(text: String) -> Document<'static> {  // Which lifetime could we write here?
+        let title = text.lines().next().unwrap_or("");  // `title` borrows `text`...
+        Document { text, title }
+        // error[E0515]: cannot return value referencing function parameter `text`
+        // error[E0505]: cannot move out of `text` because it is borrowed
+    }
+    */
+
+    // There is no lifetime to write: `'a` would have to mean "as long as the
+    // struct itself lives", and Rust lifetimes can't name that.
+    // (This and the `Pin` example in section 5 are checked as compile-fail
+    // cases in tests/compile_fail/; see 28-compiler-errors.rs.)
+
+    // -------------------------------------------------------------------------
+    // 2. Why Rust Forbids It: Moves Change Addresses
+    // -------------------------------------------------------------------------
+    // Every move in Rust is a plain memory copy (memcpy) to a new location.
+    // If a struct pointed into *itself* and was then moved, the pointer would
+    // still point at the old location: a dangling reference.
+
+    println!("\n--- 2. Why Rust Forbids It: Moves Change Addresses ---");
+
+    let inline_data = [1u8, 2, 3, 4];
+    let address_before = &inline_data as *const [u8; 4] as usize;
+    let moved_data = inline_data; // Move (here: copy) into a new variable
+    let address_after = &moved_data as *const [u8; 4] as usize;
+    println!(
+        "Array before move: {:#x}, after move: {:#x}", // Two different stack slots
+        address_before, address_after
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. Alternative 1: Store Indices Instead of References
+    // -------------------------------------------------------------------------
+    // The simplest fix: store *where* the title is (a byte range) instead of a
+    // reference to it, and slice on demand. Ranges are plain numbers, so the
+    // struct can be moved freely. This is what most real code does.
+
+    println!("\n--- 3. Alternative 1: Indices ---");
+
+    struct IndexedDocument {
+        text: String,
+        title: Range<usize>, // Byte range of the title inside `text`
+    }
+
+    impl IndexedDocument {
+        fn load(text: String) -> IndexedDocument {
+            let title_len = text.lines().next().map_or(0, str::len);
+            IndexedDocument {
+                text,
+                title: 0..title_len,
+            }
+        }
+
+        fn title(&self) -> &str {
+            &self.text[self.title.clone()] // Borrow of `self`, created on demand
+        }
+    }
+
+    let indexed = IndexedDocument::load(String::from("Rust Book\nChapter 1..."));
+    let moved_indexed = indexed; // Moving is fine: the range is still correct
+    println!("Indexed title: '{}'", moved_indexed.title());
+    assert_eq!(moved_indexed.title(), "Rust Book");
+
+    // -------------------------------------------------------------------------
+    // 4. Alternative 2: An Owning-Reference Wrapper (Small, Audited `unsafe`)
+    // -------------------------------------------------------------------------
+    // A `String` keeps its characters in a *heap* buffer. Moving the `String`
+    // only copies its (pointer, length, capacity) header; the buffer stays put.
+    // So a raw pointer into the buffer stays valid across moves, as long as
+    // the owner is never mutated or dropped while the pointer is in use.
+    //
+    // `OwnedSlice` enforces those rules: `owner` is private and never handed
+    // out mutably, and `Deref` ties the returned `&str` to a borrow of `self`.
+    // Crates like `ouroboros` and `self_cell` generate this kind of wrapper.
+
+    println!("\n--- 4. Alternative 2: Owning-Reference Wrapper ---");
+
+    struct OwnedSlice {
+        owner: String,
+        slice: *const str, // Points into `owner`'s heap buffer
+    }
+
+    impl OwnedSlice {
+        // The `for<'s>` bound makes `select` work for *every* lifetime `'s`,
+        // so what it returns must live at least as long as its argument: a
+        // slice of the argument, or `'static` data such as a string literal.
+        // It can't return a borrow of anything shorter-lived, which would
+        // dangle. (A `'static` slice doesn't point into `owner`, but it's
+        // valid for as long as the wrapper, so `Deref` is still sound.)
+        fn new(owner: String, select: impl for<'s> FnOnce(&'s str) -> &'s str) -> OwnedSlice {
+            let slice = select(&owner) as *const str;
+            OwnedSlice { owner, slice }
+        }
+
+        fn owner(&self) -> &str {
+            &self.owner
+        }
+    }
+
+    impl Deref for OwnedSlice {
+        type Target = str;
+
+        fn deref(&self) -> &str {
+            // SAFETY: `slice` points into `owner`'s heap buffer, which does not
+            // move when `OwnedSlice` moves and is never mutated or freed before
+            // `self` is dropped. The returned reference can't outlive `&self`.
+            unsafe { &*self.slice }
+        }
+    }
+
+    let title = OwnedSlice::new(String::from("Rust Book\nChapter 1..."), |text| {
+        text.lines().next().unwrap_or("")
+    });
+    let moved_title = title; // Moving the wrapper keeps the slice valid
+    let boxed_title = Box::new(moved_title); // Even onto the heap
+    println!("Owned-slice title: '{}'", &**boxed_title);
+    assert_eq!(&**boxed_title, "Rust Book");
+    assert_eq!(boxed_title.len(), 9); // `str` methods work through `Deref`
+    assert!(boxed_title.owner().ends_with("Chapter 1..."));
+
+    // -------------------------------------------------------------------------
+    // 5. Alternative 3: `Pin` for Data That Must Not Move
+    // -------------------------------------------------------------------------
+    // Sometimes the referenced data lives *inline* in the struct (e.g. a
+    // fixed-size buffer), so any move really does invalidate the pointer.
+    // `Pin<Box<T>>` promises the value will never move again, and
+    // `PhantomPinned` makes the type `!Unpin` so safe code can't get a
+    // `&mut T` out of the `Pin` (which would allow `std::mem::swap`).
+    // This is exactly the problem `async fn` futures have: their locals can
+    // borrow each other across `.await`, which is why futures are polled pinned.
+
+    println!("\n--- 5. Alternative 3: `Pin` ---");
+
+    struct PinnedTitle {
+        buffer: [u8; 32], // Inline storage: moves with the struct
+        len: usize,
+        title: *const str, // Points into `buffer`
+        _pinned: PhantomPinned,
+    }
+
+    impl PinnedTitle {
+        fn new(text: &str) -> Pin<Box<PinnedTitle>> {
+            let len = text.len().min(32);
+            let mut buffer = [0u8; 32];
+            buffer[..len].copy_from_slice(&text.as_bytes()[..len]);
+            let mut boxed = Box::pin(PinnedTitle {
+                buffer,
+                len,
+                title: "",
+                _pinned: PhantomPinned,
+            });
+
+            // Now that the value has its final address, point `title` into it.
+            let stored = std::str::from_utf8(&boxed.buffer[..boxed.len]).unwrap_or("");
+            let title_ptr = stored.lines().next().unwrap_or("") as *const str;
+            // SAFETY: we only write a field; the value is not moved out of the `Pin`.
+            unsafe {
+                boxed.as_mut().get_unchecked_mut().title = title_ptr;
+            }
+            boxed
+        }
+
+        fn title(self: Pin<&Self>) -> &str {
+            // SAFETY: `title` points into `buffer`, and a pinned `PinnedTitle`
+            // never moves, so the pointer stays valid as long as `self`.
+            unsafe { &*self.get_ref().title }
+        }
+    }
+
+    let pinned = PinnedTitle::new("Rust Book\nChapter 1");
+    let moved_pinned = pinned; // Moves the `Box` pointer, not the pinned value
+    println!("Pinned title: '{}'", moved_pinned.as_ref().title());
+    assert_eq!(moved_pinned.as_ref().title(), "Rust Book");
+
+    /*
+    // Safe code cannot move the value out of its pin:
+    let mut a = PinnedTitle::new("A");
+    let mut b = PinnedTitle::new("B");
+    std::mem::swap(a.as_mut().get_mut(), b.as_mut().get_mut());
+    // error[E0277]: `PhantomPinned` cannot be unpinned
+    */
+
+    // -------------------------------------------------------------------------
+    // 6. Checking the `unsafe` Code with Miri
+    // -------------------------------------------------------------------------
+    // Sections 4 and 5 contain `unsafe` blocks. Miri is an interpreter that
+    // detects undefined behaviour (dangling pointers, invalid aliasing) while
+    // running a program. Run this lesson under it with a nightly toolchain:
+    //
+    //   rustup +nightly component add miri
+    //   cargo +nightly miri run --bin 19-self-referential-structs
+    //
+    // Nothing runs this automatically: `cargo test` uses the stable compiler
+    // and the repository has no CI, so run it by hand after changing the
+    // `unsafe` code.
+    //
+    // Prefer the options in this order: indices first, then a well-tested
+    // crate (`self_cell`, `ouroboros`), and your own `unsafe` only as a last resort.
+
+    println!("\n--- End of Self-Referential Struct Examples ---");
+}
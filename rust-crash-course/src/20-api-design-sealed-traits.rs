@@ -0,0 +1,187 @@
+// This file covers patterns library authors use to evolve a public API without
+// breaking downstream code: sealed traits, `#[non_exhaustive]` enums and
+// structs, and deprecation shims. Each pattern controls what *other crates*
+// are allowed to do with your types, so that adding something later is not
+// a breaking change.
+
+// -------------------------------------------------------------------------
+// 0. Why API Evolution Needs Planning
+// -------------------------------------------------------------------------
+// Under semantic versioning, a minor release (1.2 -> 1.3) must not break code
+// that compiled against the previous one. Some innocent-looking changes do:
+// - Adding a method to a public trait breaks every downstream `impl` of it.
+// - Adding a variant to a public enum breaks every downstream exhaustive `match`.
+// - Adding a field to a public struct breaks downstream struct literals and
+//   destructuring patterns.
+// The patterns below opt out of those guarantees up front.
+
+// The `shapes` module plays the role of "our library crate". Privacy in Rust
+// is module-based, so a private module is enough to demonstrate sealing here.
+mod shapes {
+    // -------------------------------------------------------------------------
+    // 1. Sealed Traits
+    // -------------------------------------------------------------------------
+    // `Sealed` is public, but it lives in a *private* module, so code outside
+    // `shapes` can't name it and therefore can't implement it. Because
+    // `Shape: Sealed`, nobody outside can implement `Shape` either, while
+    // everyone can still *use* it (call its methods, take `impl Shape`).
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    pub trait Shape: sealed::Sealed {
+        fn area(&self) -> f64;
+
+        // Adding a new method later is not a breaking change: we own every impl.
+        fn describe(&self) -> String {
+            format!("shape with area {:.2}", self.area())
+        }
+    }
+
+    pub struct Square(pub f64);
+    pub struct Circle(pub f64);
+
+    impl sealed::Sealed for Square {}
+    impl sealed::Sealed for Circle {}
+
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            self.0 * self.0
+        }
+    }
+
+    impl Shape for Circle {
+        fn area(&self) -> f64 {
+            std::f64::consts::PI * self.0 * self.0
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // 2. `#[non_exhaustive]` Enums and Structs
+    // -------------------------------------------------------------------------
+    // On an enum: downstream crates must include a wildcard `_` arm when they
+    // match, so adding a variant later doesn't break them.
+    // On a struct: downstream crates can't build it with a struct literal or
+    // destructure it without `..`, so adding a field later doesn't break them.
+    // (Inside the defining crate the attribute has no effect.)
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum LessonError {
+        NotFound(String),
+        MissingFeature(&'static str),
+    }
+
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub struct LessonInfo {
+        pub id: u32,
+        pub title: String,
+    }
+
+    impl LessonInfo {
+        // With literals unavailable downstream, a constructor is the way in.
+        pub fn new(id: u32, title: &str) -> LessonInfo {
+            LessonInfo {
+                id,
+                title: title.to_string(),
+            }
+        }
+    }
+
+    pub fn find_lesson(id: u32) -> Result<LessonInfo, LessonError> {
+        match id {
+            12 => Ok(LessonInfo::new(12, "Traits")),
+            16 => Err(LessonError::MissingFeature("tokio")),
+            _ => Err(LessonError::NotFound(format!("lesson {}", id))),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // 3. Deprecation Shims
+    // -------------------------------------------------------------------------
+    // When renaming or replacing a function, keep the old name for a while as
+    // a thin wrapper marked `#[deprecated]`. Existing callers keep compiling
+    // but get a warning that tells them what to use instead.
+    pub fn total_area(shapes: &[&dyn Shape]) -> f64 {
+        shapes.iter().map(|shape| shape.area()).sum()
+    }
+
+    #[deprecated(since = "1.3.0", note = "use `total_area` instead")]
+    pub fn sum_areas(shapes: &[&dyn Shape]) -> f64 {
+        total_area(shapes)
+    }
+}
+
+use shapes::{Circle, LessonError, Shape, Square};
+
+fn main() {
+    println!("--- API Design: Sealed Traits, non_exhaustive, and Deprecation ---");
+
+    // 1. Sealed traits: usable everywhere, implementable only inside `shapes`.
+    println!("\n--- 1. Sealed Traits ---");
+    let square = Square(2.0);
+    let circle = Circle(1.0);
+    println!("Square: {}", square.describe());
+    println!("Circle: {}", circle.describe());
+    assert_eq!(square.area(), 4.0);
+
+    /*
+    struct Triangle;
+    impl Shape for Triangle { // error[E0277]: the trait bound `Triangle: Sealed` is not satisfied
+        fn area(&self) -> f64 { 0.0 }
+    }
+    impl shapes::sealed::Sealed for Triangle {} // error[E0603]: module `sealed` is private
+    */
+
+    // 2. `#[non_exhaustive]`: downstream code must handle "something else".
+    println!("\n--- 2. `#[non_exhaustive]` ---");
+    for id in [12, 16, 99] {
+        match shapes::find_lesson(id) {
+            Ok(info) => println!("Found lesson {}: {}", info.id, info.title),
+            Err(LessonError::NotFound(what)) => println!("Not found: {}", what),
+            Err(LessonError::MissingFeature(feature)) => {
+                println!("Needs the '{}' feature", feature)
+            }
+            // Required in other crates; here (same crate) it is allowed but unreachable.
+            #[allow(unreachable_patterns)]
+            Err(other) => println!("Other error: {:?}", other),
+        }
+    }
+
+    /*
+    // In a *downstream* crate, these would not compile:
+    match error {
+        LessonError::NotFound(_) => {}
+        LessonError::MissingFeature(_) => {}
+    } // error[E0004]: non-exhaustive patterns: `_` not covered
+
+    let info = LessonInfo { id: 1, title: String::new() };
+    // error[E0639]: cannot create non-exhaustive struct using struct expression
+
+    let LessonInfo { id, title } = info; // error[E0638]: `..` required with struct marked as non-exhaustive
+    */
+    // tests/compile_fail/ checks the sealed-trait errors above, and the
+    // `match` error using std's non-exhaustive `io::ErrorKind` (a test file is
+    // its own crate, but can't import this lesson). The struct errors would
+    // need a library crate of ours to import, so they stay in the comment.
+    let info = shapes::LessonInfo::new(1, "Variables");
+    let shapes::LessonInfo { id, .. } = info; // `..` keeps working when fields are added
+    assert_eq!(id, 1);
+
+    // 3. Deprecation shims: old callers still work, with a compiler warning.
+    println!("\n--- 3. Deprecation Shims ---");
+    let all_shapes: [&dyn Shape; 2] = [&square, &circle];
+    let new_total = shapes::total_area(&all_shapes);
+    #[allow(deprecated)] // Without this: warning: use of deprecated function `sum_areas`
+    let old_total = shapes::sum_areas(&all_shapes);
+    println!(
+        "total_area = {:.2}, sum_areas (deprecated) = {:.2}",
+        new_total, old_total
+    );
+    assert_eq!(new_total, old_total);
+
+    // Checking your API for accidental breaking changes: the `cargo-semver-checks`
+    // tool compares two versions of a crate and reports semver violations.
+
+    println!("\n--- End of API Design Examples ---");
+}
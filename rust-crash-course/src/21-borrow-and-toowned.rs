@@ -0,0 +1,174 @@
+// This file explains the `Borrow` and `ToOwned` traits: why a
+// `HashMap<String, V>` can be searched with a `&str`, how to give your own
+// key types the same ability, the contract `Borrow` carries, and how
+// `ToOwned` lets code allocate only when it really has to.
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+fn main() {
+    println!("--- Borrow, ToOwned, and Allocation-Free Lookups ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Why `map.get("alice")` Works on a `HashMap<String, _>`
+    // -------------------------------------------------------------------------
+    // The signature of `HashMap::get` is (simplified):
+    //
+    //     fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V>
+    //     where K: Borrow<Q>, Q: Hash + Eq
+    //
+    // The key type `K` doesn't have to match the query type `Q`. It only needs
+    // to be *borrowable as* `Q`. Since `String: Borrow<str>`, a `&str` query
+    // works, and no temporary `String` is allocated just to look something up.
+
+    println!("\n--- 1. Why `map.get(\"alice\")` Works ---");
+
+    let mut scores: HashMap<String, u32> = HashMap::new();
+    scores.insert(String::from("alice"), 90);
+    scores.insert(String::from("bob"), 75);
+
+    let wasteful = scores.get(&String::from("alice")); // Allocates a String, then borrows it
+    let direct = scores.get("alice"); // Borrows the literal: no allocation
+    println!("get(&String::from(\"alice\")) = {:?}", wasteful);
+    println!("get(\"alice\")                = {:?}", direct);
+    assert_eq!(wasteful, direct);
+
+    // The same holds for other owned/borrowed pairs:
+    // `Vec<T>: Borrow<[T]>`, `PathBuf: Borrow<Path>`, `Box<T>: Borrow<T>`, and every `T: Borrow<T>`.
+    let mut seen: HashSet<Vec<u8>> = HashSet::new();
+    seen.insert(vec![1, 2, 3]);
+    assert!(seen.contains(&[1, 2, 3][..])); // Query with a slice, not a Vec
+
+    // -------------------------------------------------------------------------
+    // 2. The `Borrow` Contract
+    // -------------------------------------------------------------------------
+    // `Borrow<Q>` is more than "can give out a `&Q`" (that's `AsRef<Q>`).
+    // It promises that `Hash`, `Eq` and `Ord` give the *same answers* for the
+    // owned value and its borrowed form. The map hashes the stored `String`
+    // and the queried `&str` independently; if the hashes differed, lookups
+    // would silently miss.
+
+    println!("\n--- 2. The `Borrow` Contract ---");
+
+    use std::hash::{BuildHasher, RandomState};
+    let hasher_state = RandomState::new();
+    let owned_hash = hasher_state.hash_one(String::from("alice"));
+    let borrowed_hash = hasher_state.hash_one("alice");
+    println!(
+        "hash(String) = {:x}, hash(&str) = {:x}",
+        owned_hash, borrowed_hash
+    );
+    assert_eq!(owned_hash, borrowed_hash); // `String` hashes exactly like `str`
+
+    // -------------------------------------------------------------------------
+    // 3. Implementing `Borrow` for Your Own Key Types
+    // -------------------------------------------------------------------------
+    // A newtype such as `Email(String)` makes function signatures clearer, but
+    // by default a `HashMap<Email, _>` can only be queried with an `&Email`,
+    // forcing callers to build one (and allocate) for every lookup.
+    // Deriving `Hash`/`Eq` on a single-field newtype hashes and compares just
+    // like the inner `String` (and therefore like `str`), so implementing
+    // `Borrow<str>` keeps the contract.
+
+    println!("\n--- 3. Implementing `Borrow` for Your Own Key Types ---");
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Email(String);
+
+    impl Email {
+        fn parse(raw: &str) -> Option<Email> {
+            raw.contains('@').then(|| Email(raw.to_string()))
+        }
+    }
+
+    impl Borrow<str> for Email {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Symbol(String); // e.g. a stock ticker such as "RUST"
+
+    impl Borrow<str> for Symbol {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    let mut accounts: HashMap<Email, &str> = HashMap::new();
+    accounts.insert(Email::parse("alice@example.com").unwrap(), "Alice");
+    accounts.insert(Email::parse("bob@example.com").unwrap(), "Bob");
+
+    // Looked up with a plain `&str`: no `Email` and no `String` created.
+    let owner = accounts.get("alice@example.com");
+    println!("Account for alice@example.com: {:?}", owner);
+    assert_eq!(owner, Some(&"Alice"));
+    assert!(!accounts.contains_key("carol@example.com"));
+
+    let mut prices: HashMap<Symbol, f64> = HashMap::new();
+    prices.insert(Symbol(String::from("RUST")), 42.0);
+    assert_eq!(prices.get("RUST"), Some(&42.0));
+
+    // When would `Borrow<str>` be *wrong*? If `Email` compared case-insensitively
+    // ("Alice@x.com" == "alice@x.com") but `str` does not, then `Email` and `str`
+    // would disagree on `Eq`/`Hash` and lookups by `&str` would be unreliable.
+    // Only implement `Borrow` when the borrowed form behaves identically.
+
+    // -------------------------------------------------------------------------
+    // 4. `ToOwned`: The Way Back From Borrowed to Owned
+    // -------------------------------------------------------------------------
+    // `Clone` turns `&T` into `T`. `ToOwned` generalizes this to borrowed types
+    // whose owned form is a *different* type: `str -> String`, `[T] -> Vec<T>`,
+    // `Path -> PathBuf`. It is the inverse of `Borrow`:
+    // `impl ToOwned for str { type Owned = String; }` and `String: Borrow<str>`.
+
+    println!("\n--- 4. `ToOwned` ---");
+
+    let owned_text: String = "hello".to_owned();
+    let owned_numbers: Vec<i32> = [1, 2, 3][..].to_owned();
+    println!("to_owned: {:?}, {:?}", owned_text, owned_numbers);
+
+    // Together they enable "allocate only on a miss": look up with the borrowed
+    // form, and only call `to_owned()` when the key must actually be stored.
+    // (`entry(word.to_string())` would allocate on every call, even for hits.)
+    fn count_word(counts: &mut HashMap<String, u32>, word: &str) {
+        if let Some(count) = counts.get_mut(word) {
+            *count += 1; // Hit: no allocation
+        } else {
+            counts.insert(word.to_owned(), 1); // Miss: allocate the key once
+        }
+    }
+
+    let mut word_counts = HashMap::new();
+    for word in "the cat and the hat and the bat".split_whitespace() {
+        count_word(&mut word_counts, word);
+    }
+    println!("Word counts: {:?}", word_counts);
+    assert_eq!(word_counts.get("the"), Some(&3));
+    assert_eq!(word_counts.get("and"), Some(&2));
+
+    // A generic version works for any key type with a borrowed form `Q`.
+    fn count_key<K, Q>(counts: &mut HashMap<K, u32>, key: &Q)
+    where
+        K: Borrow<Q> + std::hash::Hash + Eq,
+        Q: ToOwned<Owned = K> + std::hash::Hash + Eq + ?Sized,
+    {
+        match counts.get_mut(key) {
+            Some(count) => *count += 1,
+            None => {
+                counts.insert(key.to_owned(), 1);
+            }
+        }
+    }
+
+    let mut byte_patterns: HashMap<Vec<u8>, u32> = HashMap::new();
+    count_key(&mut byte_patterns, &[0xCA, 0xFE][..]);
+    count_key(&mut byte_patterns, &[0xCA, 0xFE][..]);
+    assert_eq!(byte_patterns.get(&[0xCA, 0xFE][..]), Some(&2));
+
+    // `Cow<'a, B>` builds on `ToOwned` to return "borrowed, or owned only if
+    // something changed": see 56-cow-clone-on-write.rs.
+
+    println!("\n--- End of Borrow and ToOwned Examples ---");
+}
@@ -0,0 +1,211 @@
+// This file covers the rule that ties `Hash` and `Eq` together:
+// if two keys are equal, they must produce the same hash. It implements a
+// correct `CaseInsensitiveString` key, shows a deliberately broken key type
+// and the confusing `HashMap` behaviour it causes, and builds a small checker
+// that tests the invariant over many sample keys.
+
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, Hasher};
+
+// -------------------------------------------------------------------------
+// 0. How a HashMap Finds a Key
+// -------------------------------------------------------------------------
+// A lookup is a two-step process:
+// 1. Hash the key to pick a bucket (only keys in that bucket are considered).
+// 2. Compare the key with `==` against the entries in that bucket.
+// So the map relies on this invariant:  k1 == k2  ⇒  hash(k1) == hash(k2)
+// If equal keys could hash differently, step 1 would look in the wrong bucket
+// and step 2 would never see the matching entry.
+// (The reverse is not required: unequal keys may share a hash; that's a collision.)
+
+// Checks the invariant for every pair of samples and returns the first
+// violating pair of indices, if any.
+fn check_hash_eq_invariant<K: Hash + Eq>(samples: &[K]) -> Result<(), (usize, usize)> {
+    let state = RandomState::new();
+    for (i, a) in samples.iter().enumerate() {
+        for (j, b) in samples.iter().enumerate() {
+            if a == b && state.hash_one(a) != state.hash_one(b) {
+                return Err((i, j));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Produces case and spelling variations of a few words, so that the checker
+// sees many pairs that are equal under case-insensitive comparison.
+fn sample_words() -> Vec<String> {
+    let mut words = Vec::new();
+    for base in ["alice", "Bob", "rust", "straße", "", "x"] {
+        words.push(base.to_string());
+        words.push(base.to_uppercase());
+        words.push(base.to_lowercase());
+        // Alternate the case of every other character: "aLiCe"
+        let mixed: String = base
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if i % 2 == 0 {
+                    c.to_ascii_lowercase()
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            })
+            .collect();
+        words.push(mixed);
+    }
+    words
+}
+
+fn main() {
+    println!("--- Hash and Eq Correctness ---");
+
+    // -------------------------------------------------------------------------
+    // 1. A Correct Case-Insensitive Key
+    // -------------------------------------------------------------------------
+    // To compare case-insensitively, `Eq` ignores ASCII case. `Hash` must then
+    // ignore case in exactly the same way, so we feed the hasher the lowercase
+    // form of every byte. Both impls use the *same* normalization.
+
+    println!("\n--- 1. A Correct Case-Insensitive Key ---");
+
+    #[derive(Debug, Clone)]
+    struct CaseInsensitiveString(String);
+
+    impl PartialEq for CaseInsensitiveString {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.eq_ignore_ascii_case(&other.0)
+        }
+    }
+
+    impl Eq for CaseInsensitiveString {}
+
+    impl Hash for CaseInsensitiveString {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            for byte in self.0.bytes() {
+                state.write_u8(byte.to_ascii_lowercase());
+            }
+            // End marker, as `str`'s own Hash impl writes, so ("ab", "c") and ("a", "bc")
+            // hash differently when used inside a tuple key.
+            state.write_u8(0xff);
+        }
+    }
+
+    let mut logins: HashMap<CaseInsensitiveString, u32> = HashMap::new();
+    *logins
+        .entry(CaseInsensitiveString(String::from("Alice")))
+        .or_insert(0) += 1;
+    *logins
+        .entry(CaseInsensitiveString(String::from("ALICE")))
+        .or_insert(0) += 1;
+    *logins
+        .entry(CaseInsensitiveString(String::from("alice")))
+        .or_insert(0) += 1;
+    println!("Logins: {:?}", logins); // One entry, counted three times
+    assert_eq!(logins.len(), 1);
+    assert_eq!(
+        logins.get(&CaseInsensitiveString(String::from("aLiCe"))),
+        Some(&3)
+    );
+
+    // -------------------------------------------------------------------------
+    // 2. A Deliberately Broken Key
+    // -------------------------------------------------------------------------
+    // `BrokenKey` compares case-insensitively but *derives* `Hash`, which hashes
+    // the original bytes. "Alice" == "ALICE", yet their hashes differ.
+    // Nothing fails to compile; the map just starts behaving strangely.
+    // (Clippy does catch this pattern with its `derived_hash_with_manual_eq` lint,
+    // which we silence here on purpose.)
+
+    println!("\n--- 2. A Deliberately Broken Key ---");
+
+    #[allow(clippy::derived_hash_with_manual_eq)]
+    #[derive(Debug, Hash)] // Derived Hash: case-SENSITIVE
+    struct BrokenKey(String);
+
+    impl PartialEq for BrokenKey {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.eq_ignore_ascii_case(&other.0) // Case-INSENSITIVE
+        }
+    }
+
+    impl Eq for BrokenKey {}
+
+    let a = BrokenKey(String::from("Alice"));
+    let b = BrokenKey(String::from("ALICE"));
+    println!("Alice == ALICE? {}", a == b); // true
+
+    let mut broken_set = HashSet::new();
+    broken_set.insert(BrokenKey(String::from("Alice")));
+    broken_set.insert(BrokenKey(String::from("ALICE"))); // "Equal", but lands in another bucket
+    println!(
+        "Set of 'equal' keys has {} entries (expected 1)",
+        broken_set.len() // Almost always 2
+    );
+    println!(
+        "Contains 'alice'? {}",
+        broken_set.contains(&BrokenKey(String::from("alice"))) // Almost always false
+    );
+    // The results depend on the random hash seed, which makes such bugs
+    // intermittent and hard to track down. The invariant checker catches it reliably:
+    let broken_samples: Vec<BrokenKey> = sample_words().into_iter().map(BrokenKey).collect();
+    match check_hash_eq_invariant(&broken_samples) {
+        Ok(()) => println!("BrokenKey: invariant holds"),
+        Err((i, j)) => println!(
+            "BrokenKey: {:?} == {:?} but their hashes differ",
+            broken_samples[i], broken_samples[j]
+        ),
+    }
+    assert!(check_hash_eq_invariant(&broken_samples).is_err());
+
+    // -------------------------------------------------------------------------
+    // 3. Checking the Invariant for Every Key Type
+    // -------------------------------------------------------------------------
+    // A cheap "property test": build many samples, including values that are
+    // equal in non-obvious ways, and check every pair. Run it for each key type
+    // that has a hand-written `Hash` or `PartialEq`.
+
+    println!("\n--- 3. Checking the Invariant for Every Key Type ---");
+
+    let case_insensitive_samples: Vec<CaseInsensitiveString> = sample_words()
+        .into_iter()
+        .map(CaseInsensitiveString)
+        .collect();
+    assert_eq!(check_hash_eq_invariant(&case_insensitive_samples), Ok(()));
+    println!(
+        "CaseInsensitiveString: invariant holds for {} samples",
+        case_insensitive_samples.len()
+    );
+
+    // Derived impls are always consistent, because both hash and compare every field.
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct UserKey {
+        tenant: u32,
+        name: String,
+    }
+
+    let user_samples: Vec<UserKey> = sample_words()
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| UserKey {
+            tenant: (i % 3) as u32,
+            name,
+        })
+        .collect();
+    assert_eq!(check_hash_eq_invariant(&user_samples), Ok(()));
+    println!(
+        "UserKey (derived): invariant holds for {} samples",
+        user_samples.len()
+    );
+
+    // Rules of thumb:
+    // - Derive `Hash`, `PartialEq` and `Eq` together whenever you can.
+    // - If you hand-write one of them, hand-write the others from the same
+    //   normalized view of the data (same fields, same case folding).
+    // - Never include in `Hash` anything that `Eq` ignores.
+    // More composite keys (an undirected edge, a record with a cached field)
+    // and `HashSet` operations: see 55-hashset-and-custom-keys.rs.
+
+    println!("\n--- End of Hash and Eq Examples ---");
+}
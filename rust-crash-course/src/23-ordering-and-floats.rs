@@ -0,0 +1,185 @@
+// This file covers ordering in Rust: the difference between `PartialOrd` and
+// `Ord`, why `f64` only implements the partial one, what that means for
+// sorting and for `BTreeMap` keys, and how a `NotNan` newtype restores a
+// total order for floating-point values.
+// 67-ord-and-custom-sorting.rs implements `Ord` for a struct of its own and
+// covers `sort_by_key`, `sort_by` and `Reverse`.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+// -------------------------------------------------------------------------
+// 0. Partial vs. Total Order
+// -------------------------------------------------------------------------
+// `Ord` (total order): any two values can be compared, `cmp` returns an
+//   `Ordering` (Less, Equal, Greater). Integers, `char`, `String`, ... are `Ord`.
+// `PartialOrd` (partial order): some pairs are *incomparable*, so
+//   `partial_cmp` returns `Option<Ordering>` and may be `None`.
+// Floats are only `PartialOrd` because of NaN ("not a number"): NaN is not
+// less than, equal to, or greater than anything, including itself.
+
+// A float that is guaranteed not to be NaN. With NaN excluded, every pair of
+// values is comparable, so `Eq` and `Ord` can be implemented honestly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NotNan(f64);
+
+impl NotNan {
+    // The only way to build one: NaN is rejected up front.
+    fn new(value: f64) -> Option<NotNan> {
+        if value.is_nan() {
+            None
+        } else {
+            Some(NotNan(value))
+        }
+    }
+
+    fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl Eq for NotNan {}
+
+// `PartialOrd` delegates to `Ord`, so the two can never disagree.
+impl PartialOrd for NotNan {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NotNan {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Can't be `None`: neither side is NaN.
+        self.0
+            .partial_cmp(&other.0)
+            .expect("NotNan never holds NaN")
+    }
+}
+
+fn main() {
+    println!("--- Ordering and Total-Order Pitfalls ---");
+
+    // -------------------------------------------------------------------------
+    // 1. NaN Breaks Comparisons
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. NaN Breaks Comparisons ---");
+
+    let nan = f64::NAN;
+    #[allow(clippy::eq_op)] // Clippy flags `x == x` as a likely typo; here it's the point
+    let equal_to_itself = nan == nan;
+    println!("NaN == NaN: {}", equal_to_itself); // false!
+    println!("NaN < 1.0: {}, NaN > 1.0: {}", nan < 1.0, nan > 1.0); // false, false
+    println!("1.0.partial_cmp(&NaN): {:?}", 1.0f64.partial_cmp(&nan)); // None
+    assert_eq!(2.0f64.partial_cmp(&1.0), Some(Ordering::Greater));
+    assert_eq!(1.0f64.partial_cmp(&nan), None);
+
+    // NaN is easy to produce by accident, e.g. from an average of zero items
+    // or a division like the ones in the error-handling lesson:
+    let empty: [f64; 0] = [];
+    let average = empty.iter().sum::<f64>() / empty.len() as f64; // 0.0 / 0.0
+    println!("Average of no values: {}", average); // NaN
+    assert!(average.is_nan());
+
+    // -------------------------------------------------------------------------
+    // 2. Why `f64` Can't Be a `BTreeMap` Key (or `.sort()`ed)
+    // -------------------------------------------------------------------------
+    // `BTreeMap<K, V>` and `slice::sort` need `K: Ord`, because they must be
+    // able to place *every* key. `f64` isn't `Ord`, so these don't compile:
+
+    /*
+    let mut by_price: BTreeMap<f64, &str> = BTreeMap::new();
+    by_price.insert(9.99, "book"); // error[E0277]: the trait bound `f64: Ord` is not satisfied
+
+    let mut prices = vec![3.5, 1.25, 2.0];
+    prices.sort(); // error[E0277]: the trait bound `f64: Ord` is not satisfied
+    */
+
+    // -------------------------------------------------------------------------
+    // 3. `sort_by(partial_cmp)` Pitfalls
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. `sort_by(partial_cmp)` Pitfalls ---");
+
+    // a. The common workaround panics as soon as a NaN shows up.
+    let mut clean = vec![3.5, 1.25, 2.0];
+    clean.sort_by(|a, b| a.partial_cmp(b).unwrap()); // Fine without NaN
+    println!("Sorted clean values: {:?}", clean);
+
+    // (The "panicked at" message below comes from the default panic hook.)
+    let with_nan = vec![3.5, f64::NAN, 1.25];
+    let result = std::panic::catch_unwind(move || {
+        let mut values = with_nan;
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap()); // Panics: unwrap on None
+        values
+    });
+    println!(
+        "sort_by(partial_cmp().unwrap()) with NaN panicked: {}",
+        result.is_err()
+    );
+    assert!(result.is_err());
+
+    // b. "Just treat NaN as Equal" is worse: it breaks transitivity
+    //    (1.0 == NaN and NaN == 3.0, but 1.0 < 3.0). The result is an
+    //    unspecified order, and recent Rust versions may even panic with
+    //    "user-provided comparison function does not correctly implement a total order".
+    //    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal)); // Don't!
+
+    // c. `f64::total_cmp` implements the IEEE 754 *total* order: every value,
+    //    including NaN and -0.0, gets a place. Positive NaN sorts after +infinity.
+    let mut mixed = vec![3.5, f64::NAN, -0.0, 0.0, f64::NEG_INFINITY, 1.25];
+    mixed.sort_by(|a, b| a.total_cmp(b));
+    println!("Sorted with total_cmp: {:?}", mixed);
+    assert_eq!(mixed[0], f64::NEG_INFINITY);
+    assert!(mixed[5].is_nan());
+
+    // d. Or filter NaN out first, when it signals bad data.
+    let mut readings = vec![21.5, f64::NAN, 19.0, 23.25];
+    readings.retain(|r| !r.is_nan());
+    readings.sort_by(f64::total_cmp);
+    assert_eq!(readings, [19.0, 21.5, 23.25]);
+
+    // Finding a maximum has the same issue: `Iterator::max` needs `Ord`.
+    // `fold(f64::NEG_INFINITY, f64::max)` works, and `f64::max` ignores NaN.
+    let highest = readings.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    assert_eq!(highest, 23.25);
+
+    // -------------------------------------------------------------------------
+    // 4. `NotNan`: A Float With a Total Order
+    // -------------------------------------------------------------------------
+    // `NotNan` (defined above `main`) checks for NaN once, at construction.
+    // After that it's `Ord`, so it works with `sort`, `max`, and `BTreeMap`.
+    // The `ordered-float` crate provides a production-ready version of this.
+
+    println!("\n--- 4. `NotNan`: A Float With a Total Order ---");
+
+    assert!(NotNan::new(f64::NAN).is_none());
+
+    let mut prices: Vec<NotNan> = [9.99, 4.5, 19.0]
+        .iter()
+        .filter_map(|&p| NotNan::new(p))
+        .collect();
+    prices.sort(); // Plain `sort`: no closures, no unwraps
+    println!(
+        "Sorted prices: {:?}",
+        prices.iter().map(|p| p.get()).collect::<Vec<_>>()
+    );
+    assert_eq!(prices.iter().max().map(|p| p.get()), Some(19.0));
+
+    let mut catalog: BTreeMap<NotNan, &str> = BTreeMap::new();
+    catalog.insert(NotNan::new(9.99).unwrap(), "book");
+    catalog.insert(NotNan::new(4.5).unwrap(), "pen");
+    catalog.insert(NotNan::new(19.0).unwrap(), "lamp");
+    for (price, item) in &catalog {
+        println!("{:>6.2}: {}", price.get(), item); // Iterates in price order
+    }
+
+    // Range queries work because keys are totally ordered.
+    let cheap: Vec<&str> = catalog
+        .range(..NotNan::new(10.0).unwrap())
+        .map(|(_, item)| *item)
+        .collect();
+    assert_eq!(cheap, ["pen", "book"]);
+
+    println!("\n--- End of Ordering Examples ---");
+}
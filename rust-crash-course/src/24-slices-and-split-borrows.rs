@@ -0,0 +1,455 @@
+// This file covers slices (`&[T]` and `&mut [T]`): the views into arrays,
+// vectors and strings that most Rust APIs accept. It shows the common slice
+// methods, how `split_at_mut` lets you borrow two parts of one slice mutably
+// at the same time (and how to write it yourself over a little `unsafe`),
+// chunked and windowed iteration, in-place partitioning, a small timing
+// comparison of chunked vs. per-element processing, slice patterns, and
+// sorting and searching.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use fmt::human_duration;
+
+// -------------------------------------------------------------------------
+// 0. What Is a Slice?
+// -------------------------------------------------------------------------
+// A slice is a *view* into a contiguous sequence of elements owned by
+// someone else: a pointer plus a length (a "fat pointer").
+// - `&[T]`: read-only view, any number may exist at once.
+// - `&mut [T]`: exclusive view, can modify elements but not change the length.
+// Functions that take `&[T]` work with arrays, `Vec<T>`, and sub-ranges alike.
+
+// Our own `split_at_mut`. The borrow checker can't prove that `&mut slice[..mid]`
+// and `&mut slice[mid..]` don't overlap, so we build the two halves from a raw
+// pointer. The `unsafe` is wrapped in a safe function whose checks guarantee
+// the halves are disjoint, which is exactly how the standard library does it.
+fn my_split_at_mut<T>(slice: &mut [T], mid: usize) -> (&mut [T], &mut [T]) {
+    let len = slice.len();
+    assert!(mid <= len, "mid ({}) > len ({})", mid, len);
+    let ptr = slice.as_mut_ptr();
+    // SAFETY: `mid <= len`, so `[0, mid)` and `[mid, len)` are both inside the
+    // original slice and don't overlap. The returned slices borrow `slice`
+    // mutably, so nobody else can touch the elements while they are alive.
+    unsafe {
+        (
+            std::slice::from_raw_parts_mut(ptr, mid),
+            std::slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+        )
+    }
+}
+
+// Moves every element matching `pred` to the front, keeping nothing else in
+// any particular order, and returns how many matched. O(n), no allocation.
+fn partition_in_place<T>(slice: &mut [T], pred: impl Fn(&T) -> bool) -> usize {
+    let mut boundary = 0; // Everything before `boundary` matches `pred`
+    for i in 0..slice.len() {
+        if pred(&slice[i]) {
+            slice.swap(boundary, i);
+            boundary += 1;
+        }
+    }
+    boundary
+}
+
+fn sum_per_element(values: &[u32]) -> u32 {
+    let mut total = 0u32;
+    for &value in values {
+        total = total.wrapping_add(value);
+    }
+    total
+}
+
+// Sums 8 independent lanes at a time. With no dependency between the lanes,
+// and a chunk length the compiler knows statically, it can vectorize this loop.
+fn sum_chunked(values: &[u32]) -> u32 {
+    let mut lanes = [0u32; 8];
+    let chunks = values.chunks_exact(8);
+    let remainder = chunks.remainder(); // The last `len % 8` elements
+    for chunk in chunks {
+        for (lane, &value) in lanes.iter_mut().zip(chunk) {
+            *lane = lane.wrapping_add(value);
+        }
+    }
+    let mut total = lanes.iter().fold(0u32, |acc, &lane| acc.wrapping_add(lane));
+    for &value in remainder {
+        total = total.wrapping_add(value);
+    }
+    total
+}
+
+fn main() {
+    println!("--- Slices and Split Borrows ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Read-Only Slices: `&[T]`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Read-Only Slices: `&[T]` ---");
+
+    let array = [10, 20, 30, 40, 50];
+    let vector = vec![1, 2, 3];
+
+    fn describe(values: &[i32]) -> String {
+        match values {
+            [] => String::from("empty"),
+            [first, .., last] => format!("{} items, {}..{}", values.len(), first, last),
+            [only] => format!("just {}", only),
+        }
+    }
+
+    println!("Array: {}", describe(&array)); // &[i32; 5] coerces to &[i32]
+    println!("Vector: {}", describe(&vector)); // &Vec<i32> coerces to &[i32]
+    println!("Middle of array: {}", describe(&array[1..4])); // A sub-range
+    println!("Empty: {}", describe(&[]));
+
+    // Common slice methods
+    println!("first: {:?}, last: {:?}", array.first(), array.last());
+    println!("get(10): {:?}", array.get(10)); // `None` instead of a panic
+    println!("contains(&30): {}", array.contains(&30));
+    println!("binary_search(&40): {:?}", array.binary_search(&40)); // Needs sorted input
+    println!("starts_with(&[10, 20]): {}", array.starts_with(&[10, 20]));
+    let (left, right) = array.split_at(2);
+    println!("split_at(2): {:?} | {:?}", left, right);
+    assert_eq!(
+        array.iter().rev().copied().collect::<Vec<_>>(),
+        [50, 40, 30, 20, 10]
+    );
+
+    // -------------------------------------------------------------------------
+    // 2. Mutable Slices: `&mut [T]`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. Mutable Slices: `&mut [T]` ---");
+
+    let mut scores = vec![72, 95, 61, 88, 79];
+    scores.sort_unstable(); // Sorting works on any `&mut [T]`
+    scores.reverse();
+    scores.swap(0, 4);
+    scores[1..3].fill(0); // Modify just a sub-range
+    println!("After sort/reverse/swap/fill: {:?}", scores);
+    assert_eq!(scores, [61, 0, 0, 72, 95]);
+
+    let mut rotating = [1, 2, 3, 4, 5];
+    rotating.rotate_left(2);
+    assert_eq!(rotating, [3, 4, 5, 1, 2]);
+
+    // -------------------------------------------------------------------------
+    // 3. Split Borrows with `split_at_mut`
+    // -------------------------------------------------------------------------
+    // Two `&mut` into the same slice are rejected, even for different indices:
+
+    /*
+    let mut data = [1, 2, 3, 4];
+    let a = &mut data[0];
+    let b = &mut data[3]; // error[E0499]: cannot borrow `data[_]` as mutable more than once at a time
+    std::mem::swap(a, b);
+    */
+
+    // `split_at_mut` hands out two non-overlapping `&mut` halves instead.
+
+    println!("\n--- 3. Split Borrows with `split_at_mut` ---");
+
+    let mut data = [1, 2, 3, 4, 5, 6];
+    {
+        let (front, back) = data.split_at_mut(3);
+        front[0] += back[2]; // Read from one half while writing the other
+        back[0] *= 10;
+        std::mem::swap(&mut front[2], &mut back[1]);
+    }
+    println!("After split_at_mut edits: {:?}", data);
+    assert_eq!(data, [7, 2, 5, 40, 3, 6]);
+
+    // Other ways to get disjoint mutable borrows:
+    // - `split_first_mut` / `split_last_mut`
+    // - `chunks_mut` / `chunks_exact_mut` (many disjoint pieces)
+    // - `iter_mut` (one `&mut` per element)
+    if let Some((head, tail)) = data.split_first_mut() {
+        for value in tail {
+            *value -= *head; // `head` and `tail` are disjoint
+        }
+    }
+    assert_eq!(data, [7, -5, -2, 33, -4, -1]);
+
+    // 3.1 Exercise: Reimplementing `split_at_mut`
+    // `my_split_at_mut` (above `main`) wraps a small `unsafe` block in a safe API.
+    println!("\n--- 3.1 Exercise: Reimplementing `split_at_mut` ---");
+
+    let mut letters = ['a', 'b', 'c', 'd', 'e'];
+    let (first_two, rest) = my_split_at_mut(&mut letters, 2);
+    first_two[0] = 'A';
+    rest[2] = 'E';
+    assert_eq!(first_two, ['A', 'b']);
+    assert_eq!(rest, ['c', 'd', 'E']);
+
+    // Edge cases: splitting at 0 or at len gives one empty half.
+    let (empty, all) = my_split_at_mut(&mut letters, 0);
+    assert!(empty.is_empty());
+    assert_eq!(all.len(), 5);
+    let (all, empty) = my_split_at_mut(&mut letters, 5);
+    assert_eq!(all.len(), 5);
+    assert!(empty.is_empty());
+    println!("my_split_at_mut result: {:?}", letters);
+
+    // Out of range must panic, like the standard version, never hand out bad memory.
+    let out_of_range = std::panic::catch_unwind(|| {
+        let mut small = [1, 2];
+        let _ = my_split_at_mut(&mut small, 3);
+    });
+    assert!(out_of_range.is_err());
+
+    // -------------------------------------------------------------------------
+    // 4. Chunks and Windows
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Chunks and Windows ---");
+
+    let readings = [3, 1, 4, 1, 5, 9, 2, 6];
+
+    // `chunks(n)`: non-overlapping pieces; the last one may be shorter.
+    for chunk in readings.chunks(3) {
+        println!("chunk: {:?}", chunk);
+    }
+    // `chunks_exact(n)`: every piece has exactly n elements; leftovers are in `remainder()`.
+    let exact = readings.chunks_exact(3);
+    println!("chunks_exact(3) remainder: {:?}", exact.remainder());
+    assert_eq!(exact.count(), 2);
+
+    // `windows(n)`: every overlapping run of n consecutive elements.
+    let moving_sums: Vec<i32> = readings.windows(3).map(|w| w.iter().sum()).collect();
+    println!("Moving sums of 3: {:?}", moving_sums);
+    assert_eq!(moving_sums, [8, 6, 10, 15, 16, 17]);
+    let rising = readings.windows(2).filter(|pair| pair[1] > pair[0]).count();
+    println!("Number of increases: {}", rising);
+    assert_eq!(rising, 4);
+
+    // `chunks_mut`: process pieces in place, e.g. swap bytes in 2-byte words.
+    let mut words = [0x12u8, 0x34, 0xAB, 0xCD];
+    for pair in words.chunks_exact_mut(2) {
+        pair.swap(0, 1);
+    }
+    assert_eq!(words, [0x34, 0x12, 0xCD, 0xAB]);
+
+    // -------------------------------------------------------------------------
+    // 5. In-Place Partitioning
+    // -------------------------------------------------------------------------
+    // Rearranging a slice without allocating a new one.
+
+    println!("\n--- 5. In-Place Partitioning ---");
+
+    let mut numbers = [7, 2, 9, 4, 1, 8, 6];
+    let evens = partition_in_place(&mut numbers, |n| n % 2 == 0);
+    let (even_part, odd_part) = numbers.split_at(evens);
+    println!("Evens: {:?}, odds: {:?}", even_part, odd_part);
+    assert_eq!(evens, 4);
+    assert!(even_part.iter().all(|n| n % 2 == 0));
+    assert!(odd_part.iter().all(|n| n % 2 == 1));
+
+    let mut nothing: [i32; 0] = [];
+    assert_eq!(partition_in_place(&mut nothing, |_| true), 0);
+
+    // The standard library has related tools:
+    // - `select_nth_unstable(k)`: puts the k-th smallest at index k, smaller ones before it
+    // - `retain` on `Vec` (keeps order, shrinks the Vec)
+    // - `partition_point` on sorted slices: index of the first element failing the predicate
+    let mut latencies = [120, 15, 300, 42, 87, 9, 230];
+    let (_, median, _) = latencies.select_nth_unstable(3);
+    println!("Median latency: {}", median);
+    assert_eq!(*median, 87);
+
+    let sorted = [1, 3, 5, 8, 10, 12];
+    assert_eq!(sorted.partition_point(|&n| n < 8), 3);
+
+    // -------------------------------------------------------------------------
+    // 6. Chunked vs. Per-Element Processing
+    // -------------------------------------------------------------------------
+    // A rough timing comparison. Build with `--release` for meaningful numbers;
+    // in debug builds neither version is optimized. (For real measurements,
+    // use a benchmarking crate such as `criterion`.)
+
+    println!("\n--- 6. Chunked vs. Per-Element Processing ---");
+
+    let values: Vec<u32> = (0..1_000_003u32)
+        .map(|i| i.wrapping_mul(2_654_435_761))
+        .collect();
+    assert_eq!(sum_per_element(&values), sum_chunked(&values)); // Same answer first
+    assert_eq!(sum_chunked(&values[..5]), sum_per_element(&values[..5])); // Remainder only
+
+    let rounds = 20;
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(sum_per_element(black_box(&values))); // `black_box` stops the
+    } // compiler from skipping work whose result is unused
+    let per_element_time = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        black_box(sum_chunked(black_box(&values)));
+    }
+    let chunked_time = start.elapsed();
+
+    println!(
+        "Per-element: {} for {} rounds",
+        human_duration(per_element_time),
+        rounds
+    );
+    println!(
+        "Chunked (8 lanes): {} for {} rounds",
+        human_duration(chunked_time),
+        rounds
+    );
+    // Typical result: in debug builds the chunked version is *slower* (more
+    // bookkeeping, nothing vectorized); in release builds the two are close,
+    // because wrapping integer addition may be reordered, so the optimizer
+    // vectorizes the simple loop too. Explicit lanes pay off when it can't do
+    // that on its own, e.g. for `f32`/`f64` sums, whose result depends on order.
+
+    // -------------------------------------------------------------------------
+    // 7. Slice Patterns
+    // -------------------------------------------------------------------------
+    // `match` can look inside a slice: fixed positions, `..` for "any number of
+    // elements", and `name @ ..` to bind that middle part as a sub-slice.
+    // (The pattern matching lesson covers the rest of the pattern syntax.)
+
+    println!("\n--- 7. Slice Patterns ---");
+
+    // Recursion over a slice, the way you'd write it for a list.
+    fn sum(values: &[i32]) -> i32 {
+        match values {
+            [] => 0,
+            [first, rest @ ..] => first + sum(rest),
+        }
+    }
+    assert_eq!(sum(&[1, 2, 3, 4]), 10);
+    assert_eq!(sum(&[]), 0);
+
+    // Patterns can pin down both ends and keep the middle.
+    fn strip_brackets<'a>(tokens: &'a [&'a str]) -> &'a [&'a str] {
+        match tokens {
+            ["[", inner @ .., "]"] => inner,
+            _ => tokens,
+        }
+    }
+    assert_eq!(strip_brackets(&["[", "a", "b", "]"]), ["a", "b"]);
+    assert_eq!(strip_brackets(&["a", "]"]), ["a", "]"]);
+
+    // Literals, lengths and bindings together make a tiny command parser.
+    fn run(command: &[&str]) -> String {
+        match command {
+            ["get", key] => format!("GET {}", key),
+            ["set", key, value] => format!("SET {}={}", key, value),
+            ["del", keys @ ..] if !keys.is_empty() => format!("DEL {}", keys.join(",")),
+            [name, ..] => format!("unknown command '{}'", name),
+            [] => String::from("empty command"),
+        }
+    }
+    for line in [
+        "get color",
+        "set color blue",
+        "del a b c",
+        "del",
+        "ping",
+        "",
+    ] {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        println!("{:>16} -> {}", format!("{:?}", line), run(&words));
+    }
+    assert_eq!(run(&["set", "x", "1"]), "SET x=1");
+    assert_eq!(run(&["del"]), "unknown command 'del'"); // The guard failed
+    assert_eq!(run(&[]), "empty command");
+
+    // Through `&mut [T]`, the bindings are `&mut T` and can modify the slice.
+    let mut ends = vec![1, 2, 3, 4];
+    if let [first, .., last] = ends.as_mut_slice() {
+        std::mem::swap(first, last);
+    }
+    assert_eq!(ends, [4, 2, 3, 1]);
+
+    // On an *array* the length is known, so an exact pattern is irrefutable...
+    let [red, green, blue] = [255u8, 128, 0];
+    assert_eq!((red, green, blue), (255, 128, 0));
+    // ...while on a slice it needs `if let`/`let else`, because the length may differ.
+    let pixel: &[u8] = &[10, 20, 30];
+    let [r, g, b] = pixel else {
+        panic!("expected exactly 3 channels");
+    };
+    assert_eq!(*r as u32 + *g as u32 + *b as u32, 60);
+
+    /*
+    fn first_two(values: &[i32]) -> i32 {
+        match values {
+            [a, b, ..] => a + b,
+            [a] => *a,
+        }
+        // error[E0004]: non-exhaustive patterns: `&[]` not covered
+    }
+    */
+
+    // -------------------------------------------------------------------------
+    // 8. Sorting and Searching
+    // -------------------------------------------------------------------------
+    // All of these are methods on slices, so they work on arrays, `Vec`s and
+    // sub-ranges alike.
+    // - `sort`/`sort_by_key`: stable, equal elements keep their order
+    // - `sort_unstable*`: faster, no allocation, equal elements may be reordered
+    // - `binary_search*`: O(log n), but only correct on sorted input
+    // How these algorithms work inside: see 52-sorting-and-searching.rs.
+
+    println!("\n--- 8. Sorting and Searching ---");
+
+    let mut people = vec![("Ada", 36), ("Linus", 21), ("Grace", 36), ("Alan", 41)];
+
+    // Stable sort by age: Ada stays before Grace because she came first.
+    people.sort_by_key(|&(_, age)| age);
+    println!("By age: {:?}", people);
+    assert_eq!(
+        people,
+        [("Linus", 21), ("Ada", 36), ("Grace", 36), ("Alan", 41)]
+    );
+
+    // Several keys: oldest first, then by name. `Ordering::then_with` chains
+    // comparisons; `Reverse` flips one key inside `sort_by_key`.
+    people.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    assert_eq!(people[..3], [("Alan", 41), ("Ada", 36), ("Grace", 36)]);
+    people.sort_by_key(|&(name, age)| (std::cmp::Reverse(age), name));
+    assert_eq!(people[0], ("Alan", 41));
+
+    // Floats aren't `Ord` (because of NaN), so sort them with `total_cmp`.
+    // See the ordering-and-floats lesson for why.
+    let mut readings = [2.5, -1.0, 9.75, 0.0];
+    readings.sort_by(f64::total_cmp);
+    assert_eq!(readings, [-1.0, 0.0, 2.5, 9.75]);
+
+    // `binary_search` returns `Ok(index)` if found, or `Err(index)` where the
+    // value would have to be inserted to keep the slice sorted.
+    let mut sorted_ids = vec![3, 8, 15, 23, 42];
+    assert_eq!(sorted_ids.binary_search(&23), Ok(3));
+    assert_eq!(sorted_ids.binary_search(&10), Err(2));
+    if let Err(pos) = sorted_ids.binary_search(&10) {
+        sorted_ids.insert(pos, 10); // Insert without breaking the order
+    }
+    assert!(sorted_ids.is_sorted());
+    println!("After sorted insert: {:?}", sorted_ids);
+
+    // Searching by a field of sorted records.
+    let by_age = [("Linus", 21), ("Ada", 36), ("Alan", 41)];
+    assert_eq!(by_age.binary_search_by_key(&36, |&(_, age)| age), Ok(1));
+
+    // On unsorted data, fall back to a linear search.
+    let unsorted = [42, 7, 19, 7];
+    assert_eq!(unsorted.iter().position(|&n| n == 7), Some(1));
+    assert_eq!(unsorted.iter().rposition(|&n| n == 7), Some(3));
+    // `binary_search` on unsorted input doesn't panic; its answer is just meaningless.
+
+    // Sorting first also makes removing duplicates easy: `dedup` only removes
+    // *consecutive* repeats.
+    let mut tags = vec!["rust", "web", "rust", "cli", "web"];
+    tags.sort_unstable();
+    tags.dedup();
+    assert_eq!(tags, ["cli", "rust", "web"]);
+
+    println!("\n--- End of Slices Examples ---");
+}
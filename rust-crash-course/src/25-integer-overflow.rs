@@ -0,0 +1,225 @@
+// This file covers integer overflow: what happens when arithmetic goes past
+// the limits of a fixed-size integer type, how that differs between debug and
+// release builds, and the explicit `checked_*`, `wrapping_*`, `saturating_*`
+// and `overflowing_*` methods that let you choose the behaviour yourself.
+// It ends with a safe percentage helper that never overflows or divides by zero.
+
+use std::hint::black_box;
+
+// A percentage (0..=100) of `done` out of `total`, rounded down.
+// - `total == 0` would divide by zero: report `None` instead of panicking.
+// - `done * 100` can overflow for large counts: widen to `u128` first, where
+//   `u64::MAX * 100` fits easily.
+// - `done > total` is clamped to 100 rather than reporting e.g. 250%.
+fn percent(done: u64, total: u64) -> Option<u8> {
+    if total == 0 {
+        return None;
+    }
+    let ratio = (done as u128 * 100) / total as u128;
+    Some(ratio.min(100) as u8)
+}
+
+// A ratio scaled by `scale` (e.g. per-mille with 1000), using checked
+// arithmetic all the way through, so every failure mode shows up as `None`.
+fn checked_ratio(part: u32, whole: u32, scale: u32) -> Option<u32> {
+    part.checked_mul(scale)?.checked_div(whole)
+}
+
+fn main() {
+    println!("--- Integer Overflow and Checked Arithmetic ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Integer Limits
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Integer Limits ---");
+
+    println!("u8:  {} ..= {}", u8::MIN, u8::MAX);
+    println!("i8:  {} ..= {}", i8::MIN, i8::MAX);
+    println!("i32: {} ..= {}", i32::MIN, i32::MAX);
+    println!("u64: {} ..= {}", u64::MIN, u64::MAX);
+
+    // Overflow that the compiler can see is a hard error (the `arithmetic_overflow` lint):
+    /*
+    let too_big: u8 = 255 + 1; // error: this arithmetic operation will overflow
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. Debug vs. Release Behaviour
+    // -------------------------------------------------------------------------
+    // When the values are only known at runtime:
+    // - Debug builds (`cargo run`): overflow *panics* ("attempt to add with overflow").
+    // - Release builds (`cargo run --release`): overflow *wraps* silently
+    //   (255u8 + 1 == 0), because the checks cost performance.
+    // Neither is what you usually want, which is why the explicit methods below exist.
+    // (`overflow-checks = true` under `[profile.release]` in Cargo.toml keeps
+    // the panics in release builds too.)
+
+    println!("\n--- 2. Debug vs. Release Behaviour ---");
+
+    let build = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    println!("This is a {} build", build);
+
+    // `black_box` hides the value from the optimizer, so this is a runtime overflow.
+    let counter: u8 = black_box(255);
+    let result = std::panic::catch_unwind(|| counter + 1);
+    match result {
+        Ok(value) => println!("255u8 + 1 wrapped to {}", value), // Release (by default)
+        Err(_) => println!("255u8 + 1 panicked"),                // Debug (message printed above)
+    }
+
+    // -------------------------------------------------------------------------
+    // 3. `checked_*`: Get `None` on Overflow
+    // -------------------------------------------------------------------------
+    // Use when overflow means the input was invalid and you want to handle it.
+
+    println!("\n--- 3. `checked_*` ---");
+
+    println!("250u8.checked_add(5): {:?}", 250u8.checked_add(5)); // Some(255)
+    println!("250u8.checked_add(6): {:?}", 250u8.checked_add(6)); // None
+    println!("0u32.checked_sub(1): {:?}", 0u32.checked_sub(1)); // None: no negative u32
+    println!("10i32.checked_div(0): {:?}", 10i32.checked_div(0)); // None, not a panic
+    // The one signed-division overflow: -128 / -1 would be +128, which doesn't fit in i8.
+    println!("i8::MIN.checked_div(-1): {:?}", i8::MIN.checked_div(-1)); // None
+    assert_eq!(250u8.checked_add(5), Some(u8::MAX));
+    assert_eq!(250u8.checked_add(6), None);
+    assert_eq!(i8::MIN.checked_div(-1), None);
+    assert_eq!(i32::MIN.checked_abs(), None); // |i32::MIN| doesn't fit either
+
+    // `?` makes chains of checked operations short:
+    fn total_price(unit_cents: u32, quantity: u32, shipping_cents: u32) -> Option<u32> {
+        unit_cents
+            .checked_mul(quantity)?
+            .checked_add(shipping_cents)
+    }
+    assert_eq!(total_price(1_999, 3, 500), Some(6_497));
+    assert_eq!(total_price(u32::MAX / 2, 3, 0), None);
+
+    // -------------------------------------------------------------------------
+    // 4. `wrapping_*`: Modular Arithmetic on Purpose
+    // -------------------------------------------------------------------------
+    // Use when wrapping around *is* the intended behaviour: hashes, checksums,
+    // ring-buffer indices, sequence numbers.
+
+    println!("\n--- 4. `wrapping_*` ---");
+
+    println!("255u8.wrapping_add(1): {}", 255u8.wrapping_add(1)); // 0
+    println!("0u8.wrapping_sub(1): {}", 0u8.wrapping_sub(1)); // 255
+    println!("i8::MAX.wrapping_add(1): {}", i8::MAX.wrapping_add(1)); // -128
+    assert_eq!(255u8.wrapping_add(1), 0);
+    assert_eq!(i8::MAX.wrapping_add(1), i8::MIN);
+
+    // A tiny checksum: the byte sum modulo 256.
+    let checksum = b"hello".iter().fold(0u8, |acc, &b| acc.wrapping_add(b));
+    println!("Checksum of \"hello\": {}", checksum);
+    assert_eq!(checksum, 532u32 as u8); // 104+101+108+108+111 = 532, mod 256 = 20
+
+    // `std::num::Wrapping<T>` makes the normal operators wrap.
+    use std::num::Wrapping;
+    let sequence = Wrapping(u16::MAX) + Wrapping(2);
+    assert_eq!(sequence.0, 1);
+
+    // -------------------------------------------------------------------------
+    // 5. `saturating_*`: Clamp at the Limits
+    // -------------------------------------------------------------------------
+    // Use when "as far as it goes" is the right answer: volume, health points,
+    // retry counters, progress bars.
+
+    println!("\n--- 5. `saturating_*` ---");
+
+    println!("250u8.saturating_add(10): {}", 250u8.saturating_add(10)); // 255
+    println!("5u32.saturating_sub(10): {}", 5u32.saturating_sub(10)); // 0
+    println!("i32::MIN.saturating_sub(1): {}", i32::MIN.saturating_sub(1)); // i32::MIN
+    assert_eq!(250u8.saturating_add(10), u8::MAX);
+    assert_eq!(5u32.saturating_sub(10), 0);
+    assert_eq!(i32::MIN.saturating_sub(1), i32::MIN);
+    assert_eq!(i8::MIN.saturating_mul(-1), i8::MAX);
+
+    let mut stock: u32 = 3;
+    stock = stock.saturating_sub(5); // Never "goes negative" (or wraps to ~4 billion)
+    assert_eq!(stock, 0);
+
+    // -------------------------------------------------------------------------
+    // 6. `overflowing_*`: The Wrapped Result *and* a Flag
+    // -------------------------------------------------------------------------
+    // Use when you need both, e.g. to implement arithmetic on bigger numbers
+    // from smaller pieces (the flag is the carry).
+
+    println!("\n--- 6. `overflowing_*` ---");
+
+    println!(
+        "200u8.overflowing_add(100): {:?}",
+        200u8.overflowing_add(100) // (44, true)
+    );
+    println!("200u8.overflowing_add(50): {:?}", 200u8.overflowing_add(50)); // (250, false)
+    assert_eq!(200u8.overflowing_add(100), (44, true));
+    assert_eq!(200u8.overflowing_add(50), (250, false));
+
+    // Adding two 128-bit numbers stored as (high, low) pairs of u64.
+    fn add_u128_parts(a: (u64, u64), b: (u64, u64)) -> (u64, u64) {
+        let (low, carry) = a.1.overflowing_add(b.1);
+        let high = a.0.wrapping_add(b.0).wrapping_add(carry as u64);
+        (high, low)
+    }
+    let sum = add_u128_parts((0, u64::MAX), (0, 1));
+    assert_eq!(sum, (1, 0)); // The carry moved into the high half
+    assert_eq!(
+        ((sum.0 as u128) << 64) | sum.1 as u128,
+        u64::MAX as u128 + 1
+    );
+
+    // -------------------------------------------------------------------------
+    // 7. Conversions Between Integer Types
+    // -------------------------------------------------------------------------
+    // `as` never fails: it truncates or reinterprets bits. `try_from` checks.
+
+    println!("\n--- 7. Conversions Between Integer Types ---");
+
+    let big: i32 = 300;
+    println!("300i32 as u8: {}", big as u8); // 44: silently truncated
+    println!("-1i32 as u32: {}", -1i32 as u32); // 4294967295: bits reinterpreted
+    println!("u8::try_from(300i32): {:?}", u8::try_from(big)); // Err(TryFromIntError(()))
+    assert_eq!(big as u8, 44);
+    assert!(u8::try_from(big).is_err());
+    assert_eq!(u8::try_from(200i32), Ok(200));
+    assert!(u32::try_from(-1i32).is_err());
+
+    // -------------------------------------------------------------------------
+    // 8. A Safe Percentage Helper
+    // -------------------------------------------------------------------------
+    // Progress reports like "3 of 12 lessons done (25%)" look trivial, but the
+    // naive `done * 100 / total` panics when `total` is 0 and overflows for
+    // large counts. `percent` and `checked_ratio` (defined above `main`) don't.
+
+    println!("\n--- 8. A Safe Percentage Helper ---");
+
+    let lessons_done = 3;
+    let lessons_total = 12;
+    if let Some(p) = percent(lessons_done, lessons_total) {
+        println!(
+            "{} of {} lessons done ({}%)",
+            lessons_done, lessons_total, p
+        );
+    }
+
+    // Boundary values
+    assert_eq!(percent(0, 12), Some(0));
+    assert_eq!(percent(12, 12), Some(100));
+    assert_eq!(percent(1, 3), Some(33)); // Rounded down
+    assert_eq!(percent(0, 0), None); // No division by zero
+    assert_eq!(percent(5, 2), Some(100)); // Clamped
+    assert_eq!(percent(u64::MAX, u64::MAX), Some(100)); // `u64::MAX * 100` would overflow a u64
+    assert_eq!(percent(u64::MAX / 2, u64::MAX), Some(49));
+    assert_eq!(percent(1, u64::MAX), Some(0));
+
+    assert_eq!(checked_ratio(7, 8, 1000), Some(875)); // Per-mille
+    assert_eq!(checked_ratio(1, 0, 1000), None); // Division by zero
+    assert_eq!(checked_ratio(u32::MAX, 1, 1000), None); // Multiplication overflow
+    println!("percent() and checked_ratio() passed all boundary checks");
+
+    println!("\n--- End of Integer Overflow Examples ---");
+}
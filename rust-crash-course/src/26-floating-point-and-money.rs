@@ -0,0 +1,204 @@
+// This file covers comparing floating-point numbers and representing money.
+// It explains why `0.1 + 0.2 != 0.3`, builds `approx_eq` helpers using an
+// absolute epsilon, a relative epsilon, and ULP distance, and then sidesteps
+// the problem for prices with a fixed-point `Money` type stored as integer cents.
+
+#[path = "lessons/money.rs"]
+mod money;
+
+use money::{Money, MoneyError};
+
+// -------------------------------------------------------------------------
+// 0. Why `0.1 + 0.2 != 0.3`
+// -------------------------------------------------------------------------
+// `f64` stores numbers in binary. Just as 1/3 has no exact decimal form
+// (0.3333...), 1/10 has no exact binary form, so `0.1` is stored as the
+// nearest representable value. The tiny errors of `0.1` and `0.2` add up to
+// a result that is one step away from the value stored for `0.3`.
+
+// Absolute tolerance: fine when you know the scale of the values (e.g. 0..1).
+fn approx_eq_abs(a: f64, b: f64, epsilon: f64) -> bool {
+    (a - b).abs() <= epsilon
+}
+
+// Relative tolerance: scales with the size of the inputs, so it works for
+// both 0.000001 and 1_000_000.0. Near zero it is useless: nothing but 0.0
+// itself is within any relative tolerance of 0.0. Use `approx_eq` there.
+fn approx_eq_rel(a: f64, b: f64, relative: f64) -> bool {
+    if a == b {
+        return true; // Also covers infinities of the same sign
+    }
+    let diff = (a - b).abs();
+    let largest = a.abs().max(b.abs());
+    diff <= largest * relative
+}
+
+// Both: equal if *either* tolerance is met. The absolute one handles values
+// near zero (such as the result of a subtraction that should cancel out),
+// the relative one handles everything else.
+fn approx_eq(a: f64, b: f64, absolute: f64, relative: f64) -> bool {
+    approx_eq_abs(a, b, absolute) || approx_eq_rel(a, b, relative)
+}
+
+// ULP ("units in the last place") distance: how many representable `f64`
+// values lie between `a` and `b`. Finite floats of the same sign are ordered
+// like their bit patterns, so this maps each float onto an integer line
+// (negative values mirrored below zero) and subtracts.
+fn ulp_distance(a: f64, b: f64) -> Option<u64> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    fn to_ordered(x: f64) -> i64 {
+        let bits = x.to_bits() as i64;
+        if bits < 0 { i64::MIN - bits } else { bits }
+    }
+    Some(to_ordered(a).abs_diff(to_ordered(b)))
+}
+
+fn approx_eq_ulps(a: f64, b: f64, max_ulps: u64) -> bool {
+    ulp_distance(a, b).is_some_and(|distance| distance <= max_ulps)
+}
+
+fn main() {
+    println!("--- Floating-Point Comparison and a Money Type ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Problem
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. The Problem ---");
+
+    let sum = 0.1 + 0.2;
+    println!("0.1 + 0.2 = {}", sum); // 0.30000000000000004
+    println!("0.1 + 0.2 == 0.3? {}", sum == 0.3); // false
+    println!("Stored 0.1 is really {:.20}", 0.1f64); // 0.10000000000000000555
+    assert_ne!(sum, 0.3);
+
+    // Errors accumulate: adding ten cents ten times doesn't give exactly one dollar.
+    let mut total = 0.0;
+    for _ in 0..10 {
+        total += 0.1;
+    }
+    println!("0.1 added 10 times = {}", total); // 0.9999999999999999
+    assert_ne!(total, 1.0);
+
+    // -------------------------------------------------------------------------
+    // 2. Approximate Equality
+    // -------------------------------------------------------------------------
+    // Compare with a tolerance instead of `==`. Which tolerance depends on the data.
+
+    println!("\n--- 2. Approximate Equality ---");
+
+    // a. Absolute epsilon: good for values of a known, modest size.
+    assert!(approx_eq_abs(sum, 0.3, 1e-9));
+    assert!(approx_eq_abs(total, 1.0, 1e-9));
+    // ...but far too strict for big numbers, whose spacing is larger than epsilon:
+    let big = 1e16;
+    assert!(!approx_eq_abs(big + 2.0, big, 1e-9)); // Neighbouring floats, yet "not equal"
+    // ...and far too loose for tiny ones:
+    assert!(approx_eq_abs(1e-12, 2e-12, 1e-9)); // One is twice the other, yet "equal"
+
+    // b. Relative epsilon: the tolerance grows with the magnitude.
+    assert!(approx_eq_rel(sum, 0.3, 1e-12));
+    assert!(approx_eq_rel(big + 2.0, big, 1e-12));
+    assert!(!approx_eq_rel(1e-12, 2e-12, 1e-12));
+    assert!(approx_eq_rel(0.0, -0.0, 1e-12));
+    assert!(!approx_eq_rel(f64::NAN, f64::NAN, 1e-12)); // NaN is never equal
+    let cancelled = 0.1 + 0.2 - 0.3; // 5.55e-17, not 0.0
+    assert!(!approx_eq_rel(cancelled, 0.0, 1e-12)); // Relative fails near zero...
+    assert!(approx_eq(cancelled, 0.0, 1e-12, 1e-12)); // ...the absolute part catches it
+    assert!(approx_eq(big + 2.0, big, 1e-12, 1e-12));
+    assert!(!approx_eq(1.0, 1.0001, 1e-12, 1e-12));
+
+    // c. ULPs: "at most N representable values apart", independent of scale.
+    println!(
+        "ULPs between 0.1 + 0.2 and 0.3: {:?}",
+        ulp_distance(sum, 0.3)
+    ); // Some(1)
+    assert_eq!(ulp_distance(sum, 0.3), Some(1));
+    assert_eq!(ulp_distance(1.0, 1.0), Some(0));
+    assert_eq!(ulp_distance(0.0, -0.0), Some(0)); // +0 and -0 are the same point
+    assert_eq!(ulp_distance(f64::NAN, 1.0), None);
+    assert!(approx_eq_ulps(sum, 0.3, 4));
+    assert!(approx_eq_ulps(big + 2.0, big, 4));
+    assert!(!approx_eq_ulps(1.0, 1.0001, 4));
+    println!("approx_eq helpers behaved as expected");
+
+    // -------------------------------------------------------------------------
+    // 3. Money as Integer Cents
+    // -------------------------------------------------------------------------
+    // For money, "approximately equal" isn't good enough: a total has to be
+    // exact to the cent. Storing whole cents in an integer makes addition and
+    // multiplication by quantities exact, which is why `Money`
+    // (lessons/money.rs) exists, and why the `Order` example in the data
+    // structures lesson stores its total as `Money` instead of an `f64`.
+
+    println!("\n--- 3. Money as Integer Cents ---");
+
+    let ten_cents = Money::from_cents(10);
+    let dollar: Money = std::iter::repeat_n(ten_cents, 10).sum();
+    println!("10 cents added 10 times = {}", dollar); // $1.00, exactly
+    assert_eq!(dollar, Money::new(1, 0));
+
+    let widget = Money::new(19, 99);
+    let shipping: Money = "4.5".parse().unwrap();
+    let order_total = widget * 3 + shipping;
+    println!("3 x {} + {} shipping = {}", widget, shipping, order_total);
+    assert_eq!(order_total.cents(), 6_447);
+    assert_eq!(order_total.to_string(), "$64.47");
+
+    // Display
+    assert_eq!(Money::from_cents(5).to_string(), "$0.05");
+    assert_eq!((-Money::new(3, 50)).to_string(), "-$3.50");
+    assert_eq!(-Money::new(0, 50), Money::from_cents(-50)); // -$0.50
+    assert_eq!((-Money::new(0, 50)).to_string(), "-$0.50");
+    assert_eq!(Money::from_cents(i64::MIN).cents(), i64::MIN); // Displays without overflow
+    println!("Most negative amount: {}", Money::from_cents(i64::MIN));
+
+    // Parsing never goes through `f64`
+    assert_eq!("150.75".parse::<Money>(), Ok(Money::new(150, 75)));
+    assert_eq!("$20".parse::<Money>(), Ok(Money::new(20, 0)));
+    assert_eq!("-0.5".parse::<Money>(), Ok(-Money::new(0, 50)));
+    assert_eq!("".parse::<Money>(), Err(MoneyError::Empty));
+    assert_eq!(
+        "1.999".parse::<Money>(),
+        Err(MoneyError::TooManyDecimals(String::from("1.999")))
+    );
+    for bad in ["abc", "1.2.3", ".5", "1e3"] {
+        let error = bad.parse::<Money>().unwrap_err();
+        println!("Parsing {:?} failed: {}", bad, error);
+    }
+
+    // Converting from and to `f64` at the edges
+    assert_eq!(Money::from_f64(0.1 + 0.2), Ok(Money::from_cents(30)));
+    assert_eq!(Money::from_f64(f64::NAN), Err(MoneyError::NotFinite));
+    assert_eq!(Money::from_f64(1e300), Err(MoneyError::OutOfRange));
+    assert!(approx_eq_rel(Money::new(150, 75).to_f64(), 150.75, 1e-12));
+
+    // Overflow is detectable
+    assert_eq!(
+        Money::from_cents(i64::MAX).checked_add(Money::from_cents(1)),
+        None
+    );
+    assert_eq!(widget.checked_mul(3), Some(Money::from_cents(5_997)));
+
+    // Splitting a bill: no cent is lost or invented.
+    let bill = Money::new(100, 0);
+    let shares = bill.split(3);
+    println!(
+        "{} split 3 ways: {}",
+        bill,
+        shares
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    assert_eq!(shares.iter().copied().sum::<Money>(), bill);
+    assert_eq!(bill - shares[0], shares[1] + shares[2]);
+
+    // For interest rates, taxes and currency conversion you also need explicit
+    // rounding rules; crates like `rust_decimal` provide a full decimal type.
+
+    println!("\n--- End of Floating-Point and Money Examples ---");
+}
@@ -0,0 +1,207 @@
+// This file generalizes the `find_first_vowel` example from the Options lesson
+// into a small set of character classification utilities: predicates for
+// vowels, consonants, digits and punctuation that also understand accented
+// letters, a `classify` function, and `char_indices`-based span reporting that
+// tells you *where* in a string each kind of character occurs.
+
+use std::ops::Range;
+
+#[path = "lessons/text.rs"]
+mod text;
+
+use text::{CharClass, classify, is_digit};
+
+// -------------------------------------------------------------------------
+// 0. Characters Are More Than ASCII
+// -------------------------------------------------------------------------
+// `"aeiouAEIOU".contains(c)` misses 'é', 'Ö' or 'ı'. Two ways to handle them:
+// - Unicode normalization (NFD) splits 'é' into 'e' + a combining accent
+//   (U+0301), after which the base letter can be checked. This needs the
+//   `unicode-normalization` crate (see section 4).
+// - A fold table mapping common accented Latin letters to their base letter:
+//   no dependencies, but only the letters listed.
+// `classify` and the predicates behind it live in lessons/text.rs. Its
+// `base_letter` uses NFD with the `ecosystem` feature and the fold table
+// without it (and for letters NFD doesn't split, like 'ø').
+
+// The generalized `find_first_vowel`: the first character of any class,
+// together with its *byte* offset, so the caller can slice the string there.
+fn find_first(s: &str, class: CharClass) -> Option<(usize, char)> {
+    s.char_indices().find(|&(_, c)| classify(c) == class)
+}
+
+fn find_first_vowel(s: &str) -> Option<char> {
+    find_first(s, CharClass::Vowel).map(|(_, c)| c)
+}
+
+// Splits `s` into runs of characters of the same class, reported as byte
+// ranges. Every byte of `s` is covered by exactly one span, and every range
+// starts and ends on a char boundary, so `&s[range]` never panics.
+fn class_spans(s: &str) -> Vec<(Range<usize>, CharClass)> {
+    let mut spans: Vec<(Range<usize>, CharClass)> = Vec::new();
+    for (start, c) in s.char_indices() {
+        let class = classify(c);
+        let end = start + c.len_utf8();
+        match spans.last_mut() {
+            Some((range, last_class)) if *last_class == class => range.end = end,
+            _ => spans.push((start..end, class)),
+        }
+    }
+    spans
+}
+
+fn main() {
+    println!("--- Character Classification ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Classification Predicates
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Classification Predicates ---");
+
+    for c in ['a', 'É', 'ö', 'b', 'Ç', '7', '!', '¿', ' ', 'ж', '🦀'] {
+        println!("{:?} -> {:?}", c, classify(c));
+    }
+    assert_eq!(classify('É'), CharClass::Vowel);
+    assert_eq!(classify('Ç'), CharClass::Consonant);
+    assert_eq!(classify('¿'), CharClass::Punctuation);
+    assert_eq!(classify('\t'), CharClass::Whitespace);
+    assert_eq!(classify('ж'), CharClass::Other); // Cyrillic: not covered by the Latin table
+    assert!(!is_digit('²') && '²'.is_numeric()); // Superscript two is "numeric", not a digit
+
+    // -------------------------------------------------------------------------
+    // 2. A Better `find_first_vowel`
+    // -------------------------------------------------------------------------
+    // The Options lesson checks `"aeiouAEIOU".contains(c)`. With `classify`,
+    // accented vowels count too, and `find_first` works for any class.
+
+    println!("\n--- 2. A Better `find_first_vowel` ---");
+
+    let ascii_only = |s: &str| s.chars().find(|&c| "aeiouAEIOU".contains(c));
+    for word in ["hello", "rhythm", "élan", "Øresund", "crème"] {
+        println!(
+            "{:>8}: ASCII-only {:?}, classify-based {:?}",
+            word,
+            ascii_only(word),
+            find_first_vowel(word)
+        );
+    }
+    assert_eq!(find_first_vowel("hello"), Some('e'));
+    assert_eq!(find_first_vowel("rhythm"), None);
+    assert_eq!(ascii_only("élan"), Some('a')); // Skips the 'é'
+    assert_eq!(find_first_vowel("élan"), Some('é'));
+    assert_eq!(find_first_vowel("Øresund"), Some('Ø'));
+
+    // Byte offsets come from `char_indices`, so slicing at them is always safe.
+    let sentence = "Größe: 42 cm";
+    let (digit_at, _) = find_first(sentence, CharClass::Digit).unwrap();
+    println!(
+        "First digit of {:?} at byte {}: {:?}",
+        sentence,
+        digit_at,
+        &sentence[digit_at..]
+    );
+    assert_eq!(digit_at, 9); // Not 7: 'ö' and 'ß' take two bytes each
+    assert_eq!(&sentence[digit_at..], "42 cm");
+    assert_eq!(find_first(sentence, CharClass::Punctuation), Some((7, ':')));
+
+    // -------------------------------------------------------------------------
+    // 3. Span Reporting
+    // -------------------------------------------------------------------------
+    // Runs of the same class, with byte ranges into the original string.
+
+    println!("\n--- 3. Span Reporting ---");
+
+    let samples = [
+        "Hello, world!",
+        "¿Qué tal? 3 días…",
+        "Øl koster 45 kr.",
+        "Привет, мир 2024",
+        "Rust 🦀 ist toll",
+    ];
+
+    for sample in samples {
+        let spans = class_spans(sample);
+        println!("{:?}", sample);
+        for (range, class) in &spans {
+            println!(
+                "  {:>2}..{:<2} {:<11} {:?}",
+                range.start,
+                range.end,
+                format!("{:?}", class),
+                &sample[range.clone()]
+            );
+        }
+
+        // The spans cover the string exactly, in order, with no gaps or overlaps.
+        let mut expected_start = 0;
+        for (range, _) in &spans {
+            assert_eq!(range.start, expected_start);
+            assert!(sample.is_char_boundary(range.end));
+            expected_start = range.end;
+        }
+        assert_eq!(expected_start, sample.len());
+        let rebuilt: String = spans
+            .iter()
+            .map(|(range, _)| &sample[range.clone()])
+            .collect();
+        assert_eq!(rebuilt, sample);
+        // Neighbouring spans always have different classes.
+        assert!(spans.windows(2).all(|pair| pair[0].1 != pair[1].1));
+    }
+
+    let spans = class_spans("¿Qué tal?");
+    assert_eq!(spans[0], (0..2, CharClass::Punctuation)); // '¿' is 2 bytes
+    assert_eq!(spans[1], (2..3, CharClass::Consonant)); // 'Q'
+    assert_eq!(spans[2], (3..6, CharClass::Vowel)); // "ué" ('é' is 2 bytes)
+    assert!(class_spans("").is_empty());
+
+    let vowel_count = class_spans("Øl koster 45 kr.")
+        .iter()
+        .filter(|(_, class)| *class == CharClass::Vowel)
+        .map(|(range, _)| "Øl koster 45 kr."[range.clone()].chars().count())
+        .sum::<usize>();
+    assert_eq!(vowel_count, 3); // Ø, o, e
+
+    // -------------------------------------------------------------------------
+    // 4. Full Unicode Support with Normalization
+    // -------------------------------------------------------------------------
+    // The fold table only knows the letters it lists, and it only handles
+    // *precomposed* characters. "e\u{301}" (an 'e' followed by a combining
+    // acute accent) looks like 'é' but is two `char`s:
+    let decomposed = "e\u{301}lan";
+    println!("\n--- 4. Full Unicode Support with Normalization ---");
+    println!("{:?} has {} chars", decomposed, decomposed.chars().count()); // 5, not 4
+    assert_eq!(classify('\u{301}'), CharClass::Other); // The accent on its own
+
+    // The `unicode-normalization` crate decomposes any accented letter
+    // Unicode knows. It's an optional dependency, so this part runs with
+    // `cargo run --features ecosystem --bin 27-char-classification`; without
+    // the feature, `base_letter` falls back to the fold table alone.
+    let vietnamese = 'ế'; // 'e' with a circumflex *and* an acute accent
+    #[cfg(feature = "ecosystem")]
+    {
+        use text::strip_accents;
+
+        println!("{:?} -> {:?} (NFD)", vietnamese, classify(vietnamese));
+        assert_eq!(classify(vietnamese), CharClass::Vowel);
+        assert_eq!(classify('Ǹ'), CharClass::Consonant); // Not in the table either
+        assert_eq!(classify('ø'), CharClass::Vowel); // No decomposition: the table
+        assert_eq!(strip_accents("Crème brûlée"), "Creme brulee");
+        assert_eq!(strip_accents(decomposed), "elan");
+    }
+    #[cfg(not(feature = "ecosystem"))]
+    {
+        println!(
+            "{:?} -> {:?} (fold table)",
+            vietnamese,
+            classify(vietnamese)
+        );
+        assert_eq!(classify(vietnamese), CharClass::Other); // Not listed
+    }
+
+    // For "what the user sees as one character" (e.g. flags or family emoji),
+    // see grapheme clusters in the data structures lesson (section 4f).
+
+    println!("\n--- End of Character Classification Examples ---");
+}
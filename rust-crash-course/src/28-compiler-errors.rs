@@ -0,0 +1,541 @@
+// This file maps the compiler errors beginners meet most often to the lessons
+// that explain them. Each entry in `ERROR_MAP` has the error code, a short
+// explanation, where the topic is taught, and a demo function showing the
+// *fixed* version of the code. The last section reads the JSON messages that
+// `cargo build --message-format=json` prints and annotates each error with
+// the matching lesson.
+
+// -------------------------------------------------------------------------
+// 0. Reading an Error Code
+// -------------------------------------------------------------------------
+// Every rustc error has a code such as `error[E0382]`. The compiler ships a
+// long explanation for each one:
+//
+//   rustc --explain E0382
+//
+// The table below is the short version, plus a pointer into this course.
+
+struct ErrorEntry {
+    code: &'static str,
+    title: &'static str,
+    explanation: &'static str,
+    lesson: &'static str,
+    fixed_demo: fn() -> String,
+}
+
+static ERROR_MAP: &[ErrorEntry] = &[
+    ErrorEntry {
+        code: "E0382",
+        title: "use of moved value",
+        explanation: "A non-`Copy` value was moved (into another variable or a function) and then used again. \
+                      Borrow it with `&` instead, or `.clone()` it if you really need two owners.",
+        lesson: "02-ownership_borrowing.rs, section 2 (Moving Ownership)",
+        fixed_demo: fix_use_after_move,
+    },
+    ErrorEntry {
+        code: "E0499",
+        title: "cannot borrow as mutable more than once at a time",
+        explanation: "Two `&mut` references to the same value were alive at the same time. \
+                      Finish with one before creating the next, or split the data (`split_at_mut`).",
+        lesson: "02-ownership_borrowing.rs, section 4 (Mutable References); 24-slices-and-split-borrows.rs, section 3",
+        fixed_demo: fix_two_mutable_borrows,
+    },
+    ErrorEntry {
+        code: "E0502",
+        title: "cannot borrow as mutable because it is also borrowed as immutable",
+        explanation: "A shared `&` reference was still in use when a `&mut` was taken (e.g. pushing to a Vec \
+                      while holding a reference to one of its elements). Copy the value out or end the shared borrow first.",
+        lesson: "02-ownership_borrowing.rs, section 4 (Mutable References)",
+        fixed_demo: fix_shared_then_mutable,
+    },
+    ErrorEntry {
+        code: "E0597",
+        title: "borrowed value does not live long enough",
+        explanation: "A reference outlives the value it points to (usually a local dropped at the end of a block). \
+                      Move the value to an outer scope, or return an owned value instead of a reference.",
+        lesson: "11-lifetimes.rs, section 0 (The Problem Lifetimes Solve)",
+        fixed_demo: fix_does_not_live_long_enough,
+    },
+    ErrorEntry {
+        code: "E0277",
+        title: "the trait bound is not satisfied",
+        explanation: "A type was used where a trait is required but doesn't implement it, e.g. `{}` on a type \
+                      without `Display`, or an `f64` as a `BTreeMap` key (`Ord`). Implement or derive the trait, or pick another type.",
+        lesson: "12-traits.rs; 23-ordering-and-floats.rs, section 2",
+        fixed_demo: fix_missing_trait,
+    },
+    ErrorEntry {
+        code: "E0106",
+        title: "missing lifetime specifier",
+        explanation: "A function returns a reference (or a struct holds one) and the compiler can't tell which input \
+                      it borrows from. Add a lifetime parameter that ties them together.",
+        lesson: "11-lifetimes.rs, section 3 (Explicit Lifetime Annotations)",
+        fixed_demo: fix_missing_lifetime,
+    },
+    ErrorEntry {
+        code: "E0308",
+        title: "mismatched types",
+        explanation: "An expression has a different type than expected, often a missing return value or a stray `;` \
+                      after the last expression of a function.",
+        lesson: "03-functions.rs",
+        fixed_demo: fix_mismatched_types,
+    },
+];
+
+fn explain(code: &str) -> Option<&'static ErrorEntry> {
+    ERROR_MAP
+        .iter()
+        .find(|entry| entry.code.eq_ignore_ascii_case(code))
+}
+
+// -------------------------------------------------------------------------
+// Fixed Versions of Each Error
+// -------------------------------------------------------------------------
+// Each function shows the failing code in a comment and runs the fixed code.
+// The failing versions also live in tests/compile_fail/ as real files, and
+// `cargo test --test compile_fail` checks that each still fails this way.
+
+fn fix_use_after_move() -> String {
+    /*
+    let name = String::from("Ferris");
+    let greeting = name; // `name` moved here
+    println!("{}", name); // error[E0382]: borrow of moved value: `name`
+    */
+    let name = String::from("Ferris");
+    let greeting = &name; // Borrow instead of move
+    format!("{} / {}", name, greeting)
+}
+
+fn fix_two_mutable_borrows() -> String {
+    /*
+    let mut scores = vec![1, 2];
+    let first = &mut scores[0];
+    let second = &mut scores[1]; // error[E0499]: cannot borrow `scores` as mutable more than once at a time
+    *first += *second;
+    */
+    let mut scores = vec![1, 2];
+    let (left, right) = scores.split_at_mut(1);
+    left[0] += right[0];
+    format!("{:?}", scores)
+}
+
+fn fix_shared_then_mutable() -> String {
+    /*
+    let mut items = vec![10, 20];
+    let first = &items[0];
+    items.push(30); // error[E0502]: cannot borrow `items` as mutable because it is also borrowed as immutable
+    println!("{}", first);
+    */
+    let mut items = vec![10, 20];
+    let first = items[0]; // Copy the value out; no borrow stays alive
+    items.push(30);
+    format!("first = {}, items = {:?}", first, items)
+}
+
+fn fix_does_not_live_long_enough() -> String {
+    /*
+    let longest;
+    {
+        let local = String::from("inner");
+        longest = &local; // error[E0597]: `local` does not live long enough
+    }
+    println!("{}", longest);
+    */
+    let local = String::from("inner"); // Declared in the outer scope instead
+    let longest;
+    {
+        longest = &local;
+    }
+    longest.to_string()
+}
+
+fn fix_missing_trait() -> String {
+    /*
+    struct Point { x: i32, y: i32 }
+    println!("{:?}", Point { x: 1, y: 2 }); // error[E0277]: `Point` doesn't implement `Debug`
+    */
+    #[derive(Debug)]
+    #[allow(dead_code)] // Fields are only read by the `Debug` output
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    format!("{:?}", Point { x: 1, y: 2 })
+}
+
+fn fix_missing_lifetime() -> String {
+    /*
+    fn longer(a: &str, b: &str) -> &str { // error[E0106]: missing lifetime specifier
+        if a.len() >= b.len() { a } else { b }
+    }
+    */
+    fn longer<'a>(a: &'a str, b: &'a str) -> &'a str {
+        if a.len() >= b.len() { a } else { b }
+    }
+    longer("borrow", "checker").to_string()
+}
+
+fn fix_mismatched_types() -> String {
+    /*
+    fn double(x: i32) -> i32 {
+        x * 2; // error[E0308]: mismatched types (the `;` makes the body return `()`)
+    }
+    */
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+    double(21).to_string()
+}
+
+// -------------------------------------------------------------------------
+// Annotating `cargo build --message-format=json` Output
+// -------------------------------------------------------------------------
+// With `--message-format=json`, cargo prints one JSON object per line.
+// A compiler error looks like this (shortened, and the real order):
+//
+//   {"reason":"compiler-message",...,"message":{
+//     "rendered":"error[E0382]: borrow of moved value: `name`\n...",
+//     "children":[{"level":"help","message":"consider cloning the value ...",
+//                  "spans":[{"file_name":"src/main.rs","line_start":3,...}]}],
+//     "level":"error","message":"borrow of moved value: `name`",
+//     "spans":[{"file_name":"src/main.rs","line_start":3,"is_primary":false,...},
+//              {"file_name":"src/main.rs","line_start":4,"is_primary":true,...}],
+//     "code":{"code":"E0382","explanation":"..."}}}
+//
+// The notes in `children` and the labels in `spans` have messages and
+// locations of their own, so searching the line for the first `"message"` or
+// `"file_name"` finds the wrong ones. The error is the top-level `message`,
+// and its location is the span marked `"is_primary":true`. Reading that
+// needs the nesting, so below is a small std-only JSON parser. A real tool
+// would use `serde_json`.
+
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    // The value of `key`, if this is an object that has it.
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn as_u32(&self) -> Option<u32> {
+        match *self {
+            Json::Number(n) if n.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&n) => {
+                Some(n as u32)
+            }
+            _ => None,
+        }
+    }
+}
+
+// Parses one complete JSON value; `None` if `text` isn't valid JSON.
+fn parse_json(text: &str) -> Option<Json> {
+    let mut parser = JsonParser {
+        chars: text.chars().peekable(),
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    parser.chars.peek().is_none().then_some(value)
+}
+
+struct JsonParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl JsonParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    // Consumes `expected` (after any whitespace), or returns `None`.
+    fn eat(&mut self, expected: char) -> Option<()> {
+        self.skip_whitespace();
+        self.chars.next_if_eq(&expected).map(|_| ())
+    }
+
+    fn value(&mut self) -> Option<Json> {
+        self.skip_whitespace();
+        match *self.chars.peek()? {
+            '{' => self.object(),
+            '[' => self.array(),
+            '"' => self.string().map(Json::String),
+            't' => self.literal("true", Json::Bool(true)),
+            'f' => self.literal("false", Json::Bool(false)),
+            'n' => self.literal("null", Json::Null),
+            _ => self.number(),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Option<Json> {
+        for expected in word.chars() {
+            self.chars.next_if_eq(&expected)?;
+        }
+        Some(value)
+    }
+
+    fn number(&mut self) -> Option<Json> {
+        let mut text = String::new();
+        while let Some(c) = self
+            .chars
+            .next_if(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            text.push(c);
+        }
+        text.parse().ok().map(Json::Number)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.eat('"')?;
+        let mut out = String::new();
+        loop {
+            match self.chars.next()? {
+                '"' => return Some(out),
+                '\\' => match self.chars.next()? {
+                    'n' => out.push('\n'),
+                    't' => out.push('\t'),
+                    'r' => out.push('\r'),
+                    'b' => out.push('\u{8}'),
+                    'f' => out.push('\u{c}'),
+                    'u' => {
+                        let hex: String =
+                            (0..4).map(|_| self.chars.next()).collect::<Option<_>>()?;
+                        let code = u32::from_str_radix(&hex, 16).ok()?;
+                        // Half of a surrogate pair becomes U+FFFD; rustc's output doesn't need them.
+                        out.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                    }
+                    escaped => out.push(escaped), // `\"`, `\\` and `\/`
+                },
+                c => out.push(c),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Option<Json> {
+        self.eat('[')?;
+        let mut items = Vec::new();
+        if self.eat(']').is_some() {
+            return Some(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(']').is_some() {
+                return Some(Json::Array(items));
+            }
+            self.eat(',')?;
+        }
+    }
+
+    fn object(&mut self) -> Option<Json> {
+        self.eat('{')?;
+        let mut fields = Vec::new();
+        if self.eat('}').is_some() {
+            return Some(Json::Object(fields));
+        }
+        loop {
+            let key = self.string()?;
+            self.eat(':')?;
+            fields.push((key, self.value()?));
+            if self.eat('}').is_some() {
+                return Some(Json::Object(fields));
+            }
+            self.eat(',')?;
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct CompilerError {
+    code: String,
+    message: String,
+    location: Option<String>, // "file:line" of the primary span
+}
+
+fn parse_cargo_errors(output: &str) -> Vec<CompilerError> {
+    output
+        .lines()
+        .filter_map(parse_json)
+        .filter(|line| line.get("reason").and_then(Json::as_str) == Some("compiler-message"))
+        .filter_map(|line| {
+            // Only the top-level fields of `message`; `children` is never looked at.
+            let message = line.get("message")?;
+            if message.get("level")?.as_str()? != "error" {
+                return None;
+            }
+            // "code":{"code":"E0382",...}, or null for the "aborting" summary
+            let code = message.get("code")?.get("code")?.as_str()?.to_string();
+            let text = message.get("message")?.as_str()?.to_string();
+            let primary = message
+                .get("spans")?
+                .as_array()?
+                .iter()
+                .find(|span| span.get("is_primary") == Some(&Json::Bool(true)));
+            let location = primary.and_then(|span| {
+                let file = span.get("file_name")?.as_str()?;
+                let line_no = span.get("line_start")?.as_u32()?;
+                Some(format!("{}:{}", file, line_no))
+            });
+            Some(CompilerError {
+                code,
+                message: text,
+                location,
+            })
+        })
+        .collect()
+}
+
+fn annotate(error: &CompilerError) -> String {
+    let location = error.location.as_deref().unwrap_or("?");
+    match explain(&error.code) {
+        Some(entry) => format!(
+            "{} error[{}]: {}\n    -> see {}",
+            location, error.code, error.message, entry.lesson
+        ),
+        None => format!(
+            "{} error[{}]: {}\n    -> not covered yet; try `rustc --explain {}`",
+            location, error.code, error.message, error.code
+        ),
+    }
+}
+
+fn main() {
+    println!("--- Compiler Errors Explained ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Error Map
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. The Error Map ---");
+
+    for entry in ERROR_MAP {
+        println!("{}: {}", entry.code, entry.title);
+        println!("    Taught in: {}", entry.lesson);
+        println!("    Fixed version prints: {}", (entry.fixed_demo)());
+    }
+
+    // Codes are unique, and every entry points at a lesson.
+    for (i, entry) in ERROR_MAP.iter().enumerate() {
+        assert!(
+            ERROR_MAP[i + 1..]
+                .iter()
+                .all(|other| other.code != entry.code)
+        );
+        assert!(entry.lesson.contains(".rs"));
+    }
+
+    // -------------------------------------------------------------------------
+    // 2. Looking Up a Single Error
+    // -------------------------------------------------------------------------
+    // The lookup an `explain-error E0382` command would do.
+
+    println!("\n--- 2. Looking Up a Single Error ---");
+
+    for code in ["E0382", "e0502", "E9999"] {
+        match explain(code) {
+            Some(entry) => println!("{} ({}):\n  {}", entry.code, entry.title, entry.explanation),
+            None => println!("{}: not in the map; try `rustc --explain {}`", code, code),
+        }
+    }
+    assert_eq!(
+        explain("E0597").map(|e| e.title),
+        Some("borrowed value does not live long enough")
+    );
+    assert!(explain("e0277").is_some()); // Case-insensitive
+    assert!(explain("E9999").is_none());
+
+    // -------------------------------------------------------------------------
+    // 3. Annotating Build Output
+    // -------------------------------------------------------------------------
+    // `28-cargo-build-output.jsonl` is the real stdout of
+    // `cargo build --message-format=json` on this program:
+    //
+    //    1  fn main() {
+    //    2      let name = String::from("Ferris");
+    //    3      let moved = name;
+    //    4      println!("{} {}", name, moved);
+    //    5
+    //    6      let mut items = vec![10, 20];
+    //    7      let first = &items[0];
+    //    8      items.push(30);
+    //    9      println!("{}", first);
+    //   10
+    //   11      let unused = 1;
+    //   12  }
+    //   13
+    //   14  fn total(prices: &[u32]) -> u32 {
+    //   15      prices.iter().summ()
+    //   16  }
+    //
+    // In a real project: `cargo build --message-format=json 2>/dev/null`, and
+    // feed stdout to `parse_cargo_errors`.
+
+    println!("\n--- 3. Annotating Build Output ---");
+
+    let cargo_output = include_str!("28-cargo-build-output.jsonl");
+
+    let errors = parse_cargo_errors(cargo_output);
+    for error in &errors {
+        println!("{}", annotate(error));
+    }
+
+    // The warning, the two "failure-note" summaries and `build-finished` are skipped
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0].code, "E0599");
+    assert_eq!(errors[0].location.as_deref(), Some("src/main.rs:15"));
+    assert!(annotate(&errors[0]).contains("rustc --explain E0599")); // Not in the map
+    // The top-level message and primary span, not the "consider cloning" help
+    // at line 3 that comes first in the line
+    assert_eq!(
+        errors[1],
+        CompilerError {
+            code: String::from("E0382"),
+            message: String::from("borrow of moved value: `name`"),
+            location: Some(String::from("src/main.rs:4")),
+        }
+    );
+    assert_eq!(
+        errors[2].message,
+        "cannot borrow `items` as mutable because it is also borrowed as immutable"
+    );
+    assert_eq!(errors[2].location.as_deref(), Some("src/main.rs:8"));
+    assert!(annotate(&errors[2]).contains("02-ownership_borrowing.rs"));
+    assert!(parse_cargo_errors("").is_empty());
+    assert!(parse_cargo_errors("not json").is_empty());
+
+    // The parser on its own: nesting, escapes and the literals
+    let json = parse_json(r#"{"a": [1, -2.5e1, true, null], "b": {"c": "q\"\u00e9\n"}}"#).unwrap();
+    assert_eq!(
+        json.get("b").and_then(|b| b.get("c")),
+        Some(&Json::String(String::from("q\"é\n")))
+    );
+    assert_eq!(
+        json.get("a").and_then(Json::as_array).map(|a| a.len()),
+        Some(4)
+    );
+    assert_eq!(parse_json("[1, 2"), None);
+
+    println!("\n--- End of Compiler Errors Examples ---");
+}
@@ -0,0 +1,379 @@
+// This file is a capstone for the ownership lessons: a small course-enrolment
+// model written the "easy" way (clone whatever the borrow checker complains
+// about, `Rc` everywhere) and then refactored step by step. Each step ends
+// with automated checks: allocation counts from a counting allocator, and
+// leak checks using `Rc::strong_count`, `Rc::weak_count` and a drop counter.
+// Try doing each refactoring yourself before reading the solution module.
+
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// -------------------------------------------------------------------------
+// 0. Measuring Tools
+// -------------------------------------------------------------------------
+// The same counting allocator as in the zero-copy parsing lesson, plus a
+// counter of dropped model objects. A value that is never dropped has leaked.
+
+#[path = "lessons/alloc.rs"]
+mod alloc;
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use alloc::measure_allocations;
+use fmt::human_bytes;
+
+#[global_allocator]
+static GLOBAL: alloc::CountingAllocator = alloc::CountingAllocator;
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+fn count_drops(f: impl FnOnce()) -> usize {
+    let before = DROPS.load(Ordering::SeqCst);
+    f();
+    DROPS.load(Ordering::SeqCst) - before
+}
+
+// Prints the outcome of a check and stops the program if it failed.
+fn check(step: &str, description: &str, passed: bool) {
+    println!(
+        "[{}] {}: {}",
+        if passed { "PASS" } else { "FAIL" },
+        step,
+        description
+    );
+    assert!(passed, "{}: {}", step, description);
+}
+
+// -------------------------------------------------------------------------
+// Step 0: The Leaky Design (the starting point)
+// -------------------------------------------------------------------------
+// Problems to find:
+// - Functions take `String` / `Vec<String>` by value, so callers clone.
+// - `Course` and `Student` point at each other with *strong* `Rc`s: a cycle.
+//   Their strong counts never reach zero, so neither is ever dropped.
+mod leaky {
+    use super::DROPS;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::atomic::Ordering;
+
+    pub struct Course {
+        pub title: String,
+        pub students: Vec<Rc<RefCell<Student>>>,
+    }
+
+    pub struct Student {
+        pub name: String,
+        pub courses: Vec<Rc<RefCell<Course>>>, // Strong back-link: creates a cycle
+    }
+
+    impl Drop for Course {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl Drop for Student {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    pub fn new_course(title: String) -> Rc<RefCell<Course>> {
+        Rc::new(RefCell::new(Course {
+            title,
+            students: Vec::new(),
+        }))
+    }
+
+    pub fn new_student(name: String) -> Rc<RefCell<Student>> {
+        Rc::new(RefCell::new(Student {
+            name,
+            courses: Vec::new(),
+        }))
+    }
+
+    pub fn enroll(course: &Rc<RefCell<Course>>, student: &Rc<RefCell<Student>>) {
+        course.borrow_mut().students.push(Rc::clone(student));
+        student.borrow_mut().courses.push(Rc::clone(course));
+    }
+
+    pub fn roster(course: &Rc<RefCell<Course>>) -> Vec<String> {
+        course
+            .borrow()
+            .students
+            .iter()
+            .map(|s| s.borrow().name.clone())
+            .collect()
+    }
+
+    // Takes ownership of everything it only needs to look at.
+    pub fn is_enrolled(roster: Vec<String>, name: String) -> bool {
+        roster.contains(&name)
+    }
+
+    pub fn shout_title(title: String) -> usize {
+        title.chars().filter(|c| c.is_uppercase()).count()
+    }
+}
+
+// -------------------------------------------------------------------------
+// Step 1: Borrowed APIs
+// -------------------------------------------------------------------------
+// Read-only parameters become `&str` / `&[String]`, so callers can pass what
+// they already have. No clones, no allocations per query.
+mod borrowed {
+    pub fn is_enrolled(roster: &[String], name: &str) -> bool {
+        roster.iter().any(|n| n == name)
+    }
+
+    pub fn shout_title(title: &str) -> usize {
+        title.chars().filter(|c| c.is_uppercase()).count()
+    }
+}
+
+// -------------------------------------------------------------------------
+// Step 3: `Weak` Back-Links
+// -------------------------------------------------------------------------
+// Ownership now flows one way: a course owns (strongly) its students' handles,
+// and a student only *refers* back to its courses with `Weak`. Dropping the
+// last outside `Rc` of a course drops it, which releases its students.
+mod fixed {
+    use super::DROPS;
+    use std::cell::RefCell;
+    use std::rc::{Rc, Weak};
+    use std::sync::atomic::Ordering;
+
+    pub struct Course {
+        pub title: String,
+        pub students: Vec<Rc<RefCell<Student>>>,
+    }
+
+    pub struct Student {
+        pub name: String,
+        pub courses: Vec<Weak<RefCell<Course>>>, // Weak: doesn't keep the course alive
+    }
+
+    impl Drop for Course {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl Drop for Student {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    pub fn new_course(title: &str) -> Rc<RefCell<Course>> {
+        Rc::new(RefCell::new(Course {
+            title: title.to_string(),
+            students: Vec::new(),
+        }))
+    }
+
+    pub fn new_student(name: &str) -> Rc<RefCell<Student>> {
+        Rc::new(RefCell::new(Student {
+            name: name.to_string(),
+            courses: Vec::new(),
+        }))
+    }
+
+    pub fn enroll(course: &Rc<RefCell<Course>>, student: &Rc<RefCell<Student>>) {
+        course.borrow_mut().students.push(Rc::clone(student));
+        student.borrow_mut().courses.push(Rc::downgrade(course));
+    }
+
+    // Visits names without copying them out.
+    pub fn for_each_name(course: &Rc<RefCell<Course>>, mut visit: impl FnMut(&str)) {
+        for student in &course.borrow().students {
+            visit(&student.borrow().name);
+        }
+    }
+
+    // Following a `Weak` link means upgrading it, which fails once the course is gone.
+    pub fn course_titles(student: &Rc<RefCell<Student>>) -> Vec<String> {
+        student
+            .borrow()
+            .courses
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|course| course.borrow().title.clone())
+            .collect()
+    }
+}
+
+fn main() {
+    println!("--- Ownership Capstone: Refactoring a Leaky Design ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Starting Point
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. The Starting Point ---");
+
+    let course = leaky::new_course(String::from("Rust 101"));
+    let student = leaky::new_student(String::from("Ferris"));
+    leaky::enroll(&course, &student);
+    let roster = leaky::roster(&course);
+    println!("{} roster: {:?}", course.borrow().title, roster);
+    println!(
+        "{} has {} course(s)",
+        student.borrow().name,
+        student.borrow().courses.len()
+    );
+
+    // Every query clones: the roster Vec, every name in it, and the search key.
+    let (found, leaky_allocations) = measure_allocations(|| {
+        let mut hits = 0;
+        for _ in 0..100 {
+            if leaky::is_enrolled(roster.clone(), String::from("Ferris")) {
+                hits += 1;
+            }
+            hits += leaky::shout_title(course.borrow().title.clone());
+        }
+        hits
+    });
+    println!(
+        "100 leaky queries: {} allocations ({})",
+        leaky_allocations.count,
+        human_bytes(leaky_allocations.bytes)
+    );
+    assert_eq!(found, 200); // 100 hits + 100 * one uppercase letter ("R")
+
+    // -------------------------------------------------------------------------
+    // 2. Step 1: Borrowed APIs
+    // -------------------------------------------------------------------------
+    // Exercise: change `is_enrolled` and `shout_title` to borrow their inputs,
+    // then update the caller. Solution: the `borrowed` module.
+
+    println!("\n--- 2. Step 1: Borrowed APIs ---");
+
+    let (found, borrowed_allocations) = measure_allocations(|| {
+        let mut hits = 0;
+        for _ in 0..100 {
+            if borrowed::is_enrolled(&roster, "Ferris") {
+                hits += 1;
+            }
+            hits += borrowed::shout_title(&course.borrow().title);
+        }
+        hits
+    });
+    println!(
+        "100 borrowed queries: {} allocations ({})",
+        borrowed_allocations.count,
+        human_bytes(borrowed_allocations.bytes)
+    );
+    check("Step 1", "same answers as the leaky version", found == 200);
+    check(
+        "Step 1",
+        "queries allocate nothing",
+        borrowed_allocations.count == 0,
+    );
+    check(
+        "Step 1",
+        "the leaky version allocated at least 3 times per query",
+        leaky_allocations.count >= 300,
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. Step 2: Finding the Leak
+    // -------------------------------------------------------------------------
+    // Each object is held by a local *and* by the other object.
+
+    println!("\n--- 3. Step 2: Finding the Leak ---");
+
+    println!(
+        "Course strong count: {}, student strong count: {}",
+        Rc::strong_count(&course),
+        Rc::strong_count(&student)
+    );
+    check(
+        "Step 2",
+        "the cycle doubles the strong counts",
+        Rc::strong_count(&course) == 2 && Rc::strong_count(&student) == 2,
+    );
+
+    // Dropping both locals leaves each count at 1, held by the other side.
+    let leaked_drops = count_drops(|| {
+        drop(roster);
+        drop(course);
+        drop(student);
+    });
+    println!(
+        "Objects dropped after releasing the leaky model: {} of 2",
+        leaked_drops
+    );
+    check(
+        "Step 2",
+        "the leaky model really leaks (nothing dropped)",
+        leaked_drops == 0,
+    );
+
+    // -------------------------------------------------------------------------
+    // 4. Step 3: `Weak` Back-Links
+    // -------------------------------------------------------------------------
+    // Exercise: make one direction of the link `Weak`, so the owner graph has
+    // no cycle. Solution: the `fixed` module.
+
+    println!("\n--- 4. Step 3: `Weak` Back-Links ---");
+
+    let fixed_drops = count_drops(|| {
+        let course = fixed::new_course("Rust 101");
+        let alice = fixed::new_student("Alice");
+        let bob = fixed::new_student("Bob");
+        fixed::enroll(&course, &alice);
+        fixed::enroll(&course, &bob);
+
+        let mut names = Vec::new();
+        fixed::for_each_name(&course, |name| names.push(name.len()));
+        println!("Name lengths: {:?}", names);
+        println!("Alice's courses: {:?}", fixed::course_titles(&alice));
+
+        check(
+            "Step 3",
+            "course has one strong owner (the local)",
+            Rc::strong_count(&course) == 1,
+        );
+        check(
+            "Step 3",
+            "each student points back weakly",
+            Rc::weak_count(&course) == 2,
+        );
+        check(
+            "Step 3",
+            "students are owned by the local and the course",
+            Rc::strong_count(&alice) == 2,
+        );
+
+        // Dropping the course releases it even though students still refer to it.
+        drop(course);
+        check(
+            "Step 3",
+            "back-links can't reach a dropped course",
+            fixed::course_titles(&alice).is_empty(),
+        );
+        check(
+            "Step 3",
+            "students lost the course's strong reference",
+            Rc::strong_count(&alice) == 1,
+        );
+    });
+    println!(
+        "Objects dropped after releasing the fixed model: {} of 3",
+        fixed_drops
+    );
+    check(
+        "Step 3",
+        "the fixed model frees every object",
+        fixed_drops == 3,
+    );
+
+    // Rules of thumb from this capstone:
+    // - Borrow (`&str`, `&[T]`) in parameters you only read; own only what you store.
+    // - Pick one owning direction in a graph; make the other direction `Weak`.
+    // - Measure: allocation counts and `Rc` counts turn "probably fine" into a check.
+
+    println!("\n--- End of Ownership Capstone ---");
+}
@@ -0,0 +1,293 @@
+// This file covers running other programs with `std::process::Command` and
+// connecting them into a pipeline, like `textstats < input | table` in a shell.
+// To stay self-contained, the program spawns *itself* in different roles:
+// `textstats` counts words from stdin, `table` renders those counts as a
+// table, and `fail` exits with an error. It shows `Stdio::piped`, chaining one
+// child's stdout into another's stdin, reading output concurrently to avoid
+// deadlocks, and combining the exit statuses of every stage.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+
+#[path = "lessons/ext.rs"]
+mod ext;
+
+use ext::ResultExt;
+
+// -------------------------------------------------------------------------
+// 0. The Child Roles
+// -------------------------------------------------------------------------
+// `main` checks its first argument. With no argument it runs the lesson;
+// otherwise it behaves like one of these small command-line tools.
+
+// Reads text from stdin and prints "word<TAB>count" lines, most frequent first.
+fn run_textstats() -> i32 {
+    let mut text = String::new();
+    if io::stdin().read_to_string(&mut text).is_err() {
+        eprintln!("textstats: input is not valid UTF-8");
+        return 2;
+    }
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for word in text.split_whitespace() {
+        let word = word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        if !word.is_empty() {
+            *counts.entry(word).or_insert(0) += 1;
+        }
+    }
+    let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for (word, count) in sorted {
+        if writeln!(out, "{}\t{}", word, count).is_err() {
+            return 1; // The reader went away (broken pipe)
+        }
+    }
+    eprintln!("textstats: done"); // Diagnostics go to stderr, data to stdout
+    0
+}
+
+// Reads "key<TAB>value" lines from stdin and prints them as an aligned table.
+fn run_table() -> i32 {
+    let rows: Vec<(String, String)> = io::stdin()
+        .lock()
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| {
+            line.split_once('\t')
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+        })
+        .collect();
+    if rows.is_empty() {
+        eprintln!("table: no rows on stdin");
+        return 1;
+    }
+    let width = rows.iter().map(|(k, _)| k.len()).max().unwrap_or(0).max(4);
+    println!("+-{}-+-------+", "-".repeat(width));
+    println!("| {:<width$} | count |", "word", width = width);
+    println!("+-{}-+-------+", "-".repeat(width));
+    for (key, value) in &rows {
+        println!("| {:<width$} | {:>5} |", key, value, width = width);
+    }
+    println!("+-{}-+-------+", "-".repeat(width));
+    0
+}
+
+// -------------------------------------------------------------------------
+// Pipeline Helpers
+// -------------------------------------------------------------------------
+
+// Spawns this same executable in the given role.
+fn spawn_role(role: &str, stdin: Stdio, stdout: Stdio) -> io::Result<Child> {
+    Command::new(std::env::current_exe()?)
+        .arg(role)
+        .stdin(stdin)
+        .stdout(stdout)
+        .stderr(Stdio::piped())
+        .spawn()
+}
+
+// Reads a child's stderr on a separate thread. Reading stdout and stderr one
+// after the other can deadlock: if the child fills the stderr pipe buffer
+// (~64 KiB) while we're blocked waiting on stdout, both sides wait forever.
+fn collect_stderr(child: &mut Child) -> thread::JoinHandle<String> {
+    let mut stderr = child.stderr.take().expect("stderr was piped");
+    thread::spawn(move || {
+        let mut text = String::new();
+        stderr
+            .read_to_string(&mut text)
+            .log_err("reading child stderr");
+        text
+    })
+}
+
+// Looks up a word's count in the table printed by `run_table`.
+fn table_count(table: &str, word: &str) -> Option<usize> {
+    table.lines().find_map(|line| {
+        let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+        match cells.as_slice() {
+            ["", w, count, ""] if *w == word => count.parse().ok(),
+            _ => None,
+        }
+    })
+}
+
+#[derive(Debug)]
+struct StageResult {
+    role: &'static str,
+    status: ExitStatus,
+    stderr: String,
+}
+
+// The pipeline succeeds only if *every* stage succeeds (like `set -o pipefail`).
+// Returns the first failing stage, if any.
+fn first_failure(stages: &[StageResult]) -> Option<&StageResult> {
+    stages.iter().find(|stage| !stage.status.success())
+}
+
+// Runs `textstats | table` (or any two roles) over `input`.
+fn run_pipeline(
+    first_role: &'static str,
+    second_role: &'static str,
+    input: &str,
+) -> io::Result<(String, Vec<StageResult>)> {
+    let mut first = spawn_role(first_role, Stdio::piped(), Stdio::piped())?;
+
+    // Hand the first child's stdout directly to the second child as stdin.
+    // The data flows between the two processes without passing through us.
+    let first_stdout = first.stdout.take().expect("stdout was piped");
+    let mut second = spawn_role(second_role, Stdio::from(first_stdout), Stdio::piped())?;
+
+    // Feed the input from another thread. If we wrote everything first and
+    // only then read the output, a large input could fill every pipe buffer
+    // along the way and block the whole pipeline.
+    let mut first_stdin = first.stdin.take().expect("stdin was piped");
+    let input = input.to_string();
+    let writer = thread::spawn(move || {
+        let _ = first_stdin.write_all(input.as_bytes());
+        // `first_stdin` is dropped here, closing the pipe: the child sees end-of-file.
+    });
+
+    let first_stderr = collect_stderr(&mut first);
+    let second_stderr = collect_stderr(&mut second);
+
+    let mut output = String::new();
+    second
+        .stdout
+        .take()
+        .expect("stdout was piped")
+        .read_to_string(&mut output)?;
+
+    writer.join().expect("writer thread panicked");
+    let stages = vec![
+        StageResult {
+            role: first_role,
+            status: first.wait()?,
+            stderr: first_stderr.join().expect("stderr thread panicked"),
+        },
+        StageResult {
+            role: second_role,
+            status: second.wait()?,
+            stderr: second_stderr.join().expect("stderr thread panicked"),
+        },
+    ];
+    Ok((output, stages))
+}
+
+fn main() {
+    // Child roles: behave like a small tool and exit with its status code.
+    match std::env::args().nth(1).as_deref() {
+        Some("textstats") => std::process::exit(run_textstats()),
+        Some("table") => std::process::exit(run_table()),
+        Some("fail") => {
+            let mut ignored = String::new();
+            let _ = io::stdin().read_to_string(&mut ignored);
+            eprintln!("fail: refusing to produce output");
+            std::process::exit(3);
+        }
+        Some(other) => {
+            eprintln!("unknown role: {}", other);
+            std::process::exit(64);
+        }
+        None => {} // No role: run the lesson below
+    }
+
+    println!("--- Process Pipelines ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Running a Single Command
+    // -------------------------------------------------------------------------
+    // `Command::output()` runs a program to completion and collects its
+    // stdout, stderr and exit status. It reads both streams concurrently for you.
+
+    println!("\n--- 1. Running a Single Command ---");
+
+    let exe = std::env::current_exe().expect("current executable path");
+    let single = Command::new(&exe)
+        .arg("textstats")
+        .stdin(Stdio::null()) // Empty input
+        .output()
+        .expect("failed to run textstats");
+    println!(
+        "textstats on empty input: status {}, stdout {:?}, stderr {:?}",
+        single.status,
+        String::from_utf8_lossy(&single.stdout),
+        String::from_utf8_lossy(&single.stderr).trim()
+    );
+    assert!(single.status.success());
+    assert!(single.stdout.is_empty());
+
+    // -------------------------------------------------------------------------
+    // 2. A Two-Stage Pipeline
+    // -------------------------------------------------------------------------
+    // The equivalent of: `echo "$TEXT" | textstats | table`
+
+    println!("\n--- 2. A Two-Stage Pipeline ---");
+
+    let text = "the quick brown fox jumps over the lazy dog. The dog sleeps; the fox runs!";
+    let (table, stages) =
+        run_pipeline("textstats", "table", text).expect("pipeline failed to start");
+    print!("{}", table);
+    for stage in &stages {
+        println!(
+            "{}: {} (stderr: {:?})",
+            stage.role,
+            stage.status,
+            stage.stderr.trim()
+        );
+    }
+    assert!(first_failure(&stages).is_none());
+    assert_eq!(table_count(&table, "the"), Some(4)); // "The" and "the" both count
+    assert_eq!(table_count(&table, "fox"), Some(2));
+    assert_eq!(table_count(&table, "dog"), Some(2)); // "dog." with the period trimmed
+    assert_eq!(stages[0].stderr.trim(), "textstats: done");
+
+    // A larger input: several hundred KiB through two processes, which would
+    // hang if any of the pipes above were written and read in the wrong order.
+    let big_input = "lorem ipsum dolor sit amet ".repeat(20_000);
+    let (big_table, big_stages) =
+        run_pipeline("textstats", "table", &big_input).expect("pipeline failed to start");
+    assert!(first_failure(&big_stages).is_none());
+    assert_eq!(table_count(&big_table, "lorem"), Some(20_000));
+    println!(
+        "Large input ({} bytes) went through the pipeline",
+        big_input.len()
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. Aggregating Exit Statuses
+    // -------------------------------------------------------------------------
+    // A shell pipeline reports only the *last* command's status by default, so
+    // a failure earlier in the pipe goes unnoticed. Checking every stage
+    // catches it.
+
+    println!("\n--- 3. Aggregating Exit Statuses ---");
+
+    let (output, stages) = run_pipeline("fail", "table", text).expect("pipeline failed to start");
+    for stage in &stages {
+        println!(
+            "{}: {} (stderr: {:?})",
+            stage.role,
+            stage.status,
+            stage.stderr.trim()
+        );
+    }
+    match first_failure(&stages) {
+        Some(stage) => println!(
+            "Pipeline failed at '{}' with exit code {:?}",
+            stage.role,
+            stage.status.code()
+        ),
+        None => println!("Pipeline succeeded"),
+    }
+    assert_eq!(first_failure(&stages).map(|s| s.role), Some("fail"));
+    assert_eq!(stages[0].status.code(), Some(3));
+    assert_eq!(stages[1].status.code(), Some(1)); // `table` got no rows
+    assert!(output.is_empty());
+
+    println!("\n--- End of Process Pipeline Examples ---");
+}
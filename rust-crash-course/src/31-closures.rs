@@ -0,0 +1,288 @@
+// This file covers closures: anonymous functions that can capture variables
+// from the scope they're defined in. Closures appear all over the iterator
+// lesson (`map(|x| x * 2)`); here we look at how they capture values, the
+// three closure traits `Fn`, `FnMut` and `FnOnce`, how to pass and return
+// closures, and how to store them in structs.
+
+fn main() {
+    println!("--- Closures ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Closure Syntax
+    // -------------------------------------------------------------------------
+    // `|parameters| body`. Parameter and return types are usually inferred.
+
+    println!("\n--- 1. Closure Syntax ---");
+
+    // A regular function, for comparison
+    fn add_one_fn(x: i32) -> i32 {
+        x + 1
+    }
+    let add_one_annotated = |x: i32| -> i32 { x + 1 }; // Fully annotated
+    let add_one = |x| x + 1; // Types inferred from the first use
+    println!("add_one_fn(1) = {}", add_one_fn(1));
+    println!("add_one_annotated(1) = {}", add_one_annotated(1));
+    println!("add_one(1) = {}", add_one(1));
+    assert_eq!(add_one(41), 42);
+
+    // Once inferred, the types are fixed:
+    /*
+    let identity = |x| x;
+    let s = identity(String::from("hi"));
+    let n = identity(5); // error[E0308]: mismatched types (expected `String`, found integer)
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. Capturing the Environment
+    // -------------------------------------------------------------------------
+    // Unlike functions, closures can use variables from the surrounding scope.
+    // The compiler picks the *least* powerful capture mode that works:
+    // 1. by shared reference (`&T`) if the closure only reads the value,
+    // 2. by mutable reference (`&mut T`) if it modifies it,
+    // 3. by value (moving `T` in) if it consumes it.
+
+    println!("\n--- 2. Capturing the Environment ---");
+
+    // a. Capture by shared reference
+    let greeting = String::from("Hello");
+    let greet = |name: &str| format!("{}, {}!", greeting, name);
+    println!("{}", greet("Ferris"));
+    println!("`greeting` is still usable: {}", greeting); // Only borrowed
+
+    /*
+    fn greet_fn(name: &str) -> String {
+        format!("{}, {}!", greeting, name) // error[E0434]: can't capture dynamic environment in a fn item
+    }
+    */
+
+    // b. Capture by mutable reference
+    let mut counter = 0;
+    let mut increment = || counter += 1; // The closure itself must be `mut`
+    increment();
+    increment();
+    // While `increment` is alive, `counter` is mutably borrowed; after its
+    // last use, the borrow ends and `counter` can be read again.
+    println!("counter after two increments: {}", counter);
+    assert_eq!(counter, 2);
+
+    // c. Capture by value (the closure consumes the captured value)
+    let names = vec![String::from("Ada"), String::from("Grace")];
+    let consume = || names; // Returning `names` moves it out of the closure
+    let taken = consume();
+    // println!("{:?}", names); // error[E0382]: borrow of moved value: `names`
+    println!("Taken by the closure: {:?}", taken);
+
+    // d. `move` forces capture by value, even if the closure only reads.
+    // Needed when the closure outlives the current scope, e.g. in a thread.
+    let label = String::from("worker");
+    let handle = std::thread::spawn(move || format!("{} finished", label));
+    println!("{}", handle.join().unwrap());
+    // `label` has been moved into the closure and can't be used here any more.
+
+    // Copy types are copied by `move`, so the original stays usable.
+    let limit = 10;
+    let below_limit = move |x: i32| x < limit;
+    println!("5 below {}? {}", limit, below_limit(5));
+
+    // e. Capturing disjoint fields (edition 2021+): closures capture only the
+    // fields they use, so other fields stay available.
+    struct Player {
+        name: String,
+        score: u32,
+    }
+    let mut player = Player {
+        name: String::from("Ferris"),
+        score: 0,
+    };
+    let mut add_points = |points: u32| player.score += points; // Borrows only `player.score`
+    add_points(5);
+    println!("Name is still readable: {}", player.name);
+    add_points(10);
+    assert_eq!(player.score, 15);
+
+    // -------------------------------------------------------------------------
+    // 3. The Three Closure Traits
+    // -------------------------------------------------------------------------
+    // Every closure implements one or more of these, based on what its body
+    // does with the captured values (not on how they were captured):
+    // - `FnOnce`: can be called at least once. *Every* closure implements it.
+    //   Closures that move a captured value out can *only* be `FnOnce`.
+    // - `FnMut`: can be called repeatedly and may mutate captured state.
+    // - `Fn`: can be called repeatedly, even concurrently; only reads state.
+    // `Fn` is a subtrait of `FnMut`, which is a subtrait of `FnOnce`, so an
+    // `Fn` closure can be passed wherever `FnMut` or `FnOnce` is expected.
+
+    println!("\n--- 3. The Three Closure Traits ---");
+
+    fn call_twice<F: Fn() -> String>(f: F) -> String {
+        format!("{} {}", f(), f())
+    }
+
+    fn call_n_times<F: FnMut()>(mut f: F, n: usize) {
+        for _ in 0..n {
+            f();
+        }
+    }
+
+    fn call_once<F: FnOnce() -> Vec<String>>(f: F) -> Vec<String> {
+        f()
+    }
+
+    let name = String::from("Ferris");
+    println!("Fn: {}", call_twice(|| name.clone())); // Reads `name`
+
+    let mut log = Vec::new();
+    call_n_times(|| log.push("tick"), 3); // Mutates `log`
+    println!("FnMut: {:?}", log);
+    assert_eq!(log.len(), 3);
+
+    let owned = vec![String::from("only"), String::from("once")];
+    println!("FnOnce: {:?}", call_once(move || owned)); // Gives `owned` away
+
+    /*
+    let owned = vec![String::from("x")];
+    call_twice(move || { drop(owned); String::new() });
+    // error[E0525]: expected a closure that implements the `Fn` trait, but this closure only implements `FnOnce`
+    */
+
+    // Regular functions implement all three traits too, so they can be
+    // passed wherever a closure is expected.
+    fn shout() -> String {
+        String::from("HEY")
+    }
+    assert_eq!(call_twice(shout), "HEY HEY");
+
+    // This is why `Option::map` takes `FnOnce` (called at most once), while
+    // `Iterator::map` takes `FnMut` (called once per element), and
+    // `sort_by_key` takes `FnMut` as well.
+    let mut calls = 0;
+    let lengths: Vec<usize> = ["a", "bb", "ccc"]
+        .iter()
+        .map(|s| {
+            calls += 1; // Allowed: `Iterator::map` accepts `FnMut`
+            s.len()
+        })
+        .collect();
+    assert_eq!((lengths, calls), (vec![1, 2, 3], 3));
+
+    // -------------------------------------------------------------------------
+    // 4. Returning Closures
+    // -------------------------------------------------------------------------
+    // Every closure has its own unique, unnameable type, so a function returns
+    // one either as `impl Fn...` (static dispatch, one concrete type) or as
+    // `Box<dyn Fn...>` (dynamic dispatch, can differ per branch).
+
+    println!("\n--- 4. Returning Closures ---");
+
+    fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+        move |x| x + n // `move`: the closure must own `n`, which dies with the function
+    }
+
+    fn make_counter() -> impl FnMut() -> u32 {
+        let mut count = 0;
+        move || {
+            count += 1;
+            count
+        }
+    }
+
+    fn make_operation(op: char) -> Box<dyn Fn(i32, i32) -> i32> {
+        match op {
+            '+' => Box::new(|a, b| a + b),
+            '*' => Box::new(|a, b| a * b),
+            _ => Box::new(|a, _| a), // Different closure types: needs `Box<dyn ...>`
+        }
+    }
+
+    let add_five = make_adder(5);
+    println!("add_five(10) = {}", add_five(10));
+    assert_eq!(add_five(10), 15);
+
+    let mut next_id = make_counter();
+    let ids = [next_id(), next_id(), next_id()];
+    println!("Counter ids: {:?}", ids); // Each call sees the updated state
+    assert_eq!(ids, [1, 2, 3]);
+
+    let multiply = make_operation('*');
+    println!("make_operation('*')(6, 7) = {}", multiply(6, 7));
+    assert_eq!(make_operation('+')(6, 7), 13);
+
+    // Composing closures: a function that takes two and returns a new one.
+    fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+        move |x| g(f(x))
+    }
+    let add_then_double = compose(make_adder(1), |x| x * 2);
+    assert_eq!(add_then_double(4), 10);
+
+    // -------------------------------------------------------------------------
+    // 5. Storing Closures in Structs
+    // -------------------------------------------------------------------------
+    // a. With a generic parameter: one closure type per struct instance, no
+    //    allocation, calls can be inlined.
+    // b. With `Box<dyn Fn>`: different closures in one collection.
+
+    println!("\n--- 5. Storing Closures in Structs ---");
+
+    // a. A lazily computed, cached value.
+    struct Lazy<F: FnOnce() -> u64> {
+        init: Option<F>,
+        value: Option<u64>,
+    }
+
+    impl<F: FnOnce() -> u64> Lazy<F> {
+        fn new(init: F) -> Self {
+            Lazy {
+                init: Some(init),
+                value: None,
+            }
+        }
+
+        fn get(&mut self) -> u64 {
+            if let Some(init) = self.init.take() {
+                self.value = Some(init()); // `FnOnce`: called at most once
+            }
+            self.value.unwrap_or_default()
+        }
+    }
+
+    let mut computations = 0;
+    let mut expensive = Lazy::new(|| {
+        computations += 1;
+        (1..=20).product() // 20!
+    });
+    println!("Lazy value: {}", expensive.get());
+    println!("Lazy value again: {}", expensive.get());
+    // `expensive` is no longer used, so its closure's borrow of `computations` has ended.
+    assert_eq!(computations, 1);
+
+    // b. An event dispatcher holding different handlers.
+    type ClickHandler = Box<dyn Fn(&str) -> String>; // A type alias keeps signatures readable
+
+    struct Button {
+        label: String,
+        on_click: Vec<ClickHandler>,
+    }
+
+    impl Button {
+        fn click(&self) -> Vec<String> {
+            self.on_click
+                .iter()
+                .map(|handler| handler(&self.label))
+                .collect()
+        }
+    }
+
+    let prefix = String::from(">>");
+    let button = Button {
+        label: String::from("Save"),
+        on_click: vec![
+            Box::new(|label| format!("{} clicked", label)),
+            Box::new(move |label| format!("{} {}", prefix, label.to_uppercase())),
+        ],
+    };
+    let messages = button.click();
+    println!("Button handlers: {:?}", messages);
+    assert_eq!(messages, ["Save clicked", ">> SAVE"]);
+
+    println!("\n--- End of Closures Examples ---");
+}
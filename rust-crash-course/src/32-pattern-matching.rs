@@ -0,0 +1,486 @@
+// This file goes beyond the basic `match` from the enums lesson: match
+// guards, `@` bindings, range patterns, nested destructuring, or-patterns,
+// `ref`/`ref mut` and default binding modes, and how exhaustiveness checking
+// helps. A small arithmetic expression evaluator ties everything together.
+
+use std::fmt;
+
+// -------------------------------------------------------------------------
+// Mini Expression Evaluator (used in section 8)
+// -------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Num(i64),
+    Var(String),
+    Neg(Box<Expr>),
+    Bin(Box<Expr>, Op, Box<Expr>),
+    Let(String, Box<Expr>, Box<Expr>), // let name = value in body
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, PartialEq)]
+enum EvalError {
+    UnboundVariable(String),
+    DivisionByZero,
+    Overflow,
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Num(n) if *n < 0 => write!(f, "({})", n),
+            Expr::Num(n) => write!(f, "{}", n),
+            Expr::Var(name) => write!(f, "{}", name),
+            Expr::Neg(inner) => write!(f, "-{}", inner),
+            Expr::Bin(left, op, right) => {
+                let symbol = match op {
+                    Op::Add => '+',
+                    Op::Sub => '-',
+                    Op::Mul => '*',
+                    Op::Div => '/',
+                };
+                write!(f, "({} {} {})", left, symbol, right)
+            }
+            Expr::Let(name, value, body) => write!(f, "(let {} = {} in {})", name, value, body),
+        }
+    }
+}
+
+// Variables are looked up in a simple list of (name, value) pairs; inner
+// `let`s are pushed to the end, so searching from the back finds the innermost.
+fn eval(expr: &Expr, env: &mut Vec<(String, i64)>) -> Result<i64, EvalError> {
+    match expr {
+        Expr::Num(n) => Ok(*n),
+        Expr::Var(name) => env
+            .iter()
+            .rev()
+            .find(|(bound, _)| bound == name)
+            .map(|&(_, value)| value)
+            .ok_or_else(|| EvalError::UnboundVariable(name.clone())),
+        Expr::Neg(inner) => eval(inner, env)?.checked_neg().ok_or(EvalError::Overflow),
+        Expr::Bin(left, op, right) => {
+            let (a, b) = (eval(left, env)?, eval(right, env)?);
+            match (op, b) {
+                (Op::Div, 0) => Err(EvalError::DivisionByZero),
+                (Op::Add, _) => a.checked_add(b).ok_or(EvalError::Overflow),
+                (Op::Sub, _) => a.checked_sub(b).ok_or(EvalError::Overflow),
+                (Op::Mul, _) => a.checked_mul(b).ok_or(EvalError::Overflow),
+                (Op::Div, _) => a.checked_div(b).ok_or(EvalError::Overflow),
+            }
+        }
+        Expr::Let(name, value, body) => {
+            let value = eval(value, env)?;
+            env.push((name.clone(), value));
+            let result = eval(body, env);
+            env.pop();
+            result
+        }
+    }
+}
+
+// Algebraic simplification, written almost entirely as patterns. Patterns
+// can't look inside a `Box`, so the children are unboxed and simplified first,
+// and the resulting tuple is matched instead.
+fn simplify(expr: Expr) -> Expr {
+    use Expr::{Bin, Neg, Num};
+    match expr {
+        Bin(left, op, right) => {
+            let (left, right) = (simplify(*left), simplify(*right));
+            match (left, op, right) {
+                // x + 0, 0 + x, x - 0, x * 1, 1 * x, x / 1  =>  x
+                (x, Op::Add | Op::Sub, Num(0)) | (Num(0), Op::Add, x) => x,
+                (x, Op::Mul | Op::Div, Num(1)) | (Num(1), Op::Mul, x) => x,
+                // x * 0, 0 * x  =>  0
+                (_, Op::Mul, Num(0)) | (Num(0), Op::Mul, _) => Num(0),
+                // x - x  =>  0 (only when both sides are the same variable)
+                (Expr::Var(a), Op::Sub, Expr::Var(b)) if a == b => Num(0),
+                // Two constants: fold them, unless that would fail at runtime
+                (Num(a), op, Num(b)) => match eval(
+                    &Bin(Box::new(Num(a)), op, Box::new(Num(b))),
+                    &mut Vec::new(),
+                ) {
+                    Ok(n) => Num(n),
+                    Err(_) => Bin(Box::new(Num(a)), op, Box::new(Num(b))),
+                },
+                (left, op, right) => Bin(Box::new(left), op, Box::new(right)),
+            }
+        }
+        Neg(inner) => match simplify(*inner) {
+            Neg(x) => *x,                       // --x  =>  x
+            Num(n) if n != i64::MIN => Num(-n), // -(5) =>  -5
+            other => Neg(Box::new(other)),
+        },
+        Expr::Let(name, value, body) => {
+            Expr::Let(name, Box::new(simplify(*value)), Box::new(simplify(*body)))
+        }
+        leaf @ (Num(_) | Expr::Var(_)) => leaf,
+    }
+}
+
+// Small constructors so the examples stay readable.
+fn num(n: i64) -> Expr {
+    Expr::Num(n)
+}
+fn var(name: &str) -> Expr {
+    Expr::Var(name.to_string())
+}
+fn bin(left: Expr, op: Op, right: Expr) -> Expr {
+    Expr::Bin(Box::new(left), op, Box::new(right))
+}
+
+fn main() {
+    println!("--- Pattern Matching Mastery ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Match Guards
+    // -------------------------------------------------------------------------
+    // `pattern if condition =>` adds an arbitrary boolean test to an arm.
+    // The arm only matches if both the pattern and the guard do.
+
+    println!("\n--- 1. Match Guards ---");
+
+    fn describe_temperature(celsius: i32) -> &'static str {
+        match celsius {
+            t if t < 0 => "freezing",
+            t if t < 15 => "cold",
+            t if t < 25 => "pleasant",
+            _ => "hot",
+        }
+    }
+    for t in [-5, 10, 20, 30] {
+        println!("{}°C is {}", t, describe_temperature(t));
+    }
+    assert_eq!(describe_temperature(0), "cold");
+
+    // Guards can use variables bound by the pattern *and* outside variables.
+    let target = 4;
+    let pair = (2, 2);
+    match pair {
+        (a, b) if a + b == target => println!("{:?} adds up to the target", pair),
+        (a, b) if a == b => println!("{:?} is a pair of twins", pair),
+        _ => println!("{:?} is nothing special", pair),
+    }
+
+    // -------------------------------------------------------------------------
+    // 2. Range Patterns
+    // -------------------------------------------------------------------------
+    // Inclusive ranges (`a..=b`), and half-open ranges (`..b`, `a..`) work on
+    // integers and `char`s.
+
+    println!("\n--- 2. Range Patterns ---");
+
+    fn grade(score: u8) -> char {
+        match score {
+            90..=100 => 'A',
+            80..90 => 'B',
+            70..80 => 'C',
+            0..70 => 'F',
+            101.. => '?', // Exhaustiveness forces us to handle the rest of `u8`
+        }
+    }
+    assert_eq!(grade(95), 'A');
+    assert_eq!(grade(80), 'B');
+    assert_eq!(grade(79), 'C');
+    assert_eq!(grade(200), '?');
+    println!(
+        "Grades for 95, 85, 42: {} {} {}",
+        grade(95),
+        grade(85),
+        grade(42)
+    );
+
+    fn char_kind(c: char) -> &'static str {
+        match c {
+            'a'..='z' => "lowercase",
+            'A'..='Z' => "uppercase",
+            '0'..='9' => "digit",
+            _ => "other",
+        }
+    }
+    assert_eq!(char_kind('q'), "lowercase");
+    assert_eq!(char_kind('7'), "digit");
+
+    // -------------------------------------------------------------------------
+    // 3. `@` Bindings
+    // -------------------------------------------------------------------------
+    // `name @ pattern` tests a value against a pattern *and* keeps the value.
+
+    println!("\n--- 3. `@` Bindings ---");
+
+    fn classify_age(age: u32) -> String {
+        match age {
+            child @ 0..=12 => format!("child aged {}", child),
+            teen @ 13..=19 => format!("teenager aged {}", teen),
+            adult => format!("adult aged {}", adult),
+        }
+    }
+    println!("{}", classify_age(9));
+    println!("{}", classify_age(16));
+    assert_eq!(classify_age(40), "adult aged 40");
+
+    // `@` also works with nested patterns, e.g. keeping a whole struct.
+    #[derive(Debug)]
+    struct Message {
+        id: u32,
+        priority: u8,
+    }
+    let message = Message { id: 7, priority: 9 };
+    if let urgent @ Message { priority: 8.., .. } = &message {
+        println!("Urgent message {}: {:?}", urgent.id, urgent);
+    }
+
+    // -------------------------------------------------------------------------
+    // 4. Or-Patterns
+    // -------------------------------------------------------------------------
+    // `p1 | p2` matches either. Since Rust 2021, or-patterns can be nested
+    // anywhere inside a pattern, not just at the top level.
+
+    println!("\n--- 4. Or-Patterns ---");
+
+    fn is_weekend(day: &str) -> bool {
+        matches!(day, "Sat" | "Sun")
+    }
+    assert!(is_weekend("Sun"));
+    assert!(!is_weekend("Mon"));
+
+    // Nested: both alternatives must bind the same variables with the same types.
+    let command = ("move", 3);
+    match command {
+        ("move" | "go", steps @ 1..=5) => println!("Moving {} steps", steps),
+        ("move" | "go", steps) => println!("{} steps is too far", steps),
+        (other, _) => println!("Unknown command: {}", other),
+    }
+
+    // -------------------------------------------------------------------------
+    // 5. Nested Destructuring
+    // -------------------------------------------------------------------------
+    // Patterns mirror the shape of the data: structs inside enums inside
+    // tuples, slices, references...
+
+    println!("\n--- 5. Nested Destructuring ---");
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug)]
+    enum Shape {
+        Circle { center: Point, radius: u32 },
+        Line(Point, Point),
+    }
+
+    fn describe_shape(shape: &Shape) -> String {
+        match shape {
+            Shape::Circle {
+                center: Point { x: 0, y: 0 },
+                radius,
+            } => {
+                format!("circle of radius {} at the origin", radius)
+            }
+            Shape::Circle {
+                center: Point { x, y },
+                radius: r @ 10..,
+            } => {
+                format!("big circle (r = {}) at ({}, {})", r, x, y)
+            }
+            Shape::Circle { radius, .. } => format!("circle of radius {}", radius),
+            Shape::Line(Point { x: x1, .. }, Point { x: x2, .. }) if x1 == x2 => {
+                format!("vertical line at x = {}", x1)
+            }
+            Shape::Line(start, end) => format!("line from {:?} to {:?}", start, end),
+        }
+    }
+
+    let shapes = [
+        Shape::Circle {
+            center: Point { x: 0, y: 0 },
+            radius: 3,
+        },
+        Shape::Circle {
+            center: Point { x: 5, y: -2 },
+            radius: 12,
+        },
+        Shape::Circle {
+            center: Point { x: 1, y: 1 },
+            radius: 2,
+        },
+        Shape::Line(Point { x: 4, y: 0 }, Point { x: 4, y: 9 }),
+        Shape::Line(Point { x: 0, y: 0 }, Point { x: 3, y: 4 }),
+    ];
+    for shape in &shapes {
+        println!("{}", describe_shape(shape));
+    }
+    assert_eq!(describe_shape(&shapes[3]), "vertical line at x = 4");
+
+    // Slice patterns: match on length and pick out elements.
+    fn summarize(values: &[i32]) -> String {
+        match values {
+            [] => String::from("nothing"),
+            [single] => format!("just {}", single),
+            [first, second] => format!("{} and {}", first, second),
+            [first, .., last] => format!("{} to {} ({} items)", first, last, values.len()),
+        }
+    }
+    assert_eq!(summarize(&[]), "nothing");
+    assert_eq!(summarize(&[1, 2]), "1 and 2");
+    assert_eq!(summarize(&[1, 2, 3, 4]), "1 to 4 (4 items)");
+
+    // Destructuring works in `let`, function parameters and `for` loops too.
+    let (name, Point { x, y }) = ("home", Point { x: 3, y: 7 });
+    println!("{} is at ({}, {})", name, x, y);
+    let distances: Vec<i32> = [(1, 2), (3, 4)].iter().map(|&(a, b)| b - a).collect();
+    assert_eq!(distances, [1, 1]);
+
+    // -------------------------------------------------------------------------
+    // 6. Binding Modes: `ref`, `ref mut`, and Matching on References
+    // -------------------------------------------------------------------------
+    // Matching a *value* moves non-`Copy` fields into the bindings. `ref`
+    // borrows them instead; `ref mut` borrows them mutably.
+
+    println!("\n--- 6. Binding Modes ---");
+
+    let maybe_name = Some(String::from("Ferris"));
+    // Without `ref`, `n` would take the String out of `maybe_name`.
+    if let Some(ref n) = maybe_name {
+        println!("Borrowed name: {}", n);
+    }
+    println!("Still usable: {:?}", maybe_name);
+
+    let mut maybe_count = Some(5);
+    if let Some(ref mut count) = maybe_count {
+        *count += 1;
+    }
+    assert_eq!(maybe_count, Some(6));
+
+    // Default binding modes ("match ergonomics"): when you match a *reference*
+    // with a non-reference pattern, bindings automatically become references.
+    // This is why `ref` is rarely written in modern code.
+    let maybe_title = Some(String::from("Rust"));
+    if let Some(title) = &maybe_title {
+        println!("title is a &String of length {}", title.len()); // `title: &String`
+    }
+    let mut scores = vec![(String::from("Ada"), 3), (String::from("Bob"), 5)];
+    for (_, score) in &mut scores {
+        *score *= 10; // `score: &mut i32`
+    }
+    assert_eq!(scores[1].1, 50);
+
+    // -------------------------------------------------------------------------
+    // 7. Exhaustiveness and Irrefutable Patterns
+    // -------------------------------------------------------------------------
+    // A `match` must cover every possible value. The compiler tells you which
+    // values are missing, which makes adding enum variants safe: every `match`
+    // that needs updating becomes a compile error.
+
+    println!("\n--- 7. Exhaustiveness ---");
+
+    /*
+    match grade(50) {
+        'A' | 'B' | 'C' => {}
+    } // error[E0004]: non-exhaustive patterns: '\0'..='@', 'D'..='\u{10ffff}' not covered
+    */
+
+    // Prefer listing variants over `_` for your own enums: with `_`, a newly
+    // added variant silently falls into the catch-all.
+    fn op_name(op: Op) -> &'static str {
+        match op {
+            Op::Add => "add",
+            Op::Sub => "subtract",
+            Op::Mul => "multiply",
+            Op::Div => "divide",
+        }
+    }
+    assert_eq!(op_name(Op::Div), "divide");
+
+    // `let` needs an *irrefutable* pattern (one that always matches).
+    // For refutable ones use `if let`, `let ... else`, or `match`.
+    /*
+    let Some(x) = maybe_count; // error[E0005]: refutable pattern in local binding
+    */
+    let Some(count) = maybe_count else {
+        panic!("count must be set");
+    };
+    assert_eq!(count, 6);
+
+    // -------------------------------------------------------------------------
+    // 8. Putting It Together: An Expression Evaluator
+    // -------------------------------------------------------------------------
+    // `eval` and `simplify` (above `main`) use nested enum patterns, tuple
+    // matching with guards, or-patterns, `@` bindings and box dereferencing.
+
+    println!("\n--- 8. Putting It Together: An Expression Evaluator ---");
+
+    // let x = 6 in (x * 7) + (0 * y)
+    let program = Expr::Let(
+        String::from("x"),
+        Box::new(num(6)),
+        Box::new(bin(
+            bin(var("x"), Op::Mul, num(7)),
+            Op::Add,
+            bin(num(0), Op::Mul, var("y")),
+        )),
+    );
+    println!("Program: {}", program);
+    assert_eq!(
+        eval(&program, &mut Vec::new()),
+        Err(EvalError::UnboundVariable(String::from("y")))
+    );
+
+    let simplified = simplify(program.clone());
+    println!("Simplified: {}", simplified); // `0 * y` is gone
+    assert_eq!(eval(&simplified, &mut Vec::new()), Ok(42));
+
+    let mut env = vec![(String::from("y"), 100)];
+    assert_eq!(eval(&program, &mut env), Ok(42));
+
+    // Shadowing: the inner `let` wins.
+    let shadowed = Expr::Let(
+        String::from("a"),
+        Box::new(num(1)),
+        Box::new(Expr::Let(
+            String::from("a"),
+            Box::new(num(2)),
+            Box::new(var("a")),
+        )),
+    );
+    assert_eq!(eval(&shadowed, &mut Vec::new()), Ok(2));
+
+    // Errors
+    assert_eq!(
+        eval(&bin(num(1), Op::Div, num(0)), &mut Vec::new()),
+        Err(EvalError::DivisionByZero)
+    );
+    assert_eq!(
+        eval(&bin(num(i64::MAX), Op::Add, num(1)), &mut Vec::new()),
+        Err(EvalError::Overflow)
+    );
+    assert_eq!(
+        eval(&Expr::Neg(Box::new(num(i64::MIN))), &mut Vec::new()),
+        Err(EvalError::Overflow)
+    );
+
+    // Simplification rules
+    assert_eq!(simplify(bin(var("z"), Op::Sub, var("z"))), num(0));
+    assert_eq!(simplify(bin(num(1), Op::Mul, var("z"))), var("z"));
+    assert_eq!(simplify(bin(num(2), Op::Add, num(3))), num(5));
+    assert_eq!(
+        simplify(Expr::Neg(Box::new(Expr::Neg(Box::new(var("z")))))),
+        var("z")
+    );
+    // Constant folding leaves runtime errors for `eval` to report:
+    let division = bin(num(1), Op::Div, num(0));
+    assert_eq!(simplify(division.clone()), division);
+    println!("{} stays {}", division, simplify(division.clone()));
+
+    println!("\n--- End of Pattern Matching Examples ---");
+}
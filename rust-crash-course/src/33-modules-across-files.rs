@@ -0,0 +1,135 @@
+// This file continues the package/crate/module lesson, this time with code
+// that really lives in other files. Everything under `lessons::modules_demo`
+// is loaded from `src/lessons/`:
+//
+//   src/33-modules-across-files.rs   <- crate root (this file): `mod lessons;`
+//   src/lessons/mod.rs               <- `pub mod modules_demo;`
+//   src/lessons/modules_demo/mod.rs  <- `mod api; mod storage;` + `pub use` re-exports
+//   src/lessons/modules_demo/api.rs
+//   src/lessons/modules_demo/storage.rs
+//
+// Read those files alongside this one: they show `mod`, `pub`, `pub(crate)`,
+// `pub(super)`, `use` with `crate::`/`super::`/`self::` paths, and re-exports.
+// Compile it like the other lessons: `rustc --edition 2024 src/33-modules-across-files.rs`.
+
+// -------------------------------------------------------------------------
+// 0. Declaring a Module That Lives in a File
+// -------------------------------------------------------------------------
+// `mod lessons;` (with a semicolon instead of a `{ ... }` body) tells the
+// compiler "the contents of this module are in another file". It looks next
+// to the current file for `lessons.rs` or `lessons/mod.rs`. There is no
+// `#include`: every file is reached through exactly one `mod` declaration.
+
+mod lessons;
+
+// `use` creates a shortcut to a path. This one points at a *re-export*:
+// `NoteApi` is defined in `modules_demo/api.rs`, but `modules_demo/mod.rs`
+// re-exports it, so it can be named without mentioning `api` at all.
+use lessons::modules_demo::{ApiError, NoteApi};
+
+fn main() {
+    println!("--- Modules Across Files ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Using the Public API
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Using the Public API ---");
+
+    let mut api = NoteApi::new();
+    let first = api
+        .create("Learn   modules", "mod, pub, use")
+        .expect("valid note");
+    let second = api
+        .create("Read the api.rs file", "It uses super:: paths")
+        .expect("valid note");
+    println!("{}", api.render(first).expect("note exists"));
+    println!("Notes stored: {}", api.count());
+
+    // The title was normalized by a *private* function in `modules_demo/mod.rs`,
+    // called from `storage.rs` via `super::normalize_title`.
+    assert_eq!(api.title(first), Ok("Learn modules"));
+    assert_eq!(api.count(), 2);
+
+    // -------------------------------------------------------------------------
+    // 2. Paths: Absolute, Relative, and Re-exported
+    // -------------------------------------------------------------------------
+    // The same type can be reached by several paths:
+    // - `crate::lessons::modules_demo::NoteId` (absolute, from the crate root)
+    // - `lessons::modules_demo::NoteId` (relative to the current module)
+    // - through a `use` shortcut.
+    // All of them go through the re-export, because `storage` itself is private.
+
+    println!("\n--- 2. Paths ---");
+
+    let id: crate::lessons::modules_demo::NoteId = second;
+    let same_id: lessons::modules_demo::NoteId = second;
+    assert_eq!(id, same_id);
+    println!("Second note has id {}", id.value());
+
+    /*
+    let id: lessons::modules_demo::storage::NoteId = second;
+    // error[E0603]: module `storage` is private
+    */
+
+    // A `pub(crate)` item is usable anywhere in this crate, even though it's
+    // not re-exported as part of the module's public API.
+    println!(
+        "Title limit (pub(crate)): {}",
+        lessons::modules_demo::MAX_TITLE_LEN
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. Visibility Is Enforced Everywhere
+    // -------------------------------------------------------------------------
+    // Each of these fails to compile, because the item is private to the
+    // module (or file) that defines it.
+
+    println!("\n--- 3. Visibility ---");
+
+    /*
+    let title = lessons::modules_demo::normalize_title(" x ");
+    // error[E0603]: function `normalize_title` is private
+    // (private items are visible only in their module and its children)
+
+    let forged = lessons::modules_demo::NoteId(99);
+    // error[E0423]: cannot initialize a tuple struct which contains private fields
+    // (so ids can only come from `NoteApi::create`)
+
+    let count = api.store.len();
+    // error[E0616]: field `store` of struct `NoteApi` is private
+    */
+
+    // Private fields + public methods = invariants the caller can't break.
+    // Errors come back as the re-exported `ApiError`.
+    let too_long = "x".repeat(lessons::modules_demo::MAX_TITLE_LEN + 1);
+    match api.create(&too_long, "") {
+        Err(err) => println!("Rejected: {}", err),
+        Ok(_) => unreachable!("title is over the limit"),
+    }
+    assert_eq!(api.create("   ", ""), Err(ApiError::EmptyTitle));
+
+    api.delete(first).expect("note exists");
+    assert_eq!(api.delete(first), Err(ApiError::NotFound(first)));
+    println!(
+        "After deleting: {} note(s), deleting again: {}",
+        api.count(),
+        api.delete(first).unwrap_err()
+    );
+
+    // -------------------------------------------------------------------------
+    // 4. Summary of Visibility Levels
+    // -------------------------------------------------------------------------
+    // - (nothing): the current module and its children.
+    //   `normalize_title`, `validate_title`, `NoteStore::notes`
+    // - `pub(super)`: the parent module, and so its other children too.
+    //   `NoteStore`, `Note`
+    // - `pub(crate)`: the whole crate. `MAX_TITLE_LEN`
+    // - `pub`: anyone who can reach the path. `NoteApi`, `NoteId`, `ApiError`
+    //
+    // A `pub` item inside a private module is only reachable through a
+    // `pub use` re-export, which is how `modules_demo/mod.rs` keeps its file
+    // structure an implementation detail.
+
+    println!("\n--- End of Modules Across Files Examples ---");
+}
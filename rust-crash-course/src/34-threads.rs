@@ -0,0 +1,327 @@
+// This file covers concurrency with operating-system threads, the foundation
+// the async lesson builds on: spawning threads and joining them, sharing data
+// with `Arc`, protecting it with `Mutex` and `RwLock`, what happens when a
+// thread panics while holding a lock (poisoning), and scoped threads that can
+// borrow local data. It ends with a multi-threaded word count.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
+
+#[path = "lessons/table.rs"]
+mod table;
+
+use table::{Align, Table};
+
+// Counts words in `text` using `workers` threads. Each thread counts its own
+// chunk of lines into a private map, and the maps are merged at the end, so
+// the threads never wait for each other while counting.
+fn parallel_word_count(text: &str, workers: usize) -> HashMap<String, usize> {
+    let lines: Vec<&str> = text.lines().collect();
+    let chunk_size = lines.len().div_ceil(workers.max(1)).max(1);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = lines
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    let mut counts = HashMap::new();
+                    for word in chunk.iter().flat_map(|line| line.split_whitespace()) {
+                        let word = word
+                            .trim_matches(|c: char| !c.is_alphanumeric())
+                            .to_lowercase();
+                        if !word.is_empty() {
+                            *counts.entry(word).or_insert(0) += 1;
+                        }
+                    }
+                    counts
+                })
+            })
+            .collect();
+
+        let mut total = HashMap::new();
+        for handle in handles {
+            for (word, count) in handle.join().expect("worker panicked") {
+                *total.entry(word).or_insert(0) += count;
+            }
+        }
+        total
+    })
+}
+
+// The same count, but every thread updates one shared map behind a `Mutex`.
+// Simpler to write, but the threads contend for the lock on every word.
+fn shared_map_word_count(text: &str, workers: usize) -> HashMap<String, usize> {
+    let counts = Arc::new(Mutex::new(HashMap::new()));
+    let lines: Arc<Vec<String>> = Arc::new(text.lines().map(String::from).collect());
+
+    let handles: Vec<_> = (0..workers)
+        .map(|worker| {
+            let counts = Arc::clone(&counts);
+            let lines = Arc::clone(&lines);
+            thread::spawn(move || {
+                // Worker `n` takes every `workers`-th line, starting at line `n`.
+                for line in lines.iter().skip(worker).step_by(workers) {
+                    for word in line.split_whitespace() {
+                        let word = word
+                            .trim_matches(|c: char| !c.is_alphanumeric())
+                            .to_lowercase();
+                        if !word.is_empty() {
+                            *counts.lock().unwrap().entry(word).or_insert(0) += 1;
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().expect("worker panicked");
+    }
+    // All other `Arc`s were dropped with their threads, so we own the map again.
+    Arc::try_unwrap(counts)
+        .expect("all workers have finished")
+        .into_inner()
+        .unwrap()
+}
+
+fn main() {
+    println!("--- Threads, Mutex, RwLock and Arc ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Spawning Threads and Joining Them
+    // -------------------------------------------------------------------------
+    // `thread::spawn` runs a closure on a new OS thread and returns a
+    // `JoinHandle<T>`. `join()` waits for the thread and returns its result:
+    // `Ok(T)`, or `Err` if the thread panicked.
+
+    println!("\n--- 1. Spawning Threads ---");
+
+    let handle = thread::spawn(|| {
+        let mut sum = 0u64;
+        for i in 1..=1_000 {
+            sum += i;
+        }
+        sum // The thread's return value
+    });
+    let sum = handle.join().expect("thread panicked");
+    println!("Sum computed on another thread: {}", sum);
+    assert_eq!(sum, 500_500);
+
+    // Several threads run concurrently; their output can interleave in any order.
+    let handles: Vec<thread::JoinHandle<String>> = (1..=3)
+        .map(|id| {
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10 * (4 - id))); // Later ids finish first
+                format!("worker {} done", id)
+            })
+        })
+        .collect();
+    // Joining in spawn order collects the results in that order, whatever order they finished in.
+    let results: Vec<String> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    println!("{:?}", results);
+
+    // The closure must be `'static`: it may outlive the function that spawned it,
+    // so it can't borrow locals. `move` transfers ownership instead.
+    let name = String::from("Ferris");
+    /*
+    let greeter = thread::spawn(|| println!("Hello, {}", name));
+    // error[E0373]: closure may outlive the current function, but it borrows `name`
+    */
+    let greeter = thread::spawn(move || format!("Hello, {}", name));
+    println!("{}", greeter.join().unwrap());
+
+    // A panic in a thread doesn't crash the program; `join` reports it.
+    let panicking = thread::spawn(|| {
+        let empty: Vec<i32> = Vec::new();
+        empty[0] // Index out of bounds
+    });
+    let outcome = panicking.join();
+    println!("Joining a panicked thread: is_err = {}", outcome.is_err());
+    assert!(outcome.is_err());
+
+    // -------------------------------------------------------------------------
+    // 2. Sharing Data: `Arc<Mutex<T>>`
+    // -------------------------------------------------------------------------
+    // - `Arc<T>` (atomically reference counted) gives several threads shared
+    //   *ownership* of one value. `Rc` can't do this: its count isn't atomic,
+    //   so it doesn't implement `Send` (see 64-send-sync-and-auto-traits.rs).
+    // - `Mutex<T>` gives one thread at a time *mutable* access. `lock()`
+    //   returns a guard; the lock is released when the guard is dropped.
+
+    println!("\n--- 2. `Arc<Mutex<T>>` ---");
+
+    let counter = Arc::new(Mutex::new(0));
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let counter = Arc::clone(&counter); // Each thread gets its own handle
+            thread::spawn(move || {
+                for _ in 0..1_000 {
+                    *counter.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!(
+        "Counter after 8 x 1000 increments: {}",
+        *counter.lock().unwrap()
+    );
+    assert_eq!(*counter.lock().unwrap(), 8_000);
+
+    /*
+    let shared = std::rc::Rc::new(5);
+    thread::spawn(move || println!("{}", shared));
+    // error[E0277]: `Rc<i32>` cannot be sent between threads safely
+    */
+    // (Shared `Vec`s, the `Rc<RefCell<T>>` equivalent, and deadlock: see
+    // 57-shared-state-arc-mutex.rs.)
+
+    // Keep critical sections short: the guard holds the lock until it's dropped.
+    let log = Mutex::new(Vec::new());
+    {
+        let mut entries = log.lock().unwrap();
+        entries.push("first");
+        entries.push("second");
+    } // Guard dropped here: lock released
+    log.lock().unwrap().push("third"); // Temporary guard dropped at the end of the statement
+    assert_eq!(log.lock().unwrap().len(), 3);
+
+    // -------------------------------------------------------------------------
+    // 3. `RwLock<T>`: Many Readers or One Writer
+    // -------------------------------------------------------------------------
+    // `RwLock` allows any number of simultaneous `read()` guards, or a single
+    // `write()` guard. Useful for data that is read often and changed rarely,
+    // like configuration.
+
+    println!("\n--- 3. `RwLock<T>` ---");
+
+    let config = Arc::new(RwLock::new(HashMap::from([("mode", "fast")])));
+
+    let readers: Vec<_> = (0..4)
+        .map(|id| {
+            let config = Arc::clone(&config);
+            thread::spawn(move || {
+                let settings = config.read().unwrap(); // Readers don't block each other
+                format!("reader {} sees mode={}", id, settings["mode"])
+            })
+        })
+        .collect();
+    for reader in readers {
+        println!("{}", reader.join().unwrap());
+    }
+
+    {
+        let mut settings = config.write().unwrap(); // Waits until no readers remain
+        settings.insert("mode", "safe");
+    }
+    assert_eq!(config.read().unwrap()["mode"], "safe");
+
+    // Both locks can deadlock if one thread takes the same lock twice:
+    /*
+    let guard = config.read().unwrap();
+    let mut writer = config.write().unwrap(); // Blocks forever (or panics): we hold a read guard
+    */
+
+    // -------------------------------------------------------------------------
+    // 4. Poisoning
+    // -------------------------------------------------------------------------
+    // If a thread panics while holding a `Mutex` (or `RwLock` write guard), the
+    // lock becomes *poisoned*: the data might be half-updated. Later `lock()`
+    // calls return `Err(PoisonError)`. That's why `lock().unwrap()` is common:
+    // it propagates the panic instead of silently using broken data.
+
+    println!("\n--- 4. Poisoning ---");
+
+    let balances = Arc::new(Mutex::new(vec![100, 100]));
+    let poisoner = {
+        let balances = Arc::clone(&balances);
+        thread::spawn(move || {
+            let mut accounts = balances.lock().unwrap();
+            accounts[0] -= 50; // Money leaves account 0...
+            panic!("crashed before crediting account 1"); // ...but never arrives
+        })
+    };
+    assert!(poisoner.join().is_err());
+    println!("Mutex poisoned: {}", balances.is_poisoned());
+
+    match balances.lock() {
+        Ok(_) => unreachable!("the lock is poisoned"),
+        Err(poisoned) => {
+            // The data is still reachable if you decide it's safe to use.
+            let accounts = poisoned.into_inner();
+            println!("Recovered (inconsistent) data: {:?}", *accounts);
+            assert_eq!(accounts.iter().sum::<i32>(), 150); // 50 went missing
+        }
+    }
+    // After repairing the data, the poison flag can be cleared.
+    balances.clear_poison();
+    assert!(balances.lock().is_ok());
+
+    // -------------------------------------------------------------------------
+    // 5. Scoped Threads
+    // -------------------------------------------------------------------------
+    // `thread::scope` guarantees that every thread spawned inside it is joined
+    // before the scope returns. So the threads *can* borrow local variables:
+    // no `Arc`, no `move`, no `'static` requirement.
+
+    println!("\n--- 5. Scoped Threads ---");
+
+    let numbers: Vec<u64> = (1..=100).collect();
+    let mut evens = Vec::new();
+    let mut odd_sum = 0;
+
+    thread::scope(|s| {
+        s.spawn(|| evens = numbers.iter().copied().filter(|n| n % 2 == 0).collect());
+        s.spawn(|| odd_sum = numbers.iter().filter(|&&n| n % 2 == 1).sum());
+        // Both threads borrow `numbers` immutably; each one mutably borrows a
+        // *different* variable, so the borrow checker accepts it.
+    }); // Both threads are joined here
+
+    println!("{} evens, odd sum {}", evens.len(), odd_sum);
+    assert_eq!(evens.len(), 50);
+    assert_eq!(odd_sum, 2_500);
+    println!("`numbers` is still ours: {} items", numbers.len());
+
+    // -------------------------------------------------------------------------
+    // 6. Putting It Together: A Multi-Threaded Word Count
+    // -------------------------------------------------------------------------
+    // `parallel_word_count` (scoped threads, one map per thread, merged at the
+    // end) and `shared_map_word_count` (`Arc<Mutex<HashMap>>` updated by every
+    // thread) must agree with a single-threaded count.
+
+    println!("\n--- 6. Multi-Threaded Word Count ---");
+
+    let text = "The quick brown fox jumps over the lazy dog.\n\
+                The dog sleeps, the fox runs!\n\
+                A quick fox is a happy fox.\n\
+                Lazy dogs and quick foxes: the end.\n"
+        .repeat(250);
+
+    let single = parallel_word_count(&text, 1);
+    let scoped = parallel_word_count(&text, 4);
+    let shared = shared_map_word_count(&text, 4);
+
+    let mut top: Vec<(&String, &usize)> = scoped.iter().collect();
+    top.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let mut top_table = Table::new(["word", "count"]).align(1, Align::Right);
+    for (word, count) in &top[..3] {
+        top_table.row([word.to_string(), count.to_string()]);
+    }
+    println!("Top words:\n{}", top_table);
+
+    assert_eq!(scoped, single);
+    assert_eq!(shared, single);
+    assert_eq!(scoped["the"], 5 * 250);
+    assert_eq!(scoped["fox"], 4 * 250);
+    assert_eq!(parallel_word_count("", 4), HashMap::new());
+
+    // Which design to prefer: give each thread its own data and combine the
+    // results (no locking while working) whenever the problem allows it.
+    // Reach for a shared `Mutex` when threads really need to see each other's updates.
+
+    println!("\n--- End of Threads Examples ---");
+}
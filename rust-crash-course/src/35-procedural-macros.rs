@@ -0,0 +1,220 @@
+// This file uses real procedural macros from the `lesson-derive` crate in this
+// workspace (see `lesson-derive/src/lib.rs`): `#[derive(Summary)]`, which
+// writes the traits lesson's `impl Summary` blocks for us, and `#[log_call]`,
+// an attribute macro. The macros lesson only sketched these; here they run.
+//
+// Procedural macros must live in their own `proc-macro` crate, so this lesson
+// needs that crate built first:
+//
+//   cargo build -p lesson-derive
+//   rustc --edition 2024 src/35-procedural-macros.rs -L target/debug --extern lesson_derive
+//
+// To see what the macros generate, `cargo expand` (a separate tool) prints
+// the expanded code; the comments below show it by hand.
+
+use lesson_derive::{Summary, log_call};
+
+// The same trait as in the traits lesson. A proc-macro crate can only export
+// macros, not traits, so the trait is defined here and the derive generates
+// `impl Summary for ...` that refers to it by name.
+trait Summary {
+    fn summarize(&self) -> String;
+    fn author_info(&self) -> String;
+}
+
+// -------------------------------------------------------------------------
+// Types Using the Derive
+// -------------------------------------------------------------------------
+
+// `#[summary]` marks the fields to include in `summarize()`, and
+// `#[summary(author)]` the field for `author_info()`. These are *helper
+// attributes*: registered by the derive (`attributes(summary)`), they mean
+// nothing on their own and are only read by the macro.
+#[derive(Debug, Summary)]
+struct NewsArticle {
+    #[summary]
+    headline: String,
+    #[summary]
+    location: String,
+    #[summary(author)]
+    author: String,
+    content: String, // Not in the summary
+}
+
+// Expands to (roughly):
+//
+// impl Summary for NewsArticle {
+//     fn summarize(&self) -> String {
+//         format!("NewsArticle { headline: {}, location: {} }", self.headline, self.location)
+//     }
+//     fn author_info(&self) -> String {
+//         format!("Author: {}", self.author)
+//     }
+// }
+
+#[derive(Debug, Summary)]
+pub struct Tweet {
+    #[summary(author)]
+    pub username: String,
+    #[summary]
+    pub content: String,
+    pub reply: bool,
+    pub retweet: bool,
+}
+
+// No helper attributes: every field is summarized, and there's no author.
+#[derive(Summary)]
+struct Measurement {
+    sensor: &'static str,
+    celsius: f64,
+}
+
+// -------------------------------------------------------------------------
+// Functions Using the Attribute Macro
+// -------------------------------------------------------------------------
+// `#[log_call]` receives the whole function as tokens and returns it with a
+// `println!` inserted at the start of the body.
+
+#[log_call]
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+// Attribute arguments are passed to the macro too: here, the log label.
+#[log_call(audit)]
+fn transfer(from: &str, to: &str, cents: u64) -> String {
+    format!("moved {} cents from {} to {}", cents, from, to)
+}
+
+fn main() {
+    println!("--- Procedural Macros ---");
+
+    // -------------------------------------------------------------------------
+    // 1. A Custom Derive
+    // -------------------------------------------------------------------------
+    // `#[derive(Summary)]` works exactly like `#[derive(Debug)]`: the compiler
+    // hands the struct definition to a function in a proc-macro crate, and
+    // compiles the `impl` it returns as if you had written it.
+
+    println!("\n--- 1. A Custom Derive ---");
+
+    let article = NewsArticle {
+        headline: String::from("Penguins win the Stanley Cup!"),
+        location: String::from("Pittsburgh, PA, USA"),
+        author: String::from("Iceburgh"),
+        content: String::from("The Pittsburgh Penguins once again triumphed over their rivals."),
+    };
+    println!("{}", article.summarize());
+    println!("{}", article.author_info());
+    println!("(content has {} characters)", article.content.len());
+    assert_eq!(
+        article.summarize(),
+        "NewsArticle { headline: Penguins win the Stanley Cup!, location: Pittsburgh, PA, USA }"
+    );
+    assert_eq!(article.author_info(), "Author: Iceburgh");
+
+    let tweet = Tweet {
+        username: String::from("horse_ebooks"),
+        content: String::from("of course, as you probably already know, people"),
+        reply: false,
+        retweet: false,
+    };
+    println!("{}", tweet.summarize());
+    println!("{}", tweet.author_info());
+    println!("(reply: {}, retweet: {})", tweet.reply, tweet.retweet);
+    assert_eq!(tweet.author_info(), "Author: horse_ebooks");
+
+    let reading = Measurement {
+        sensor: "greenhouse",
+        celsius: 21.5,
+    };
+    println!("{}", reading.summarize());
+    assert_eq!(
+        reading.summarize(),
+        "Measurement { sensor: greenhouse, celsius: 21.5 }"
+    );
+    assert_eq!(reading.author_info(), "Author: unknown");
+
+    // Derived impls are ordinary impls, so generic code works with them.
+    fn print_all(items: &[&dyn Summary]) {
+        for item in items {
+            println!("- {}", item.summarize());
+        }
+    }
+    print_all(&[&article, &tweet, &reading]);
+
+    // -------------------------------------------------------------------------
+    // 2. Errors From a Macro
+    // -------------------------------------------------------------------------
+    // A macro reports misuse by expanding to `compile_error!`, so the user sees
+    // a normal compiler error. The error is reported at the span of the
+    // `compile_error!` tokens: these macros give them the span of the input
+    // token at fault (the `enum` keyword, the `title` option), so the error
+    // points at the user's code rather than at the `#[derive]`.
+
+    println!("\n--- 2. Errors From a Macro ---");
+
+    /*
+    #[derive(Summary)]
+    enum Status { Active, Inactive }
+    // error: `#[derive(Summary)]` only supports structs with named fields
+
+    #[derive(Summary)]
+    struct Labelled {
+        #[summary(title)]
+        label: String,
+    }
+    // error: unknown option `title`, expected `#[summary]` or `#[summary(author)]`
+
+    #[derive(Summary)]
+    struct Point { x: Vec<i32> }
+    // error[E0277]: `Vec<i32>` doesn't implement `std::fmt::Display`
+    // (the macro only works on tokens: it can't know a field's type implements
+    // `Display`; the generated `format!` is what fails to compile, and since
+    // the macro wrote that code, the error points at `#[derive(Summary)]`)
+
+    #[log_call("{}")]
+    fn add(a: i32, b: i32) -> i32 { a + b }
+    // error: `#[log_call]` takes at most one identifier as its label, e.g. `#[log_call(audit)]`
+    // (the label is pasted into the `println!` format string, so a string
+    // with `{}` in it would change the generated code)
+
+    // Each of these cases is checked in lesson-derive/tests/compile_fail/.
+    */
+    println!("See the commented examples for the errors these macros produce.");
+
+    // -------------------------------------------------------------------------
+    // 3. An Attribute Macro
+    // -------------------------------------------------------------------------
+    // `#[log_call]` rewrites the function it's attached to. Calling `add`
+    // prints "[call] add" before running the original body.
+
+    println!("\n--- 3. An Attribute Macro ---");
+
+    let sum = add(2, 3);
+    assert_eq!(sum, 5);
+    let receipt = transfer("checking", "savings", 2_500);
+    println!("{}", receipt);
+    assert_eq!(receipt, "moved 2500 cents from checking to savings");
+
+    // -------------------------------------------------------------------------
+    // 4. How It Works
+    // -------------------------------------------------------------------------
+    // Read `lesson-derive/src/lib.rs` alongside this summary:
+    // - `Cargo.toml` sets `[lib] proc-macro = true`. The crate runs inside the
+    //   compiler, at compile time, and may only export macros.
+    // - `#[proc_macro_derive(Summary, attributes(summary))]` registers the
+    //   derive and its helper attribute. It gets the struct as a `TokenStream`
+    //   and returns *additional* items (the original struct is kept).
+    // - `#[proc_macro_attribute]` gets the attribute arguments and the item,
+    //   and returns a *replacement* for the item. `log_call` builds it from
+    //   the item's own tokens plus a new `println!`, so an error in the body
+    //   keeps its span. Formatting the body into a string and parsing it back
+    //   would report that error at `#[log_call]` instead.
+    // - Walking raw tokens is tedious and fragile (see the angle-bracket
+    //   counting in `parse_fields`). Real macros use `syn` to parse and
+    //   `quote!` to generate, which also keeps the original spans so errors
+    //   point at the right code.
+
+    println!("\n--- End of Procedural Macros Examples ---");
+}
@@ -0,0 +1,241 @@
+// This file covers calling C code from Rust (FFI, the Foreign Function
+// Interface). Unlike the other lessons it needs a C compiler and a build
+// script, so it's built by the `lesson-ffi` package in this workspace:
+//
+//   cargo run -p lesson-ffi
+//
+// - `lesson-ffi/c/textutil.c` is a small C library.
+// - `lesson-ffi/build.rs` compiles it with the `cc` crate and links it in.
+// - This file declares the C functions in an `extern "C"` block, converts
+//   between Rust strings and C strings (`CString` / `CStr`), and wraps every
+//   `unsafe` call in a safe Rust API.
+
+use std::ffi::{CStr, CString, NulError, c_char, c_int};
+use std::fmt;
+
+// -------------------------------------------------------------------------
+// 1. Declaring the C Functions
+// -------------------------------------------------------------------------
+// The signatures must match `textutil.h` exactly; the compiler can't check
+// them. A mistake here (wrong argument type, missing argument) is undefined
+// behavior, not a compile error. Tools like `bindgen` generate these
+// declarations from the header to avoid that.
+
+// `#[repr(C)]` lays out the fields like a C compiler would, so a pointer to
+// this struct can be handed to C code expecting a `tu_version`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+// Raw declarations, kept private: only the safe wrappers below may call them.
+// Since edition 2024, `extern` blocks must be marked `unsafe`, as a reminder
+// that the programmer vouches for the signatures.
+mod raw {
+    use super::Version;
+    use std::ffi::{c_char, c_int};
+
+    unsafe extern "C" {
+        pub fn tu_count_words(text: *const c_char) -> usize;
+        pub fn tu_checksum(data: *const u8, len: usize) -> u32;
+        pub fn tu_parse_version(text: *const c_char, out: *mut Version) -> c_int;
+        pub fn tu_reverse_words(text: *const c_char) -> *mut c_char;
+        pub fn tu_free(text: *mut c_char);
+    }
+}
+
+// -------------------------------------------------------------------------
+// 2. Safe Wrappers
+// -------------------------------------------------------------------------
+// Each wrapper upholds the C side's requirements (valid, NUL-terminated
+// pointers; who frees what) so callers never write `unsafe` themselves.
+
+#[derive(Debug, PartialEq)]
+pub enum FfiError {
+    // Rust strings may contain `\0`; C strings end at the first one.
+    InteriorNul(usize),
+    InvalidVersion(String),
+    OutOfMemory,
+    InvalidUtf8,
+}
+
+impl fmt::Display for FfiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FfiError::InteriorNul(pos) => write!(f, "string contains a NUL byte at {}", pos),
+            FfiError::InvalidVersion(text) => write!(f, "'{}' is not MAJOR.MINOR.PATCH", text),
+            FfiError::OutOfMemory => write!(f, "the C library ran out of memory"),
+            FfiError::InvalidUtf8 => write!(f, "the C library returned invalid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for FfiError {}
+
+impl From<NulError> for FfiError {
+    fn from(err: NulError) -> Self {
+        FfiError::InteriorNul(err.nul_position())
+    }
+}
+
+pub fn count_words(text: &str) -> Result<usize, FfiError> {
+    // `CString::new` copies the bytes and appends the terminating `\0`.
+    let c_text = CString::new(text)?;
+    // SAFETY: `c_text` is a valid NUL-terminated string that lives until the
+    // end of this function; `tu_count_words` only reads it.
+    Ok(unsafe { raw::tu_count_words(c_text.as_ptr()) })
+}
+
+// Slices cross the boundary as a pointer plus a length: no copy, no NUL needed.
+pub fn checksum(data: &[u8]) -> u32 {
+    // SAFETY: the pointer is valid for reads of `data.len()` bytes.
+    unsafe { raw::tu_checksum(data.as_ptr(), data.len()) }
+}
+
+pub fn parse_version(text: &str) -> Result<Version, FfiError> {
+    let c_text = CString::new(text)?;
+    let mut version = Version::default();
+    // SAFETY: `c_text` is NUL-terminated and `version` is a valid, writable
+    // `#[repr(C)]` struct for the duration of the call.
+    let status: c_int = unsafe { raw::tu_parse_version(c_text.as_ptr(), &mut version) };
+    match status {
+        0 => Ok(version),
+        _ => Err(FfiError::InvalidVersion(text.to_string())),
+    }
+}
+
+// Owns a string allocated by the C library and frees it with the library's
+// own `tu_free` on drop. Freeing C memory with Rust's allocator (e.g. by
+// turning it into a `CString` or `Box`) would be undefined behavior.
+struct CLibString(*mut c_char);
+
+impl CLibString {
+    fn as_c_str(&self) -> &CStr {
+        // SAFETY: the pointer came from the C library, is non-null (checked before
+        // construction), NUL-terminated, and valid until we free it in `drop`.
+        unsafe { CStr::from_ptr(self.0) }
+    }
+}
+
+impl Drop for CLibString {
+    fn drop(&mut self) {
+        // SAFETY: allocated by `tu_reverse_words`, freed exactly once here.
+        unsafe { raw::tu_free(self.0) }
+    }
+}
+
+pub fn reverse_words(text: &str) -> Result<String, FfiError> {
+    let c_text = CString::new(text)?;
+    // SAFETY: `c_text` is a valid NUL-terminated string.
+    let ptr = unsafe { raw::tu_reverse_words(c_text.as_ptr()) };
+    if ptr.is_null() {
+        return Err(FfiError::OutOfMemory);
+    }
+    let owned = CLibString(ptr); // From here on, `drop` frees it, even on early return
+    // `CStr` borrows the C memory; `to_str` checks UTF-8 and `to_owned` copies
+    // it into a Rust `String` before the C memory is freed.
+    owned
+        .as_c_str()
+        .to_str()
+        .map(str::to_owned)
+        .map_err(|_| FfiError::InvalidUtf8)
+}
+
+fn main() {
+    println!("--- FFI: Calling C With a Build Script ---");
+
+    // -------------------------------------------------------------------------
+    // 3. Strings Across the Boundary
+    // -------------------------------------------------------------------------
+    // Rust `&str`: pointer + length, UTF-8, may contain `\0`, no terminator.
+    // C `char *`:   pointer only, any bytes, ends at the first `\0`.
+    // - `CString`: an owned, NUL-terminated string to pass *to* C.
+    // - `CStr`:    a borrowed view of a NUL-terminated string *from* C.
+    // (`CString`/`CStr` are to C strings what `String`/`&str` are to Rust strings.)
+
+    println!("\n--- 3. Strings Across the Boundary ---");
+
+    let sentence = "the quick  brown fox";
+    let words = count_words(sentence).expect("no NUL bytes");
+    println!("C counted {} words in {:?}", words, sentence);
+    assert_eq!(words, 4);
+    assert_eq!(count_words("").unwrap(), 0);
+
+    // A `&str` with an interior NUL can't become a C string: C would see
+    // only "hello". `CString::new` refuses instead of silently truncating.
+    let with_nul = "hello\0world";
+    println!(
+        "count_words({:?}) = {:?}",
+        with_nul,
+        count_words(with_nul).map_err(|e| e.to_string())
+    );
+    assert_eq!(count_words(with_nul), Err(FfiError::InteriorNul(5)));
+
+    // C string literals (`c"..."`) are `&CStr` values with the NUL built in.
+    let literal: &CStr = c"from a literal";
+    // SAFETY: a `&CStr` is always a valid NUL-terminated string.
+    let literal_words = unsafe { raw::tu_count_words(literal.as_ptr()) };
+    assert_eq!(literal_words, 3);
+
+    // -------------------------------------------------------------------------
+    // 4. Slices, Structs and Status Codes
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Slices, Structs and Status Codes ---");
+
+    let bytes = b"hello";
+    println!("checksum(b\"hello\") = {}", checksum(bytes));
+    assert_eq!(checksum(bytes), 104 + 101 + 108 + 108 + 111);
+    assert_eq!(checksum(&[]), 0); // An empty slice still has a valid (dangling) pointer
+
+    // C reports errors with return codes and out-parameters; the wrapper turns
+    // them into a `Result`.
+    let version = parse_version("1.84.0").expect("valid version");
+    println!("Parsed version: {:?}", version);
+    assert_eq!(
+        version,
+        Version {
+            major: 1,
+            minor: 84,
+            patch: 0
+        }
+    );
+    for bad in ["1.84", "1.x.0", "1.2.3-beta", "99999999999.0.0"] {
+        println!(
+            "parse_version({:?}) -> {}",
+            bad,
+            parse_version(bad).unwrap_err()
+        );
+    }
+    assert!(parse_version("1.2").is_err());
+
+    // -------------------------------------------------------------------------
+    // 5. Memory Allocated by C
+    // -------------------------------------------------------------------------
+    // Whoever allocates must free: memory from C's `malloc` goes back through
+    // the library's `tu_free`, which `CLibString`'s `Drop` impl guarantees.
+
+    println!("\n--- 5. Memory Allocated by C ---");
+
+    let reversed = reverse_words("  one two   three ").expect("reverse succeeded");
+    println!("Reversed by C: {:?}", reversed);
+    assert_eq!(reversed, "three two one");
+    assert_eq!(reverse_words("").unwrap(), "");
+    assert_eq!(reverse_words("héllo wörld").unwrap(), "wörld héllo"); // UTF-8 bytes pass through
+
+    // -------------------------------------------------------------------------
+    // 6. Checklist for Wrapping a C Library
+    // -------------------------------------------------------------------------
+    // - Keep the raw `extern` declarations private; expose only safe wrappers.
+    // - Use `#[repr(C)]` for every struct that crosses the boundary.
+    // - Convert strings explicitly: `CString::new` going in (rejects interior
+    //   NULs), `CStr::from_ptr` + `to_str` coming out (checks UTF-8).
+    // - Know who owns each pointer, and free it with the matching deallocator.
+    // - Turn status codes and null pointers into `Result`s.
+    // - Write a `// SAFETY:` comment for every `unsafe` block saying why it's sound.
+
+    println!("\n--- End of FFI Examples ---");
+}
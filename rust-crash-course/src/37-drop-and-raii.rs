@@ -0,0 +1,291 @@
+// This file covers the `Drop` trait and the RAII pattern mentioned in the
+// ownership lesson ("memory is deallocated when the owner goes out of scope").
+// The same mechanism frees *any* resource, not just memory: implement `Drop`,
+// and cleanup runs automatically at a well-defined point. We look at drop
+// order, `std::mem::drop`, and build two guard types: a `TimerGuard` that
+// reports how long a scope took and a `FileLock` that removes its lock file.
+
+use std::cell::RefCell;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+#[path = "lessons/fmt.rs"]
+mod fmt;
+#[path = "lessons/testing.rs"]
+mod testing;
+
+use fmt::human_duration;
+use testing::{DropTracker, Tracked};
+
+// Announces its own drop. The drop order is asserted through the `Tracked`
+// field, which records `name` in the tracker from the pointers lesson
+// (lessons/testing.rs).
+struct Noisy {
+    name: &'static str,
+    _tracked: Tracked,
+}
+
+impl Noisy {
+    fn new(name: &'static str, tracker: &DropTracker) -> Noisy {
+        Noisy {
+            name,
+            _tracked: tracker.track(name),
+        }
+    }
+}
+
+impl Drop for Noisy {
+    // `drop` takes `&mut self`: the value is still valid here. Its fields
+    // (including `_tracked`, which does the recording) are dropped
+    // automatically *after* this method returns.
+    fn drop(&mut self) {
+        println!("drop {}", self.name);
+    }
+}
+
+// -------------------------------------------------------------------------
+// Guard Types
+// -------------------------------------------------------------------------
+
+// Measures the time from creation until it is dropped and records it.
+struct TimerGuard {
+    label: &'static str,
+    start: Instant,
+    timings: Rc<RefCell<Vec<(&'static str, Duration)>>>,
+}
+
+impl TimerGuard {
+    fn start(
+        label: &'static str,
+        timings: &Rc<RefCell<Vec<(&'static str, Duration)>>>,
+    ) -> TimerGuard {
+        TimerGuard {
+            label,
+            start: Instant::now(),
+            timings: Rc::clone(timings),
+        }
+    }
+}
+
+impl Drop for TimerGuard {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        println!("[timer] {} took {}", self.label, human_duration(elapsed));
+        self.timings.borrow_mut().push((self.label, elapsed));
+    }
+}
+
+// An exclusive lock represented by a file: acquiring creates the file (and
+// fails if it already exists), dropping the guard deletes it.
+#[derive(Debug)]
+struct FileLock {
+    path: PathBuf,
+}
+
+impl FileLock {
+    fn acquire(path: &Path) -> io::Result<FileLock> {
+        // `create_new` fails with `AlreadyExists` if someone else holds the lock.
+        OpenOptions::new().write(true).create_new(true).open(path)?;
+        Ok(FileLock {
+            path: path.to_path_buf(),
+        })
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        // `drop` can't return an error, so failures can only be ignored or logged.
+        if let Err(err) = fs::remove_file(&self.path) {
+            eprintln!("failed to release lock {:?}: {}", self.path, err);
+        }
+    }
+}
+
+fn main() {
+    println!("--- The Drop Trait and RAII Guards ---");
+
+    let tracker = DropTracker::new();
+
+    // -------------------------------------------------------------------------
+    // 1. Implementing `Drop`
+    // -------------------------------------------------------------------------
+    // `Drop::drop` runs when a value goes out of scope. You never call it
+    // yourself; the compiler inserts the call.
+
+    println!("\n--- 1. Implementing `Drop` ---");
+
+    {
+        let _a = Noisy::new("a", &tracker);
+        println!(
+            "Inside the scope, nothing dropped yet: {:?}",
+            tracker.dropped()
+        );
+    } // `_a` dropped here
+    assert_eq!(tracker.take(), ["a"]);
+
+    /*
+    let value = Noisy::new("x", &tracker);
+    value.drop(); // error[E0040]: explicit use of destructor method
+    */
+
+    /*
+    #[derive(Clone, Copy)]
+    struct Handle;
+    impl Drop for Handle { fn drop(&mut self) {} }
+    // error[E0184]: the trait `Copy` cannot be implemented for this type; the type has a destructor
+    // (a bitwise copy would run the cleanup twice)
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. Drop Order
+    // -------------------------------------------------------------------------
+    // - Local variables: reverse order of declaration (last created, first dropped),
+    //   so later values can safely refer to earlier ones.
+    // - Struct fields and tuple/array/Vec elements: in declaration/index order.
+    // - A value's own `drop` runs before its fields are dropped.
+
+    println!("\n--- 2. Drop Order ---");
+
+    {
+        let _first = Noisy::new("first", &tracker);
+        let _second = Noisy::new("second", &tracker);
+        let _third = Noisy::new("third", &tracker);
+    }
+    let locals = tracker.take();
+    println!("Locals: {:?}", locals);
+    assert_eq!(locals, ["third", "second", "first"]);
+
+    struct Pair {
+        left: Noisy,
+        right: Noisy,
+    }
+    let pair = Pair {
+        left: Noisy::new("left", &tracker),
+        right: Noisy::new("right", &tracker),
+    };
+    println!("Pair holds {} and {}", pair.left.name, pair.right.name);
+    drop(pair);
+    let fields = tracker.take();
+    println!("Struct fields: {:?}", fields);
+    assert_eq!(fields, ["left", "right"]);
+
+    drop(vec![Noisy::new("v0", &tracker), Noisy::new("v1", &tracker)]);
+    assert_eq!(tracker.take(), ["v0", "v1"]);
+
+    // Temporaries are dropped at the end of the statement that created them.
+    let name_len = Noisy::new("temporary", &tracker).name.len();
+    assert_eq!(tracker.take(), ["temporary"]);
+    assert_eq!(name_len, 9);
+
+    // Moving a value moves the responsibility to drop it.
+    fn consume(value: Noisy) {
+        println!("consume() now owns {}", value.name);
+    } // Dropped here, at the end of `consume`
+    let moved = Noisy::new("moved", &tracker);
+    consume(moved);
+    assert_eq!(tracker.take(), ["moved"]);
+
+    // -------------------------------------------------------------------------
+    // 3. Dropping Early: `std::mem::drop`
+    // -------------------------------------------------------------------------
+    // `drop(value)` is just `fn drop<T>(_x: T) {}`: it takes ownership and lets
+    // the value go out of scope immediately. Useful to release a resource (a
+    // lock, a file, a `RefCell` borrow) before the end of the scope.
+
+    println!("\n--- 3. Dropping Early ---");
+
+    let early = Noisy::new("early", &tracker);
+    let late = Noisy::new("late", &tracker);
+    drop(early);
+    assert_eq!(tracker.take(), ["early"]);
+    println!("`late` ({}) is still alive", late.name);
+    drop(late);
+    tracker.take();
+
+    // `_` vs `_name`: `let _ = value` does not bind, so a freshly created
+    // value is dropped *immediately*. `let _guard = value` keeps it alive until
+    // the end of the scope, which is what you want for guards.
+    {
+        let _ = Noisy::new("underscore", &tracker);
+        let _kept = Noisy::new("kept", &tracker);
+        assert_eq!(tracker.dropped(), ["underscore"]); // Already gone
+    }
+    assert_eq!(tracker.take(), ["underscore", "kept"]);
+
+    // `std::mem::forget` skips the destructor entirely. It's safe (leaking
+    // is not undefined behavior) but rarely what you want.
+    std::mem::forget(Noisy::new("forgotten", &tracker));
+    assert!(tracker.dropped().is_empty());
+
+    // -------------------------------------------------------------------------
+    // 4. RAII Guard: `TimerGuard`
+    // -------------------------------------------------------------------------
+    // A guard acquires something in its constructor and releases it in `drop`.
+    // The cleanup can't be forgotten, and it runs on every exit path: normal
+    // return, early `return`/`?`, and even panics (during unwinding).
+
+    println!("\n--- 4. `TimerGuard` ---");
+
+    let timings = Rc::new(RefCell::new(Vec::new()));
+
+    fn slow_sum(limit: u64, timings: &Rc<RefCell<Vec<(&'static str, Duration)>>>) -> Option<u64> {
+        let _timer = TimerGuard::start("slow_sum", timings);
+        if limit == 0 {
+            return None; // The timer still records this call
+        }
+        std::thread::sleep(Duration::from_millis(5));
+        Some((1..=limit).sum())
+    }
+
+    assert_eq!(slow_sum(100, &timings), Some(5050));
+    assert_eq!(slow_sum(0, &timings), None);
+    assert_eq!(timings.borrow().len(), 2);
+    assert!(timings.borrow()[0].1 >= Duration::from_millis(5));
+
+    // On panic, unwinding drops every live local, so guards still run.
+    let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _timer = TimerGuard::start("panicking work", &timings);
+        panic!("something went wrong");
+    }));
+    assert!(panicked.is_err());
+    assert_eq!(timings.borrow().last().map(|t| t.0), Some("panicking work"));
+
+    // -------------------------------------------------------------------------
+    // 5. RAII Guard: `FileLock`
+    // -------------------------------------------------------------------------
+    // The standard library uses the same pattern everywhere: `File` closes its
+    // descriptor, `MutexGuard` unlocks, `Vec` frees its buffer, `JoinHandle`...
+    // Here's one for an external resource: a lock file.
+
+    println!("\n--- 5. `FileLock` ---");
+
+    let lock_path = std::env::temp_dir().join(format!("rust-raii-{}.lock", std::process::id()));
+    let _ = fs::remove_file(&lock_path); // Clean up a leftover from an earlier crashed run
+
+    {
+        let lock = FileLock::acquire(&lock_path).expect("lock is free");
+        println!("Acquired {:?}", lock.path);
+        assert!(lock_path.exists());
+
+        // A second attempt fails while the first guard is alive.
+        let second = FileLock::acquire(&lock_path);
+        println!(
+            "Second acquire: {:?}",
+            second.as_ref().map_err(|e| e.kind())
+        );
+        assert_eq!(second.unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    } // `lock` dropped: the file is removed
+    assert!(!lock_path.exists());
+
+    // Released early with `drop`, the lock can be taken again right away.
+    let lock = FileLock::acquire(&lock_path).expect("lock is free again");
+    drop(lock);
+    let relock = FileLock::acquire(&lock_path).expect("lock was released by drop");
+    drop(relock);
+    assert!(!lock_path.exists());
+
+    println!("\n--- End of Drop and RAII Examples ---");
+}
@@ -0,0 +1,295 @@
+// This file covers converting between types with the standard conversion
+// traits: `From`/`Into` for conversions that always succeed, and
+// `TryFrom`/`TryInto` for ones that can fail. Implementing them for your own
+// domain types (temperatures, user ids) gives callers one familiar API, and
+// `From` is also what lets `?` convert errors, as in the `MyError` example of
+// the error handling lesson.
+
+use std::fmt;
+use std::num::ParseIntError;
+
+// -------------------------------------------------------------------------
+// Domain Types
+// -------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Celsius(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Fahrenheit(f64);
+
+// Infallible: every Celsius value has a Fahrenheit equivalent.
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Self {
+        Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    fn from(f: Fahrenheit) -> Self {
+        Celsius((f.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+// A user id must be positive. Wrapping it in its own type means a `UserId`
+// is always valid, and it can't be mixed up with other integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct UserId(u32);
+
+#[derive(Debug, PartialEq)]
+enum UserIdError {
+    Zero,
+    Negative(i64),
+    TooLarge(i64),
+    NotANumber(String),
+}
+
+impl fmt::Display for UserIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserIdError::Zero => write!(f, "user ids start at 1"),
+            UserIdError::Negative(n) => write!(f, "user id {} is negative", n),
+            UserIdError::TooLarge(n) => write!(f, "user id {} does not fit in 32 bits", n),
+            UserIdError::NotANumber(text) => write!(f, "'{}' is not a user id", text),
+        }
+    }
+}
+
+impl std::error::Error for UserIdError {}
+
+// Fallible: most `i64`s are not valid ids. `TryFrom` returns a `Result`.
+impl TryFrom<i64> for UserId {
+    type Error = UserIdError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Err(UserIdError::Zero),
+            n if n < 0 => Err(UserIdError::Negative(n)),
+            n => u32::try_from(n)
+                .map(UserId)
+                .map_err(|_| UserIdError::TooLarge(n)),
+        }
+    }
+}
+
+// Parsing from text goes through `FromStr` (used by `str::parse`), which can
+// reuse the `TryFrom` impl. `ParseIntError` is mapped to our own error.
+impl std::str::FromStr for UserId {
+    type Err = UserIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let n: i64 = s
+            .trim()
+            .parse()
+            .map_err(|_: ParseIntError| UserIdError::NotANumber(s.to_string()))?;
+        UserId::try_from(n)
+    }
+}
+
+// The other direction can't fail, so it's a plain `From`.
+impl From<UserId> for u32 {
+    fn from(id: UserId) -> Self {
+        id.0
+    }
+}
+
+// -------------------------------------------------------------------------
+// Errors Converted by `?`
+// -------------------------------------------------------------------------
+// Like `MyError` in the error handling lesson: one error type for the whole
+// operation, with a `From` impl for every error that can happen inside it.
+
+#[derive(Debug)]
+enum LookupError {
+    BadId(UserIdError),
+    Io(std::io::Error),
+    UnknownUser(UserId),
+}
+
+impl From<UserIdError> for LookupError {
+    fn from(err: UserIdError) -> Self {
+        LookupError::BadId(err)
+    }
+}
+
+impl From<std::io::Error> for LookupError {
+    fn from(err: std::io::Error) -> Self {
+        LookupError::Io(err)
+    }
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LookupError::BadId(err) => write!(f, "bad id: {}", err),
+            LookupError::Io(err) => write!(f, "I/O error: {}", err),
+            LookupError::UnknownUser(id) => write!(f, "no user with id {}", id.0),
+        }
+    }
+}
+
+fn lookup_user_name(raw_id: &str, users: &[(UserId, &str)]) -> Result<String, LookupError> {
+    // `?` on a `Result<_, UserIdError>` calls `LookupError::from(err)` for us.
+    let id: UserId = raw_id.parse()?;
+    users
+        .iter()
+        .find(|(user_id, _)| *user_id == id)
+        .map(|(_, name)| name.to_string())
+        .ok_or(LookupError::UnknownUser(id))
+}
+
+fn main() {
+    println!("--- Conversions: From, Into, TryFrom, TryInto ---");
+
+    // -------------------------------------------------------------------------
+    // 1. `From` and `Into`
+    // -------------------------------------------------------------------------
+    // Implement `From<A> for B` and you get `Into<B> for A` for free, through
+    // a blanket impl in the standard library:
+    //
+    //     impl<T, U> Into<U> for T where U: From<T> {
+    //         fn into(self) -> U { U::from(self) }
+    //     }
+    //
+    // So always implement `From`; use whichever reads better at the call site.
+
+    println!("\n--- 1. `From` and `Into` ---");
+
+    let boiling = Celsius(100.0);
+    let as_f = Fahrenheit::from(boiling); // Target type named explicitly
+    let also_f: Fahrenheit = boiling.into(); // Target type inferred from the annotation
+    println!("{:?} = {:?}", boiling, as_f);
+    assert_eq!(as_f, Fahrenheit(212.0));
+    assert_eq!(also_f, as_f);
+
+    let body: Celsius = Fahrenheit(98.6).into();
+    println!("98.6°F = {:.1}°C", body.0);
+    assert!((body.0 - 37.0).abs() < 1e-9);
+
+    /*
+    let unknown = boiling.into(); // error[E0283]: type annotations needed
+    */
+
+    // Standard library examples of the same traits:
+    let s: String = "text".into(); // From<&str> for String
+    let wide: i64 = i64::from(7i32); // Widening integers can't fail
+    let v: Vec<u8> = "abc".into(); // From<&str> for Vec<u8>
+    let boxed: Box<str> = String::from("boxed").into();
+    assert_eq!(
+        (s.as_str(), wide, v.as_slice(), &*boxed),
+        ("text", 7, &b"abc"[..], "boxed")
+    );
+
+    // `Into` in a parameter accepts anything convertible, which makes APIs
+    // pleasant to call: both `&str` and `String` work here.
+    fn greet(name: impl Into<String>) -> String {
+        let name: String = name.into();
+        format!("Hello, {}!", name)
+    }
+    assert_eq!(greet("Ada"), greet(String::from("Ada")));
+
+    // Generic code can convert between any pair with a `From` impl.
+    fn convert_all<A: Copy, B: From<A>>(values: &[A]) -> Vec<B> {
+        values.iter().map(|&v| B::from(v)).collect()
+    }
+    let forecast: Vec<Fahrenheit> = convert_all(&[Celsius(0.0), Celsius(-40.0), Celsius(30.0)]);
+    println!("Forecast in °F: {:?}", forecast);
+    assert_eq!(forecast[1], Fahrenheit(-40.0)); // Where the scales meet
+
+    // -------------------------------------------------------------------------
+    // 2. `TryFrom` and `TryInto`
+    // -------------------------------------------------------------------------
+    // For conversions that can fail. Same blanket pattern: implement
+    // `TryFrom`, get `TryInto` for free. Both return `Result<T, Self::Error>`.
+
+    println!("\n--- 2. `TryFrom` and `TryInto` ---");
+
+    let id = UserId::try_from(42).expect("valid id");
+    println!("UserId::try_from(42) = {:?}", id);
+
+    for raw in [0i64, -3, 5_000_000_000] {
+        let result: Result<UserId, _> = raw.try_into();
+        println!(
+            "{} -> {}",
+            raw,
+            result.map_or_else(|e| e.to_string(), |id| format!("{:?}", id))
+        );
+    }
+    assert_eq!(UserId::try_from(0), Err(UserIdError::Zero));
+    assert_eq!(UserId::try_from(-3), Err(UserIdError::Negative(-3)));
+    assert_eq!(
+        UserId::try_from(5_000_000_000),
+        Err(UserIdError::TooLarge(5_000_000_000))
+    );
+
+    // Back to a plain integer: infallible, so `From`/`into` again.
+    let raw: u32 = id.into();
+    assert_eq!(raw, 42);
+
+    // Numeric narrowing in the standard library works the same way,
+    // unlike `as`, which silently truncates (see the integer overflow lesson).
+    assert!(u8::try_from(300i32).is_err());
+    assert_eq!(300i32 as u8, 44);
+
+    // `FromStr` builds on top: `str::parse` calls it.
+    let parsed: UserId = " 7 ".parse().expect("valid id");
+    assert_eq!(parsed, UserId(7));
+    assert_eq!(
+        "seven".parse::<UserId>(),
+        Err(UserIdError::NotANumber(String::from("seven")))
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. `From` Powers `?`
+    // -------------------------------------------------------------------------
+    // When `?` sees an `Err(e)`, it returns `Err(From::from(e))`. So once
+    // `LookupError: From<UserIdError>`, the parse error converts by itself,
+    // exactly like `io::Error` converts into `MyError` in the error handling lesson.
+
+    println!("\n--- 3. `From` Powers `?` ---");
+
+    let users = [(UserId(1), "Ada"), (UserId(2), "Grace")];
+    for raw_id in ["2", "abc", "0", "9"] {
+        match lookup_user_name(raw_id, &users) {
+            Ok(name) => println!("{:>3} -> {}", raw_id, name),
+            Err(err) => println!("{:>3} -> error: {}", raw_id, err),
+        }
+    }
+    assert_eq!(lookup_user_name("1", &users).unwrap(), "Ada");
+    assert!(matches!(
+        lookup_user_name("0", &users),
+        Err(LookupError::BadId(UserIdError::Zero))
+    ));
+    assert!(matches!(
+        lookup_user_name("9", &users),
+        Err(LookupError::UnknownUser(UserId(9)))
+    ));
+
+    // The `Io` variant is filled the same way by `?` on any `io::Result`.
+    fn read_user_file(path: &str) -> Result<String, LookupError> {
+        Ok(std::fs::read_to_string(path)?)
+    }
+    let missing = read_user_file("/definitely/not/here/users.txt");
+    assert!(matches!(missing, Err(LookupError::Io(_))));
+    println!("Missing file: {}", missing.unwrap_err());
+
+    /*
+    fn no_from_impl(raw: &str) -> Result<u32, UserIdError> {
+        Ok(raw.parse::<u32>()?)
+        // error[E0277]: `?` couldn't convert the error to `UserIdError`
+        // (there's no `impl From<ParseIntError> for UserIdError`)
+    }
+    */
+
+    // -------------------------------------------------------------------------
+    // 4. Choosing a Conversion
+    // -------------------------------------------------------------------------
+    // - Always succeeds, no information lost  -> `From` (and get `Into` free)
+    // - Can fail                              -> `TryFrom` (and get `TryInto` free)
+    // - From text                             -> `FromStr` (enables `.parse()`)
+    // - Cheap reference-to-reference view     -> `AsRef` / `Borrow` (see the Borrow lesson)
+    // - Lossy numeric cast you really want    -> `as`, with a comment saying why
+    // Don't implement `Into` directly: the blanket impl already covers it.
+
+    println!("\n--- End of Conversions Examples ---");
+}
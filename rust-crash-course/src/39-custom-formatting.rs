@@ -0,0 +1,342 @@
+// This file extends the `Display` example from the traits lesson into a full
+// tour of formatting: the format-spec mini-language (`{:>8.2}` and friends),
+// writing `Display` impls that respect width, precision and flags from the
+// `Formatter`, implementing `LowerHex`/`UpperHex`/`Binary` for a bit-flags
+// type, custom `Debug` output, and a reusable table printer that lives in
+// `src/lessons/table.rs` so other lessons can print tables too.
+
+use std::fmt;
+use std::ops::BitOr;
+
+#[path = "lessons/table.rs"]
+mod table;
+
+use table::{Align, Table};
+
+// -------------------------------------------------------------------------
+// Types With Custom Formatting
+// -------------------------------------------------------------------------
+
+// The traits lesson's `Person` used `write!(f, "{} {}", ...)`, which ignores
+// any width the caller asks for. `f.pad` applies width, fill and alignment
+// (and precision, as truncation) to the whole string.
+struct Person {
+    first_name: String,
+    last_name: String,
+}
+
+impl fmt::Display for Person {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            // `{:#}`: an alternative form, here "Last, First"
+            f.pad(&format!("{}, {}", self.last_name, self.first_name))
+        } else {
+            f.pad(&format!("{} {}", self.first_name, self.last_name))
+        }
+    }
+}
+
+// A temperature that honors precision (`{:.1}`) and the `+` flag, forwarding
+// to `f64`'s own formatting and then padding the result.
+#[derive(Debug, Clone, Copy)]
+struct Celsius(f64);
+
+impl fmt::Display for Celsius {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let number = match (f.precision(), f.sign_plus()) {
+            (Some(p), true) => format!("{:+.*}", p, self.0),
+            (Some(p), false) => format!("{:.*}", p, self.0),
+            (None, true) => format!("{:+}", self.0),
+            (None, false) => format!("{}", self.0),
+        };
+        // Padding goes around the full "21.5°C", so build it first.
+        // (Not `f.pad` here: it would treat the precision as a truncation.)
+        let text = format!("{}°C", number);
+        match f.width() {
+            Some(width) => match f.align() {
+                Some(fmt::Alignment::Left) => write!(f, "{:<width$}", text),
+                Some(fmt::Alignment::Center) => write!(f, "{:^width$}", text),
+                _ => write!(f, "{:>width$}", text), // Numbers align right by default
+            },
+            None => f.write_str(&text),
+        }
+    }
+}
+
+// Unix-style permission bits: a "bit flags" type, one bit per permission.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Permissions(u8);
+
+impl Permissions {
+    const NONE: Permissions = Permissions(0);
+    const READ: Permissions = Permissions(0b100);
+    const WRITE: Permissions = Permissions(0b010);
+    const EXECUTE: Permissions = Permissions(0b001);
+
+    fn contains(self, other: Permissions) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Permissions {
+    type Output = Permissions;
+
+    fn bitor(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 | rhs.0)
+    }
+}
+
+// `{}`: the familiar "rwx" notation.
+impl fmt::Display for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let flag = |p: Permissions, c: char| if self.contains(p) { c } else { '-' };
+        let text: String = [
+            flag(Permissions::READ, 'r'),
+            flag(Permissions::WRITE, 'w'),
+            flag(Permissions::EXECUTE, 'x'),
+        ]
+        .iter()
+        .collect();
+        f.pad(&text)
+    }
+}
+
+// `{:x}`, `{:X}`, `{:b}`: delegate to the inner integer's impl, passing the
+// same `Formatter` along so flags like `#` and `08` keep working.
+impl fmt::LowerHex for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Binary for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Binary::fmt(&self.0, f)
+    }
+}
+
+// `{:?}`: list the flag names, like `Permissions(READ | EXECUTE)`.
+impl fmt::Debug for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = [
+            (Permissions::READ, "READ"),
+            (Permissions::WRITE, "WRITE"),
+            (Permissions::EXECUTE, "EXECUTE"),
+        ]
+        .iter()
+        .filter(|(flag, _)| self.contains(*flag))
+        .map(|(_, name)| *name)
+        .collect();
+        if names.is_empty() {
+            write!(f, "Permissions(NONE)")
+        } else {
+            write!(f, "Permissions({})", names.join(" | "))
+        }
+    }
+}
+
+fn main() {
+    println!("--- Custom Formatting ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Format Spec Mini-Language
+    // -------------------------------------------------------------------------
+    // `{[argument]:[fill][align][sign][#][0][width][.precision][type]}`
+    // - align: `<` left, `>` right, `^` center (fill character before it)
+    // - sign: `+` always show the sign
+    // - `#`: alternate form (`0x` prefix, pretty `Debug`)
+    // - `0`: pad numbers with zeros after the sign
+    // - type: nothing (`Display`), `?` (`Debug`), `x`, `X`, `b`, `o`, `e`
+
+    println!("\n--- 1. The Format Spec Mini-Language ---");
+
+    let pi = std::f64::consts::PI;
+    assert_eq!(format!("[{:>8}]", "right"), "[   right]");
+    assert_eq!(format!("[{:<8}]", "left"), "[left    ]");
+    assert_eq!(format!("[{:^8}]", "mid"), "[  mid   ]");
+    assert_eq!(format!("[{:*^9}]", "star"), "[**star***]");
+    assert_eq!(format!("{:.3}", pi), "3.142");
+    assert_eq!(format!("{:08.2}", -pi), "-0003.14");
+    assert_eq!(format!("{:+}", 5), "+5");
+    assert_eq!(format!("{:#x} {:#b} {:o}", 255, 5, 8), "0xff 0b101 10");
+    assert_eq!(format!("{:e}", 1234.5), "1.2345e3");
+    assert_eq!(format!("{:.3}", "truncate"), "tru"); // Precision truncates strings
+
+    // Width and precision can come from arguments: `width$`, `.prec$`, or `.*`.
+    let width = 10;
+    let precision = 2;
+    assert_eq!(format!("[{:>width$.precision$}]", pi), "[      3.14]");
+    assert_eq!(format!("[{:>1$}]", "x", 4), "[   x]"); // Width from positional argument 1
+    assert_eq!(format!("{:.*}", 1, pi), "3.1"); // Precision, then the value
+
+    // Arguments by position, by name, and captured from scope.
+    let item = "apples";
+    let count = 3;
+    assert_eq!(format!("{0} and {0}", "again"), "again and again");
+    assert_eq!(format!("{n} {item}", n = count), "3 apples");
+    assert_eq!(format!("{item:>8}|{count:03}"), "  apples|003");
+
+    // `{{` and `}}` print literal braces.
+    assert_eq!(format!("{{}} around {}", count), "{} around 3");
+
+    // -------------------------------------------------------------------------
+    // 2. `Display` That Respects the Caller's Flags
+    // -------------------------------------------------------------------------
+    // A `Formatter` carries the spec: `f.width()`, `f.precision()`,
+    // `f.align()`, `f.fill()`, `f.alternate()`, `f.sign_plus()`.
+
+    println!("\n--- 2. `Display` That Respects Flags ---");
+
+    let jane = Person {
+        first_name: String::from("Jane"),
+        last_name: String::from("Austen"),
+    };
+    println!("[{:>15}]", jane);
+    println!("[{:<15}]", jane);
+    println!("[{:#}]", jane);
+    assert_eq!(format!("[{:^15}]", jane), "[  Jane Austen  ]");
+    assert_eq!(format!("{:#}", jane), "Austen, Jane");
+    assert_eq!(format!("{:.4}", jane), "Jane"); // `pad` truncates like `str`
+
+    let temp = Celsius(21.456);
+    assert_eq!(format!("{}", temp), "21.456°C");
+    assert_eq!(format!("{:.1}", temp), "21.5°C");
+    assert_eq!(format!("{:+.0}", temp), "+21°C");
+    assert_eq!(format!("[{:>9.1}]", temp), "[   21.5°C]");
+    assert_eq!(format!("[{:<9.1}]", Celsius(-3.0)), "[-3.0°C   ]");
+    println!(
+        "Temperatures: {:.1} / {:+.2} / [{:^10.0}]",
+        temp,
+        Celsius(-3.0),
+        temp
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. Bit Flags: `LowerHex`, `UpperHex`, `Binary` and a Custom `Debug`
+    // -------------------------------------------------------------------------
+    // Each format type is its own trait: `{:x}` needs `LowerHex`, `{:b}`
+    // needs `Binary`, and so on. Implement only the ones that make sense.
+
+    println!("\n--- 3. Bit Flags ---");
+
+    let script = Permissions::READ | Permissions::EXECUTE;
+    let all = Permissions::READ | Permissions::WRITE | Permissions::EXECUTE;
+    println!(
+        "script: {} = {:#05b} = {:#x} = {:?}",
+        script, script, script, script
+    );
+    assert_eq!(format!("{}", script), "r-x");
+    assert_eq!(format!("{:b}", script), "101");
+    assert_eq!(format!("{:#05b}", script), "0b101");
+    assert_eq!(format!("{:08b}", all), "00000111");
+    assert_eq!(format!("{:#X}", all), "0x7");
+    assert_eq!(format!("{:?}", script), "Permissions(READ | EXECUTE)");
+    assert_eq!(format!("{:?}", Permissions::NONE), "Permissions(NONE)");
+    assert_eq!(format!("[{:>5}]", Permissions::WRITE), "[  -w-]");
+
+    /*
+    println!("{:o}", script); // error[E0277]: the trait bound `Permissions: Octal` is not satisfied
+    */
+
+    // -------------------------------------------------------------------------
+    // 4. Custom `Debug` With the Builder Helpers
+    // -------------------------------------------------------------------------
+    // `debug_struct`, `debug_tuple`, `debug_list` and `debug_map` produce the
+    // same layout as `#[derive(Debug)]`, including pretty-printing with `{:#?}`,
+    // while letting you hide or reformat fields.
+
+    println!("\n--- 4. Custom `Debug` ---");
+
+    struct ApiKey {
+        owner: String,
+        secret: String,
+        scopes: Vec<&'static str>,
+    }
+
+    impl fmt::Debug for ApiKey {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.debug_struct("ApiKey")
+                .field("owner", &self.owner)
+                .field(
+                    "secret",
+                    &format_args!("<{} chars hidden>", self.secret.len()),
+                )
+                .field("scopes", &self.scopes)
+                .finish()
+        }
+    }
+
+    let key = ApiKey {
+        owner: String::from("ferris"),
+        secret: String::from("s3cr3t-t0k3n"),
+        scopes: vec!["read", "write"],
+    };
+    println!("{:?}", key);
+    println!("{:#?}", key);
+    let debug = format!("{:?}", key);
+    assert!(!debug.contains("s3cr3t"));
+    assert_eq!(
+        debug,
+        r#"ApiKey { owner: "ferris", secret: <12 chars hidden>, scopes: ["read", "write"] }"#
+    );
+
+    // -------------------------------------------------------------------------
+    // 5. A Reusable Table Printer
+    // -------------------------------------------------------------------------
+    // `Table` (in `src/lessons/table.rs`) computes each column's width from
+    // its contents, then prints every cell with `{:<width$}`, `{:>width$}`
+    // or `{:^width$}`. Cells can be anything that implements `Display`,
+    // including the types above.
+
+    println!("\n--- 5. A Reusable Table Printer ---");
+
+    let mut files = Table::new(["file", "mode", "octal", "size"])
+        .align(2, Align::Center)
+        .align(3, Align::Right);
+    files.row([
+        "build.sh",
+        &all.to_string(),
+        &format!("{:o}", all.0),
+        "1 204",
+    ]);
+    files.row([
+        "notes.txt",
+        &script.to_string(),
+        &format!("{:o}", script.0),
+        "87",
+    ]);
+    files.row([
+        "résumé.pdf",
+        &Permissions::READ.to_string(),
+        &format!("{:o}", Permissions::READ.0),
+        "120 331",
+    ]);
+    println!("{}", files);
+
+    let rendered = files.to_string();
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(files.len(), 3);
+    assert_eq!(lines.len(), 7); // 3 separators + header + 3 rows
+    // Every line has the same width in characters, even with "é" in a cell.
+    assert!(
+        lines
+            .iter()
+            .all(|l| l.chars().count() == lines[0].chars().count())
+    );
+    assert!(rendered.contains("| build.sh   | rwx  |   7   |   1 204 |"));
+
+    let mut forecast = Table::new(["day", "high"]).align(1, Align::Right);
+    for (day, high) in [("Mon", Celsius(18.25)), ("Tue", Celsius(-2.5))] {
+        forecast.row([day.to_string(), format!("{:+.1}", high)]);
+    }
+    println!("{}", forecast);
+    assert!(forecast.to_string().contains("| Tue |  -2.5°C |"));
+
+    println!("\n--- End of Custom Formatting Examples ---");
+}
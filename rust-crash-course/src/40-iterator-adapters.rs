@@ -0,0 +1,265 @@
+// This file builds our own versions of three iterator adapters from the
+// iterator lesson, `map`, `filter` and `take`, to show what they really are:
+// small structs that wrap another iterator and implement `Iterator` by calling
+// the inner iterator's `next()`. An extension trait then makes them chainable
+// with method syntax, exactly like the standard library does.
+
+// -------------------------------------------------------------------------
+// The Adapters
+// -------------------------------------------------------------------------
+
+// `MyMap` holds the inner iterator and the closure. It does nothing until
+// someone calls `next()`; that's all "lazy" means.
+struct MyMap<I, F> {
+    inner: I,
+    f: F,
+}
+
+impl<I, F, B> Iterator for MyMap<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> B, // `FnMut`: called once per item and may keep state
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        // Pull one item from the inner iterator, transform it, pass it on.
+        self.inner.next().map(&mut self.f)
+    }
+
+    // Mapping never changes how many items there are, so the inner hint is exact.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+struct MyFilter<I, P> {
+    inner: I,
+    predicate: P,
+}
+
+impl<I, P> Iterator for MyFilter<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool, // Gets a reference: it only looks at the item
+{
+    type Item = I::Item;
+
+    // Written out by hand; `self.inner.find(&mut self.predicate)` does the same.
+    #[allow(clippy::manual_find)]
+    fn next(&mut self) -> Option<I::Item> {
+        // Keep pulling until an item passes, or the inner iterator runs out.
+        // One call to `next()` here may call the inner `next()` many times.
+        for item in self.inner.by_ref() {
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    // Anywhere from none to all of the inner items may pass.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+struct MyTake<I> {
+    inner: I,
+    remaining: usize,
+}
+
+impl<I: Iterator> Iterator for MyTake<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.remaining == 0 {
+            return None; // Stop *without* touching the inner iterator
+        }
+        self.remaining -= 1;
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let upper = match upper {
+            Some(n) => n.min(self.remaining),
+            None => self.remaining, // Even an infinite inner iterator yields at most `remaining`
+        };
+        (lower.min(self.remaining), Some(upper))
+    }
+}
+
+// -------------------------------------------------------------------------
+// Method Syntax via an Extension Trait
+// -------------------------------------------------------------------------
+// `iter.map(f)` works because `map` is a provided method on the `Iterator`
+// trait. We can't add methods to `Iterator`, but we can define our own trait
+// with a blanket impl for every iterator.
+
+trait MyIteratorExt: Iterator + Sized {
+    fn my_map<B, F: FnMut(Self::Item) -> B>(self, f: F) -> MyMap<Self, F> {
+        MyMap { inner: self, f }
+    }
+
+    fn my_filter<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> MyFilter<Self, P> {
+        MyFilter {
+            inner: self,
+            predicate,
+        }
+    }
+
+    fn my_take(self, n: usize) -> MyTake<Self> {
+        MyTake {
+            inner: self,
+            remaining: n,
+        }
+    }
+}
+
+impl<I: Iterator> MyIteratorExt for I {}
+
+fn main() {
+    println!("--- Building Iterator Adapters ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Using the Structs Directly
+    // -------------------------------------------------------------------------
+    // Without any helper methods, an adapter chain is just nested structs.
+
+    println!("\n--- 1. Adapters Are Structs ---");
+
+    let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let doubled = MyMap {
+        inner: numbers.iter(),
+        f: |x: &i32| x * 2,
+    };
+    let doubled: Vec<i32> = doubled.collect(); // `collect` comes from `Iterator`, for free
+    println!("Doubled: {:?}", doubled);
+    assert_eq!(doubled, [2, 4, 6, 8, 10, 12, 14, 16, 18, 20]);
+
+    // `numbers.iter().filter(..).map(..).take(3)` written out by hand:
+    let nested = MyTake {
+        inner: MyMap {
+            inner: MyFilter {
+                inner: numbers.iter(),
+                predicate: |x: &&i32| *x % 2 == 0,
+            },
+            f: |x: &i32| x * x,
+        },
+        remaining: 3,
+    };
+    let squares: Vec<i32> = nested.collect();
+    println!("First three even squares: {:?}", squares);
+    assert_eq!(squares, [4, 16, 36]);
+
+    // -------------------------------------------------------------------------
+    // 2. Method Syntax
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. Method Syntax ---");
+
+    let mine: Vec<i32> = numbers
+        .iter()
+        .my_filter(|x| *x % 2 == 0)
+        .my_map(|x| x * x)
+        .my_take(3)
+        .collect();
+    let std_version: Vec<i32> = numbers
+        .iter()
+        .filter(|x| *x % 2 == 0)
+        .map(|x| x * x)
+        .take(3)
+        .collect();
+    println!("Ours: {:?}, std: {:?}", mine, std_version);
+    assert_eq!(mine, std_version);
+
+    // The type of the chain spells out the nesting:
+    let chain: MyTake<MyMap<MyFilter<std::ops::Range<u32>, _>, _>> = (1..100)
+        .my_filter(|n| n % 7 == 0)
+        .my_map(|n| n * 10)
+        .my_take(2);
+    assert_eq!(chain.collect::<Vec<_>>(), [70, 140]);
+
+    // -------------------------------------------------------------------------
+    // 3. Laziness, Step by Step
+    // -------------------------------------------------------------------------
+    // Nothing runs when the chain is built. Each `next()` on the outermost
+    // adapter pulls items through the chain one at a time.
+
+    println!("\n--- 3. Laziness ---");
+
+    let mut log = Vec::new();
+    {
+        let mut lazy = (1..=10)
+            .my_map(|n| {
+                log.push(format!("map {}", n));
+                n
+            })
+            .my_filter(|n| n % 3 == 0)
+            .my_take(2);
+        // Nothing has been logged yet: only structs were created.
+
+        assert_eq!(lazy.next(), Some(3)); // Pulls 1, 2, 3 through `map`
+        assert_eq!(lazy.next(), Some(6)); // Pulls 4, 5, 6
+        assert_eq!(lazy.next(), None); // `take` is done: doesn't pull 7
+    }
+    println!("Calls made: {:?}", log);
+    assert_eq!(log.len(), 6);
+
+    // That's why infinite iterators work, as long as something stops them.
+    let powers: Vec<u64> = (0..).my_map(|n: u32| 2u64.pow(n)).my_take(5).collect();
+    assert_eq!(powers, [1, 2, 4, 8, 16]);
+
+    // -------------------------------------------------------------------------
+    // 4. `size_hint`: Helping `collect` Allocate Once
+    // -------------------------------------------------------------------------
+    // `size_hint()` returns (lower bound, optional upper bound) for the number
+    // of remaining items. `collect::<Vec<_>>` uses it to reserve capacity.
+    // The default is `(0, None)`: correct but unhelpful.
+
+    println!("\n--- 4. `size_hint` ---");
+
+    let mapped = numbers.iter().my_map(|x| x + 1);
+    let filtered = numbers.iter().my_filter(|x| **x > 5);
+    let taken = (0..).my_take(4);
+    println!("map: {:?}", mapped.size_hint());
+    println!("filter: {:?}", filtered.size_hint());
+    println!("take on an infinite range: {:?}", taken.size_hint());
+    assert_eq!(mapped.size_hint(), (10, Some(10)));
+    assert_eq!(filtered.size_hint(), (0, Some(10)));
+    assert_eq!(taken.size_hint(), (4, Some(4)));
+    // Same answers as the real adapters:
+    assert_eq!(
+        numbers.iter().filter(|x| **x > 5).size_hint(),
+        (0, Some(10))
+    );
+    assert_eq!((0..).take(4).size_hint(), (4, Some(4)));
+
+    // -------------------------------------------------------------------------
+    // 5. What a `for` Loop Desugars To
+    // -------------------------------------------------------------------------
+    // `for x in expr { body }` is roughly:
+    //
+    //     let mut iter = IntoIterator::into_iter(expr);
+    //     while let Some(x) = iter.next() { body }
+
+    println!("\n--- 5. `for` Desugared ---");
+
+    let mut from_for = Vec::new();
+    for word in ["a", "bb", "ccc"].iter().my_map(|w| w.len()) {
+        from_for.push(word);
+    }
+
+    let mut from_while = Vec::new();
+    let mut iter = IntoIterator::into_iter(["a", "bb", "ccc"].iter().my_map(|w| w.len()));
+    #[allow(clippy::while_let_on_iterator)] // Spelled out on purpose
+    while let Some(word) = iter.next() {
+        from_while.push(word);
+    }
+    assert_eq!(from_for, from_while);
+    println!("Both loops produced {:?}", from_for);
+
+    println!("\n--- End of Iterator Adapter Examples ---");
+}
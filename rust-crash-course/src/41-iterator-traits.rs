@@ -0,0 +1,262 @@
+// This file extends the `Counter` iterator from the iterator lesson with the
+// extra iterator traits that unlock more methods:
+// - `DoubleEndedIterator`: iterate from the back too (`rev()`, `rfind()`, `next_back()`)
+// - `ExactSizeIterator`: know the exact remaining length (`len()`)
+// - `FusedIterator`: a promise that after the first `None`, it stays `None`
+// plus why `fuse()` exists for iterators that don't make that promise.
+
+use std::iter::FusedIterator;
+
+// -------------------------------------------------------------------------
+// Counter, Upgraded
+// -------------------------------------------------------------------------
+// The original `Counter` counted 1 to 5 from the front only. To iterate from
+// both ends it needs to track both ends: the remaining items are `front..=back`.
+
+#[derive(Debug, Clone)]
+struct Counter {
+    front: u32, // Next value returned by `next()`
+    back: u32,  // Next value returned by `next_back()`
+}
+
+impl Counter {
+    // Counts 1 to 5, like the original.
+    fn new() -> Counter {
+        Counter::up_to(5)
+    }
+
+    fn up_to(max: u32) -> Counter {
+        Counter {
+            front: 1,
+            back: max,
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.front > self.back
+    }
+}
+
+impl Iterator for Counter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.is_done() {
+            return None;
+        }
+        let value = self.front;
+        self.front += 1;
+        Some(value)
+    }
+
+    // Required for a correct `ExactSizeIterator`: lower == upper == remaining.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.is_done() {
+            0
+        } else {
+            (self.back - self.front + 1) as usize
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Counter {
+    fn next_back(&mut self) -> Option<u32> {
+        if self.is_done() {
+            return None;
+        }
+        let value = self.back;
+        // `back` can't go below 0; when the two ends cross, we're done either way.
+        match self.back.checked_sub(1) {
+            Some(b) => self.back = b,
+            None => self.front = 1, // back was 0: mark as done by keeping front > back
+        }
+        Some(value)
+    }
+}
+
+// No methods to write: `len()` is provided, built on our exact `size_hint`.
+impl ExactSizeIterator for Counter {}
+
+// Also no methods: it's a marker trait. Once `front > back`, both `next` and
+// `next_back` keep returning `None`, so the promise holds.
+impl FusedIterator for Counter {}
+
+// -------------------------------------------------------------------------
+// A Non-Fused Iterator
+// -------------------------------------------------------------------------
+// `Iterator` only says that `None` means "nothing right now". Some iterators
+// can produce items again afterwards, e.g. one that reads from a queue that
+// is refilled between calls. This one yields in bursts: two items, a `None`,
+// two more items, another `None`, and so on.
+
+struct Bursts {
+    next_value: u32,
+    in_burst: u32,
+}
+
+impl Iterator for Bursts {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.in_burst == 2 {
+            self.in_burst = 0;
+            return None; // A gap between bursts
+        }
+        self.in_burst += 1;
+        self.next_value += 1;
+        Some(self.next_value)
+    }
+}
+
+fn main() {
+    println!("--- DoubleEndedIterator, ExactSizeIterator, FusedIterator ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Still a Plain Iterator
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. The Same Counter as Before ---");
+
+    let mut counter = Counter::new();
+    assert_eq!(counter.next(), Some(1));
+    assert_eq!(counter.next(), Some(2));
+    let rest: Vec<u32> = counter.collect();
+    println!("Remaining after two `next()` calls: {:?}", rest);
+    assert_eq!(rest, [3, 4, 5]);
+
+    // -------------------------------------------------------------------------
+    // 2. `DoubleEndedIterator`: `next_back`, `rev`, `rfind`
+    // -------------------------------------------------------------------------
+    // Implementing `next_back` unlocks every method that works from the end.
+    // Taking from both ends meets in the middle: no item is returned twice.
+
+    println!("\n--- 2. `DoubleEndedIterator` ---");
+
+    let reversed: Vec<u32> = Counter::new().rev().collect();
+    println!("rev(): {:?}", reversed);
+    assert_eq!(reversed, [5, 4, 3, 2, 1]);
+
+    let mut both_ends = Counter::new();
+    assert_eq!(both_ends.next(), Some(1));
+    assert_eq!(both_ends.next_back(), Some(5));
+    assert_eq!(both_ends.next(), Some(2));
+    assert_eq!(both_ends.next_back(), Some(4));
+    assert_eq!(both_ends.next(), Some(3));
+    assert_eq!(both_ends.next_back(), None); // The ends have met
+    println!("Alternating ends gave 1, 5, 2, 4, 3, then None");
+
+    // `rfind` searches from the back: the *last* even number.
+    assert_eq!(Counter::up_to(9).rfind(|n| n % 2 == 0), Some(8));
+    assert_eq!(Counter::up_to(9).find(|n| n % 2 == 0), Some(2));
+    // `nth_back`, `rfold` and `rposition` (on `ExactSizeIterator`s) come along too.
+    assert_eq!(Counter::new().nth_back(1), Some(4));
+    let digits = Counter::new().rfold(String::new(), |acc, n| acc + &n.to_string());
+    assert_eq!(digits, "54321");
+
+    // Adapters keep the trait when they can: `map` and `filter` are still
+    // double-ended, so `rev()` works at the end of a chain.
+    let last_squares: Vec<u32> = Counter::up_to(6).map(|n| n * n).rev().take(2).collect();
+    assert_eq!(last_squares, [36, 25]);
+
+    // Counting down from 0 must not underflow.
+    let mut zero = Counter { front: 0, back: 0 };
+    assert_eq!(zero.next_back(), Some(0));
+    assert_eq!(zero.next_back(), None);
+    assert_eq!(zero.next(), None);
+
+    /*
+    let lengths = std::collections::HashSet::from([1, 2]).into_iter().rev();
+    // error[E0277]: the trait bound `std::collections::hash_set::IntoIter<{integer}>: DoubleEndedIterator` is not satisfied
+    // (a `HashSet` has no meaningful "back")
+    */
+
+    // -------------------------------------------------------------------------
+    // 3. `ExactSizeIterator`: `len()`
+    // -------------------------------------------------------------------------
+    // `len()` returns the exact number of remaining items. It relies on
+    // `size_hint` returning `(n, Some(n))`, so implement that first.
+
+    println!("\n--- 3. `ExactSizeIterator` ---");
+
+    let mut counter = Counter::up_to(10);
+    println!("len() before: {}", counter.len());
+    assert_eq!(counter.len(), 10);
+    counter.next();
+    counter.next_back();
+    assert_eq!(counter.len(), 8);
+    assert_eq!(counter.size_hint(), (8, Some(8)));
+
+    // Adapters that keep the count exact keep the trait; `filter` can't know
+    // how many items will pass, so it loses it:
+    assert_eq!(Counter::new().map(|n| n + 1).len(), 5); // `map`: still exact
+    assert_eq!(Counter::new().skip(2).len(), 3); // `skip`: still exact
+
+    /*
+    Counter::new().filter(|n| n % 2 == 0).len();
+    // error[E0599]: no method named `len` found for struct `Filter<I, P>` in the current scope
+    */
+
+    // Knowing the length lets code index from the end, e.g. `rposition`:
+    assert_eq!(Counter::new().rposition(|n| n < 3), Some(1)); // Index of the 2
+
+    // -------------------------------------------------------------------------
+    // 4. `FusedIterator` and Why `fuse()` Exists
+    // -------------------------------------------------------------------------
+    // After returning `None`, an arbitrary iterator may return `Some` again.
+    // Code that calls `next()` after a `None` can't rely on it staying `None`
+    // unless the iterator is fused.
+
+    println!("\n--- 4. `FusedIterator` and `fuse()` ---");
+
+    let mut bursts = Bursts {
+        next_value: 0,
+        in_burst: 0,
+    };
+    let calls: Vec<Option<u32>> = (0..7).map(|_| bursts.next()).collect();
+    println!("Unfused: {:?}", calls);
+    assert_eq!(
+        calls,
+        [Some(1), Some(2), None, Some(3), Some(4), None, Some(5)]
+    );
+
+    // A `for` loop stops at the first `None` and never sees the later items.
+    let first_burst: Vec<u32> = Bursts {
+        next_value: 0,
+        in_burst: 0,
+    }
+    .collect();
+    assert_eq!(first_burst, [1, 2]);
+
+    // `fuse()` wraps any iterator so that after the first `None` it always
+    // returns `None`. Use it when your code may call `next()` again after the
+    // end, e.g. when merging several iterators.
+    let mut fused = Bursts {
+        next_value: 0,
+        in_burst: 0,
+    }
+    .fuse();
+    let calls: Vec<Option<u32>> = (0..7).map(|_| fused.next()).collect();
+    println!("Fused:   {:?}", calls);
+    assert_eq!(calls, [Some(1), Some(2), None, None, None, None, None]);
+
+    // For iterators that implement `FusedIterator` (like `Counter`, and most
+    // of the standard library's), `fuse()` is specialized to cost nothing.
+    let mut counter = Counter::up_to(1).fuse();
+    assert_eq!(counter.next(), Some(1));
+    assert_eq!(counter.next(), None);
+    assert_eq!(counter.next(), None);
+
+    // Generic code can *require* the promise with a `FusedIterator` bound.
+    fn drain_twice<I: FusedIterator<Item = u32>>(mut iter: I) -> (Vec<u32>, Option<u32>) {
+        let first_pass: Vec<u32> = iter.by_ref().collect();
+        (first_pass, iter.next()) // Guaranteed `None`
+    }
+    assert_eq!(drain_twice(Counter::up_to(3)), (vec![1, 2, 3], None));
+    /*
+    drain_twice(Bursts { next_value: 0, in_burst: 0 });
+    // error[E0277]: the trait bound `Bursts: FusedIterator` is not satisfied
+    */
+
+    println!("\n--- End of Iterator Traits Examples ---");
+}
@@ -0,0 +1,356 @@
+// This file goes deeper than section 4 of the data structures lesson: how a
+// `String` is laid out in memory, how UTF-8 encodes each `char`, why `s[0]`
+// doesn't compile, and the three different "boundaries" in a string (bytes,
+// chars, and user-visible graphemes). It ends with safe substring helpers and
+// a `truncate_utf8_safe` utility that never splits a character, or a
+// character from the accents and modifiers attached to it.
+
+use std::ops::Range;
+
+// -------------------------------------------------------------------------
+// Helpers
+// -------------------------------------------------------------------------
+
+// Characters that attach to the one before them instead of standing alone.
+// This is a small subset of the Unicode rules (the `unicode-segmentation`
+// crate implements all of them), but it covers accents, emoji skin tones,
+// variation selectors and the zero-width joiner used in emoji sequences.
+fn is_extending(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'   // Combining diacritical marks (accents)
+        | '\u{1F3FB}'..='\u{1F3FF}' // Emoji skin-tone modifiers
+        | '\u{FE00}'..='\u{FE0F}'   // Variation selectors
+        | '\u{200D}'                // Zero-width joiner
+    )
+}
+
+// Byte offsets where a new (approximate) grapheme cluster starts. A char
+// starts a new cluster unless it extends the previous one, or the previous
+// one was a zero-width joiner gluing the two together.
+fn grapheme_starts(s: &str) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut previous: Option<char> = None;
+    for (i, c) in s.char_indices() {
+        let joined = previous == Some('\u{200D}');
+        if previous.is_none() || !(is_extending(c) || joined) {
+            starts.push(i);
+        }
+        previous = Some(c);
+    }
+    starts
+}
+
+fn approx_grapheme_count(s: &str) -> usize {
+    grapheme_starts(s).len()
+}
+
+// Converts a range of *char* positions into a byte range, or `None` if the
+// range is reversed or runs past the end of the string.
+fn char_range_to_bytes(s: &str, chars: Range<usize>) -> Option<Range<usize>> {
+    if chars.start > chars.end {
+        return None;
+    }
+    // Every char start, plus `s.len()` as the position "after the last char".
+    let mut offsets = s.char_indices().map(|(i, _)| i).chain([s.len()]);
+    let start = offsets.nth(chars.start)?;
+    let end = if chars.end == chars.start {
+        start
+    } else {
+        offsets.nth(chars.end - chars.start - 1)?
+    };
+    Some(start..end)
+}
+
+// A substring by char positions that reports bad input instead of clamping
+// (compare `slice_chars` in the data structures lesson, which clamps).
+fn substring(s: &str, chars: Range<usize>) -> Option<&str> {
+    char_range_to_bytes(s, chars).map(|bytes| &s[bytes])
+}
+
+// The first `n` user-visible characters (approximate graphemes).
+fn first_graphemes(s: &str, n: usize) -> &str {
+    match grapheme_starts(s).get(n) {
+        Some(&end) => &s[..end],
+        None => s, // Fewer than `n` graphemes: keep everything
+    }
+}
+
+// Shortens `s` to at most `max_bytes` bytes without producing invalid UTF-8
+// and without separating a character from the marks attached to it: "é"
+// written as 'e' + U+0301 is kept or dropped as a whole, as is "👋🏽".
+fn truncate_utf8_safe(s: &str, max_bytes: usize) -> &str {
+    // Step back to a char boundary (or `s.len()` if `max_bytes` is past the
+    // end). `truncate_at_char_boundary` in lessons/strings.rs (used by
+    // 07-rust_data_structures.rs) writes this loop out by hand with
+    // `is_char_boundary`.
+    let mut end = s.floor_char_boundary(max_bytes);
+    // Then step back over whole chars while the cut would separate the next
+    // char from the one before it.
+    while end > 0 {
+        let next = s[end..].chars().next();
+        let before = s[..end].chars().next_back();
+        let splits_cluster = next.is_some_and(is_extending) || before == Some('\u{200D}');
+        if !splits_cluster {
+            break;
+        }
+        end -= before.map_or(1, char::len_utf8);
+    }
+    &s[..end]
+}
+
+// The same rule applied in place to an owned `String`.
+fn truncate_string_utf8_safe(s: &mut String, max_bytes: usize) {
+    let keep = truncate_utf8_safe(s, max_bytes).len();
+    s.truncate(keep); // Would panic if `keep` weren't a char boundary
+}
+
+fn main() {
+    println!("--- String Internals and UTF-8 ---");
+
+    // -------------------------------------------------------------------------
+    // 1. What a `String` Is
+    // -------------------------------------------------------------------------
+    // A `String` is a `Vec<u8>` with one extra guarantee: the bytes are always
+    // valid UTF-8. Like a `Vec`, it is a pointer, a length and a capacity, and
+    // `len()` counts *bytes*.
+
+    println!("\n--- 1. A `Vec<u8>` That Is Always UTF-8 ---");
+
+    let mut s = String::with_capacity(16);
+    assert_eq!((s.len(), s.capacity()), (0, 16));
+    s.push_str("héllo");
+    println!(
+        "'{}': len {} bytes, capacity {}, bytes {:?}",
+        s,
+        s.len(),
+        s.capacity(),
+        s.as_bytes()
+    );
+    assert_eq!(s.len(), 6); // 'é' takes two bytes
+    assert_eq!(s.as_bytes()[1..3], [0xC3, 0xA9]);
+    assert_eq!(
+        std::mem::size_of::<String>(),
+        3 * std::mem::size_of::<usize>()
+    );
+    assert_eq!(
+        std::mem::size_of::<&str>(),
+        2 * std::mem::size_of::<usize>()
+    ); // No capacity
+
+    // Going from bytes to a `String` has to check that guarantee.
+    let bytes = s.clone().into_bytes(); // Free: hands over the buffer
+    assert_eq!(String::from_utf8(bytes).as_deref(), Ok("héllo"));
+
+    let broken = vec![b'o', b'k', 0xC3, b'!']; // 0xC3 starts a 2-byte char, '!' can't continue it
+    let err = String::from_utf8(broken.clone()).unwrap_err();
+    println!("from_utf8 error: {}", err);
+    assert_eq!(err.utf8_error().valid_up_to(), 2); // "ok" was fine
+    assert_eq!(err.utf8_error().error_len(), Some(1)); // One bad byte
+    // `from_utf8_lossy` replaces bad sequences with U+FFFD instead of failing.
+    assert_eq!(String::from_utf8_lossy(&broken), "ok\u{FFFD}!");
+
+    // -------------------------------------------------------------------------
+    // 2. How UTF-8 Encodes a `char`
+    // -------------------------------------------------------------------------
+    // A `char` is one Unicode scalar value and always takes 4 bytes in memory.
+    // Inside a string it is encoded with 1 to 4 bytes: ASCII stays 1 byte, so
+    // plain English text costs the same as ASCII.
+
+    println!("\n--- 2. UTF-8 Encoding ---");
+
+    for c in ['A', 'é', 'З', '€', '👋'] {
+        let mut buffer = [0u8; 4];
+        let encoded = c.encode_utf8(&mut buffer);
+        println!(
+            "{:?} U+{:04X}: {} byte(s) {:02X?}",
+            c,
+            c as u32,
+            c.len_utf8(),
+            encoded.as_bytes()
+        );
+    }
+    assert_eq!(std::mem::size_of::<char>(), 4);
+    assert_eq!(['A', 'é', '€', '👋'].map(char::len_utf8), [1, 2, 3, 4]);
+    // Continuation bytes always look like 0b10xx_xxxx, which is how code can
+    // find char boundaries without decoding from the start.
+    assert!(
+        "€".as_bytes()[1..]
+            .iter()
+            .all(|b| b & 0b1100_0000 == 0b1000_0000)
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. Why `s[i]` Is Forbidden
+    // -------------------------------------------------------------------------
+    // What should `"Здравствуйте"[0]` return? The first byte (half of 'З')?
+    // The first char (which would need an O(n) scan for `s[5]`)? Rust refuses
+    // to guess, so integer indexing doesn't compile. You pick explicitly:
+    // `as_bytes()[i]`, `chars().nth(i)`, or a byte *range*.
+
+    println!("\n--- 3. No Integer Indexing ---");
+
+    /*
+    let first = "Здравствуйте"[0];
+    // error[E0277]: the type `str` cannot be indexed by `{integer}`
+    */
+
+    let russian = "Здравствуйте";
+    assert_eq!(russian.as_bytes()[0], 0xD0); // A byte
+    assert_eq!(russian.chars().nth(1), Some('д')); // A char, found by scanning
+    assert_eq!(&russian[0..2], "З"); // A byte range on char boundaries
+
+    // Byte ranges *do* compile, but panic at runtime if they split a char.
+    let result = std::panic::catch_unwind(|| &russian[0..1]);
+    assert!(result.is_err());
+    // `get` is the non-panicking version.
+    assert_eq!(russian.get(0..1), None);
+    assert!(!russian.is_char_boundary(1));
+
+    // Byte positions returned by `str` methods are always valid boundaries.
+    let at = russian.find('в').expect("contains 'в'");
+    println!(
+        "find('в') = byte {}, which is char {}",
+        at,
+        russian[..at].chars().count()
+    );
+    assert_eq!((at, &russian[at..at + 2]), (8, "в"));
+
+    // -------------------------------------------------------------------------
+    // 4. Bytes vs. Chars vs. Graphemes
+    // -------------------------------------------------------------------------
+    // Three different answers to "how long is this string?":
+    // - bytes: what `len()` returns and what slicing uses
+    // - chars: Unicode scalar values, what `chars()` yields
+    // - graphemes: what a person would call one character on screen
+
+    println!("\n--- 4. Bytes vs. Chars vs. Graphemes ---");
+
+    let samples = [
+        ("plain", "cafe"),
+        ("precomposed é", "caf\u{E9}"),
+        ("e + accent", "cafe\u{301}"),
+        ("skin tone", "👋🏽"),
+        ("family (ZWJ)", "👨\u{200D}👩\u{200D}👧"),
+    ];
+    for (label, text) in samples {
+        println!(
+            "{:<14} {:>6}: {:>2} bytes, {} chars, {} graphemes",
+            label,
+            text,
+            text.len(),
+            text.chars().count(),
+            approx_grapheme_count(text)
+        );
+    }
+    // The two spellings of "café" look identical but are different strings.
+    assert_ne!("caf\u{E9}", "cafe\u{301}");
+    assert_eq!(approx_grapheme_count("caf\u{E9}"), 4);
+    assert_eq!(approx_grapheme_count("cafe\u{301}"), 4);
+    assert_eq!(approx_grapheme_count("👨\u{200D}👩\u{200D}👧"), 1); // 5 chars, 18 bytes
+    assert_eq!(approx_grapheme_count(""), 0);
+
+    // Reversing by chars tears clusters apart: the accent moves onto 'f'.
+    let reversed: String = "cafe\u{301}".chars().rev().collect();
+    assert_eq!(reversed, "\u{301}efac");
+
+    // -------------------------------------------------------------------------
+    // 5. `char_indices`: Chars with Their Byte Offsets
+    // -------------------------------------------------------------------------
+    // `char_indices()` yields `(byte_offset, char)`. The offsets are exactly
+    // the positions where slicing is safe, which makes it the tool for
+    // tokenizers and for mapping char positions to byte positions.
+
+    println!("\n--- 5. `char_indices` ---");
+
+    let text = "Zürich → Genève";
+    let words: Vec<(usize, &str)> = text
+        .char_indices()
+        .filter(|&(i, c)| !c.is_whitespace() && (i == 0 || text[..i].ends_with(' ')))
+        .map(|(start, _)| {
+            let end = text[start..].find(' ').map_or(text.len(), |n| start + n);
+            (start, &text[start..end])
+        })
+        .collect();
+    println!("Words with byte offsets: {:?}", words);
+    assert_eq!(words, [(0, "Zürich"), (8, "→"), (12, "Genève")]);
+
+    // -------------------------------------------------------------------------
+    // 6. Safe Substring Helpers
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 6. Safe Substrings ---");
+
+    assert_eq!(char_range_to_bytes(russian, 1..3), Some(2..6));
+    assert_eq!(substring(russian, 1..3), Some("др"));
+    assert_eq!(substring(russian, 6..12), Some("твуйте")); // Up to the very end
+    assert_eq!(substring(russian, 12..12), Some("")); // Empty at the end is fine
+    assert_eq!(substring(russian, 6..13), None); // Past the end
+    assert_eq!(substring(russian, Range { start: 3, end: 1 }), None); // Reversed
+    println!("substring(russian, 1..3) = {:?}", substring(russian, 1..3));
+
+    assert_eq!(first_graphemes("cafe\u{301} au lait", 4), "cafe\u{301}");
+    assert_eq!(first_graphemes("👋🏽👋", 1), "👋🏽");
+    assert_eq!(first_graphemes("ab", 5), "ab");
+
+    // The standard library's checked forms return `None` instead of panicking:
+    assert_eq!(russian.split_at_checked(1), None);
+    assert_eq!(russian.split_at_checked(2), Some(("З", "дравствуйте")));
+
+    // -------------------------------------------------------------------------
+    // 7. `truncate_utf8_safe`
+    // -------------------------------------------------------------------------
+    // A common need: fit text into a byte budget (a database column, a log
+    // line, a network packet). Cutting at `max_bytes` may split a char (a
+    // panic) or separate an accent from its letter (a visibly wrong result).
+
+    println!("\n--- 7. `truncate_utf8_safe` ---");
+
+    let cases = [
+        ("hello", 3, "hel"),        // ASCII: every byte is a boundary
+        ("hello", 10, "hello"),     // Already short enough
+        ("héllo", 2, "h"),          // 2 bytes would split 'é'
+        ("cafe\u{301}!", 4, "caf"), // Don't strand 'e' without its accent...
+        ("cafe\u{301}!", 5, "caf"), // ...or cut into the accent
+        ("cafe\u{301}!", 6, "cafe\u{301}"),
+        ("hi 👋🏽", 7, "hi "), // Hand without its skin tone isn't kept
+        ("hi 👋🏽", 11, "hi 👋🏽"),
+        ("👨\u{200D}👩", 8, ""), // Don't end on a joiner
+        ("", 0, ""),
+    ];
+    for (input, max_bytes, expected) in cases {
+        let truncated = truncate_utf8_safe(input, max_bytes);
+        println!(
+            "{:>12?} to {:>2} bytes -> {:?}",
+            input, max_bytes, truncated
+        );
+        assert_eq!(
+            truncated, expected,
+            "truncating {:?} to {}",
+            input, max_bytes
+        );
+    }
+
+    // For every budget, the result is a prefix, fits, and ends on a cluster
+    // boundary.
+    let tricky = "Ünïcödé 👋🏽 cafe\u{301} 👨\u{200D}👩\u{200D}👧!";
+    let cluster_starts = grapheme_starts(tricky);
+    for max_bytes in 0..=tricky.len() {
+        let truncated = truncate_utf8_safe(tricky, max_bytes);
+        assert!(tricky.starts_with(truncated));
+        assert!(truncated.len() <= max_bytes);
+        assert!(truncated.len() == tricky.len() || cluster_starts.contains(&truncated.len()));
+    }
+
+    let mut owned = String::from("naïve");
+    truncate_string_utf8_safe(&mut owned, 3);
+    assert_eq!(owned, "na"); // 3 bytes would split 'ï'
+    truncate_string_utf8_safe(&mut owned, 100);
+    assert_eq!(owned, "na");
+
+    /*
+    let mut owned = String::from("naïve");
+    owned.truncate(3); // Panics at runtime: "assertion failed: self.is_char_boundary(new_len)"
+    */
+
+    println!("\n--- End of String Internals Examples ---");
+}
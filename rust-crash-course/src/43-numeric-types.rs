@@ -0,0 +1,283 @@
+// This file is a tour of Rust's numeric types: the integer and float
+// families, literals and type inference, how `/` and `%` behave, what `as`
+// casts do at the edges, the four ways to handle overflow side by side, and
+// the float values that surprise people (NaN, infinities, -0.0, precision
+// limits). The integer overflow and floating-point lessons go deeper into
+// overflow handling and float comparison; this one puts everything in one
+// place and checks each claim with an assertion.
+
+use std::hint::black_box;
+
+#[path = "lessons/table.rs"]
+mod table;
+
+use table::{Align, Table};
+
+// Compares two floats with a tolerance scaled to their size, so it works for
+// both tiny and huge values (see `approx_eq_rel` in the floating-point lesson).
+fn approx_eq(a: f64, b: f64) -> bool {
+    let scale = a.abs().max(b.abs()).max(1.0);
+    (a - b).abs() <= f64::EPSILON * 4.0 * scale
+}
+
+fn main() {
+    println!("--- Numeric Types and Overflow ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Integer and Float Families
+    // -------------------------------------------------------------------------
+    // - Signed integers:   i8, i16, i32, i64, i128, isize
+    // - Unsigned integers: u8, u16, u32, u64, u128, usize
+    // - Floats:            f32, f64 (IEEE 754)
+    // `isize`/`usize` are pointer-sized (64 bits on most machines today);
+    // `usize` is the type of lengths and indexes.
+
+    println!("\n--- 1. The Numeric Types ---");
+
+    let mut types = Table::new(["type", "bytes", "min", "max"])
+        .align(1, Align::Right)
+        .align(2, Align::Right)
+        .align(3, Align::Right);
+    types.row(["i8", "1", &i8::MIN.to_string(), &i8::MAX.to_string()]);
+    types.row(["u8", "1", &u8::MIN.to_string(), &u8::MAX.to_string()]);
+    types.row(["i16", "2", &i16::MIN.to_string(), &i16::MAX.to_string()]);
+    types.row(["i32", "4", &i32::MIN.to_string(), &i32::MAX.to_string()]);
+    types.row(["u32", "4", &u32::MIN.to_string(), &u32::MAX.to_string()]);
+    types.row(["i64", "8", &i64::MIN.to_string(), &i64::MAX.to_string()]);
+    types.row(["u128", "16", &u128::MIN.to_string(), &u128::MAX.to_string()]);
+    types.row([
+        "f32",
+        "4",
+        &format!("{:e}", f32::MIN),
+        &format!("{:e}", f32::MAX),
+    ]);
+    types.row([
+        "f64",
+        "8",
+        &format!("{:e}", f64::MIN),
+        &format!("{:e}", f64::MAX),
+    ]);
+    println!("{}", types);
+
+    assert_eq!(std::mem::size_of::<i128>(), 16);
+    assert_eq!(
+        std::mem::size_of::<usize>(),
+        std::mem::size_of::<*const u8>()
+    );
+    assert_eq!(i8::MIN, -128); // One more negative value than positive
+    assert_eq!(u32::BITS as usize, 8 * std::mem::size_of::<u32>());
+
+    // -------------------------------------------------------------------------
+    // 2. Literals and Inference
+    // -------------------------------------------------------------------------
+    // An unconstrained integer literal defaults to `i32`, a float literal to
+    // `f64`. A suffix or an annotation picks another type. Underscores are
+    // ignored and only help readability.
+
+    println!("\n--- 2. Literals and Inference ---");
+
+    let million = 1_000_000; // i32
+    let small = 200u8; // Suffix
+    let big: u64 = 5_000_000_000; // Annotation
+    let ratio = 0.5; // f64
+    let single = 0.5f32;
+    assert_eq!(std::mem::size_of_val(&million), 4);
+    assert_eq!(std::mem::size_of_val(&ratio), 8);
+    assert_eq!(std::mem::size_of_val(&single), 4);
+    println!("{} {} {} {} {}", million, small, big, ratio, single);
+
+    // Other bases, a byte literal and scientific notation.
+    let (hex, octal, binary, byte) = (0xFF, 0o17, 0b1010_1010u8, b'A');
+    assert_eq!((hex, octal, binary, byte), (255, 15, 170, 65));
+    let thousand = 1e3;
+    assert_eq!(thousand, 1000.0);
+
+    /*
+    let too_big: u8 = 256;
+    // error: literal out of range for `u8` (a deny-by-default lint)
+    */
+
+    /*
+    let mixed = 1u32 + 1u64;
+    // error[E0308]: mismatched types
+    // error[E0277]: cannot add `u64` to `u32`
+    // (no implicit widening: convert with `u64::from(x)` or `as`)
+    */
+
+    // -------------------------------------------------------------------------
+    // 3. Division and Remainder
+    // -------------------------------------------------------------------------
+    // Integer `/` truncates toward zero, and `%` takes the sign of the left
+    // operand. For "always non-negative" results (clock arithmetic, array
+    // wrap-around) use `rem_euclid`/`div_euclid`.
+
+    println!("\n--- 3. Division and Remainder ---");
+
+    let (seven, minus_seven) = (7i32, -7i32);
+    assert_eq!(seven / 2, 3);
+    assert_eq!(minus_seven / 2, -3); // Not -4: toward zero, not down
+    assert_eq!(minus_seven % 3, -1); // Sign follows -7
+    assert_eq!(minus_seven.rem_euclid(3), 2);
+    assert_eq!(minus_seven.div_euclid(2), -4);
+    println!(
+        "-7 / 2 = {}, -7 % 3 = {}, (-7).rem_euclid(3) = {}",
+        minus_seven / 2,
+        minus_seven % 3,
+        minus_seven.rem_euclid(3)
+    );
+
+    // One hour before 00:xx on a 24-hour clock:
+    let hour: i32 = 0;
+    assert_eq!((hour - 1).rem_euclid(24), 23);
+
+    // Integer division by zero always panics (in every build); the checked
+    // form returns `None`. Float division by zero gives infinity or NaN.
+    let zero = black_box(0);
+    assert!(std::panic::catch_unwind(|| 10 / zero).is_err());
+    assert_eq!(10i32.checked_div(zero), None);
+    let float_zero = 0.0f64;
+    assert_eq!(1.0 / float_zero, f64::INFINITY);
+    assert!((-1.0 / float_zero).is_infinite());
+    assert!((float_zero / 0.0).is_nan());
+
+    // -------------------------------------------------------------------------
+    // 4. Casting with `as`
+    // -------------------------------------------------------------------------
+    // `as` never fails, so at the edges it has to do *something*:
+    // - integer -> smaller integer: keeps the low bits (truncates)
+    // - signed <-> unsigned of the same size: reinterprets the bits
+    // - float -> integer: rounds toward zero and *saturates*; NaN becomes 0
+    // - integer -> float: rounds to the nearest representable value
+    // Prefer `From`/`TryFrom` when a lossy result would be a bug (see the
+    // conversions lesson).
+
+    println!("\n--- 4. `as` Casts ---");
+
+    assert_eq!(300i32 as u8, 44); // 300 - 256
+    assert_eq!(-1i32 as u32, u32::MAX); // Same bits, read as unsigned
+    assert_eq!(200u8 as i8, -56);
+    assert_eq!(2.9f64 as i32, 2);
+    assert_eq!(-2.9f64 as i32, -2);
+    assert_eq!(1e20f64 as i32, i32::MAX); // Saturates instead of wrapping
+    assert_eq!(-1.0f64 as u8, 0);
+    let nan = f64::NAN;
+    assert_eq!(nan as i32, 0);
+    println!(
+        "1e20 as i32 = {}, NaN as i32 = {}",
+        1e20f64 as i32, nan as i32
+    );
+
+    // An `f64` has 53 bits of precision, so not every `u64` survives a round trip.
+    let exact = 2u64.pow(53);
+    assert_eq!((exact as f64) as u64, exact);
+    assert_eq!(((exact + 1) as f64) as u64, exact); // The +1 was rounded away
+
+    // The lossless direction has a `From` impl; the lossy one only `TryFrom`.
+    assert_eq!(f64::from(u32::MAX), 4_294_967_295.0);
+    assert!(u8::try_from(300i32).is_err());
+
+    // -------------------------------------------------------------------------
+    // 5. Overflow: Four Explicit Policies
+    // -------------------------------------------------------------------------
+    // Plain `+`, `-`, `*` panic on overflow in debug builds and wrap in release
+    // builds (unless `overflow-checks` is enabled). When overflow is possible,
+    // say what you want instead. Here is the same operation, 250u8 + 10, under
+    // each policy; the integer overflow lesson covers each family in depth.
+
+    println!("\n--- 5. Overflow Policies ---");
+
+    let (a, b) = (250u8, 10u8);
+    let mut policies = Table::new(["method", "250u8 + 10", "use when"]);
+    policies.row([
+        "checked_add".to_string(),
+        format!("{:?}", a.checked_add(b)),
+        "overflow is an error".to_string(),
+    ]);
+    policies.row([
+        "wrapping_add".to_string(),
+        a.wrapping_add(b).to_string(),
+        "modular math: hashes, counters".to_string(),
+    ]);
+    policies.row([
+        "saturating_add".to_string(),
+        a.saturating_add(b).to_string(),
+        "clamp at the limit: volume, HP".to_string(),
+    ]);
+    policies.row([
+        "overflowing_add".to_string(),
+        format!("{:?}", a.overflowing_add(b)),
+        "need the result and a carry".to_string(),
+    ]);
+    println!("{}", policies);
+
+    assert_eq!(a.checked_add(b), None);
+    assert_eq!(a.wrapping_add(b), 4);
+    assert_eq!(a.saturating_add(b), u8::MAX);
+    assert_eq!(a.overflowing_add(b), (4, true));
+    assert_eq!(a.checked_add(5), Some(255)); // Fits: same as `+`
+
+    // The same four families exist for sub, mul, pow, neg, shl, abs...
+    assert_eq!(2u32.checked_pow(40), None);
+    assert_eq!(i32::MIN.wrapping_abs(), i32::MIN); // |MIN| doesn't fit, wraps back
+    assert_eq!(0u8.saturating_sub(1), 0);
+    assert_eq!(i64::MAX.overflowing_mul(2), (-2, true));
+
+    // What plain `+` does depends on the build profile.
+    let sum = std::panic::catch_unwind(|| black_box(a) + black_box(b));
+    if cfg!(debug_assertions) {
+        assert!(sum.is_err()); // "attempt to add with overflow"
+        println!("Debug build: 250u8 + 10 panicked");
+    } else {
+        assert_eq!(sum.ok(), Some(4));
+        println!("Release build: 250u8 + 10 wrapped to 4");
+    }
+
+    // -------------------------------------------------------------------------
+    // 6. Float Pitfalls
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 6. Float Pitfalls ---");
+
+    // a. Decimal fractions aren't exact in binary, so compare with a tolerance.
+    assert_ne!(0.1 + 0.2, 0.3);
+    assert!(approx_eq(0.1 + 0.2, 0.3));
+    assert!(approx_eq(1e15 + 0.1, 1e15 + 0.1000001)); // Tolerance scales with size
+    assert!(!approx_eq(1.0, 1.0001));
+    // `EPSILON` is the gap between 1.0 and the next float, not a universal
+    // tolerance: near 1e15 the gap is far bigger.
+    let (one, huge) = (1.0f64, 1e16f64);
+    assert_eq!(one + f64::EPSILON / 4.0, one);
+    assert_eq!(huge + 1.0, huge); // Adding 1 changes nothing at this size
+
+    // b. NaN is not equal to anything, itself included, and it spreads.
+    let nan = f64::NAN;
+    #[allow(clippy::eq_op)] // Clippy flags `x == x` as a likely typo; here it's the point
+    let equal_to_itself = nan == nan;
+    assert!(!equal_to_itself);
+    assert!((nan + 1.0).is_nan());
+    assert!((-1.0f64).sqrt().is_nan());
+    assert_eq!(nan.max(3.0), 3.0); // `max`/`min` ignore NaN...
+    assert!(nan.partial_cmp(&3.0).is_none()); // ...but comparisons can't order it
+    println!("NaN == NaN: {}", equal_to_itself);
+
+    // c. Infinities and signed zero.
+    let (largest, infinity, negative_zero) = (f64::MAX, f64::INFINITY, -0.0f64);
+    assert_eq!(largest * 2.0, infinity); // Floats overflow to infinity, never panic
+    assert!((infinity + f64::NEG_INFINITY).is_nan());
+    assert_eq!(negative_zero, 0.0); // Equal...
+    assert_eq!(one / negative_zero, f64::NEG_INFINITY); // ...but not identical
+    assert!(negative_zero.is_sign_negative());
+
+    // d. `f32` runs out of precision quickly.
+    let limit = 16_777_216f32; // 2^24
+    assert_eq!(limit + 1.0, limit); // 2^24 + 1 isn't representable
+    let third = 1.0f32 / 3.0;
+    println!("1/3 as f32: {:.10}, as f64: {:.10}", third, 1.0f64 / 3.0);
+
+    // e. Summation order changes the result.
+    let forward: f64 = [1e16, 1.0, -1e16].iter().sum();
+    let reordered: f64 = [1e16, -1e16, 1.0].iter().sum();
+    assert_eq!((forward, reordered), (0.0, 1.0));
+
+    println!("\n--- End of Numeric Types Examples ---");
+}
@@ -0,0 +1,328 @@
+// This file covers values that live outside any function: `const` (a value
+// copied into every place it's used), `static` (one value at one fixed
+// address for the whole program), why `static mut` is best avoided, and the
+// modern way to build a global that needs runtime setup: `OnceLock` and
+// `LazyLock`. It ends with a `LessonConfig` singleton, read once from
+// environment variables and shared by all the code below.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::thread;
+
+#[path = "lessons/ext.rs"]
+mod ext;
+
+use ext::ResultExt;
+
+// -------------------------------------------------------------------------
+// `const`
+// -------------------------------------------------------------------------
+// A `const` has no address of its own: the compiler pastes the value in
+// wherever it's named. It needs an explicit type and a value computable at
+// compile time, which includes calls to `const fn`s.
+
+const MAX_RETRIES: u32 = 3;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const GREETING: &str = "hello"; // `&'static str`: the `'static` is implied
+
+const fn kib(n: usize) -> usize {
+    n * 1024
+}
+
+const BUFFER_SIZE: usize = kib(4); // Evaluated by the compiler
+static BUFFER_TEMPLATE: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE]; // Consts work as array lengths
+
+struct Limits;
+
+impl Limits {
+    // Associated consts belong to a type, like `u8::MAX` or `f64::EPSILON`.
+    const MAX_NAME_LEN: usize = 32;
+}
+
+// -------------------------------------------------------------------------
+// `static`
+// -------------------------------------------------------------------------
+// A `static` is a single value at a fixed address that lives for the entire
+// program, so references to it are `&'static`. Statics shared between
+// threads must be `Sync`; to change one safely, give it interior mutability
+// (an atomic or a `Mutex`).
+
+static APP_NAME: &str = "rust-crash-course";
+static REQUESTS_SERVED: AtomicUsize = AtomicUsize::new(0);
+static EVENT_LOG: Mutex<Vec<String>> = Mutex::new(Vec::new()); // `Mutex::new` is a `const fn`
+
+fn serve_request(path: &str) -> usize {
+    let number = REQUESTS_SERVED.fetch_add(1, Ordering::Relaxed) + 1;
+    EVENT_LOG
+        .lock()
+        .unwrap()
+        .push(format!("#{} {}", number, path));
+    number
+}
+
+// -------------------------------------------------------------------------
+// `static mut`
+// -------------------------------------------------------------------------
+// A mutable global with no synchronization at all. Every access is `unsafe`
+// because the compiler can't rule out two threads touching it at once (a
+// data race, which is undefined behavior). Prefer an atomic or a `Mutex`.
+
+static mut UNSAFE_COUNTER: u32 = 0;
+
+fn bump_unsafe_counter() -> u32 {
+    // SAFETY: only ever called from the main thread in this lesson, and no
+    // reference to the static is kept. Nothing in the type system checks this.
+    unsafe {
+        UNSAFE_COUNTER += 1;
+        UNSAFE_COUNTER
+    }
+}
+
+// -------------------------------------------------------------------------
+// Lazy Initialization: `OnceLock` and `LazyLock`
+// -------------------------------------------------------------------------
+// A `static` initializer must be computable at compile time, so it can't read
+// a file, an environment variable or build a `HashMap`. Both types below run
+// the initializer at runtime, the first time the value is needed, exactly
+// once even if several threads race for it.
+// - `LazyLock<T>`: the initializer is fixed where the static is declared.
+// - `OnceLock<T>`: set from anywhere, once; `get` tells you if it's set yet.
+
+static KEYWORDS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    println!("(building KEYWORDS)");
+    HashMap::from([
+        ("fn", "declares a function"),
+        ("let", "binds a variable"),
+        ("mut", "makes a binding mutable"),
+        ("static", "declares a global with a fixed address"),
+    ])
+});
+
+// The lesson configuration, read from environment variables once.
+#[derive(Debug, Clone, PartialEq)]
+struct LessonConfig {
+    verbose: bool,     // LESSON_VERBOSE=1
+    line_width: usize, // LESSON_WIDTH=<n>, default 60
+}
+
+impl LessonConfig {
+    fn from_env() -> LessonConfig {
+        let verbose = std::env::var("LESSON_VERBOSE").is_ok_and(|v| v == "1");
+        let line_width = std::env::var("LESSON_WIDTH")
+            .ok()
+            .and_then(|w| w.parse().log_err("LESSON_WIDTH")) // Warn instead of silently ignoring
+            .filter(|&w| w >= 20)
+            .unwrap_or(60);
+        LessonConfig {
+            verbose,
+            line_width,
+        }
+    }
+}
+
+static CONFIG: OnceLock<LessonConfig> = OnceLock::new();
+static CONFIG_LOADS: AtomicUsize = AtomicUsize::new(0);
+
+// The one way to reach the configuration. The first caller loads it; every
+// later caller, on any thread, gets the same `&'static LessonConfig`.
+fn config() -> &'static LessonConfig {
+    CONFIG.get_or_init(|| {
+        CONFIG_LOADS.fetch_add(1, Ordering::Relaxed);
+        LessonConfig::from_env()
+    })
+}
+
+// Code anywhere in the program can use the settings without them being
+// passed in.
+fn heading(title: &str) -> String {
+    let width = config().line_width;
+    let padding = width.saturating_sub(title.len() + 2) / 2;
+    format!("{0} {1} {0}", "=".repeat(padding), title)
+}
+
+fn debug_note(message: &str) -> Option<String> {
+    config().verbose.then(|| format!("[debug] {}", message))
+}
+
+fn main() {
+    println!("--- const, static, and Lazy Initialization ---");
+
+    // -------------------------------------------------------------------------
+    // 1. `const`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. `const` ---");
+
+    println!(
+        "MAX_RETRIES = {}, SECONDS_PER_DAY = {}, BUFFER_SIZE = {}",
+        MAX_RETRIES, SECONDS_PER_DAY, BUFFER_SIZE
+    );
+    assert_eq!(SECONDS_PER_DAY, 86_400);
+    assert_eq!(BUFFER_SIZE, 4096);
+    assert_eq!(BUFFER_TEMPLATE.len(), BUFFER_SIZE);
+    assert_eq!(GREETING.len(), 5);
+    assert!("a short name".len() <= Limits::MAX_NAME_LEN);
+
+    // Every use of a `const` is a fresh copy of the value, so each of these
+    // temporaries is separate. A `static` would be one shared location.
+    const EMPTY: Vec<u32> = Vec::new();
+    let mut first = EMPTY;
+    first.push(1);
+    let second = EMPTY; // Still empty: `first` got its own copy
+    assert_eq!((first.len(), second.len()), (1, 0));
+
+    // `const` blocks force compile-time evaluation inside an expression.
+    let mask = const { (1u32 << 12) - 1 };
+    assert_eq!(mask, 0xFFF);
+
+    /*
+    const NOW: std::time::Instant = std::time::Instant::now();
+    // error[E0015]: cannot call non-const associated function `Instant::now` in constants
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. `static`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. `static` ---");
+
+    // One address, no matter where it's used from.
+    let a: &'static &str = &APP_NAME;
+    let b: &'static &str = &APP_NAME;
+    assert!(std::ptr::eq(a, b));
+    println!("APP_NAME = {} at {:p}", APP_NAME, a);
+
+    let workers: Vec<_> = (0..4)
+        .map(|i| thread::spawn(move || serve_request(&format!("/page/{}", i))))
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    serve_request("/done");
+    assert_eq!(REQUESTS_SERVED.load(Ordering::Relaxed), 5);
+    let log = EVENT_LOG.lock().unwrap();
+    println!("Event log: {:?}", log);
+    assert_eq!(log.len(), 5);
+    assert_eq!(log.last().map(String::as_str), Some("#5 /done"));
+    drop(log);
+
+    /*
+    static NUMBERS: Vec<u32> = Vec::new();
+    NUMBERS.push(1);
+    // error[E0596]: cannot borrow immutable static item `NUMBERS` as mutable
+    */
+
+    /*
+    static SHARED: std::cell::Cell<u32> = std::cell::Cell::new(0);
+    // error[E0277]: `Cell<u32>` cannot be shared between threads safely
+    */
+
+    // -------------------------------------------------------------------------
+    // 3. `static mut` and Why to Avoid It
+    // -------------------------------------------------------------------------
+    // - Every read and write needs `unsafe`, and *you* must prove no other
+    //   thread accesses it at the same time.
+    // - Since the 2024 edition, taking a reference (`&UNSAFE_COUNTER`, or
+    //   implicitly through a method call or `println!`) is an error by default,
+    //   because that reference may alias a later write.
+
+    println!("\n--- 3. `static mut` ---");
+
+    bump_unsafe_counter();
+    let value = bump_unsafe_counter();
+    println!("UNSAFE_COUNTER = {}", value);
+    assert_eq!(value, 2);
+
+    /*
+    UNSAFE_COUNTER += 1;
+    // error[E0133]: use of mutable static is unsafe and requires unsafe block
+    */
+
+    /*
+    let counter_ref = unsafe { &UNSAFE_COUNTER };
+    // error: creating a shared reference to mutable static
+    */
+
+    // The safe replacement is an atomic: same cost for a counter, no `unsafe`.
+    static SAFE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    SAFE_COUNTER.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(SAFE_COUNTER.load(Ordering::Relaxed), 4000);
+
+    // -------------------------------------------------------------------------
+    // 4. `LazyLock`: A Static Built at Runtime
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. `LazyLock` ---");
+
+    println!("Before first use: nothing built yet");
+    let meaning = KEYWORDS.get("static").copied(); // Builds the map here
+    println!("`static` {}", meaning.unwrap_or("?"));
+    assert_eq!(KEYWORDS.len(), 4); // Already built: no second "(building ...)"
+    assert_eq!(KEYWORDS.get("loop"), None);
+
+    /*
+    static TABLE: HashMap<&str, u32> = HashMap::from([("a", 1)]);
+    // error[E0015]: cannot call non-const associated function `<HashMap<&str, u32> as From<[(&str, u32); 1]>>::from` in statics
+    */
+
+    // -------------------------------------------------------------------------
+    // 5. `OnceLock`: A Configuration Singleton
+    // -------------------------------------------------------------------------
+    // `LessonConfig` is loaded the first time `config()` is called. Try:
+    //     rustc --edition 2024 src/44-const-static-lazy.rs
+    //     LESSON_WIDTH=40 LESSON_VERBOSE=1 ./44-const-static-lazy
+
+    println!("\n--- 5. `OnceLock` Configuration ---");
+
+    assert!(CONFIG.get().is_none()); // Not loaded yet
+    println!("{}", heading("Configuration"));
+    println!("Loaded: {:?}", config());
+    assert!(CONFIG.get().is_some());
+
+    // Many threads asking at once still load it exactly once.
+    let widths: Vec<usize> = thread::scope(|s| {
+        let handles: Vec<_> = (0..8).map(|_| s.spawn(|| config().line_width)).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert!(widths.iter().all(|&w| w == config().line_width));
+    assert_eq!(CONFIG_LOADS.load(Ordering::Relaxed), 1);
+
+    // Every caller shares the same instance.
+    assert!(std::ptr::eq(config(), config()));
+    assert!(heading("x").len() <= config().line_width);
+    if let Some(note) = debug_note("config loaded once") {
+        println!("{}", note);
+    }
+
+    // Once set, a `OnceLock` can't be replaced: `set` hands the value back.
+    let loaded = config().clone();
+    let rejected = LessonConfig {
+        verbose: !loaded.verbose,
+        line_width: loaded.line_width + 40,
+    };
+    let override_attempt = CONFIG.set(rejected.clone());
+    assert_eq!(override_attempt, Err(rejected)); // Our value, returned unused
+    assert_eq!(config(), &loaded); // Still the configuration loaded above
+
+    // -------------------------------------------------------------------------
+    // 6. Choosing
+    // -------------------------------------------------------------------------
+    // - A value known at compile time, no address needed  -> `const`
+    // - One shared location for the whole program          -> `static`
+    // - A global that changes                              -> `static` + atomic/`Mutex`
+    // - A global that needs runtime setup                   -> `LazyLock` / `OnceLock`
+    // - `static mut`                                        -> almost never
+    // Globals are still hidden inputs: passing values as parameters is
+    // usually easier to test. Keep them for truly program-wide settings.
+
+    println!("\n--- End of const, static, and Lazy Initialization Examples ---");
+}
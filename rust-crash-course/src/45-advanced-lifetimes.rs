@@ -0,0 +1,301 @@
+// This file picks up where the lifetimes lesson stops (structs, methods and
+// elision). It covers higher-ranked trait bounds (`for<'a>`) for closures that
+// must accept references of *any* lifetime, the difference between
+// `T: 'static` and `&'static T`, variance (why a `&'static str` can stand in
+// for a shorter `&'a str`, but not behind a `&mut`), and lifetime bounds on
+// generic structs and trait objects.
+
+use std::fmt::Debug;
+use std::thread;
+
+// -------------------------------------------------------------------------
+// Higher-Ranked Trait Bounds
+// -------------------------------------------------------------------------
+
+// `F` is called on a `String` created *inside* this function. No lifetime the
+// caller could name would fit that local borrow, so the bound has to say "for
+// every lifetime `'a`": `for<'a> Fn(&'a str) -> &'a str`.
+fn apply_to_each_line<F>(text: &str, f: F) -> Vec<String>
+where
+    F: for<'a> Fn(&'a str) -> &'a str,
+{
+    text.lines()
+        .map(|line| {
+            let owned = line.trim().to_lowercase(); // Local: lives for one iteration
+            f(&owned).to_string()
+        })
+        .collect()
+}
+
+// Writing `Fn(&str) -> &str` means exactly the same thing: elision in `Fn`
+// bounds produces the `for<'a>` for you.
+fn first_word_lengths(text: &str, first_word: impl Fn(&str) -> &str) -> Vec<usize> {
+    text.lines()
+        .map(|line| first_word(&line.to_uppercase()).len())
+        .collect()
+}
+
+// `for<'a>` can't be written by elision when the reference appears in a
+// *where clause on another type*. This sums any collection whose references
+// iterate over `&u32`: `Vec<u32>`, `[u32; N]`, `BTreeSet<u32>`...
+fn sum_by_ref<C>(collection: &C) -> u32
+where
+    for<'a> &'a C: IntoIterator<Item = &'a u32>,
+{
+    collection.into_iter().sum()
+}
+
+// Boxed closures use the same syntax.
+type Parser = Box<dyn for<'a> Fn(&'a str) -> Option<&'a str>>;
+
+fn first_word(s: &str) -> &str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+// -------------------------------------------------------------------------
+// `T: 'static`
+// -------------------------------------------------------------------------
+
+// `T: 'static` means "`T` holds no borrows that could expire", *not* "the
+// value lives forever". Owned types like `String` and `Vec<u8>` qualify; the
+// value itself can still be dropped at any time.
+fn spawn_and_describe<T: Debug + Send + 'static>(value: T) -> String {
+    // `thread::spawn` needs `'static`: the thread may outlive this function.
+    thread::spawn(move || format!("{:?}", value))
+        .join()
+        .unwrap()
+}
+
+// -------------------------------------------------------------------------
+// Variance
+// -------------------------------------------------------------------------
+
+// Accepting a longer lifetime where a shorter one is expected is always fine
+// for shared references: `&'a T` is *covariant* in `'a`.
+fn shorten<'a>(s: &'static str) -> &'a str {
+    s
+}
+
+// Writes through a `&mut`. Because the slot can be *written*, `&mut T` is
+// *invariant* in `T`: the caller's `&mut &'static str` can't be treated as a
+// `&mut &'a str`, or this function could store a short-lived borrow in it.
+fn overwrite<'a>(slot: &mut &'a str, value: &'a str) {
+    *slot = value;
+}
+
+// -------------------------------------------------------------------------
+// Lifetime Bounds on Generic Structs
+// -------------------------------------------------------------------------
+
+// A tokenizer borrowing its input. The tokens it returns borrow the *input*
+// (`'src`), not the tokenizer (`&mut self`), so they stay usable after the
+// tokenizer is gone.
+struct Tokenizer<'src> {
+    rest: &'src str,
+}
+
+impl<'src> Tokenizer<'src> {
+    fn new(input: &'src str) -> Self {
+        Tokenizer { rest: input }
+    }
+
+    fn next_token(&mut self) -> Option<&'src str> {
+        let trimmed = self.rest.trim_start();
+        if trimmed.is_empty() {
+            return None;
+        }
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let (token, rest) = trimmed.split_at(end);
+        self.rest = rest;
+        Some(token)
+    }
+}
+
+// A generic struct holding `&'a T` needs `T: 'a` (the referent must outlive
+// the reference). Since the 2018 edition the compiler infers that bound from
+// the field, so writing it out is optional.
+#[derive(Debug)]
+struct Labeled<'a, T: 'a + ?Sized> {
+    label: &'static str,
+    value: &'a T,
+}
+
+// `'long: 'short` reads "`'long` outlives `'short`". It lets a function
+// return either input as the shorter lifetime.
+fn pick<'short, 'long: 'short>(
+    fallback: &'short str,
+    preferred: Option<&'long str>,
+) -> &'short str {
+    preferred.unwrap_or(fallback)
+}
+
+// Trait objects carry a lifetime too. `Box<dyn Fn() -> usize>` is short for
+// `Box<dyn Fn() -> usize + 'static>`, so a closure that borrows a local needs
+// `+ '_` (or a named lifetime) to be boxed.
+fn counter_over<'a>(words: &'a [&str]) -> Box<dyn Fn(usize) -> usize + 'a> {
+    Box::new(move |min_len| words.iter().filter(|w| w.len() >= min_len).count())
+}
+
+fn main() {
+    println!("--- Advanced Lifetimes ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Higher-Ranked Trait Bounds: `for<'a>`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. `for<'a>` Bounds ---");
+
+    let text = "  Hello World\nRust Is Fun  \n";
+    let firsts = apply_to_each_line(text, first_word);
+    println!("First words: {:?}", firsts);
+    assert_eq!(firsts, ["hello", "rust"]);
+
+    // Closures work too, as long as they return something borrowed from the input.
+    let trimmed = apply_to_each_line("a-b\nc-d", |s| s.split('-').next_back().unwrap_or(s));
+    assert_eq!(trimmed, ["b", "d"]);
+    assert_eq!(first_word_lengths("one two\nthree", first_word), [3, 5]);
+
+    // Returning something not borrowed from the argument is fine too, as long
+    // as it lives at least as long: a `'static` literal outlives every `'a`.
+    assert_eq!(
+        apply_to_each_line("x\ny", |_| "constant"),
+        ["constant", "constant"]
+    );
+
+    assert_eq!(sum_by_ref(&vec![1, 2, 3]), 6);
+    assert_eq!(sum_by_ref(&[10, 20]), 30);
+    assert_eq!(sum_by_ref(&std::collections::BTreeSet::from([4, 5])), 9);
+
+    let parsers: Vec<Parser> = vec![
+        Box::new(|s| s.strip_prefix("--")),
+        Box::new(|s| s.strip_suffix(';')),
+    ];
+    let input = String::from("--verbose");
+    let results: Vec<_> = parsers.iter().map(|p| p(&input)).collect();
+    assert_eq!(results, [Some("verbose"), None]);
+
+    /*
+    // With a lifetime chosen by the *caller*, the local `owned` can't match it:
+    fn apply_named<'a, F: Fn(&'a str) -> &'a str>(f: F) -> String {
+        let owned = String::from("local");
+        f(&owned).to_string()
+        // error[E0597]: `owned` does not live long enough
+    }
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. `T: 'static` vs. `&'static T`
+    // -------------------------------------------------------------------------
+    // - `&'static T`: a reference valid for the rest of the program
+    //   (string literals, statics, `Box::leak`).
+    // - `T: 'static`: a *type* bound. The type contains no borrow shorter
+    //   than `'static`. Every owned type without references satisfies it.
+
+    println!("\n--- 2. `T: 'static` vs. `&'static T` ---");
+
+    let owned = String::from("owned data");
+    println!("{}", spawn_and_describe(owned)); // `String: 'static`, moved in
+    println!("{}", spawn_and_describe(vec![1, 2, 3]));
+    println!("{}", spawn_and_describe("a literal")); // `&'static str: 'static`
+
+    // `T: 'static` doesn't keep the value alive: it's dropped like any other.
+    let temporary = String::from("dropped right away");
+    fn takes_static<T: 'static>(value: T) -> usize {
+        std::mem::size_of_val(&value)
+    } // `value` dropped here
+    assert_eq!(takes_static(temporary), std::mem::size_of::<String>());
+
+    /*
+    let local = String::from("local");
+    spawn_and_describe(&local);
+    // error[E0597]: `local` does not live long enough
+    // (`&'a String` is only `'static` if `'a` is)
+    */
+
+    // A `&'static` can be made at runtime by leaking: the memory is never
+    // freed. Fine for data that's needed until exit anyway (e.g. config).
+    let leaked: &'static str = Box::leak(String::from("leaked").into_boxed_str());
+    assert_eq!(spawn_and_describe(leaked), "\"leaked\"");
+
+    // -------------------------------------------------------------------------
+    // 3. Variance: Covariant `&T`, Invariant `&mut T`
+    // -------------------------------------------------------------------------
+    // Variance says when a type with a longer lifetime may be used where a
+    // shorter one is expected:
+    // - `&'a T`, `Box<T>`, `Vec<T>`: covariant, a longer lifetime can shrink
+    // - `&'a mut T`, `Cell<T>`, `RefCell<T>`: invariant in `T`, no change allowed
+    // - `fn(T)`: contravariant in `T` (rarely matters in practice)
+
+    println!("\n--- 3. Variance ---");
+
+    let local = String::from("short-lived");
+    let mut words: Vec<&str> = vec![shorten("static"), &local]; // Both become the shorter lifetime
+    words.push("another literal");
+    assert_eq!(words.len(), 3);
+
+    // Same lifetime on both sides: fine.
+    let mut current: &str = "initial";
+    overwrite(&mut current, &local);
+    assert_eq!(current, "short-lived");
+
+    /*
+    let mut forever: &'static str = "initial";
+    {
+        let short = String::from("short");
+        overwrite(&mut forever, &short);
+        // error[E0597]: `short` does not live long enough
+    }
+    println!("{}", forever); // Would read freed memory if the call were allowed
+    */
+
+    // The covariant version of the same idea compiles, because nothing is
+    // written through the outer reference:
+    let forever: &'static str = "still static";
+    let view: &&str = &forever; // `&&'static str` shrinks to `&&'a str`
+    assert_eq!(*view, "still static");
+
+    // -------------------------------------------------------------------------
+    // 4. Lifetime Bounds on Generic Structs and Trait Objects
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Bounds on Structs and Trait Objects ---");
+
+    let source = String::from("let x = 42 ;");
+    let tokens: Vec<&str> = {
+        let mut tokenizer = Tokenizer::new(&source);
+        std::iter::from_fn(|| tokenizer.next_token()).collect()
+    }; // `tokenizer` is gone; the tokens borrow `source`, so they're still valid
+    println!("Tokens: {:?}", tokens);
+    assert_eq!(tokens, ["let", "x", "=", "42", ";"]);
+
+    let numbers = [3, 1, 2];
+    let labeled = Labeled {
+        label: "numbers",
+        value: &numbers[..],
+    };
+    println!("{:?}", labeled);
+    assert_eq!(labeled.value.len(), 3);
+    let labeled_str = Labeled {
+        label: "text",
+        value: source.as_str(),
+    };
+    assert_eq!((labeled_str.label, labeled_str.value.len()), ("text", 12));
+
+    assert_eq!(pick(&source, Some("preferred")), "preferred");
+    assert_eq!(pick(&source, None), "let x = 42 ;");
+
+    let vocabulary = ["a", "tree", "of", "words"];
+    let count_at_least = counter_over(&vocabulary);
+    assert_eq!(count_at_least(2), 3);
+    assert_eq!(count_at_least(5), 1);
+
+    /*
+    fn counter_static(words: &[&str]) -> Box<dyn Fn(usize) -> usize> {
+        Box::new(move |n| words.iter().filter(|w| w.len() >= n).count())
+        // error: lifetime may not live long enough
+        // (the box defaults to `+ 'static`, but the closure borrows `words`)
+    }
+    */
+
+    println!("\n--- End of Advanced Lifetimes Examples ---");
+}
@@ -0,0 +1,346 @@
+// This file covers `PhantomData` and the typestate pattern: encoding the state
+// of an object in its *type*, so that calling a method in the wrong state is
+// a compile error instead of a runtime check. We build a
+// `Connection<Closed>` -> `Connection<Open>` -> `Connection<Authenticated>`
+// API from zero-sized marker types, then use the same trick for typed ids
+// that can't be mixed up.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+// -------------------------------------------------------------------------
+// Marker Types
+// -------------------------------------------------------------------------
+// Structs with no fields are *zero-sized*: they take no memory and exist only
+// for the type checker. Each one names a state of the connection.
+
+struct Closed;
+struct Open;
+struct Authenticated;
+
+// A trait shared by the states, so generic code can print the state's name.
+// Only these three types implement it (see 20-api-design-sealed-traits.rs
+// for how to stop other crates from adding more).
+trait ConnectionState {
+    const NAME: &'static str;
+}
+
+impl ConnectionState for Closed {
+    const NAME: &'static str = "closed";
+}
+
+impl ConnectionState for Open {
+    const NAME: &'static str = "open";
+}
+
+impl ConnectionState for Authenticated {
+    const NAME: &'static str = "authenticated";
+}
+
+// -------------------------------------------------------------------------
+// The Typestate Connection
+// -------------------------------------------------------------------------
+// `State` isn't stored in any field, and Rust rejects unused type parameters
+// (E0392). `PhantomData<State>` is a zero-sized field that "uses" it.
+
+struct Connection<State> {
+    host: String,
+    log: Vec<String>,
+    _state: PhantomData<State>,
+}
+
+#[derive(Debug, PartialEq)]
+enum AuthError {
+    EmptyUser,
+    WrongPassword,
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthError::EmptyUser => write!(f, "user name is empty"),
+            AuthError::WrongPassword => write!(f, "wrong password"),
+        }
+    }
+}
+
+// Methods available in *every* state.
+impl<State: ConnectionState> Connection<State> {
+    fn host(&self) -> &str {
+        &self.host
+    }
+
+    fn state(&self) -> &'static str {
+        State::NAME
+    }
+
+    fn log(&self) -> &[String] {
+        &self.log
+    }
+
+    // Moves the fields into a connection of another state. Private: only the
+    // transition methods below decide which changes are allowed.
+    fn transition<Next>(mut self, event: &str) -> Connection<Next> {
+        self.log.push(event.to_string());
+        Connection {
+            host: self.host,
+            log: self.log,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl Connection<Closed> {
+    // The only constructor: every connection starts closed.
+    fn new(host: &str) -> Self {
+        Connection {
+            host: host.to_string(),
+            log: Vec::new(),
+            _state: PhantomData,
+        }
+    }
+
+    // Takes `self` by value: the closed connection is consumed, so the old
+    // handle can't be used after opening.
+    fn open(self) -> Connection<Open> {
+        let event = format!("open {}", self.host);
+        self.transition(&event)
+    }
+}
+
+impl Connection<Open> {
+    // A transition that can fail returns the original state on error, so the
+    // caller still owns a usable connection.
+    fn authenticate(
+        self,
+        user: &str,
+        password: &str,
+    ) -> Result<Connection<Authenticated>, (Connection<Open>, AuthError)> {
+        if user.is_empty() {
+            return Err((self, AuthError::EmptyUser));
+        }
+        if password != "hunter2" {
+            let event = format!("auth failed for {}", user);
+            return Err((self.transition(&event), AuthError::WrongPassword));
+        }
+        let event = format!("auth {}", user);
+        Ok(self.transition(&event))
+    }
+
+    fn close(self) -> Connection<Closed> {
+        self.transition("close")
+    }
+}
+
+impl Connection<Authenticated> {
+    // Only an authenticated connection can send.
+    fn send(&mut self, message: &str) -> usize {
+        self.log.push(format!("send {:?}", message));
+        message.len()
+    }
+
+    fn close(self) -> Connection<Closed> {
+        self.transition("close")
+    }
+}
+
+impl<State: ConnectionState> fmt::Debug for Connection<State> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Connection<{}>({})", State::NAME, self.host)
+    }
+}
+
+// -------------------------------------------------------------------------
+// Typed Ids
+// -------------------------------------------------------------------------
+// The same idea for data: an `Id<User>` and an `Id<Order>` are both a `u32`
+// at runtime, but different types at compile time.
+
+struct User;
+struct Order;
+
+// `PhantomData<fn() -> T>` instead of `PhantomData<T>`: the id doesn't own a
+// `T`, so it shouldn't inherit `T`'s `Send`/`Sync` or drop behavior.
+struct Id<T> {
+    value: u32,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    fn new(value: u32) -> Self {
+        Id {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// Derives would require `T: Clone`, `T: PartialEq`..., which the markers
+// don't implement, so these impls are written by hand without that bound.
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Id({})", self.value)
+    }
+}
+
+fn cancel_order(order: Id<Order>, by: Id<User>) -> String {
+    format!("order {} cancelled by user {}", order.value, by.value)
+}
+
+fn main() {
+    println!("--- PhantomData and the Typestate Pattern ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Zero-Sized Markers
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Zero-Sized Markers ---");
+
+    assert_eq!(std::mem::size_of::<Open>(), 0);
+    assert_eq!(std::mem::size_of::<PhantomData<String>>(), 0);
+    // The state costs nothing at runtime: every `Connection<_>` has the same size.
+    assert_eq!(
+        std::mem::size_of::<Connection<Closed>>(),
+        std::mem::size_of::<Connection<Authenticated>>()
+    );
+    println!(
+        "size_of::<Connection<_>>() = {} bytes in every state",
+        std::mem::size_of::<Connection<Open>>()
+    );
+
+    /*
+    struct Broken<State> {
+        host: String,
+    }
+    // error[E0392]: type parameter `State` is never used
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. Walking Through the States
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. Walking Through the States ---");
+
+    let conn = Connection::new("db.example.com");
+    println!("{:?} is {}", conn, conn.state());
+    assert_eq!(conn.state(), "closed");
+
+    let conn = conn.open(); // Shadowing: same name, new type
+    assert_eq!(conn.state(), "open");
+
+    // A failed login hands the open connection back.
+    let conn = match conn.authenticate("ada", "wrong") {
+        Ok(_) => unreachable!("the password is wrong"),
+        Err((conn, err)) => {
+            println!("Login failed: {}", err);
+            assert_eq!(err, AuthError::WrongPassword);
+            conn
+        }
+    };
+    let (conn, err) = conn.authenticate("", "hunter2").unwrap_err();
+    assert_eq!(err, AuthError::EmptyUser);
+
+    let mut conn = match conn.authenticate("ada", "hunter2") {
+        Ok(conn) => conn,
+        Err((_, err)) => panic!("login failed: {}", err),
+    };
+    assert_eq!(conn.send("SELECT 1"), 8);
+    conn.send("SELECT 2");
+    println!("{:?} is {}", conn, conn.state());
+
+    let conn = conn.close();
+    assert_eq!(conn.state(), "closed");
+    assert_eq!(conn.host(), "db.example.com");
+    println!("Log: {:#?}", conn.log());
+    assert_eq!(
+        conn.log(),
+        [
+            "open db.example.com",
+            "auth failed for ada",
+            "auth ada",
+            "send \"SELECT 1\"",
+            "send \"SELECT 2\"",
+            "close",
+        ]
+    );
+
+    // -------------------------------------------------------------------------
+    // 3. What the Compiler Rejects
+    // -------------------------------------------------------------------------
+    // Each of these is a protocol mistake that a runtime-checked API would
+    // only catch when the code runs (if at all).
+
+    println!("\n--- 3. Mistakes Caught at Compile Time ---");
+
+    /*
+    let conn = Connection::new("db").open();
+    conn.send("too early");
+    // error[E0599]: no method named `send` found for struct `Connection<S>` in the current scope
+    */
+
+    /*
+    let closed = Connection::new("db");
+    let open = closed.open();
+    closed.open(); // Reusing the old state
+    // error[E0382]: use of moved value: `closed`
+    */
+
+    /*
+    let conn: Connection<Authenticated> = Connection { host: String::new(), log: Vec::new(), _state: PhantomData };
+    // Compiles here, in the same module. From another module, the private
+    // fields make this impossible, so `new` + transitions are the only way in
+    // (see 33-modules-across-files.rs for field privacy).
+    */
+
+    /*
+    Connection::new("db").close();
+    // error[E0599]: no method named `close` found for struct `Connection<Closed>` in the current scope
+    */
+
+    println!("(see the commented-out examples in this section)");
+
+    // Generic code can still accept any state through the shared trait.
+    fn describe<S: ConnectionState>(conn: &Connection<S>) -> String {
+        format!("{} ({} events)", conn.state(), conn.log().len())
+    }
+    let fresh = Connection::new("cache.local");
+    assert_eq!(describe(&fresh), "closed (0 events)");
+    let opened = fresh.open();
+    assert_eq!(describe(&opened), "open (1 events)");
+    // Closing is allowed from `Open` too, without ever authenticating.
+    assert_eq!(describe(&opened.close()), "closed (2 events)");
+
+    // -------------------------------------------------------------------------
+    // 4. Typed Ids
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Typed Ids ---");
+
+    let user: Id<User> = Id::new(7);
+    let order: Id<Order> = Id::new(1001);
+    println!("{}", cancel_order(order, user));
+    assert_eq!(cancel_order(order, user), "order 1001 cancelled by user 7");
+    assert_eq!(user, Id::new(7));
+    assert_eq!(std::mem::size_of::<Id<Order>>(), std::mem::size_of::<u32>());
+
+    /*
+    cancel_order(user, order); // Arguments swapped
+    // error[E0308]: arguments to this function are incorrect
+    */
+
+    println!("\n--- End of PhantomData and Typestate Examples ---");
+}
@@ -0,0 +1,334 @@
+// This file expands on the `MyVec` newtype from the traits lesson. A newtype
+// is a tuple struct with one field: `struct Meters(f64);`. It costs nothing at
+// runtime, but gives the value its own type, so the compiler can stop you from
+// adding meters to seconds, let you implement foreign traits (the orphan
+// rule), and protect invariants behind a private field. We cover unit types,
+// deriving and forwarding trait impls, `#[repr(transparent)]`, and when a
+// wrapper should implement `Deref` instead of offering explicit accessors.
+
+use std::fmt;
+use std::ops::{Add, Deref, Div, Mul, Sub};
+
+// -------------------------------------------------------------------------
+// Newtypes for Units
+// -------------------------------------------------------------------------
+// Derives forward to the inner `f64`: `PartialEq` compares the fields,
+// `Debug` prints `Meters(1.5)`. Arithmetic has to be written out, which is
+// the point: only the operations that make sense for the unit exist.
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+struct Seconds(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct MetersPerSecond(f64);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0 + other.0)
+    }
+}
+
+impl Sub for Meters {
+    type Output = Meters;
+
+    fn sub(self, other: Meters) -> Meters {
+        Meters(self.0 - other.0)
+    }
+}
+
+impl Add for Seconds {
+    type Output = Seconds;
+
+    fn add(self, other: Seconds) -> Seconds {
+        Seconds(self.0 + other.0)
+    }
+}
+
+// Scaling by a plain number keeps the unit...
+impl Mul<f64> for Meters {
+    type Output = Meters;
+
+    fn mul(self, factor: f64) -> Meters {
+        Meters(self.0 * factor)
+    }
+}
+
+// ...and dividing two units produces a third.
+impl Div<Seconds> for Meters {
+    type Output = MetersPerSecond;
+
+    fn div(self, time: Seconds) -> MetersPerSecond {
+        MetersPerSecond(self.0 / time.0)
+    }
+}
+
+// `Sum` lets `.sum()` work on an iterator of `Meters`.
+impl std::iter::Sum for Meters {
+    fn sum<I: Iterator<Item = Meters>>(iter: I) -> Meters {
+        iter.fold(Meters::default(), Add::add)
+    }
+}
+
+impl fmt::Display for Meters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Forward the caller's precision (`{:.1}`) to the inner `f64`.
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, " m")
+    }
+}
+
+impl fmt::Display for MetersPerSecond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, " m/s")
+    }
+}
+
+// -------------------------------------------------------------------------
+// Newtypes That Guard an Invariant
+// -------------------------------------------------------------------------
+// The field is private (it would be, from another module), so the only way
+// to get a `Username` is through `TryFrom`, and every `Username` is valid.
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Username(String);
+
+impl TryFrom<&str> for Username {
+    type Error = String;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        let valid_chars = name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if (3..=16).contains(&name.len()) && valid_chars {
+            Ok(Username(name.to_string()))
+        } else {
+            Err(format!("invalid username: {:?}", name))
+        }
+    }
+}
+
+// Explicit, read-only accessors: callers get a `&str`, but can't mutate the
+// `String` and break the rules checked above.
+impl Username {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl AsRef<str> for Username {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Username {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "@{}", self.0)
+    }
+}
+
+// -------------------------------------------------------------------------
+// `Deref` for Collection Wrappers
+// -------------------------------------------------------------------------
+// A vector that is always sorted. `Deref<Target = [i32]>` hands out every
+// *read-only* slice method (`len`, `iter`, `binary_search`, `first`...) for
+// free. There is deliberately no `DerefMut`: `&mut [i32]` would allow
+// `reverse()` or `swap()` and break the ordering.
+
+#[derive(Debug, Default)]
+struct SortedVec(Vec<i32>);
+
+impl SortedVec {
+    fn insert(&mut self, value: i32) {
+        let index = self.0.partition_point(|&x| x < value);
+        self.0.insert(index, value);
+    }
+}
+
+impl Deref for SortedVec {
+    type Target = [i32];
+
+    fn deref(&self) -> &[i32] {
+        &self.0
+    }
+}
+
+impl FromIterator<i32> for SortedVec {
+    fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+        let mut values: Vec<i32> = iter.into_iter().collect();
+        values.sort();
+        SortedVec(values)
+    }
+}
+
+// `for x in &sorted` needs `IntoIterator` for the reference; `Deref` alone
+// doesn't provide it.
+impl<'a> IntoIterator for &'a SortedVec {
+    type Item = &'a i32;
+    type IntoIter = std::slice::Iter<'a, i32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+// -------------------------------------------------------------------------
+// `#[repr(transparent)]`
+// -------------------------------------------------------------------------
+// A plain newtype *usually* has the same layout as its field, but Rust
+// doesn't promise it. `#[repr(transparent)]` does: same size, alignment and
+// calling convention as the single field. That makes it safe to pass across
+// FFI in place of the field, and to reinterpret a `&[u64]` as `&[UserId]`.
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct UserId(u64);
+
+fn ids_from_raw(raw: &[u64]) -> &[UserId] {
+    // SAFETY: `UserId` is `#[repr(transparent)]` over `u64`, so the two slices
+    // have identical layout, and every `u64` is a valid `UserId`.
+    unsafe { std::slice::from_raw_parts(raw.as_ptr().cast::<UserId>(), raw.len()) }
+}
+
+fn main() {
+    println!("--- Newtype and Wrapper Patterns ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Units That Can't Be Mixed Up
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Units ---");
+
+    let legs = [Meters(120.0), Meters(80.5), Meters(300.0)];
+    let distance: Meters = legs.iter().copied().sum();
+    let time = Seconds(50.0) + Seconds(10.0);
+    let speed = distance / time;
+    println!("{:.1} in {:?} = {:.2}", distance, time, speed);
+    assert_eq!(distance, Meters(500.5));
+    assert_eq!(distance * 2.0, Meters(1001.0));
+    assert_eq!(Meters(10.0) - Meters(4.0), Meters(6.0));
+    assert!(speed > MetersPerSecond(8.0));
+    assert_eq!(format!("{:.1}", Meters(2.25)), "2.2 m");
+
+    // No runtime cost: the wrapper is exactly the size of an `f64`.
+    assert_eq!(std::mem::size_of::<Meters>(), std::mem::size_of::<f64>());
+
+    /*
+    let nonsense = Meters(1.0) + Seconds(2.0);
+    // error[E0308]: mismatched types
+    */
+
+    /*
+    fn sprint(distance: Meters, time: Seconds) -> MetersPerSecond { distance / time }
+    sprint(Seconds(10.0), Meters(100.0)); // Arguments swapped
+    // error[E0308]: arguments to this function are incorrect
+    // (with bare `f64` parameters this would compile and be silently wrong)
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. Deriving and Forwarding Trait Impls
+    // -------------------------------------------------------------------------
+    // - `#[derive]` forwards `Debug`, `Clone`, `PartialEq`, `Hash`... to the field.
+    // - Operators (`Add`, `Mul`), `Display`, `Sum` and the like must be written
+    //   by hand, usually as a one-line delegation to `self.0`.
+    // - Implementing a foreign trait for a foreign type needs a newtype because
+    //   of the orphan rule (see the `MyVec` example in 12-traits.rs).
+
+    println!("\n--- 2. Forwarding Impls ---");
+
+    let mut by_speed = [
+        MetersPerSecond(3.5),
+        MetersPerSecond(1.25),
+        MetersPerSecond(2.0),
+    ];
+    // `PartialOrd` was derived, so the `f64` comparison carries over.
+    by_speed.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(by_speed[0], MetersPerSecond(1.25));
+    println!("Slowest: {}", by_speed[0]);
+
+    // `Default` derived from `f64::default()`.
+    assert_eq!(Seconds::default(), Seconds(0.0));
+
+    // -------------------------------------------------------------------------
+    // 3. Invariants and Explicit Accessors
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. Invariants ---");
+
+    let user = Username::try_from("ferris_42").unwrap();
+    println!("{} ({} chars)", user, user.as_str().len());
+    assert_eq!(user.as_ref(), "ferris_42");
+    assert!(Username::try_from("No Spaces").is_err());
+    assert!(Username::try_from("ab").is_err());
+
+    // Any `AsRef<str>` function accepts it, without exposing a `&mut String`.
+    fn shout(s: impl AsRef<str>) -> String {
+        s.as_ref().to_uppercase()
+    }
+    assert_eq!(shout(&user), "FERRIS_42");
+    assert_eq!(user.into_inner(), "ferris_42"); // Unwrap when you're done with the guarantee
+
+    // -------------------------------------------------------------------------
+    // 4. `Deref` vs. Accessors
+    // -------------------------------------------------------------------------
+    // Implement `Deref` when the wrapper *is* a kind of its target and every
+    // method of the target is fine to call (smart pointers, collection
+    // wrappers exposing a slice). Prefer named accessors when:
+    // - the wrapper adds meaning (`Meters` is not "an `f64` with extras"),
+    // - some target methods would break an invariant (`DerefMut` on `SortedVec`),
+    // - you want the conversion to be visible at the call site.
+    // `Deref` also isn't inheritance: the wrapper still doesn't implement the
+    // target's traits, and generic code bounded on them won't accept it.
+
+    println!("\n--- 4. `Deref` ---");
+
+    let mut sorted: SortedVec = [5, 1, 4].into_iter().collect();
+    sorted.insert(3);
+    sorted.insert(0);
+    println!("{:?}", sorted);
+    // All of these are slice methods, reached through `Deref`:
+    assert_eq!(sorted.len(), 5);
+    assert_eq!(sorted.first(), Some(&0));
+    assert_eq!(sorted.binary_search(&4), Ok(3));
+    assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+    // Deref coercion: `&SortedVec` is accepted where `&[i32]` is expected.
+    fn total(values: &[i32]) -> i32 {
+        values.iter().sum()
+    }
+    assert_eq!(total(&sorted), 13);
+    let doubled: Vec<i32> = (&sorted).into_iter().map(|x| x * 2).collect();
+    assert_eq!(doubled, [0, 2, 6, 8, 10]);
+
+    /*
+    sorted.reverse();
+    // error[E0596]: cannot borrow data in dereference of `SortedVec` as mutable
+    */
+
+    // -------------------------------------------------------------------------
+    // 5. `#[repr(transparent)]`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 5. `#[repr(transparent)]` ---");
+
+    assert_eq!(std::mem::size_of::<UserId>(), std::mem::size_of::<u64>());
+    assert_eq!(std::mem::align_of::<UserId>(), std::mem::align_of::<u64>());
+
+    let raw = [42u64, 7, 19];
+    let ids = ids_from_raw(&raw); // No copy: the same memory, viewed as `UserId`s
+    println!("{:?}", ids);
+    assert_eq!(ids.iter().max(), Some(&UserId(42)));
+    assert!(std::ptr::eq(ids.as_ptr().cast::<u64>(), raw.as_ptr()));
+    // For the FFI side of this, see 36-ffi-with-build-rs.rs.
+
+    println!("\n--- End of Newtype and Wrapper Examples ---");
+}
@@ -0,0 +1,244 @@
+// This file revisits the handwritten `MyError` from the error handling lesson
+// using two popular crates:
+// - `thiserror` derives `Display`, `Error` and `From` for your own error
+//   types. It's the usual choice in *libraries*, whose callers need to match
+//   on specific variants.
+// - `anyhow` gives *applications* one catch-all error type, plus `context`
+//   to say what was being attempted when something failed.
+// Both are optional dependencies behind the `ecosystem` feature:
+//     cargo run --features ecosystem --bin 48-thiserror-anyhow
+
+use std::fs;
+use std::io;
+use std::num::ParseIntError;
+
+use anyhow::{Context, bail, ensure};
+use thiserror::Error;
+
+// -------------------------------------------------------------------------
+// Library Side: `thiserror`
+// -------------------------------------------------------------------------
+// The same four variants as the handwritten `MyError`. Each `#[error]`
+// attribute becomes the `Display` text, `#[from]` writes the `From` impl that
+// `?` uses, and also reports the wrapped error as the `source()`.
+
+#[derive(Debug, Error)]
+enum MyError {
+    #[error("not found")]
+    NotFound,
+    #[error("permission denied")]
+    PermissionDenied,
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+    #[error("I/O error")]
+    Io(#[from] io::Error),
+}
+
+// Same logic as `do_something_risky` in the error handling lesson, writing
+// into the system temp directory instead of the current one.
+fn do_something_risky(value: i32) -> Result<String, MyError> {
+    if value == 0 {
+        Err(MyError::NotFound)
+    } else if value < 0 {
+        Err(MyError::PermissionDenied)
+    } else if value > 100 {
+        Err(MyError::InvalidInput(format!(
+            "Value {} is too large.",
+            value
+        )))
+    } else {
+        let path = std::env::temp_dir().join(format!("data_{}.txt", value));
+        fs::write(&path, format!("Some data for {}", value))?; // `io::Error` -> `MyError::Io`
+        let _ = fs::remove_file(&path);
+        Ok(format!("Operation successful with value: {}", value))
+    }
+}
+
+// Fields can be named, and the message can use them. `#[source]` marks the
+// cause without generating a `From` impl (useful when the same source type
+// appears in several variants).
+#[derive(Debug, Error)]
+enum SettingError {
+    #[error("missing key `{0}`")]
+    Missing(String),
+    #[error("`{key}` must be a number, got {value:?}")]
+    NotANumber {
+        key: String,
+        value: String,
+        #[source]
+        cause: ParseIntError,
+    },
+}
+
+fn parse_setting(line: &str) -> Result<(String, u32), SettingError> {
+    let (key, value) = line
+        .split_once('=')
+        .ok_or_else(|| SettingError::Missing(line.trim().to_string()))?;
+    let (key, value) = (key.trim(), value.trim());
+    let number = value.parse().map_err(|cause| SettingError::NotANumber {
+        key: key.to_string(),
+        value: value.to_string(),
+        cause,
+    })?;
+    Ok((key.to_string(), number))
+}
+
+// -------------------------------------------------------------------------
+// Application Side: `anyhow`
+// -------------------------------------------------------------------------
+// `anyhow::Result<T>` is `Result<T, anyhow::Error>`. Any error type that
+// implements `std::error::Error` converts into it with `?`, so one function
+// can mix errors from many libraries without a wrapper enum.
+
+#[derive(Debug)]
+struct Settings {
+    port: u32,
+    workers: u32,
+}
+
+fn load_settings(text: &str) -> anyhow::Result<Settings> {
+    let mut port = None;
+    let mut workers = None;
+    for (number, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        // `with_context` adds a message on top of the original error, lazily.
+        let (key, value) = parse_setting(line).with_context(|| format!("line {}", number + 1))?;
+        match key.as_str() {
+            "port" => port = Some(value),
+            "workers" => workers = Some(value),
+            other => bail!("unknown setting `{}`", other), // Early return with a new error
+        }
+    }
+    let port = port.context("no `port` setting")?; // `context` also works on `Option`
+    ensure!(port >= 1024, "port {} is reserved", port); // `assert!` that returns an error
+    Ok(Settings {
+        port,
+        workers: workers.unwrap_or(1),
+    })
+}
+
+fn load_settings_file(path: &str) -> anyhow::Result<Settings> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path))?;
+    load_settings(&text).with_context(|| format!("parsing {}", path))
+}
+
+fn main() {
+    println!("--- thiserror and anyhow ---");
+
+    // -------------------------------------------------------------------------
+    // 1. `thiserror`: The Same `MyError`, Derived
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. `thiserror` ---");
+
+    for value in [0, -5, 150, 50] {
+        match do_something_risky(value) {
+            Ok(msg) => println!("{}", msg),
+            Err(e) => println!("Risky operation failed: {}", e), // `Display` now comes from `#[error]`
+        }
+    }
+    assert_eq!(do_something_risky(0).unwrap_err().to_string(), "not found");
+    assert_eq!(
+        do_something_risky(150).unwrap_err().to_string(),
+        "invalid input: Value 150 is too large."
+    );
+
+    // `#[from]` generated this conversion:
+    let io_error = io::Error::other("disk full");
+    let wrapped = MyError::from(io_error);
+    assert!(matches!(wrapped, MyError::Io(_)));
+    // ...and `source()` points at the wrapped error.
+    let source = std::error::Error::source(&wrapped).map(|s| s.to_string());
+    assert_eq!(source.as_deref(), Some("disk full"));
+
+    // Callers of a library can still match on exactly what went wrong.
+    match parse_setting("port = eighty") {
+        Err(SettingError::NotANumber { key, value, .. }) => {
+            println!("Bad number for {}: {:?}", key, value);
+            assert_eq!((key.as_str(), value.as_str()), ("port", "eighty"));
+        }
+        other => panic!("expected NotANumber, got {:?}", other),
+    }
+    assert_eq!(parse_setting("workers = 4").unwrap(), ("workers".into(), 4));
+
+    // -------------------------------------------------------------------------
+    // 2. `anyhow`: Context for Application Code
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. `anyhow` ---");
+
+    let settings = load_settings("port = 8080\nworkers = 4\n").unwrap();
+    assert_eq!((settings.port, settings.workers), (8080, 4));
+    println!(
+        "Loaded port {} with {} workers",
+        settings.port, settings.workers
+    );
+
+    let err = load_settings("port = 8080\nworkers = many\n").unwrap_err();
+    // `{}` shows only the outermost message, `{:#}` the whole chain on one
+    // line. `{:?}` (what `main` prints when it returns `Err`) lists each cause.
+    println!("{{}}:   {}", err);
+    println!("{{:#}}: {:#}", err);
+    assert_eq!(err.to_string(), "line 2");
+    assert_eq!(
+        format!("{:#}", err),
+        "line 2: `workers` must be a number, got \"many\": invalid digit found in string"
+    );
+
+    // The original typed error is still inside and can be recovered.
+    let setting_error = err.downcast_ref::<SettingError>();
+    assert!(matches!(
+        setting_error,
+        Some(SettingError::NotANumber { .. })
+    ));
+
+    let messages: Vec<String> = err.chain().map(|e| e.to_string()).collect();
+    assert_eq!(messages.len(), 3);
+
+    assert_eq!(
+        load_settings("timeout = 5").unwrap_err().to_string(),
+        "unknown setting `timeout`"
+    );
+    assert_eq!(
+        load_settings("workers = 2").unwrap_err().to_string(),
+        "no `port` setting"
+    );
+    assert_eq!(
+        load_settings("port = 80").unwrap_err().to_string(),
+        "port 80 is reserved"
+    );
+
+    let missing = load_settings_file("no/such/settings.conf").unwrap_err();
+    println!("{:#}", missing);
+    assert!(
+        missing
+            .to_string()
+            .starts_with("reading no/such/settings.conf")
+    );
+    assert!(missing.downcast_ref::<io::Error>().is_some());
+
+    // -------------------------------------------------------------------------
+    // 3. Library vs. Application Errors
+    // -------------------------------------------------------------------------
+    // Library (code others call):
+    // - Return a concrete error enum (`thiserror`), so callers can `match` on
+    //   variants and decide how to recover.
+    // - Keep causes reachable through `source()` instead of flattening them
+    //   into strings.
+    // - Don't expose `anyhow::Error` in a public API: it hides which errors
+    //   are possible.
+    //
+    // Application (the binary at the top):
+    // - Mostly reports errors rather than recovering from them, so one
+    //   `anyhow::Error` type is enough.
+    // - Add `context` at each layer ("reading config.toml", "line 2") so the
+    //   final message explains *what* failed, not just *why*.
+    // - `fn main() -> anyhow::Result<()>` prints the full chain on failure.
+    //
+    // Both crates only automate what the error handling lesson does by hand:
+    // `Display`, `Error::source` and `From` impls, and `Box<dyn Error>`.
+
+    println!("\n--- End of thiserror and anyhow Examples ---");
+}
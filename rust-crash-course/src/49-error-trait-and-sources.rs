@@ -0,0 +1,259 @@
+// This file looks at the `std::error::Error` trait itself: what `Display`
+// and `source()` are for, how errors wrap each other into a *chain* (here a
+// config error wrapping an I/O error wrapping a parse error), how to walk and
+// print that chain generically, how to find a specific error inside it with
+// `downcast_ref`, and how to capture a `Backtrace` when an error is created.
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+
+// -------------------------------------------------------------------------
+// The Error Hierarchy
+// -------------------------------------------------------------------------
+// `Error` requires `Debug + Display`. `Display` is the message for *this*
+// layer only; the underlying cause is returned by `source()`, not pasted
+// into the message, so a reporter can decide how much to show.
+
+// Innermost: a line of the config file isn't `key = number`.
+#[derive(Debug)]
+struct ParseError {
+    line: usize,
+    cause: ParseIntError,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: value is not a number", self.line)
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.cause)
+    }
+}
+
+// Outermost: loading the config failed. It carries the I/O error (which may
+// itself carry a `ParseError`) and a backtrace of where it was created.
+#[derive(Debug)]
+enum ConfigError {
+    Read {
+        path: String,
+        source: io::Error,
+        backtrace: Backtrace,
+    },
+    MissingKey(&'static str),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, .. } => write!(f, "could not load config from {}", path),
+            ConfigError::MissingKey(key) => write!(f, "config has no `{}` key", key),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Read { source, .. } => Some(source),
+            ConfigError::MissingKey(_) => None, // Nothing underneath
+        }
+    }
+}
+
+impl ConfigError {
+    fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            ConfigError::Read { backtrace, .. } => Some(backtrace),
+            ConfigError::MissingKey(_) => None,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------
+// Producing the Chain
+// -------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    port: u16,
+}
+
+// Reads `key = value` lines. A bad value is reported as an `io::Error` of
+// kind `InvalidData`, the way std's readers report undecodable input, with
+// the `ParseError` stored inside it.
+fn read_pairs(reader: impl BufRead) -> io::Result<Vec<(String, u16)>> {
+    let mut pairs = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().parse().map_err(|cause| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                ParseError {
+                    line: index + 1,
+                    cause,
+                },
+            )
+        })?;
+        pairs.push((key.trim().to_string(), value));
+    }
+    Ok(pairs)
+}
+
+fn load_config(path: &str, reader: impl BufRead) -> Result<Config, ConfigError> {
+    let pairs = read_pairs(reader).map_err(|source| ConfigError::Read {
+        path: path.to_string(),
+        source,
+        // Cheap when disabled: only records frames if RUST_BACKTRACE or
+        // RUST_LIB_BACKTRACE is set.
+        backtrace: Backtrace::capture(),
+    })?;
+    let port = pairs
+        .iter()
+        .find(|(key, _)| key == "port")
+        .map(|&(_, port)| port)
+        .ok_or(ConfigError::MissingKey("port"))?;
+    Ok(Config { port })
+}
+
+// -------------------------------------------------------------------------
+// Walking the Chain Generically
+// -------------------------------------------------------------------------
+// These only know about `dyn Error`, so they work for any error type.
+
+fn chain<'a>(err: &'a (dyn Error + 'static)) -> impl Iterator<Item = &'a (dyn Error + 'static)> {
+    std::iter::successors(Some(err), |&e| e.source())
+}
+
+fn report(err: &(dyn Error + 'static)) -> String {
+    let mut out = format!("Error: {}", err);
+    let causes: Vec<_> = chain(err).skip(1).collect();
+    if !causes.is_empty() {
+        out.push_str("\n\nCaused by:");
+        for (i, cause) in causes.iter().enumerate() {
+            out.push_str(&format!("\n    {}: {}", i, cause));
+        }
+    }
+    out
+}
+
+// The first error of type `E` anywhere in the chain.
+fn find_in_chain<'a, E: Error + 'static>(err: &'a (dyn Error + 'static)) -> Option<&'a E> {
+    chain(err).find_map(|e| e.downcast_ref::<E>())
+}
+
+fn main() {
+    println!("--- The Error Trait, Source Chains and Backtraces ---");
+
+    // -------------------------------------------------------------------------
+    // 1. `Display` and `source()`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. `Display` and `source()` ---");
+
+    let good = load_config("app.conf", "name = 1\nport = 8080\n".as_bytes());
+    assert_eq!(good.unwrap(), Config { port: 8080 });
+
+    let missing = load_config("app.conf", "name = 1\n".as_bytes()).unwrap_err();
+    println!("{}", missing);
+    assert!(missing.source().is_none());
+
+    let err = load_config("app.conf", "name = 1\nport = eighty\n".as_bytes()).unwrap_err();
+    println!("{}", err); // Only this layer's message
+    assert_eq!(err.to_string(), "could not load config from app.conf");
+    let io_error = err.source().unwrap();
+    println!("  source: {}", io_error);
+
+    // -------------------------------------------------------------------------
+    // 2. Walking the Chain
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. Walking the Chain ---");
+
+    println!("{}", report(&err));
+    let messages: Vec<String> = chain(&err).map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "could not load config from app.conf",
+            "line 2: value is not a number",
+            "invalid digit found in string",
+        ]
+    );
+
+    // Only three messages for four types? An `io::Error` built with
+    // `io::Error::new(kind, inner)` is *transparent*: its `Display` shows the
+    // inner error's message and its `source()` is the inner error's source.
+    // The `ParseError` is still there, behind `get_ref()`:
+    let io_error = err.source().unwrap().downcast_ref::<io::Error>().unwrap();
+    assert_eq!(io_error.kind(), io::ErrorKind::InvalidData);
+    let parse_error = io_error
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<ParseError>())
+        .unwrap();
+    assert_eq!(parse_error.line, 2);
+
+    // `report` works on any error, including std's own.
+    let plain = "x".parse::<u8>().unwrap_err();
+    assert_eq!(report(&plain), "Error: invalid digit found in string");
+
+    // -------------------------------------------------------------------------
+    // 3. Finding an Error in the Chain
+    // -------------------------------------------------------------------------
+    // `downcast_ref` turns a `&dyn Error` back into a concrete type, if that's
+    // what it is. Combined with the chain, callers can react to one specific
+    // cause without knowing every layer in between.
+
+    println!("\n--- 3. Downcasting ---");
+
+    let int_error = find_in_chain::<ParseIntError>(&err).unwrap();
+    println!("Found ParseIntError: {:?}", int_error.kind());
+    assert!(find_in_chain::<io::Error>(&err).is_some());
+    assert!(find_in_chain::<fmt::Error>(&err).is_none());
+
+    // `Box<dyn Error>` erases the type entirely, but keeps the chain and
+    // `downcast_ref`. Strings convert into it too, for quick ad-hoc errors.
+    let boxed: Box<dyn Error + Send + Sync> = Box::new(err);
+    assert_eq!(chain(boxed.as_ref()).count(), 3);
+    assert!(boxed.downcast_ref::<ConfigError>().is_some());
+    let adhoc: Box<dyn Error> = "something went wrong".into();
+    assert!(adhoc.source().is_none());
+
+    // -------------------------------------------------------------------------
+    // 4. Backtraces
+    // -------------------------------------------------------------------------
+    // `Backtrace::capture()` records the call stack only when enabled by the
+    // environment, so it's cheap to leave in error constructors:
+    //     RUST_BACKTRACE=1 ./49-error-trait-and-sources
+    // `Backtrace::force_capture()` always records. (A standard way for `Error`
+    // to expose a backtrace, `Error::provide`, isn't stable yet, hence the
+    // `backtrace()` method on `ConfigError`.)
+
+    println!("\n--- 4. Backtraces ---");
+
+    let err = load_config("app.conf", "port = -1\n".as_bytes()).unwrap_err();
+    let backtrace = err.backtrace().unwrap();
+    match backtrace.status() {
+        BacktraceStatus::Captured => println!("Captured backtrace:\n{}", backtrace),
+        BacktraceStatus::Disabled => println!("Backtrace disabled (set RUST_BACKTRACE=1)"),
+        _ => println!("Backtraces aren't supported on this platform"),
+    }
+    assert!(ConfigError::MissingKey("port").backtrace().is_none());
+
+    let forced = Backtrace::force_capture();
+    if forced.status() == BacktraceStatus::Captured {
+        let frames = forced.to_string().lines().count();
+        println!("force_capture() recorded {} lines of frames", frames);
+        assert!(frames > 0);
+    }
+
+    println!("\n--- End of Error Trait Examples ---");
+}
@@ -0,0 +1,249 @@
+// This file is a catalogue of the `Option` and `Result` combinators that
+// turn nested `match` expressions into short chains: converting between the
+// two types (`ok_or`, `ok`), defaulting with `map_or`/`map_or_else`,
+// combining (`zip`, `and_then`, `or_else`), swapping the nesting
+// (`transpose`), removing it (`flatten`), and `?` on `Option`. It ends by
+// adding methods of our own to both types with an *extension trait*, the
+// `OptionExt`/`ResultExt` helpers in `src/lessons/ext.rs`.
+
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+#[path = "lessons/ext.rs"]
+mod ext;
+
+use ext::{OptionExt, ResultExt};
+
+// A tiny `key=value` settings store used by the examples.
+fn settings() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        ("port", "8080"),
+        ("workers", "4"),
+        ("timeout", "soon"),
+        ("host", "localhost"),
+    ])
+}
+
+// `?` works on `Option` in a function returning `Option`: `None` returns
+// early, `Some(v)` unwraps to `v`.
+fn port_and_workers(config: &HashMap<&str, &str>) -> Option<(u16, u32)> {
+    let port = config.get("port")?.parse().ok()?;
+    let workers = config.get("workers")?.parse().ok()?;
+    Some((port, workers))
+}
+
+// An optional setting that must be valid *if present*: `None` is fine, a bad
+// value is an error. `transpose` turns `Option<Result<T, E>>` into
+// `Result<Option<T>, E>`, so `?` can handle the error.
+fn optional_number(config: &HashMap<&str, &str>, key: &str) -> Result<Option<u32>, ParseIntError> {
+    let parsed = config.get(key).map(|v| v.parse::<u32>()).transpose()?;
+    Ok(parsed)
+}
+
+fn main() {
+    println!("--- Option and Result Combinators ---");
+
+    let config = settings();
+
+    // -------------------------------------------------------------------------
+    // 1. Converting Between `Option` and `Result`
+    // -------------------------------------------------------------------------
+    // - `opt.ok_or(err)` / `opt.ok_or_else(|| err)`: `None` becomes `Err`
+    // - `res.ok()`: drops the error; `res.err()`: drops the value
+
+    println!("\n--- 1. `ok_or`, `ok`, `err` ---");
+
+    let host: Result<&str, String> = config.get("host").copied().ok_or("no host".to_string());
+    assert_eq!(host, Ok("localhost"));
+
+    // `ok_or_else` builds the error only when needed (no `format!` on success).
+    let user = config
+        .get("user")
+        .ok_or_else(|| format!("missing `user` (have {} keys)", config.len()));
+    println!("{:?}", user);
+    assert!(user.is_err());
+
+    assert_eq!("42".parse::<u8>().ok(), Some(42));
+    assert_eq!("x".parse::<u8>().ok(), None);
+    assert!("x".parse::<u8>().err().is_some());
+
+    // -------------------------------------------------------------------------
+    // 2. Defaults: `map_or` and `map_or_else`
+    // -------------------------------------------------------------------------
+    // `map_or(default, f)` is `map(f).unwrap_or(default)` in one step;
+    // `map_or_else(default_fn, f)` computes the default lazily. On `Result`,
+    // the default function receives the error.
+
+    println!("\n--- 2. `map_or`, `map_or_else` ---");
+
+    let host_len = config.get("host").map_or(0, |h| h.len());
+    assert_eq!(host_len, 9);
+    let user_len = config.get("user").map_or(0, |u| u.len());
+    assert_eq!(user_len, 0);
+
+    let describe = |input: &str| {
+        input
+            .parse::<i32>()
+            .map_or_else(|e| format!("invalid ({})", e), |n| format!("number {}", n))
+    };
+    println!("{} / {}", describe("17"), describe("seventeen"));
+    assert_eq!(describe("17"), "number 17");
+    assert_eq!(
+        describe(""),
+        "invalid (cannot parse integer from empty string)"
+    );
+
+    // `is_some_and` / `is_ok_and`: test the value without unwrapping it.
+    assert!(config.get("port").is_some_and(|p| p.len() == 4));
+    assert!("5".parse::<u8>().is_ok_and(|n| n < 10));
+
+    // -------------------------------------------------------------------------
+    // 3. Combining: `zip`, `and_then`, `or_else`, `xor`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. Combining ---");
+
+    // `zip`: both `Some`, or `None`. `unzip` goes the other way.
+    let address = config.get("host").zip(config.get("port"));
+    assert_eq!(address, Some((&"localhost", &"8080")));
+    let (h, p) = address.unzip();
+    assert_eq!((h.is_some(), p.is_some()), (true, true));
+    assert_eq!(config.get("host").zip(config.get("user")), None);
+
+    // `and_then`: the next step can fail too (flat-map).
+    let timeout = config.get("timeout").and_then(|t| t.parse::<u32>().ok());
+    assert_eq!(timeout, None);
+
+    // `or_else`: try a fallback, lazily.
+    let workers = config
+        .get("threads")
+        .or_else(|| config.get("workers"))
+        .copied();
+    assert_eq!(workers, Some("4"));
+
+    // `xor`: exactly one of the two.
+    let cli_port: Option<u16> = Some(9000);
+    let file_port: Option<u16> = None;
+    assert_eq!(cli_port.xor(file_port), Some(9000));
+    assert_eq!(cli_port.xor(Some(80)), None); // Ambiguous: both set
+
+    // `Result` has the same shape: `and_then` chains fallible steps, `or_else`
+    // recovers from (or replaces) the error.
+    let parse_even = |s: &str| {
+        s.parse::<i32>().map_err(|e| e.to_string()).and_then(|n| {
+            if n % 2 == 0 {
+                Ok(n)
+            } else {
+                Err(format!("{} is odd", n))
+            }
+        })
+    };
+    assert_eq!(parse_even("42"), Ok(42));
+    assert_eq!(parse_even("7"), Err("7 is odd".to_string()));
+    assert!(parse_even("seven").is_err());
+    let recovered = "abc".parse::<i32>().or_else(|_| "0".parse::<i32>());
+    assert_eq!(recovered, Ok(0));
+
+    // -------------------------------------------------------------------------
+    // 4. `transpose`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. `transpose` ---");
+
+    assert_eq!(optional_number(&config, "workers"), Ok(Some(4)));
+    assert_eq!(optional_number(&config, "retries"), Ok(None)); // Absent is fine
+    let bad = optional_number(&config, "timeout");
+    println!("timeout: {:?}", bad);
+    assert!(bad.is_err()); // Present but invalid
+
+    // The two directions, side by side:
+    let some_ok: Option<Result<u8, String>> = Some(Ok(1));
+    assert_eq!(some_ok.transpose(), Ok(Some(1)));
+    let ok_none: Result<Option<u8>, String> = Ok(None);
+    assert_eq!(ok_none.transpose(), None);
+
+    // -------------------------------------------------------------------------
+    // 5. `flatten` and Collecting
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 5. `flatten` and Collecting ---");
+
+    // One level of nesting removed.
+    let nested: Option<Option<u8>> = Some(None);
+    assert_eq!(nested.flatten(), None);
+    let nested_result: Result<Result<u8, String>, String> = Ok(Err("inner".into()));
+    assert_eq!(nested_result.flatten(), Err("inner".to_string()));
+
+    // `Option` is iterable (zero or one item), so `flatten` on an iterator
+    // of options keeps just the `Some` values. (When the options come from a
+    // `map`, `filter_map` does both steps at once.)
+    let keys = ["port", "user", "workers"];
+    let lookups: Vec<Option<&str>> = keys.iter().map(|k| config.get(k).copied()).collect();
+    let present: Vec<&str> = lookups.iter().copied().flatten().collect();
+    assert_eq!(present, ["8080", "4"]);
+    let present_again: Vec<&str> = keys.iter().filter_map(|k| config.get(k).copied()).collect();
+    assert_eq!(present, present_again);
+
+    // Collecting into `Option<Vec<_>>` / `Result<Vec<_>, _>` stops at the
+    // first `None` / `Err`.
+    let all: Option<Vec<&str>> = keys.iter().map(|k| config.get(k).copied()).collect();
+    assert_eq!(all, None);
+    let numbers: Result<Vec<u32>, _> = ["1", "2", "x"].iter().map(|s| s.parse::<u32>()).collect();
+    assert!(numbers.is_err());
+
+    // -------------------------------------------------------------------------
+    // 6. `?` on `Option`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 6. `?` on `Option` ---");
+
+    assert_eq!(port_and_workers(&config), Some((8080, 4)));
+    let mut partial = config.clone();
+    partial.remove("workers");
+    assert_eq!(port_and_workers(&partial), None);
+
+    // `?` doesn't convert between the two: use `ok_or` or `.ok()` first.
+    /*
+    fn port(config: &HashMap<&str, &str>) -> Result<u16, ParseIntError> {
+        config.get("port")?.parse()
+        // error[E0277]: the `?` operator can only be used on `Result`s, not `Option`s, in a function that returns `Result`
+    }
+    */
+
+    // -------------------------------------------------------------------------
+    // 7. Extension Traits: `OptionExt` and `ResultExt`
+    // -------------------------------------------------------------------------
+    // We can't add inherent methods to `Option` (it's defined in `core`), but
+    // we *can* define a trait and implement it for `Option<T>`. Once the trait
+    // is in scope (`use ext::OptionExt`), its methods look built in.
+    // `src/lessons/ext.rs` defines:
+    // - `tap_none(f)`: run `f` if `None`, pass the option through
+    // - `tap_some(f)`: run `f` on the value, pass the option through
+    // - `log_err(context)`: print the error to stderr, return `.ok()`
+    // - `tap_err(f)`: run `f` on the error, pass the result through
+
+    println!("\n--- 7. Extension Traits ---");
+
+    let mut missing = Vec::new();
+    let user = config.get("user").tap_none(|| missing.push("user"));
+    let host = config.get("host").tap_none(|| missing.push("host"));
+    assert_eq!((user, host), (None, Some(&"localhost")));
+    assert_eq!(missing, ["user"]);
+
+    let mut seen = 0;
+    let port = config.get("port").tap_some(|_| seen += 1).copied();
+    assert_eq!((port, seen), (Some("8080"), 1));
+
+    // Prints "[error] parsing timeout: invalid digit found in string" to
+    // stderr and carries on with `None`.
+    let timeout = config["timeout"].parse::<u32>().log_err("parsing timeout");
+    assert_eq!(timeout, None);
+    assert_eq!("30".parse::<u32>().log_err("parsing timeout"), Some(30));
+
+    let mut errors = 0;
+    let parsed = "x".parse::<u8>().tap_err(|_| errors += 1);
+    assert!(parsed.is_err());
+    assert_eq!(errors, 1);
+
+    println!("\n--- End of Option and Result Combinator Examples ---");
+}
@@ -0,0 +1,388 @@
+// This file covers recursive types: types that contain values of their own
+// type, like a list whose tail is another list, or an expression whose
+// operands are expressions. Rust needs to know every type's size, so the
+// recursion has to go through a pointer, usually `Box`. We build a cons list
+// (with iteration, `Display`, and a `Drop` that can't overflow the stack) and
+// a binary expression tree with evaluation and pretty-printing.
+// (The pattern matching lesson uses a similar `Expr` to show off `match`;
+// here the focus is the type itself.)
+
+use std::fmt;
+
+#[path = "lessons/testing.rs"]
+mod testing;
+
+use testing::DropTracker;
+
+// -------------------------------------------------------------------------
+// Why the Indirection Is Needed
+// -------------------------------------------------------------------------
+// An enum is as big as its largest variant. If `Cons` held a `List` directly,
+// a `List` would have to be bigger than itself:
+//
+//     enum List { Cons(i32, List), Nil }
+//     // error[E0072]: recursive type `List` has infinite size
+//
+// A `Box<List>` is a pointer to a heap allocation, and a pointer has a fixed
+// size, so `Cons(i32, Box<List>)` has a fixed size too. `Vec`, `Rc` and `&`
+// break the cycle just as well; `Option<List>` doesn't (it stores the `List`
+// inline).
+
+// -------------------------------------------------------------------------
+// A Cons List
+// -------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+enum List<T> {
+    Cons(T, Box<List<T>>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl<T> List<T> {
+    fn new() -> Self {
+        Nil
+    }
+
+    // Returns a new list with `value` in front; the old list becomes the tail.
+    // O(1): nothing is copied, only the box pointer is moved.
+    fn prepend(self, value: T) -> Self {
+        Cons(value, Box::new(self))
+    }
+
+    fn head(&self) -> Option<&T> {
+        match self {
+            Cons(value, _) => Some(value),
+            Nil => None,
+        }
+    }
+
+    // Recursive, like the type.
+    fn len(&self) -> usize {
+        match self {
+            Cons(_, tail) => 1 + tail.len(),
+            Nil => 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Nil)
+    }
+
+    fn iter(&self) -> ListIter<'_, T> {
+        ListIter { current: self }
+    }
+}
+
+// `iter` walks the list with a loop instead of recursion, so it works for
+// lists of any length.
+struct ListIter<'a, T> {
+    current: &'a List<T>,
+}
+
+impl<'a, T> Iterator for ListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match self.current {
+            Cons(value, tail) => {
+                self.current = tail;
+                Some(value)
+            }
+            Nil => None,
+        }
+    }
+}
+
+// Builds the list back to front, so `[1, 2, 3]` becomes `1 -> 2 -> 3`.
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        items.into_iter().rev().fold(Nil, List::prepend)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for value in self.iter() {
+            write!(f, "{} -> ", value)?;
+        }
+        write!(f, "Nil")
+    }
+}
+
+// The automatic drop is recursive too: dropping the head box drops the tail,
+// which drops *its* tail... A list of a million nodes would need a million
+// nested calls and overflow the stack. Unlinking one node at a time keeps
+// every individual drop shallow.
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // Detach the tail; `self` is left owning a single node.
+        let mut rest = match self {
+            Cons(_, tail) => std::mem::replace(&mut **tail, Nil),
+            Nil => return,
+        };
+        // A type with `Drop` can't be moved out of by a pattern (E0509), so
+        // each next tail is swapped out through a `&mut` instead.
+        while let Cons(_, tail) = &mut rest {
+            let next = std::mem::replace(&mut **tail, Nil);
+            rest = next; // Drops one node, whose tail is already `Nil`
+        }
+    }
+}
+
+// -------------------------------------------------------------------------
+// A Binary Expression Tree
+// -------------------------------------------------------------------------
+// Every operator node owns its two operands. The tree has no cycles and
+// every node has exactly one owner, so `Box` is all it needs.
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Num(f64),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+}
+
+// Small constructors hide the `Box::new` noise when building trees by hand.
+fn num(n: f64) -> Expr {
+    Expr::Num(n)
+}
+
+fn add(a: Expr, b: Expr) -> Expr {
+    Expr::Add(Box::new(a), Box::new(b))
+}
+
+fn sub(a: Expr, b: Expr) -> Expr {
+    Expr::Sub(Box::new(a), Box::new(b))
+}
+
+fn mul(a: Expr, b: Expr) -> Expr {
+    Expr::Mul(Box::new(a), Box::new(b))
+}
+
+fn div(a: Expr, b: Expr) -> Expr {
+    Expr::Div(Box::new(a), Box::new(b))
+}
+
+impl Expr {
+    // Evaluation recurses into both operands and combines the results.
+    fn eval(&self) -> Result<f64, String> {
+        match self {
+            Expr::Num(n) => Ok(*n),
+            Expr::Add(a, b) => Ok(a.eval()? + b.eval()?),
+            Expr::Sub(a, b) => Ok(a.eval()? - b.eval()?),
+            Expr::Mul(a, b) => Ok(a.eval()? * b.eval()?),
+            Expr::Div(a, b) => {
+                let divisor = b.eval()?;
+                if divisor == 0.0 {
+                    return Err(format!("division by zero in {}", self));
+                }
+                Ok(a.eval()? / divisor)
+            }
+        }
+    }
+
+    fn node_count(&self) -> usize {
+        match self {
+            Expr::Num(_) => 1,
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+                1 + a.node_count() + b.node_count()
+            }
+        }
+    }
+
+    fn depth(&self) -> usize {
+        match self {
+            Expr::Num(_) => 1,
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) => {
+                1 + a.depth().max(b.depth())
+            }
+        }
+    }
+
+    // Operator symbol, precedence, and operands of an operator node.
+    fn parts(&self) -> Option<(char, u8, &Expr, &Expr)> {
+        match self {
+            Expr::Num(_) => None,
+            Expr::Add(a, b) => Some(('+', 1, a, b)),
+            Expr::Sub(a, b) => Some(('-', 1, a, b)),
+            Expr::Mul(a, b) => Some(('*', 2, a, b)),
+            Expr::Div(a, b) => Some(('/', 2, a, b)),
+        }
+    }
+
+    // Draws the tree sideways, one node per line:
+    //     *
+    //     ├── 2
+    //     └── +
+    //         ├── 3
+    //         └── 4
+    fn tree(&self) -> String {
+        let mut out = String::new();
+        self.tree_into(&mut out, "", "");
+        out
+    }
+
+    fn tree_into(&self, out: &mut String, first_prefix: &str, rest_prefix: &str) {
+        match self.parts() {
+            None => out.push_str(&format!("{}{}\n", first_prefix, self)),
+            Some((symbol, _, a, b)) => {
+                out.push_str(&format!("{}{}\n", first_prefix, symbol));
+                a.tree_into(
+                    out,
+                    &format!("{}├── ", rest_prefix),
+                    &format!("{}│   ", rest_prefix),
+                );
+                b.tree_into(
+                    out,
+                    &format!("{}└── ", rest_prefix),
+                    &format!("{}    ", rest_prefix),
+                );
+            }
+        }
+    }
+}
+
+// Infix printing with only the parentheses that are needed. An operand needs
+// them if it binds more loosely than its parent, or, on the right side, if it
+// binds equally (`a - (b - c)` is not `a - b - c`).
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Some((symbol, precedence, a, b)) = self.parts() else {
+            let Expr::Num(n) = self else { unreachable!() };
+            return write!(f, "{}", n);
+        };
+        let needs_parens = |operand: &Expr, right_side: bool| match operand.parts() {
+            Some((_, p, _, _)) => p < precedence || (right_side && p == precedence),
+            None => false,
+        };
+        if needs_parens(a, false) {
+            write!(f, "({})", a)?;
+        } else {
+            write!(f, "{}", a)?;
+        }
+        write!(f, " {} ", symbol)?;
+        if needs_parens(b, true) {
+            write!(f, "({})", b)
+        } else {
+            write!(f, "{}", b)
+        }
+    }
+}
+
+fn main() {
+    println!("--- Recursive Types with Box ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Sizes
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Sizes ---");
+
+    // However long the list, a `List<i32>` value is one node: a tag, an `i32`
+    // and a pointer. The rest lives on the heap.
+    let node_size = std::mem::size_of::<List<i32>>();
+    println!("size_of::<List<i32>>() = {} bytes", node_size);
+    assert_eq!(
+        std::mem::size_of::<Box<List<i32>>>(),
+        std::mem::size_of::<usize>()
+    );
+    assert!(node_size <= 2 * std::mem::size_of::<usize>());
+
+    // -------------------------------------------------------------------------
+    // 2. The Cons List
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. Cons List ---");
+
+    let list = List::new().prepend(3).prepend(2).prepend(1);
+    println!("{}", list);
+    assert_eq!(list.to_string(), "1 -> 2 -> 3 -> Nil");
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.head(), Some(&1));
+    assert_eq!(list.iter().sum::<i32>(), 6);
+
+    // Written out by hand, the same list shows every `Box`:
+    let by_hand = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    assert_eq!(list, by_hand);
+
+    let words: List<String> = ["tail", "sharing", "is", "free"]
+        .map(String::from)
+        .into_iter()
+        .collect();
+    assert_eq!(words.to_string(), "tail -> sharing -> is -> free -> Nil");
+    let empty: List<u8> = List::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_string(), "Nil");
+
+    // A long list: building, iterating and dropping never recurse per node.
+    // (`len` does, so it's avoided here.)
+    let long: List<u32> = (0..1_000_000).collect();
+    assert_eq!(long.iter().count(), 1_000_000);
+    assert_eq!(long.iter().last(), Some(&999_999));
+    drop(long); // Would overflow the stack without the `Drop` impl above
+
+    // The `Drop` impl also changes the drop order. It unlinks the tail first,
+    // so the head's value is dropped last, after `drop` returns (the automatic
+    // recursive drop would go front to back: a, b, c).
+    let tracker = DropTracker::new();
+    let tracked = List::new()
+        .prepend(tracker.track("c"))
+        .prepend(tracker.track("b"))
+        .prepend(tracker.track("a"));
+    assert_eq!(tracked.len(), 3);
+    assert!(tracker.dropped().is_empty());
+    drop(tracked);
+    assert_eq!(tracker.dropped(), ["b", "c", "a"]); // Each value exactly once
+    println!("Drop order: {:?}", tracker.dropped());
+
+    // -------------------------------------------------------------------------
+    // 3. The Expression Tree
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. Expression Tree ---");
+
+    // 2 * (3 + 4)
+    let expr = mul(num(2.0), add(num(3.0), num(4.0)));
+    println!("{} = {:?}", expr, expr.eval());
+    print!("{}", expr.tree());
+    assert_eq!(expr.to_string(), "2 * (3 + 4)");
+    assert_eq!(expr.eval(), Ok(14.0));
+    assert_eq!((expr.node_count(), expr.depth()), (5, 3));
+    assert_eq!(expr.tree(), "*\n├── 2\n└── +\n    ├── 3\n    └── 4\n");
+
+    // Only the parentheses the tree shape requires:
+    let cases = [
+        (add(num(1.0), mul(num(2.0), num(3.0))), "1 + 2 * 3", 7.0),
+        (mul(add(num(1.0), num(2.0)), num(3.0)), "(1 + 2) * 3", 9.0),
+        (sub(sub(num(10.0), num(4.0)), num(3.0)), "10 - 4 - 3", 3.0),
+        (sub(num(10.0), sub(num(4.0), num(3.0))), "10 - (4 - 3)", 9.0),
+        (div(num(8.0), mul(num(2.0), num(2.0))), "8 / (2 * 2)", 2.0),
+    ];
+    for (expr, printed, value) in &cases {
+        println!("{:<14} = {}", expr.to_string(), value);
+        assert_eq!(&expr.to_string(), printed);
+        assert_eq!(expr.eval(), Ok(*value));
+    }
+
+    let broken = add(num(1.0), div(num(5.0), sub(num(2.0), num(2.0))));
+    println!("{:?}", broken.eval());
+    assert_eq!(
+        broken.eval(),
+        Err("division by zero in 5 / (2 - 2)".to_string())
+    );
+
+    // Trees are values: clone one and change a branch without touching the original.
+    let mut changed = expr.clone();
+    if let Expr::Mul(_, right) = &mut changed {
+        **right = num(10.0);
+    }
+    assert_eq!(changed.to_string(), "2 * 10");
+    assert_eq!(expr.eval(), Ok(14.0));
+
+    println!("\n--- End of Recursive Types Examples ---");
+}
@@ -0,0 +1,383 @@
+// This file implements the classic sorting and searching algorithms by hand:
+// insertion sort, merge sort, quicksort and binary search, each generic over
+// `T: Ord`. They're a workout for generics and slices (`split_at_mut`,
+// `swap`, sub-slices), and every one is checked against the standard
+// library with randomized "property tests": many random inputs, one rule
+// that must always hold ("same result as `slice::sort`").
+// In real code, use `sort`, `sort_unstable` and `binary_search`; see
+// 24-slices-and-split-borrows.rs, and 67-ord-and-custom-sorting.rs for
+// sorting by key or by a custom comparator.
+
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+#[path = "lessons/table.rs"]
+mod table;
+
+use table::{Align, Table};
+
+// -------------------------------------------------------------------------
+// Insertion Sort: O(n^2), Stable, Fast for Small or Nearly Sorted Input
+// -------------------------------------------------------------------------
+// Grows a sorted prefix one element at a time, swapping each new element left
+// until it's in place. `>` (not `>=`) keeps equal elements in their original
+// order, which makes the sort *stable*.
+
+fn insertion_sort<T: Ord>(items: &mut [T]) {
+    for i in 1..items.len() {
+        let mut j = i;
+        while j > 0 && items[j - 1] > items[j] {
+            items.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+// -------------------------------------------------------------------------
+// Merge Sort: O(n log n), Stable, Needs O(n) Extra Space
+// -------------------------------------------------------------------------
+// Takes the `Vec` by value: split it in two, sort each half, then merge by
+// repeatedly moving the smaller front element into the output. Moving instead
+// of copying means no `T: Clone` bound is needed.
+
+fn merge_sort<T: Ord>(mut items: Vec<T>) -> Vec<T> {
+    if items.len() <= 1 {
+        return items;
+    }
+    let right = items.split_off(items.len() / 2);
+    merge(merge_sort(items), merge_sort(right))
+}
+
+fn merge<T: Ord>(left: Vec<T>, right: Vec<T>) -> Vec<T> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+        // `<=` takes from the left on ties: that's what keeps it stable.
+        if l <= r {
+            merged.extend(left.next());
+        } else {
+            merged.extend(right.next());
+        }
+    }
+    merged.extend(left); // At most one of these has anything left
+    merged.extend(right);
+    merged
+}
+
+// -------------------------------------------------------------------------
+// Quicksort: O(n log n) on Average, In Place, Not Stable
+// -------------------------------------------------------------------------
+// Picks a pivot, moves everything smaller to its left and everything larger
+// to its right, then sorts the two sides. `split_at_mut` hands out the two
+// sides as separate `&mut` slices.
+
+fn quicksort<T: Ord>(mut items: &mut [T]) {
+    // Loop on the larger side and recurse on the smaller one, so the
+    // recursion depth stays O(log n) even for unlucky pivots.
+    while items.len() > 16 {
+        let pivot = partition(items);
+        let (left, right) = items.split_at_mut(pivot);
+        let right = &mut right[1..]; // Skip the pivot: it's in its final place
+        if left.len() < right.len() {
+            quicksort(left);
+            items = right;
+        } else {
+            quicksort(right);
+            items = left;
+        }
+    }
+    insertion_sort(items); // Small slices: less overhead than recursing
+}
+
+// Lomuto partition around a median-of-three pivot. Returns the pivot's final
+// index. The median of first/middle/last avoids O(n^2) on sorted input.
+fn partition<T: Ord>(items: &mut [T]) -> usize {
+    let last = items.len() - 1;
+    let mid = last / 2;
+    if items[mid] < items[0] {
+        items.swap(mid, 0);
+    }
+    if items[last] < items[0] {
+        items.swap(last, 0);
+    }
+    if items[mid] < items[last] {
+        items.swap(mid, last);
+    }
+    // Now the median is at `last`: use it as the pivot.
+    let mut store = 0;
+    for i in 0..last {
+        if items[i] < items[last] {
+            items.swap(i, store);
+            store += 1;
+        }
+    }
+    items.swap(store, last);
+    store
+}
+
+// -------------------------------------------------------------------------
+// Binary Search: O(log n) on Sorted Input
+// -------------------------------------------------------------------------
+// Same contract as `slice::binary_search`: `Ok(index)` of a matching element,
+// or `Err(index)` where the value could be inserted to keep the slice sorted.
+// The search window is the half-open range `low..high`.
+
+fn binary_search<T: Ord>(items: &[T], target: &T) -> Result<usize, usize> {
+    let (mut low, mut high) = (0, items.len());
+    while low < high {
+        let mid = low + (high - low) / 2; // Can't overflow, unlike `(low + high) / 2`
+        match items[mid].cmp(target) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(low)
+}
+
+// -------------------------------------------------------------------------
+// Test Helpers
+// -------------------------------------------------------------------------
+
+// A tiny deterministic pseudo-random generator (xorshift64). Not suitable for
+// anything serious, but the same seed always gives the same "random" inputs,
+// so a failing case can be reproduced.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    // A vector of random length with values in `0..max_value` (small ranges
+    // produce lots of duplicates, which is where sorting bugs hide).
+    fn vec(&mut self, max_len: u64, max_value: u64) -> Vec<u64> {
+        let len = self.below(max_len + 1);
+        (0..len).map(|_| self.below(max_value)).collect()
+    }
+}
+
+// A record sorted by `key` only; `id` remembers the original position, so a
+// test can tell whether equal keys kept their order.
+#[derive(Debug, Clone, Copy)]
+struct Record {
+    key: u64,
+    id: usize,
+}
+
+impl PartialEq for Record {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Record {}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Record {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+// A value that counts how often it's compared, to measure the algorithms.
+static COMPARISONS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Counted(u64);
+
+impl PartialOrd for Counted {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Counted {
+    fn cmp(&self, other: &Self) -> Ordering {
+        COMPARISONS.fetch_add(1, AtomicOrdering::Relaxed);
+        self.0.cmp(&other.0)
+    }
+}
+
+// A sorting algorithm, adapted to one common signature for the comparison table.
+type SortFn = fn(&mut Vec<Counted>);
+
+fn comparisons_for(items: &[u64], sort: SortFn) -> usize {
+    let mut counted: Vec<Counted> = items.iter().map(|&n| Counted(n)).collect();
+    COMPARISONS.store(0, AtomicOrdering::Relaxed);
+    sort(&mut counted);
+    assert!(counted.windows(2).all(|w| w[0].0 <= w[1].0));
+    COMPARISONS.load(AtomicOrdering::Relaxed)
+}
+
+fn main() {
+    println!("--- Sorting and Searching ---");
+
+    // -------------------------------------------------------------------------
+    // 1. The Algorithms on One Input
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. The Algorithms ---");
+
+    let input = vec![38, 27, 43, 3, 9, 82, 10, 3];
+    let mut expected = input.clone();
+    expected.sort();
+
+    let mut by_insertion = input.clone();
+    insertion_sort(&mut by_insertion);
+    let by_merge = merge_sort(input.clone());
+    let mut by_quick = input.clone();
+    quicksort(&mut by_quick);
+    println!("Input:  {:?}", input);
+    println!("Sorted: {:?}", by_merge);
+    assert_eq!(by_insertion, expected);
+    assert_eq!(by_merge, expected);
+    assert_eq!(by_quick, expected);
+
+    // Generic over any `T: Ord`: strings, tuples, `Reverse`...
+    let mut words = ["pear", "apple", "fig", "banana"];
+    quicksort(&mut words);
+    assert_eq!(words, ["apple", "banana", "fig", "pear"]);
+    let descending = merge_sort(vec![1, 3, 2].into_iter().map(std::cmp::Reverse).collect());
+    assert_eq!(
+        descending.iter().map(|r| r.0).collect::<Vec<_>>(),
+        [3, 2, 1]
+    );
+
+    assert_eq!(binary_search(&expected, &43), Ok(6));
+    assert_eq!(binary_search(&expected, &11), Err(4)); // Between 10 and 27
+    assert_eq!(binary_search(&expected, &0), Err(0));
+    assert_eq!(binary_search(&expected, &100), Err(8));
+    assert_eq!(binary_search::<i32>(&[], &1), Err(0));
+
+    // -------------------------------------------------------------------------
+    // 2. Property Tests Against the Standard Library
+    // -------------------------------------------------------------------------
+    // Hand-picked examples miss edge cases (empty, one element, all equal,
+    // already sorted). Random inputs find them; the property is simple to
+    // state: every algorithm must agree with `slice::sort`.
+
+    println!("\n--- 2. Property Tests ---");
+
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let cases = 2_000;
+    for _ in 0..cases {
+        let input = rng.vec(60, 20);
+        let mut expected = input.clone();
+        expected.sort();
+
+        let mut insertion = input.clone();
+        insertion_sort(&mut insertion);
+        assert_eq!(insertion, expected, "insertion_sort({:?})", input);
+
+        assert_eq!(
+            merge_sort(input.clone()),
+            expected,
+            "merge_sort({:?})",
+            input
+        );
+
+        let mut quick = input.clone();
+        quicksort(&mut quick);
+        assert_eq!(quick, expected, "quicksort({:?})", input);
+
+        // Binary search: found values really match, and insertion points
+        // really keep the slice sorted.
+        let target = rng.below(22);
+        match binary_search(&expected, &target) {
+            Ok(i) => assert_eq!(expected[i], target),
+            Err(i) => {
+                assert!(i == 0 || expected[i - 1] < target);
+                assert!(i == expected.len() || expected[i] > target);
+            }
+        }
+        assert_eq!(
+            binary_search(&expected, &target).is_ok(),
+            expected.binary_search(&target).is_ok()
+        );
+    }
+    println!(
+        "{} random inputs: all algorithms agree with slice::sort",
+        cases
+    );
+
+    // Stability: records with equal keys must keep their original order.
+    // `sort` (not `sort_unstable`) is stable, so it's the reference.
+    for _ in 0..500 {
+        let records: Vec<Record> = rng
+            .vec(40, 5)
+            .into_iter()
+            .enumerate()
+            .map(|(id, key)| Record { key, id })
+            .collect();
+        let ids = |records: &[Record]| records.iter().map(|r| (r.key, r.id)).collect::<Vec<_>>();
+        let mut expected = records.clone();
+        expected.sort();
+
+        let mut insertion = records.clone();
+        insertion_sort(&mut insertion);
+        assert_eq!(ids(&insertion), ids(&expected));
+        assert_eq!(ids(&merge_sort(records.clone())), ids(&expected));
+
+        // Quicksort isn't stable: only the keys are guaranteed to match.
+        let mut quick = records.clone();
+        quicksort(&mut quick);
+        assert_eq!(quick, expected); // `==` compares keys only
+    }
+    println!("Insertion and merge sort are stable; quicksort sorts the keys");
+
+    // -------------------------------------------------------------------------
+    // 3. Counting Comparisons
+    // -------------------------------------------------------------------------
+    // Big-O in practice: count how often each algorithm calls `cmp` on 1,000
+    // random and 1,000 already-sorted values.
+
+    println!("\n--- 3. Counting Comparisons ---");
+
+    let random: Vec<u64> = (0..1_000).map(|_| rng.below(1_000_000)).collect();
+    let sorted: Vec<u64> = (0..1_000).collect();
+
+    let algorithms: [(&str, SortFn); 4] = [
+        ("insertion sort", |v| insertion_sort(v)),
+        ("merge sort", |v| *v = merge_sort(std::mem::take(v))),
+        ("quicksort", |v| quicksort(v)),
+        ("slice::sort", |v| v.sort()),
+    ];
+    let mut counts = Table::new(["algorithm", "random", "sorted"])
+        .align(1, Align::Right)
+        .align(2, Align::Right);
+    let mut results = Vec::new();
+    for (name, sort) in algorithms {
+        let on_random = comparisons_for(&random, sort);
+        let on_sorted = comparisons_for(&sorted, sort);
+        counts.row([
+            name.to_string(),
+            on_random.to_string(),
+            on_sorted.to_string(),
+        ]);
+        results.push((name, on_random, on_sorted));
+    }
+    println!("{}", counts);
+
+    // Insertion sort: ~n^2/4 on random input, but only n - 1 when sorted.
+    assert!(results[0].1 > 100_000);
+    assert_eq!(results[0].2, 999);
+    // Merge sort and quicksort stay near n log2 n (~10,000) on both.
+    assert!(results[1].1 < 12_000 && results[2].1 < 20_000);
+    assert!(results[2].2 < 20_000); // Median-of-three: no O(n^2) on sorted input
+
+    println!("\n--- End of Sorting and Searching Examples ---");
+}
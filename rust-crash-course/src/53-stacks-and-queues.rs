@@ -0,0 +1,380 @@
+// This file covers the two simplest "ordering" data structures and the std
+// types behind them: a *stack* (last in, first out) is just a `Vec`, and a
+// *queue* (first in, first out) or *deque* (both ends) is a `VecDeque`, a
+// growable ring buffer. It shows how the ring buffer wraps around, builds a
+// fixed-size history on top of it, and finishes with two classic exercises:
+// a bracket-matching validator (a stack) and the sliding-window maximum (a
+// deque).
+
+use std::collections::VecDeque;
+use std::fmt;
+
+// -------------------------------------------------------------------------
+// A Bounded Ring Buffer
+// -------------------------------------------------------------------------
+// Keeps only the most recent `capacity` items: pushing onto a full buffer
+// drops the oldest. `VecDeque` makes both ends O(1), so this is a thin
+// wrapper. (With a `Vec`, dropping the oldest would be `remove(0)`, which
+// shifts every other element.)
+
+struct History<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> History<T> {
+    fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "a history must hold at least one item");
+        History {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    // Returns the item that fell out, if any.
+    fn push(&mut self, item: T) -> Option<T> {
+        let evicted = if self.items.len() == self.capacity {
+            self.items.pop_front()
+        } else {
+            None
+        };
+        self.items.push_back(item);
+        evicted
+    }
+
+    fn latest(&self) -> Option<&T> {
+        self.items.back()
+    }
+
+    // Oldest first.
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+}
+
+// -------------------------------------------------------------------------
+// Bracket Matching (a Stack)
+// -------------------------------------------------------------------------
+// Every opening bracket is pushed; every closing bracket must match the most
+// recent unmatched opening one, which is exactly the top of the stack.
+
+#[derive(Debug, PartialEq)]
+enum BracketError {
+    // A closing bracket with nothing open.
+    Unexpected {
+        found: char,
+        at: usize,
+    },
+    // A closing bracket that doesn't match the innermost open one.
+    Mismatched {
+        open: char,
+        opened_at: usize,
+        found: char,
+        at: usize,
+    },
+    // Input ended with brackets still open.
+    Unclosed {
+        open: char,
+        opened_at: usize,
+    },
+}
+
+impl fmt::Display for BracketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BracketError::Unexpected { found, at } => {
+                write!(f, "unexpected '{}' at {}", found, at)
+            }
+            BracketError::Mismatched {
+                open,
+                opened_at,
+                found,
+                at,
+            } => write!(
+                f,
+                "'{}' at {} closed by '{}' at {}",
+                open, opened_at, found, at
+            ),
+            BracketError::Unclosed { open, opened_at } => {
+                write!(f, "'{}' at {} is never closed", open, opened_at)
+            }
+        }
+    }
+}
+
+fn matching_open(close: char) -> Option<char> {
+    match close {
+        ')' => Some('('),
+        ']' => Some('['),
+        '}' => Some('{'),
+        _ => None,
+    }
+}
+
+// Positions are char indices. Other characters are ignored.
+fn validate_brackets(text: &str) -> Result<(), BracketError> {
+    let mut open: Vec<(char, usize)> = Vec::new();
+    for (at, c) in text.chars().enumerate() {
+        if matches!(c, '(' | '[' | '{') {
+            open.push((c, at));
+        } else if let Some(expected) = matching_open(c) {
+            match open.pop() {
+                None => return Err(BracketError::Unexpected { found: c, at }),
+                Some((o, _)) if o == expected => {}
+                Some((o, opened_at)) => {
+                    return Err(BracketError::Mismatched {
+                        open: o,
+                        opened_at,
+                        found: c,
+                        at,
+                    });
+                }
+            }
+        }
+    }
+    // Whatever is left on the stack was never closed; report the innermost.
+    match open.pop() {
+        Some((o, opened_at)) => Err(BracketError::Unclosed { open: o, opened_at }),
+        None => Ok(()),
+    }
+}
+
+// -------------------------------------------------------------------------
+// Sliding Window Maximum (a Deque)
+// -------------------------------------------------------------------------
+// The maximum of every window of `k` consecutive values, in O(n) total.
+// The deque holds *indices* whose values are decreasing from front to back:
+// - a new value evicts every smaller value from the back (they can never be
+//   a window's maximum again, since the new one outlives them);
+// - the front is dropped once it slides out of the window;
+// - so the front is always the current window's maximum.
+
+fn sliding_window_max(values: &[i32], k: usize) -> Vec<i32> {
+    assert!(k > 0, "window size must be positive");
+    let mut candidates: VecDeque<usize> = VecDeque::new();
+    let mut maxima = Vec::with_capacity(values.len().saturating_sub(k - 1));
+    for (i, &value) in values.iter().enumerate() {
+        while candidates.back().is_some_and(|&j| values[j] <= value) {
+            candidates.pop_back();
+        }
+        candidates.push_back(i);
+        if candidates.front().is_some_and(|&j| j + k <= i) {
+            candidates.pop_front(); // Slid out of the window
+        }
+        if i + 1 >= k {
+            maxima.push(values[candidates[0]]);
+        }
+    }
+    maxima
+}
+
+// The obvious O(n * k) version, used to check the fast one.
+fn sliding_window_max_naive(values: &[i32], k: usize) -> Vec<i32> {
+    values
+        .windows(k)
+        .map(|w| *w.iter().max().unwrap())
+        .collect()
+}
+
+fn main() {
+    println!("--- Stacks, Queues and VecDeque ---");
+
+    // -------------------------------------------------------------------------
+    // 1. `Vec` as a Stack
+    // -------------------------------------------------------------------------
+    // `push` and `pop` work at the end of a `Vec`, which is O(1) (amortized
+    // for `push`). `last` peeks without removing.
+
+    println!("\n--- 1. `Vec` as a Stack ---");
+
+    let mut undo: Vec<&str> = Vec::new();
+    undo.push("type 'hello'");
+    undo.push("bold");
+    undo.push("delete line");
+    assert_eq!(undo.last(), Some(&"delete line"));
+
+    // Undo in reverse order of the edits: last in, first out.
+    let mut undone = Vec::new();
+    while let Some(action) = undo.pop() {
+        undone.push(action);
+    }
+    println!("Undone: {:?}", undone);
+    assert_eq!(undone, ["delete line", "bold", "type 'hello'"]);
+    assert_eq!(undo.pop(), None); // Empty stack: `None`, not a panic
+
+    // -------------------------------------------------------------------------
+    // 2. `VecDeque` as a Queue and Deque
+    // -------------------------------------------------------------------------
+    // `push_back` + `pop_front` is a FIFO queue; `push_front`/`pop_back` make
+    // it a double-ended queue. All four are O(1).
+
+    println!("\n--- 2. `VecDeque` as a Queue ---");
+
+    // Round-robin scheduling: each task runs for one time slice, then goes to
+    // the back of the queue if it still has work left.
+    let mut ready: VecDeque<(&str, u32)> = VecDeque::from([("A", 3), ("B", 1), ("C", 2)]);
+    let mut timeline = Vec::new();
+    while let Some((task, remaining)) = ready.pop_front() {
+        timeline.push(task);
+        if remaining > 1 {
+            ready.push_back((task, remaining - 1));
+        }
+    }
+    println!("Timeline: {}", timeline.join(" "));
+    assert_eq!(timeline, ["A", "B", "C", "A", "C", "A"]);
+
+    // An urgent item can jump the queue with `push_front`.
+    let mut jobs = VecDeque::from(["report", "backup"]);
+    jobs.push_front("hotfix");
+    assert_eq!(jobs.front(), Some(&"hotfix"));
+    assert_eq!(jobs.back(), Some(&"backup"));
+    assert_eq!(jobs.pop_back(), Some("backup"));
+    assert_eq!(jobs, ["hotfix", "report"]); // Compares with arrays and slices
+    assert_eq!(jobs[1], "report"); // Indexing works, counted from the front
+
+    // -------------------------------------------------------------------------
+    // 3. Ring Buffer Semantics
+    // -------------------------------------------------------------------------
+    // A `VecDeque` is one allocation with a `head` index that moves as items
+    // are popped from the front. The items can wrap past the end of the
+    // buffer and continue at its start, so they may be stored in two pieces.
+
+    println!("\n--- 3. Ring Buffer Semantics ---");
+
+    let mut ring: VecDeque<u32> = VecDeque::with_capacity(4);
+    let capacity = ring.capacity(); // At least 4
+    for n in 0..capacity as u32 {
+        ring.push_back(n);
+    }
+    ring.pop_front();
+    ring.pop_front(); // `head` has moved forward two slots
+    ring.push_back(100);
+    ring.push_back(101); // These reuse the two freed slots at the start
+    assert_eq!(ring.capacity(), capacity); // No reallocation
+    let (first, second) = ring.as_slices();
+    println!("as_slices: {:?} + {:?}", first, second);
+    assert_eq!(first.len() + second.len(), capacity);
+    assert_eq!(second, [100, 101]); // Wrapped around
+
+    // `make_contiguous` rearranges the buffer so it's one slice (for APIs
+    // that need `&[T]`, like `sort` or `binary_search`).
+    let slice = ring.make_contiguous();
+    slice.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(ring.as_slices().1, []);
+    assert_eq!(ring.front(), Some(&101));
+
+    // `rotate_left(n)`: the first `n` items move to the back, in place.
+    let mut dial = VecDeque::from([1, 2, 3, 4, 5]);
+    dial.rotate_left(2);
+    assert_eq!(dial, [3, 4, 5, 1, 2]);
+
+    // The bounded history built on top of it:
+    let mut history = History::new(3);
+    for command in ["ls", "cd src", "cargo build", "cargo test"] {
+        if let Some(evicted) = history.push(command) {
+            println!("History full, dropped {:?}", evicted);
+        }
+    }
+    assert_eq!(
+        history.iter().copied().collect::<Vec<_>>(),
+        ["cd src", "cargo build", "cargo test"]
+    );
+    assert_eq!(history.latest(), Some(&"cargo test"));
+
+    // -------------------------------------------------------------------------
+    // 4. Exercise: Bracket Matching
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Bracket Matching ---");
+
+    let inputs = [
+        "fn main() { let v = vec![(1, 2)]; }",
+        "{ [ ( ) ] }",
+        "",
+        "(]",
+        "map[key))",
+        "if (a { b }",
+        "((())",
+    ];
+    for input in inputs {
+        match validate_brackets(input) {
+            Ok(()) => println!("{:<38} ok", format!("{:?}", input)),
+            Err(e) => println!("{:<38} {}", format!("{:?}", input), e),
+        }
+    }
+    assert_eq!(validate_brackets(inputs[0]), Ok(()));
+    assert_eq!(validate_brackets(inputs[1]), Ok(()));
+    assert_eq!(validate_brackets(inputs[2]), Ok(()));
+    assert_eq!(
+        validate_brackets("(]"),
+        Err(BracketError::Mismatched {
+            open: '(',
+            opened_at: 0,
+            found: ']',
+            at: 1
+        })
+    );
+    assert_eq!(
+        validate_brackets("map[key))"),
+        Err(BracketError::Mismatched {
+            open: '[',
+            opened_at: 3,
+            found: ')',
+            at: 7
+        })
+    );
+    assert_eq!(
+        validate_brackets(")("),
+        Err(BracketError::Unexpected { found: ')', at: 0 })
+    );
+    assert_eq!(
+        validate_brackets("((())"),
+        Err(BracketError::Unclosed {
+            open: '(',
+            opened_at: 0
+        })
+    );
+
+    // -------------------------------------------------------------------------
+    // 5. Exercise: Sliding Window Maximum
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 5. Sliding Window Maximum ---");
+
+    let temperatures = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    let maxima = sliding_window_max(&temperatures, 3);
+    println!("Window of 3 over {:?}:\n  {:?}", temperatures, maxima);
+    assert_eq!(maxima, [4, 4, 5, 9, 9, 9, 6, 6]);
+    assert_eq!(sliding_window_max(&temperatures, 1), temperatures);
+    assert_eq!(sliding_window_max(&temperatures, 10), [9]);
+    assert!(sliding_window_max(&temperatures, 11).is_empty()); // Window larger than input
+
+    // Check against the naive version on many inputs, including decreasing
+    // runs (the deque's worst case) and repeated values.
+    let mut seed: u32 = 12345;
+    for round in 0..300 {
+        let len = round % 40;
+        let values: Vec<i32> = (0..len)
+            .map(|i| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                if round % 3 == 0 {
+                    100 - i as i32
+                } else {
+                    (seed >> 16) as i32 % 10
+                }
+            })
+            .collect();
+        for k in 1..=len.max(1) {
+            assert_eq!(
+                sliding_window_max(&values, k),
+                sliding_window_max_naive(&values, k),
+                "values {:?}, k {}",
+                values,
+                k
+            );
+        }
+    }
+    println!("Matches the O(n * k) version on 300 random inputs");
+
+    println!("\n--- End of Stacks, Queues and VecDeque Examples ---");
+}
@@ -0,0 +1,271 @@
+// This file covers the *ordered* collections, `BTreeMap` and `BTreeSet`, and
+// how they compare with `HashMap` and `HashSet`. A B-tree keeps its keys
+// sorted, so iteration comes out in order, and it can answer questions a hash
+// table can't answer without scanning everything: "which keys are between 3
+// and 7?", "what's the smallest key?", "what's the latest entry before this
+// time?". The price is O(log n) lookups instead of O(1) on average, and keys
+// must be `Ord` instead of `Hash + Eq`.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::hint::black_box;
+use std::ops::Bound;
+use std::time::Instant;
+
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use fmt::human_duration;
+
+// The sentence from the word-count example in 07-rust_data_structures.rs.
+const SENTENCE: &str = "the quick brown fox jumps over the lazy dog the quick";
+
+fn count_words_hashed(text: &str) -> HashMap<&str, u32> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Identical except for the map type: `entry` works the same on both.
+fn count_words_sorted(text: &str) -> BTreeMap<&str, u32> {
+    let mut counts = BTreeMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn main() {
+    println!("--- BTreeMap and BTreeSet ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Word Count, Sorted
+    // -------------------------------------------------------------------------
+    // A `HashMap` iterates in an arbitrary order that can change between runs
+    // (the hasher is randomly seeded). A `BTreeMap` always iterates in key
+    // order, so its output is sorted and reproducible.
+
+    println!("\n--- 1. Word Count, Sorted ---");
+
+    let hashed = count_words_hashed(SENTENCE);
+    println!("HashMap:  {:?}", hashed); // Some order; run it again to compare
+
+    let sorted = count_words_sorted(SENTENCE);
+    println!("BTreeMap: {:?}", sorted);
+    let words: Vec<&str> = sorted.keys().copied().collect();
+    assert_eq!(
+        words,
+        [
+            "brown", "dog", "fox", "jumps", "lazy", "over", "quick", "the"
+        ]
+    );
+
+    // Same contents, only the order differs. Getting sorted output from the
+    // `HashMap` means collecting and sorting every time.
+    let mut from_hashed: Vec<(&str, u32)> = hashed.into_iter().collect();
+    from_hashed.sort();
+    assert_eq!(from_hashed, sorted.clone().into_iter().collect::<Vec<_>>());
+
+    // Sorting by count is a different order, so it still needs a sort:
+    // highest count first (`Reverse`), ties alphabetical (the map already
+    // gives that, and the sort is stable).
+    let mut by_count: Vec<(&str, u32)> = sorted.iter().map(|(w, c)| (*w, *c)).collect();
+    by_count.sort_by_key(|&(_, count)| Reverse(count));
+    println!("Top 3: {:?}", &by_count[..3]);
+    assert_eq!(&by_count[..3], [("the", 3), ("quick", 2), ("brown", 1)]);
+
+    // -------------------------------------------------------------------------
+    // 2. Range Queries
+    // -------------------------------------------------------------------------
+    // `range(r)` iterates over just the keys in `r`, in order, by walking down
+    // the tree to the start of the range: O(log n + items returned). Any range
+    // syntax works (`a..b`, `a..=b`, `..b`, `a..`), as do explicit `Bound`s.
+
+    println!("\n--- 2. Range Queries ---");
+
+    let squares: BTreeMap<u32, u32> = (1..=10).map(|n| (n, n * n)).collect();
+    let middle: Vec<(&u32, &u32)> = squares.range(3..7).collect();
+    println!("range(3..7): {:?}", middle);
+    assert_eq!(middle, [(&3, &9), (&4, &16), (&5, &25), (&6, &36)]);
+    assert_eq!(squares.range(..=2).count(), 2);
+    assert_eq!(squares.range(9..).map(|(_, sq)| sq).sum::<u32>(), 81 + 100);
+
+    // Exclusive lower bound: `(Excluded(3), Included(5))` is "3 < k <= 5".
+    let open_closed: Vec<u32> = squares
+        .range((Bound::Excluded(3), Bound::Included(5)))
+        .map(|(k, _)| *k)
+        .collect();
+    assert_eq!(open_closed, [4, 5]);
+
+    // String keys sort lexicographically, so a range finds every key with a
+    // given prefix: all words from "q" up to (not including) "r".
+    let q_words: Vec<&str> = sorted.range("q".."r").map(|(w, _)| *w).collect();
+    assert_eq!(q_words, ["quick"]);
+
+    // "Latest reading at or before time t": the last item of `range(..=t)`.
+    // With a `HashMap` this needs a scan over every entry.
+    let readings: BTreeMap<u32, f64> =
+        BTreeMap::from([(900, 18.5), (1200, 23.0), (1500, 24.5), (1800, 20.0)]);
+    let at = |t: u32| readings.range(..=t).next_back().map(|(_, v)| *v);
+    println!("Temperature at 13:30: {:?}", at(1330));
+    assert_eq!(at(1330), Some(23.0));
+    assert_eq!(at(1500), Some(24.5)); // Inclusive
+    assert_eq!(at(800), None); // Before the first reading
+
+    // `range_mut` gives mutable access to the values in a range.
+    let mut prices = BTreeMap::from([("apple", 120), ("banana", 80), ("cherry", 300)]);
+    for (_, price) in prices.range_mut("a".."c") {
+        *price -= 10; // Sale on everything starting with "a" or "b"
+    }
+    assert_eq!(prices["apple"], 110);
+    assert_eq!(prices["banana"], 70);
+    assert_eq!(prices["cherry"], 300);
+
+    // A range whose start is after its end panics:
+    // squares.range(7..3); // panicked: range start is greater than range end in BTreeMap
+
+    // -------------------------------------------------------------------------
+    // 3. First and Last Entries
+    // -------------------------------------------------------------------------
+    // The smallest and largest keys are O(log n) away. `pop_first`/`pop_last`
+    // remove them, which makes a `BTreeMap` a priority queue that can also
+    // be iterated in order or searched by key.
+
+    println!("\n--- 3. First and Last Entries ---");
+
+    assert_eq!(squares.first_key_value(), Some((&1, &1)));
+    assert_eq!(squares.last_key_value(), Some((&10, &100)));
+
+    // Pending jobs keyed by (priority, sequence number): lowest first.
+    let mut jobs: BTreeMap<(u8, u32), &str> = BTreeMap::new();
+    jobs.insert((2, 0), "send newsletter");
+    jobs.insert((0, 1), "restart server");
+    jobs.insert((1, 2), "rotate logs");
+    jobs.insert((0, 3), "page on-call");
+    let mut order = Vec::new();
+    while let Some(((priority, _), job)) = jobs.pop_first() {
+        order.push(job);
+        println!("priority {}: {}", priority, job);
+    }
+    assert_eq!(
+        order,
+        [
+            "restart server",
+            "page on-call",
+            "rotate logs",
+            "send newsletter"
+        ]
+    );
+
+    // `first_entry`/`last_entry` give an `OccupiedEntry` to update or remove
+    // in place.
+    let mut stock = BTreeMap::from([("bolts", 3), ("nuts", 0), ("screws", 12)]);
+    if let Some(mut last) = stock.last_entry() {
+        *last.get_mut() -= 2;
+    }
+    assert_eq!(stock["screws"], 10);
+
+    // -------------------------------------------------------------------------
+    // 4. `BTreeSet`
+    // -------------------------------------------------------------------------
+    // A `BTreeSet<T>` is a `BTreeMap<T, ()>`: a sorted set of unique values,
+    // with the same `range`, `first`, `last` and `pop_*` methods. Set
+    // operations (`union`, `intersection`, `difference`) yield sorted output.
+
+    println!("\n--- 4. `BTreeSet` ---");
+
+    let evens: BTreeSet<u32> = (0..20).step_by(2).collect();
+    let threes: BTreeSet<u32> = (0..20).step_by(3).collect();
+    let both: Vec<u32> = evens.intersection(&threes).copied().collect();
+    println!("Multiples of 2 and 3 below 20: {:?}", both);
+    assert_eq!(both, [0, 6, 12, 18]);
+    assert_eq!(evens.first(), Some(&0));
+    assert_eq!(threes.last(), Some(&18));
+    assert_eq!(evens.range(5..11).copied().collect::<Vec<_>>(), [6, 8, 10]);
+
+    // Nearest value at or above a target: first item of `range(target..)`.
+    let sizes = BTreeSet::from([4, 8, 16, 32, 64]);
+    let fit = |n: u32| sizes.range(n..).next().copied();
+    assert_eq!(fit(10), Some(16));
+    assert_eq!(fit(16), Some(16));
+    assert_eq!(fit(65), None);
+
+    // `split_off(&k)` cuts the set in two: keys >= k move into the new set.
+    let mut low: BTreeSet<u32> = (1..=6).collect();
+    let high = low.split_off(&4);
+    assert_eq!(low.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(high.into_iter().collect::<Vec<_>>(), [4, 5, 6]);
+
+    // -------------------------------------------------------------------------
+    // 5. When Ordering Beats Hashing (and When It Doesn't)
+    // -------------------------------------------------------------------------
+    // Prefer `BTreeMap`/`BTreeSet` when you need:
+    // - sorted or reproducible iteration (output, tests, diffs, serialization)
+    // - range queries, nearest-key lookups, or the min/max key
+    // - keys that are `Ord` but not `Hash` (e.g. the `NotNan` wrapper from
+    //   23-ordering-and-floats.rs) -- or, for small maps, less memory overhead
+    // Prefer `HashMap`/`HashSet` for plain lookups by key on large maps, where
+    // O(1) beats O(log n) comparisons that each may chase a pointer.
+
+    println!("\n--- 5. When Ordering Beats Hashing ---");
+
+    let n = 100_000u32;
+    let tree: BTreeMap<u32, u32> = (0..n).map(|k| (k * 7 % n, k)).collect();
+    let hash: HashMap<u32, u32> = tree.iter().map(|(k, v)| (*k, *v)).collect();
+
+    // Range query: a tree walks straight to the range; a hash map must look at
+    // every key.
+    let rounds = 50;
+    let start = Instant::now();
+    let mut tree_total = 0;
+    for _ in 0..rounds {
+        tree_total += black_box(&tree).range(50_000..50_100).count();
+    }
+    let tree_range_time = start.elapsed();
+
+    let start = Instant::now();
+    let mut hash_total = 0;
+    for _ in 0..rounds {
+        hash_total += black_box(&hash)
+            .keys()
+            .filter(|k| (50_000..50_100).contains(*k))
+            .count();
+    }
+    let hash_range_time = start.elapsed();
+    assert_eq!(tree_total, hash_total);
+    println!(
+        "Range of 100 keys, {} rounds: BTreeMap {}, HashMap (full scan) {}",
+        rounds,
+        human_duration(tree_range_time),
+        human_duration(hash_range_time)
+    );
+
+    // Point lookups: the hash map is usually ahead.
+    let start = Instant::now();
+    let mut tree_hits = 0;
+    for k in 0..n {
+        tree_hits += black_box(&tree).contains_key(&k) as u32;
+    }
+    let tree_lookup_time = start.elapsed();
+
+    let start = Instant::now();
+    let mut hash_hits = 0;
+    for k in 0..n {
+        hash_hits += black_box(&hash).contains_key(&k) as u32;
+    }
+    let hash_lookup_time = start.elapsed();
+    assert_eq!((tree_hits, hash_hits), (n, n));
+    println!(
+        "{} point lookups: BTreeMap {}, HashMap {}",
+        n,
+        human_duration(tree_lookup_time),
+        human_duration(hash_lookup_time)
+    );
+    // Typical result: the range query is hundreds of times faster on the
+    // `BTreeMap`, while the lookups are a few times faster on the `HashMap`
+    // (closer in debug builds, and closer still for small maps).
+
+    println!("\n--- End of BTreeMap and BTreeSet Examples ---");
+}
@@ -0,0 +1,373 @@
+// This file covers `HashSet`: the basic operations, the set algebra methods
+// (union, intersection, difference), and what it takes to use your own
+// struct as a set element or map key. Deriving `Hash`, `PartialEq` and `Eq`
+// is enough when equality means "all fields equal"; when it doesn't (an
+// undirected edge, a record with a cached field), both traits have to be
+// written by hand from the same view of the data. It ends with a check that
+// catches a `Hash` impl that disagrees with `Eq`.
+// (22-hash-and-eq.rs explains why the two must agree in more depth.)
+
+use std::collections::HashSet;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+// -------------------------------------------------------------------------
+// Composite Keys
+// -------------------------------------------------------------------------
+
+// Equality is "every field equal", so all three traits can be derived. The
+// derived `Hash` hashes the fields in declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Seat {
+    row: u8,
+    letter: char,
+}
+
+// An undirected edge: `Edge::new(1, 2)` and `Edge::new(2, 1)` are the same
+// edge. Derived impls would compare (and hash) `a` and `b` positionally, so
+// both traits are written by hand on a normalized form, `(min, max)`.
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    a: u32,
+    b: u32,
+}
+
+impl Edge {
+    fn new(a: u32, b: u32) -> Self {
+        Edge { a, b }
+    }
+
+    // The one place that decides what "the same edge" means.
+    fn normalized(&self) -> (u32, u32) {
+        (self.a.min(self.b), self.a.max(self.b))
+    }
+}
+
+impl PartialEq for Edge {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl Eq for Edge {}
+
+impl Hash for Edge {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalized().hash(state);
+    }
+}
+
+// A record identified by `(department, id)`. `display_name` is a cached,
+// human-readable field that may differ between two copies of the same
+// employee, so it must be left out of *both* `Eq` and `Hash`.
+#[derive(Debug, Clone)]
+struct Employee {
+    department: &'static str,
+    id: u32,
+    display_name: String,
+}
+
+impl Employee {
+    fn key(&self) -> (&str, u32) {
+        (self.department, self.id)
+    }
+}
+
+impl PartialEq for Employee {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Employee {}
+
+impl Hash for Employee {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+// The same edge with a derived `Hash`: positional, so `(1, 2)` and `(2, 1)`
+// hash differently even though they compare equal.
+#[allow(clippy::derived_hash_with_manual_eq)]
+#[derive(Debug, Clone, Copy, Hash)]
+struct BrokenEdge {
+    a: u32,
+    b: u32,
+}
+
+impl PartialEq for BrokenEdge {
+    fn eq(&self, other: &Self) -> bool {
+        (self.a.min(self.b), self.a.max(self.b)) == (other.a.min(other.b), other.a.max(other.b))
+    }
+}
+
+impl Eq for BrokenEdge {}
+
+// -------------------------------------------------------------------------
+// Checking a Key Type
+// -------------------------------------------------------------------------
+// Two checks over a list of sample values, which should include values that
+// are equal in non-obvious ways:
+// 1. The invariant itself: a == b must imply hash(a) == hash(b).
+// 2. What users actually see: a `HashSet` built from the samples must hold
+//    exactly one element per group of equal samples, and find every sample.
+// The first pinpoints the pair; the second shows the consequence.
+
+#[derive(Debug, PartialEq)]
+enum KeyProblem {
+    HashMismatch { first: usize, second: usize },
+    SetDisagrees { expected: usize, actual: usize },
+    NotFound { index: usize },
+}
+
+fn check_key_type<K: Hash + Eq + Clone>(samples: &[K]) -> Vec<KeyProblem> {
+    let mut problems = Vec::new();
+
+    let state = RandomState::new();
+    for (i, a) in samples.iter().enumerate() {
+        for (j, b) in samples.iter().enumerate().skip(i + 1) {
+            if a == b && state.hash_one(a) != state.hash_one(b) {
+                problems.push(KeyProblem::HashMismatch {
+                    first: i,
+                    second: j,
+                });
+            }
+        }
+    }
+
+    // Groups of equal samples, found with `==` alone: a sample starts a new
+    // group if no earlier sample equals it.
+    let expected = samples
+        .iter()
+        .enumerate()
+        .filter(|(i, s)| !samples[..*i].contains(s))
+        .count();
+    let set: HashSet<K> = samples.iter().cloned().collect();
+    if set.len() != expected {
+        problems.push(KeyProblem::SetDisagrees {
+            expected,
+            actual: set.len(),
+        });
+    }
+    for (index, sample) in samples.iter().enumerate() {
+        if !set.contains(sample) {
+            problems.push(KeyProblem::NotFound { index });
+        }
+    }
+
+    problems
+}
+
+fn sorted<T: Ord + Copy>(set: &HashSet<T>) -> Vec<T> {
+    let mut items: Vec<T> = set.iter().copied().collect();
+    items.sort();
+    items
+}
+
+fn main() {
+    println!("--- HashSet and Custom Keys ---");
+
+    // -------------------------------------------------------------------------
+    // 1. `HashSet` Basics
+    // -------------------------------------------------------------------------
+    // A `HashSet<T>` is a `HashMap<T, ()>`: unique values, O(1) average
+    // `insert`, `contains` and `remove`, in no particular order.
+
+    println!("\n--- 1. `HashSet` Basics ---");
+
+    let mut tags: HashSet<&str> = HashSet::new();
+    assert!(tags.insert("rust")); // `true`: newly added
+    assert!(tags.insert("cli"));
+    assert!(!tags.insert("rust")); // `false`: already present, set unchanged
+    assert_eq!(tags.len(), 2);
+    assert!(tags.contains("cli"));
+    assert!(tags.remove("cli"));
+    assert!(!tags.remove("cli")); // Nothing to remove
+
+    // Removing duplicates while keeping the first occurrence of each:
+    // `insert` returning `bool` is exactly the filter needed.
+    let visits = ["home", "docs", "home", "blog", "docs", "home"];
+    let mut seen = HashSet::new();
+    let first_visits: Vec<&str> = visits
+        .iter()
+        .copied()
+        .filter(|page| seen.insert(*page))
+        .collect();
+    println!("First visits: {:?}", first_visits);
+    assert_eq!(first_visits, ["home", "docs", "blog"]);
+
+    // -------------------------------------------------------------------------
+    // 2. Set Operations
+    // -------------------------------------------------------------------------
+    // The methods return lazy iterators of references; the operators `|`, `&`,
+    // `-` and `^` on two `&HashSet`s build a new set (cloning the elements).
+    // The output order is arbitrary, so we sort before comparing.
+
+    println!("\n--- 2. Set Operations ---");
+
+    let rust: HashSet<&str> = HashSet::from(["ana", "ben", "chen", "dev"]);
+    let go: HashSet<&str> = HashSet::from(["chen", "dev", "eli"]);
+
+    let either = &rust | &go;
+    let both: HashSet<&str> = rust.intersection(&go).copied().collect();
+    let only_rust: HashSet<&str> = rust.difference(&go).copied().collect();
+    let exactly_one = &rust ^ &go; // `symmetric_difference`
+
+    println!("Either:      {:?}", sorted(&either));
+    println!("Both:        {:?}", sorted(&both));
+    println!("Only Rust:   {:?}", sorted(&only_rust));
+    println!("Exactly one: {:?}", sorted(&exactly_one));
+    assert_eq!(sorted(&either), ["ana", "ben", "chen", "dev", "eli"]);
+    assert_eq!(sorted(&both), ["chen", "dev"]);
+    assert_eq!(sorted(&only_rust), ["ana", "ben"]);
+    assert_eq!(sorted(&exactly_one), ["ana", "ben", "eli"]);
+    assert_eq!(&rust - &go, only_rust); // Sets compare by contents
+
+    // Relations between sets:
+    assert!(both.is_subset(&rust));
+    assert!(either.is_superset(&go));
+    assert!(only_rust.is_disjoint(&go));
+
+    // -------------------------------------------------------------------------
+    // 3. A Derived Composite Key
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. A Derived Composite Key ---");
+
+    let booked: HashSet<Seat> = HashSet::from([
+        Seat {
+            row: 12,
+            letter: 'A',
+        },
+        Seat {
+            row: 12,
+            letter: 'B',
+        },
+        Seat {
+            row: 14,
+            letter: 'C',
+        },
+    ]);
+    let wanted = Seat {
+        row: 12,
+        letter: 'B',
+    };
+    println!("{:?} booked? {}", wanted, booked.contains(&wanted));
+    assert!(booked.contains(&wanted));
+    assert!(!booked.contains(&Seat {
+        row: 21,
+        letter: 'B'
+    })); // Fields aren't mixed up
+
+    // A tuple works the same way without declaring a type, at the cost of
+    // naming: `(12, 'B')` says less than `Seat { row: 12, letter: 'B' }`.
+    let booked_tuples: HashSet<(u8, char)> = booked.iter().map(|s| (s.row, s.letter)).collect();
+    assert!(booked_tuples.contains(&(12, 'B')));
+
+    // -------------------------------------------------------------------------
+    // 4. Hand-Written `Hash` and `Eq`
+    // -------------------------------------------------------------------------
+    // `Edge` and `Employee` (above) each route both traits through one method
+    // (`normalized`, `key`), so they can't drift apart.
+
+    println!("\n--- 4. Hand-Written `Hash` and `Eq` ---");
+
+    let roads = [(1, 2), (2, 3), (2, 1), (3, 2), (3, 4)];
+    let edges: HashSet<Edge> = roads.iter().map(|&(a, b)| Edge::new(a, b)).collect();
+    println!("{} roads, {} distinct edges", roads.len(), edges.len());
+    assert_eq!(edges.len(), 3);
+    assert!(edges.contains(&Edge::new(4, 3))); // Either direction finds it
+
+    let mut staff = HashSet::new();
+    staff.insert(Employee {
+        department: "eng",
+        id: 7,
+        display_name: "Sam Lee".to_string(),
+    });
+    let renamed = Employee {
+        department: "eng",
+        id: 7,
+        display_name: "Samantha Lee".to_string(),
+    };
+    assert!(staff.contains(&renamed)); // Same key, different cached name
+    assert!(!staff.insert(renamed)); // Not a second employee
+    // `replace` swaps in the new value (and returns the old one) when an
+    // equal element is already present; `insert` would keep the old one.
+    let newest = Employee {
+        department: "eng",
+        id: 7,
+        display_name: "S. Lee".to_string(),
+    };
+    let old = staff.replace(newest).map(|e| e.display_name);
+    assert_eq!(old.as_deref(), Some("Sam Lee"));
+    let current = staff.iter().next().map(|e| e.display_name.as_str());
+    assert_eq!(current, Some("S. Lee"));
+    assert!(!staff.contains(&Employee {
+        department: "ops",
+        id: 7,
+        display_name: "S. Lee".to_string(),
+    }));
+
+    // -------------------------------------------------------------------------
+    // 5. Catching a Broken `Hash`
+    // -------------------------------------------------------------------------
+    // Samples for the edge types: both directions of every edge, plus
+    // self-loops, where the two directions are the same value.
+
+    println!("\n--- 5. Catching a Broken `Hash` ---");
+
+    let mut pairs = Vec::new();
+    for a in 0..5 {
+        for b in 0..5 {
+            pairs.push((a, b));
+        }
+    }
+
+    let good: Vec<Edge> = pairs.iter().map(|&(a, b)| Edge::new(a, b)).collect();
+    let problems = check_key_type(&good);
+    println!(
+        "Edge: {} problems over {} samples",
+        problems.len(),
+        good.len()
+    );
+    assert_eq!(problems, []);
+
+    let broken: Vec<BrokenEdge> = pairs.iter().map(|&(a, b)| BrokenEdge { a, b }).collect();
+    let problems = check_key_type(&broken);
+    println!("BrokenEdge: {} problems, e.g.", problems.len());
+    for problem in problems.iter().take(3) {
+        match problem {
+            KeyProblem::HashMismatch { first, second } => println!(
+                "  {:?} == {:?}, but the hashes differ",
+                broken[*first], broken[*second]
+            ),
+            KeyProblem::SetDisagrees { expected, actual } => {
+                println!(
+                    "  expected {} distinct edges, the set has {}",
+                    expected, actual
+                )
+            }
+            KeyProblem::NotFound { index } => println!("  {:?} not found", broken[*index]),
+        }
+    }
+    // Every reversed pair is a mismatch: (0, 1)/(1, 0), (0, 2)/(2, 0), ...
+    let mismatches = problems
+        .iter()
+        .filter(|p| matches!(p, KeyProblem::HashMismatch { .. }))
+        .count();
+    assert_eq!(mismatches, 10);
+    // 15 distinct edges, but the set keeps both directions of each: 25.
+    assert!(problems.contains(&KeyProblem::SetDisagrees {
+        expected: 15,
+        actual: 25,
+    }));
+
+    // The derived impls of `Seat` pass too, as derived impls always do.
+    let seats: Vec<Seat> = (1..=3)
+        .flat_map(|row| ['A', 'B', 'A'].map(|letter| Seat { row, letter }))
+        .collect();
+    assert_eq!(check_key_type(&seats), []);
+
+    println!("\n--- End of HashSet and Custom Key Examples ---");
+}
@@ -0,0 +1,327 @@
+// This file covers `Cow<'a, B>` ("clone on write"), the std type for a value
+// that is *either* borrowed or owned. A function that usually returns its
+// input unchanged, but sometimes has to build a new value, can return
+// `Cow<str>` and allocate only in the second case. The example is
+// `normalize_whitespace`, and a counting allocator plus a timing loop show
+// what it saves on input that is already clean.
+
+use std::borrow::Cow;
+use std::hint::black_box;
+use std::time::Instant;
+
+// -------------------------------------------------------------------------
+// 0. Counting Allocations
+// -------------------------------------------------------------------------
+// As in 18-zero-copy-parsing.rs: a global allocator that forwards to the
+// system allocator and counts every `alloc` call.
+
+#[path = "lessons/alloc.rs"]
+mod alloc;
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use alloc::{count_allocations, measure_allocations};
+use fmt::{human_bytes, human_duration};
+
+#[global_allocator]
+static GLOBAL: alloc::CountingAllocator = alloc::CountingAllocator;
+
+// -------------------------------------------------------------------------
+// Clone-on-Write Functions
+// -------------------------------------------------------------------------
+
+// Trims the ends and turns every run of whitespace (spaces, tabs, newlines)
+// into a single space. Text that is already like that is returned as
+// `Cow::Borrowed`, pointing at the input: no allocation, no copy.
+fn normalize_whitespace(input: &str) -> Cow<'_, str> {
+    if is_normalized(input) {
+        return Cow::Borrowed(input);
+    }
+    let mut output = String::with_capacity(input.len());
+    for word in input.split_whitespace() {
+        if !output.is_empty() {
+            output.push(' ');
+        }
+        output.push_str(word);
+    }
+    Cow::Owned(output)
+}
+
+// The fast path: one pass, no allocation.
+fn is_normalized(input: &str) -> bool {
+    let mut previous_was_space = true; // Rejects a leading space
+    for c in input.chars() {
+        if c.is_whitespace() {
+            if c != ' ' || previous_was_space {
+                return false;
+            }
+            previous_was_space = true;
+        } else {
+            previous_was_space = false;
+        }
+    }
+    !input.ends_with(' ')
+}
+
+// The always-allocating version, for comparison: same output, as a `String`.
+fn normalize_whitespace_owned(input: &str) -> String {
+    input.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// A second clone-on-write function, to show how to chain them.
+fn escape_html(input: &str) -> Cow<'_, str> {
+    if !input.contains(['<', '>', '&']) {
+        return Cow::Borrowed(input);
+    }
+    let mut output = String::with_capacity(input.len() + 8);
+    for c in input.chars() {
+        match c {
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '&' => output.push_str("&amp;"),
+            _ => output.push(c),
+        }
+    }
+    Cow::Owned(output)
+}
+
+// Applies a clone-on-write step to a `Cow`, keeping it borrowed if both steps
+// borrowed. If the first step already allocated, the second step's result
+// can't borrow from that temporary `String`; when the second step left it
+// unchanged (returned all of it), we keep the `String` we already have.
+fn then<'a>(value: Cow<'a, str>, step: fn(&str) -> Cow<'_, str>) -> Cow<'a, str> {
+    match value {
+        Cow::Borrowed(s) => step(s),
+        Cow::Owned(s) => match step(&s) {
+            Cow::Borrowed(unchanged) if unchanged.len() == s.len() => Cow::Owned(s),
+            other => Cow::Owned(other.into_owned()),
+        },
+    }
+}
+
+// -------------------------------------------------------------------------
+// `Cow` in a Struct
+// -------------------------------------------------------------------------
+// `Cow<'static, str>` holds either a string literal (free) or a `String`
+// built at runtime, behind one field type.
+
+#[derive(Debug)]
+struct Diagnostic {
+    code: u32,
+    message: Cow<'static, str>,
+}
+
+impl Diagnostic {
+    fn new(code: u32, message: impl Into<Cow<'static, str>>) -> Self {
+        Diagnostic {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+fn main() {
+    println!("--- Cow: Clone on Write ---");
+
+    // -------------------------------------------------------------------------
+    // 1. What `Cow` Is
+    // -------------------------------------------------------------------------
+    // `enum Cow<'a, B: ToOwned + ?Sized> { Borrowed(&'a B), Owned(B::Owned) }`
+    // For `B = str` the variants hold a `&'a str` and a `String`. `Cow`
+    // implements `Deref<Target = B>`, so either way it can be used like a
+    // `&str`. (`ToOwned`, the link between `str` and `String`, is covered in
+    // 21-borrow-and-toowned.rs.)
+
+    println!("\n--- 1. What `Cow` Is ---");
+
+    let borrowed: Cow<str> = Cow::Borrowed("hello");
+    let owned: Cow<str> = Cow::Owned(String::from("hello"));
+    assert_eq!(borrowed, owned); // Compares the text, not the variant
+    assert_eq!(borrowed.len(), 5); // `str` methods through `Deref`
+    assert!(matches!(borrowed, Cow::Borrowed(_)));
+    assert!(matches!(owned, Cow::Owned(_)));
+
+    // `From` impls pick the variant for you.
+    let from_literal: Cow<str> = "literal".into(); // Borrowed
+    let from_string: Cow<str> = format!("built {}", 1).into(); // Owned
+    println!("{:?} / {:?}", from_literal, from_string);
+
+    // std uses it too: `from_utf8_lossy` borrows valid UTF-8 and only
+    // allocates to insert U+FFFD replacement characters.
+    assert!(matches!(
+        String::from_utf8_lossy(b"valid"),
+        Cow::Borrowed(_)
+    ));
+    let repaired = String::from_utf8_lossy(b"bad \xFF byte");
+    assert!(matches!(repaired, Cow::Owned(_)));
+    assert_eq!(repaired, "bad \u{FFFD} byte");
+
+    // -------------------------------------------------------------------------
+    // 2. `normalize_whitespace`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. `normalize_whitespace` ---");
+
+    let inputs = [
+        "already clean",
+        "  padded  ",
+        "tab\tseparated",
+        "many    spaces",
+        "line\nbreak",
+        "",
+    ];
+    for input in inputs {
+        let result = normalize_whitespace(input);
+        let kind = match result {
+            Cow::Borrowed(_) => "borrowed",
+            Cow::Owned(_) => "owned",
+        };
+        println!(
+            "{:<18} -> {:<16} ({})",
+            format!("{:?}", input),
+            format!("{:?}", result),
+            kind
+        );
+        assert_eq!(result, normalize_whitespace_owned(input));
+    }
+    assert!(matches!(
+        normalize_whitespace("already clean"),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(normalize_whitespace(""), Cow::Borrowed(_)));
+    assert!(matches!(normalize_whitespace("  padded  "), Cow::Owned(_)));
+
+    // The borrowed result really is the input, not a copy of it:
+    let text = "no change needed";
+    if let Cow::Borrowed(s) = normalize_whitespace(text) {
+        assert_eq!(s.as_ptr(), text.as_ptr());
+    }
+
+    // -------------------------------------------------------------------------
+    // 3. Using a `Cow`
+    // -------------------------------------------------------------------------
+    // - `&*cow` / `cow.as_ref()`: borrow it as a `&str`
+    // - `into_owned()`: get a `String`, cloning only if it was borrowed
+    // - `to_mut()`: get a `&mut String`, cloning first if it was borrowed
+    //   (this is the "clone on write" in the name)
+
+    println!("\n--- 3. Using a `Cow` ---");
+
+    let mut title = normalize_whitespace("Release notes");
+    assert!(matches!(title, Cow::Borrowed(_)));
+    let (_, clones) = count_allocations(|| title.to_mut().push_str(" (draft)"));
+    assert!(matches!(title, Cow::Owned(_))); // Cloned on the first write...
+    assert!(clones >= 1);
+    let (_, more) = count_allocations(|| title.to_mut().make_ascii_uppercase());
+    assert_eq!(more, 0); // ...and never again
+    assert_eq!(title, "RELEASE NOTES (DRAFT)");
+
+    let owned_again: String = normalize_whitespace(" x ").into_owned(); // No clone: already owned
+    assert_eq!(owned_again, "x");
+
+    // In a struct: a literal costs nothing, a formatted message allocates.
+    let diagnostics = [
+        Diagnostic::new(1, "unexpected end of input"),
+        Diagnostic::new(2, format!("unknown key `{}` on line {}", "colour", 12)),
+    ];
+    for d in &diagnostics {
+        println!("E{:03}: {}", d.code, d.message);
+    }
+    assert!(matches!(diagnostics[0].message, Cow::Borrowed(_)));
+    assert!(matches!(diagnostics[1].message, Cow::Owned(_)));
+
+    // -------------------------------------------------------------------------
+    // 4. Chaining Clone-on-Write Steps
+    // -------------------------------------------------------------------------
+    // Each step only allocates if it changes something, so a clean input
+    // passes through a whole pipeline without a single allocation.
+
+    println!("\n--- 4. Chaining ---");
+
+    let clean = "fish and chips";
+    let (result, allocations) =
+        count_allocations(|| then(normalize_whitespace(clean), escape_html));
+    assert!(matches!(result, Cow::Borrowed(_)));
+    assert_eq!(allocations, 0);
+
+    let messy = "  fish   & chips  ";
+    let result = then(normalize_whitespace(messy), escape_html);
+    println!("{:?} -> {:?}", messy, result);
+    assert_eq!(result, "fish &amp; chips");
+
+    // Only the first step changes it: the `String` it built is kept.
+    let result = then(normalize_whitespace("  <b>  "), escape_html);
+    assert_eq!(result, "&lt;b&gt;");
+    let result = then(normalize_whitespace(" plain "), escape_html);
+    assert_eq!(result, "plain");
+
+    // -------------------------------------------------------------------------
+    // 5. Measuring the Win
+    // -------------------------------------------------------------------------
+    // Most real input (log lines, form fields, config values) is already
+    // clean, which is exactly the case `Cow` makes free.
+
+    println!("\n--- 5. Measuring the Win ---");
+
+    let lines: Vec<String> = (0..10_000)
+        .map(|i| {
+            if i % 10 == 0 {
+                format!("  entry   {}\t(needs cleanup) ", i) // 10% messy
+            } else {
+                format!("entry {} is already clean", i)
+            }
+        })
+        .collect();
+
+    let (cow_total, cow_allocations) = measure_allocations(|| {
+        lines
+            .iter()
+            .map(|line| normalize_whitespace(line).len())
+            .sum::<usize>()
+    });
+    let (owned_total, owned_allocations) = measure_allocations(|| {
+        lines
+            .iter()
+            .map(|line| normalize_whitespace_owned(line).len())
+            .sum::<usize>()
+    });
+    assert_eq!(cow_total, owned_total); // Same output
+    println!(
+        "{} lines: Cow = {} allocations ({}), always-owned = {} allocations ({})",
+        lines.len(),
+        cow_allocations.count,
+        human_bytes(cow_allocations.bytes),
+        owned_allocations.count,
+        human_bytes(owned_allocations.bytes)
+    );
+    assert_eq!(cow_allocations.count, 1_000); // One per messy line
+    assert!(owned_allocations.count >= 2 * lines.len()); // A `Vec` and a `String` per line
+
+    let rounds = 20;
+    let start = Instant::now();
+    for _ in 0..rounds {
+        for line in &lines {
+            black_box(normalize_whitespace(black_box(line)));
+        }
+    }
+    let cow_time = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..rounds {
+        for line in &lines {
+            black_box(normalize_whitespace_owned(black_box(line)));
+        }
+    }
+    let owned_time = start.elapsed();
+    println!(
+        "{} rounds: Cow {}, always-owned {}",
+        rounds,
+        human_duration(cow_time),
+        human_duration(owned_time)
+    );
+    // Typical result: the `Cow` version is several times faster, since for
+    // clean lines it only reads the input. On input that is mostly messy the
+    // two are close: `is_normalized` stops at the first problem it finds.
+
+    println!("\n--- End of Cow Examples ---");
+}
@@ -0,0 +1,304 @@
+// This file takes the single-threaded shared-mutable pattern from the
+// pointers lesson, `Rc<RefCell<T>>`, across threads. The compiler rejects it
+// (and says why), and `Arc<Mutex<T>>` is the thread-safe replacement: `Arc`
+// for shared ownership, `Mutex` for exclusive access. N threads then update a
+// shared counter and a shared `Vec`. The second half is about deadlock: the
+// two ways it happens with mutexes, a real (contained) one, and how to avoid
+// each. 34-threads.rs covers the basics of spawning, `RwLock` and poisoning.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier, Mutex, TryLockError};
+use std::thread;
+use std::time::Duration;
+
+// Counter and log updated together, so they live behind one lock and can
+// never be seen out of step.
+#[derive(Debug, Default)]
+struct Stats {
+    count: u32,
+    events: Vec<(usize, u32)>, // (thread, step)
+}
+
+// -------------------------------------------------------------------------
+// Accounts for the Deadlock Examples
+// -------------------------------------------------------------------------
+
+struct Account {
+    id: u32,
+    balance: Mutex<i64>,
+}
+
+impl Account {
+    fn new(id: u32, balance: i64) -> Arc<Account> {
+        Arc::new(Account {
+            id,
+            balance: Mutex::new(balance),
+        })
+    }
+}
+
+// Deadlock-prone: locks `from` first, then `to`. If one thread transfers
+// A -> B while another transfers B -> A, each can hold its first lock and
+// wait forever for the other's.
+fn transfer_naive(from: &Account, to: &Account, amount: i64) {
+    let mut from_balance = from.balance.lock().unwrap();
+    let mut to_balance = to.balance.lock().unwrap();
+    *from_balance -= amount;
+    *to_balance += amount;
+}
+
+// Deadlock-free: every thread locks the two accounts in the same global order
+// (lowest id first), so no thread can hold the "second" lock while waiting
+// for the "first".
+fn transfer_ordered(from: &Account, to: &Account, amount: i64) {
+    assert_ne!(from.id, to.id, "transfer to the same account");
+    let (first, second) = if from.id < to.id {
+        (from, to)
+    } else {
+        (to, from)
+    };
+    let mut first_balance = first.balance.lock().unwrap();
+    let mut second_balance = second.balance.lock().unwrap();
+    let (from_balance, to_balance) = if from.id < to.id {
+        (&mut *first_balance, &mut *second_balance)
+    } else {
+        (&mut *second_balance, &mut *first_balance)
+    };
+    *from_balance -= amount;
+    *to_balance += amount;
+}
+
+fn main() {
+    println!("--- Shared Mutable State Across Threads ---");
+
+    // -------------------------------------------------------------------------
+    // 1. `Rc<RefCell<T>>`: Shared and Mutable, One Thread Only
+    // -------------------------------------------------------------------------
+    // `Rc` gives shared ownership, `RefCell` gives mutation through a shared
+    // reference, with borrow rules checked at runtime (13-pointers.rs).
+
+    println!("\n--- 1. `Rc<RefCell<T>>` ---");
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let producers: Vec<_> = (0..3).map(|_| Rc::clone(&events)).collect();
+    for (i, producer) in producers.iter().enumerate() {
+        producer.borrow_mut().push(i);
+    }
+    println!("Events: {:?}", events.borrow());
+    assert_eq!(*events.borrow(), [0, 1, 2]);
+
+    // Neither half survives a move to another thread:
+    /*
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let events2 = Rc::clone(&events);
+    thread::spawn(move || events2.borrow_mut().push(1));
+    // error[E0277]: `Rc<RefCell<Vec<i32>>>` cannot be sent between threads safely
+    */
+    // `Rc`'s reference count is a plain integer: two threads cloning or
+    // dropping at once could corrupt it. So `Rc` isn't `Send`.
+    /*
+    let counter = Arc::new(RefCell::new(0));
+    let c2 = Arc::clone(&counter);
+    thread::spawn(move || *c2.borrow_mut() += 1);
+    // error[E0277]: `RefCell<i32>` cannot be shared between threads safely
+    */
+    // Fixing the count with `Arc` isn't enough: `RefCell`'s borrow flag isn't
+    // atomic either, so it isn't `Sync`, and `Arc<T>` is only `Send` if `T` is
+    // `Sync`. `Mutex<T>` is `Sync` (for `T: Send`): that's what it's for.
+    // (The rules behind `Send` and `Sync`: see 64-send-sync-and-auto-traits.rs.)
+
+    // -------------------------------------------------------------------------
+    // 2. `Arc<Mutex<T>>`: The Thread-Safe Version
+    // -------------------------------------------------------------------------
+    // | Single-threaded     | Multi-threaded           |
+    // |---------------------|--------------------------|
+    // | `Rc::clone`         | `Arc::clone`             |
+    // | `cell.borrow_mut()` | `mutex.lock().unwrap()`  |
+    // | conflict: panics    | conflict: *waits*        |
+    // That last row is why deadlock (sections 3 and 4) is possible.
+
+    println!("\n--- 2. `Arc<Mutex<T>>` ---");
+
+    let threads = 8;
+    let steps = 500;
+
+    // Two independent pieces of state, each behind its own lock.
+    let counter = Arc::new(Mutex::new(0u32));
+    let log: Arc<Mutex<Vec<usize>>> = Arc::new(Mutex::new(Vec::new()));
+    let handles: Vec<_> = (0..threads)
+        .map(|t| {
+            let counter = Arc::clone(&counter);
+            let log = Arc::clone(&log);
+            thread::spawn(move || {
+                for _ in 0..steps {
+                    *counter.lock().unwrap() += 1;
+                }
+                log.lock().unwrap().push(t); // "Thread t finished"
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let finished = log.lock().unwrap().clone();
+    println!(
+        "Counter: {}, finish order: {:?}",
+        counter.lock().unwrap(),
+        finished
+    );
+    assert_eq!(*counter.lock().unwrap(), threads as u32 * steps);
+    let mut sorted = finished;
+    sorted.sort();
+    assert_eq!(sorted, (0..threads).collect::<Vec<_>>()); // Each exactly once
+
+    // State that must stay consistent goes behind *one* lock. With `count`
+    // and `events` under separate locks, another thread could see the count
+    // already incremented but the event not yet pushed.
+    let stats = Arc::new(Mutex::new(Stats::default()));
+    let handles: Vec<_> = (0..threads)
+        .map(|t| {
+            let stats = Arc::clone(&stats);
+            thread::spawn(move || {
+                for step in 0..steps {
+                    let mut stats = stats.lock().unwrap();
+                    stats.count += 1;
+                    stats.events.push((t, step));
+                    // Invariant, true whenever the lock is free:
+                    assert_eq!(stats.count as usize, stats.events.len());
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    // Every thread is done, so take the `Stats` out of the `Arc<Mutex<_>>`.
+    let stats = Arc::try_unwrap(stats).unwrap().into_inner().unwrap();
+    assert_eq!(stats.count, threads as u32 * steps);
+    // Steps from one thread stay in order, even though threads interleave.
+    for t in 0..threads {
+        let mine: Vec<u32> = stats
+            .events
+            .iter()
+            .filter(|e| e.0 == t)
+            .map(|e| e.1)
+            .collect();
+        assert_eq!(mine, (0..steps).collect::<Vec<_>>());
+    }
+    println!("{} events, per-thread order preserved", stats.events.len());
+
+    // -------------------------------------------------------------------------
+    // 3. Deadlock, Kind 1: Locking a Mutex You Already Hold
+    // -------------------------------------------------------------------------
+    // `std::sync::Mutex` isn't re-entrant: a second `lock()` from the thread
+    // that holds it waits for itself, forever (or panics; it's unspecified).
+    // The easiest way to get there is a guard that lives longer than it looks.
+    // We use `try_lock` to observe the lock is held, instead of hanging.
+
+    println!("\n--- 3. Deadlock: Re-Locking ---");
+
+    let queue = Mutex::new(vec![3, 2, 1]);
+
+    // The temporary guard in a `while let` condition lives until the end of
+    // the loop body, so calling `queue.lock()` in the body would deadlock.
+    while let Some(job) = queue.lock().unwrap().pop() {
+        let still_locked = matches!(queue.try_lock(), Err(TryLockError::WouldBlock));
+        assert!(still_locked);
+        if job == 1 {
+            // queue.lock().unwrap().push(99); // Would hang right here
+        }
+    }
+
+    // Fix: end the guard's life before the body, with a separate statement.
+    let queue = Mutex::new(vec![3, 2, 1]);
+    let mut done = Vec::new();
+    loop {
+        let next = queue.lock().unwrap().pop(); // Guard dropped at the `;`
+        let Some(job) = next else { break };
+        done.push(job);
+        if job == 1 {
+            queue.lock().unwrap().push(99); // Fine now
+        }
+    }
+    assert_eq!(done, [1, 99, 2, 3]);
+
+    // -------------------------------------------------------------------------
+    // 4. Deadlock, Kind 2: Two Locks in Opposite Orders
+    // -------------------------------------------------------------------------
+    // Thread 1 holds A and waits for B; thread 2 holds B and waits for A.
+    // Below, a `Barrier` makes sure both threads hold their first lock before
+    // either asks for its second, so this deadlocks every time. The main
+    // thread waits with a timeout to detect it; the two stuck threads are
+    // abandoned, and end when the process exits.
+
+    println!("\n--- 4. Deadlock: Lock Ordering ---");
+
+    let a = Account::new(1, 100);
+    let b = Account::new(2, 100);
+    let barrier = Arc::new(Barrier::new(2));
+    let (done_tx, done_rx) = mpsc::channel();
+    for (from, to) in [
+        (Arc::clone(&a), Arc::clone(&b)),
+        (Arc::clone(&b), Arc::clone(&a)),
+    ] {
+        let barrier = Arc::clone(&barrier);
+        let done_tx = done_tx.clone();
+        thread::spawn(move || {
+            let _first = from.balance.lock().unwrap();
+            barrier.wait(); // Both threads now hold one lock each
+            let _second = to.balance.lock().unwrap(); // Never succeeds
+            done_tx.send(()).unwrap();
+        });
+    }
+    drop(done_tx);
+    let outcome = done_rx.recv_timeout(Duration::from_millis(300));
+    println!("Opposite-order locking: {:?}", outcome);
+    assert_eq!(outcome, Err(mpsc::RecvTimeoutError::Timeout)); // Stuck
+
+    // `transfer_naive` has the same bug, just not on every run, which is
+    // what makes real deadlocks hard to find.
+    let c = Account::new(3, 50);
+    let d = Account::new(4, 50);
+    transfer_naive(&c, &d, 10); // Fine with a single thread
+    assert_eq!(*d.balance.lock().unwrap(), 60);
+
+    // The fix: a global lock order. Many threads transferring in both
+    // directions all finish, and no money is created or lost.
+    let accounts: Vec<Arc<Account>> = (10..14).map(|id| Account::new(id, 1_000)).collect();
+    let accounts = Arc::new(accounts);
+    let handles: Vec<_> = (0..threads)
+        .map(|t| {
+            let accounts = Arc::clone(&accounts);
+            thread::spawn(move || {
+                for i in 0..steps as usize {
+                    let from = (t + i) % accounts.len();
+                    let to = (t + i * 3 + 1) % accounts.len();
+                    if from != to {
+                        transfer_ordered(&accounts[from], &accounts[to], 1);
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let total: i64 = accounts
+        .iter()
+        .map(|acc| *acc.balance.lock().unwrap())
+        .sum();
+    println!("Ordered transfers finished; total balance {}", total);
+    assert_eq!(total, 4_000);
+
+    // Other ways out, in order of preference:
+    // - Hold one lock at a time: copy what you need, drop the guard, then lock
+    //   the next mutex. Often possible, and nothing to get wrong.
+    // - Put data that's updated together behind one mutex (as with `Stats`).
+    // - Use a global lock order, as `transfer_ordered` does.
+    // - `try_lock` and back off (release everything, retry) when it fails.
+    //   Deadlock-free, but can livelock under contention.
+
+    println!("\n--- End of Shared Mutable State Examples ---");
+}
@@ -0,0 +1,458 @@
+// This file is a decision guide for interior mutability: changing data through
+// a shared `&` reference. The same small cache, a memo table for the Collatz
+// sequence, is written four ways: with `Cell`, `RefCell`, `Mutex` and
+// `RwLock`. Each version works when used correctly, and each fails in its own
+// way when misused: a compile error (`Cell` can't hand out references, and
+// neither `Cell` nor `RefCell` can cross threads), a runtime panic
+// (`RefCell`), or a deadlock (`Mutex`, `RwLock`).
+//
+// | Type         | Threads | Access                 | Misuse fails with     |
+// |--------------|---------|------------------------|-----------------------|
+// | `Cell<T>`    | one     | copy in / copy out     | compile error         |
+// | `RefCell<T>` | one     | `&T`/`&mut T`, checked | panic                 |
+// | `Mutex<T>`   | many    | one `&mut T` at a time | deadlock              |
+// | `RwLock<T>`  | many    | many `&T` or one `&mut`| deadlock              |
+//
+// Rules of thumb: `Cell` for small `Copy` values (counters, flags); `RefCell`
+// for anything else on one thread; `Mutex` when threads share it; `RwLock`
+// when they mostly read. `Cell`/`RefCell` are the pointers lesson's tools
+// (13-pointers.rs); `Mutex` is covered in 57-shared-state-arc-mutex.rs.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, RwLock, TryLockError};
+use std::thread;
+use std::time::Duration;
+
+// -------------------------------------------------------------------------
+// The Common Interface
+// -------------------------------------------------------------------------
+// `get` and `insert` take `&self`: that's the point of interior mutability.
+// `get_or_compute` is shared by all four, and is written so that no borrow
+// or lock is held while `compute` runs, because `compute` may use the cache
+// itself (the Collatz function below recurses through it).
+
+trait Memo {
+    fn get(&self, n: u64) -> Option<u64>;
+    fn insert(&self, n: u64, steps: u64);
+    fn hits(&self) -> u32;
+
+    fn get_or_compute(&self, n: u64, compute: impl FnOnce() -> u64) -> u64 {
+        if let Some(steps) = self.get(n) {
+            return steps;
+        }
+        let steps = compute(); // Nothing borrowed or locked here
+        self.insert(n, steps);
+        steps
+    }
+}
+
+// Steps for `n` to reach 1 under n -> n/2 (even) or 3n+1 (odd).
+fn collatz_steps(memo: &impl Memo, n: u64) -> u64 {
+    memo.get_or_compute(n, || {
+        if n == 1 {
+            0
+        } else if n.is_multiple_of(2) {
+            1 + collatz_steps(memo, n / 2)
+        } else {
+            1 + collatz_steps(memo, 3 * n + 1)
+        }
+    })
+}
+
+// -------------------------------------------------------------------------
+// 1. `Cell`: Copy In, Copy Out
+// -------------------------------------------------------------------------
+// A `Cell<T>` never hands out a reference to its contents, only copies
+// (`get`, for `T: Copy`) or whole replacements (`set`, `replace`, `take`).
+// No references means no borrow tracking and no way to fail at runtime.
+// So a `Cell` cache stores `Copy` values in fixed slots: one per `n` below
+// `slots.len()`, larger `n` aren't cached.
+
+struct CellMemo {
+    slots: Box<[Cell<Option<u64>>]>,
+    hits: Cell<u32>,
+}
+
+impl CellMemo {
+    fn new(size: usize) -> Self {
+        CellMemo {
+            slots: (0..size).map(|_| Cell::new(None)).collect(),
+            hits: Cell::new(0),
+        }
+    }
+}
+
+impl Memo for CellMemo {
+    fn get(&self, n: u64) -> Option<u64> {
+        let steps = self.slots.get(n as usize)?.get();
+        if steps.is_some() {
+            self.hits.set(self.hits.get() + 1);
+        }
+        steps
+    }
+
+    fn insert(&self, n: u64, steps: u64) {
+        if let Some(slot) = self.slots.get(n as usize) {
+            slot.set(Some(steps));
+        }
+    }
+
+    fn hits(&self) -> u32 {
+        self.hits.get()
+    }
+}
+
+// -------------------------------------------------------------------------
+// 2. `RefCell`: Borrow Checking at Runtime
+// -------------------------------------------------------------------------
+// `borrow()`/`borrow_mut()` hand out guards that act like `&T`/`&mut T`, and
+// `RefCell` counts them: a `borrow_mut` while any other guard is alive
+// panics (and so does a `borrow` during a `borrow_mut`).
+
+struct RefCellMemo {
+    entries: RefCell<HashMap<u64, u64>>,
+    hits: Cell<u32>, // A counter is the textbook `Cell`
+}
+
+impl RefCellMemo {
+    fn new() -> Self {
+        RefCellMemo {
+            entries: RefCell::new(HashMap::new()),
+            hits: Cell::new(0),
+        }
+    }
+
+    // MISUSE: keeps the `borrow_mut` guard alive while `compute` runs. If
+    // `compute` reads the cache, that `borrow()` panics.
+    fn get_or_compute_holding(&self, n: u64, compute: impl FnOnce() -> u64) -> u64 {
+        let mut entries = self.entries.borrow_mut();
+        if let Some(&steps) = entries.get(&n) {
+            return steps;
+        }
+        let steps = compute(); // `entries` is still borrowed here
+        entries.insert(n, steps);
+        steps
+    }
+}
+
+impl Memo for RefCellMemo {
+    fn get(&self, n: u64) -> Option<u64> {
+        let steps = self.entries.borrow().get(&n).copied(); // Guard dropped at the `;`
+        if steps.is_some() {
+            self.hits.set(self.hits.get() + 1);
+        }
+        steps
+    }
+
+    fn insert(&self, n: u64, steps: u64) {
+        self.entries.borrow_mut().insert(n, steps);
+    }
+
+    fn hits(&self) -> u32 {
+        self.hits.get()
+    }
+}
+
+// -------------------------------------------------------------------------
+// 3. `Mutex`: One Thread at a Time
+// -------------------------------------------------------------------------
+// Like a `RefCell` that only has `borrow_mut`, and that *waits* instead of
+// panicking when the value is in use. Waiting is right when another thread
+// holds the lock; when the current thread holds it, it waits forever.
+
+struct MutexMemo {
+    entries: Mutex<HashMap<u64, u64>>,
+    hits: AtomicU32, // The thread-safe counterpart of `Cell<u32>`
+}
+
+impl MutexMemo {
+    fn new() -> Self {
+        MutexMemo {
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU32::new(0),
+        }
+    }
+
+    // MISUSE: the same mistake as `RefCellMemo::get_or_compute_holding`.
+    // Here a re-entrant `compute` deadlocks instead of panicking.
+    fn get_or_compute_holding(&self, n: u64, compute: impl FnOnce() -> u64) -> u64 {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(&steps) = entries.get(&n) {
+            return steps;
+        }
+        let steps = compute(); // `entries` is still locked here
+        entries.insert(n, steps);
+        steps
+    }
+}
+
+impl Memo for MutexMemo {
+    fn get(&self, n: u64) -> Option<u64> {
+        let steps = self.entries.lock().unwrap().get(&n).copied();
+        if steps.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+        steps
+    }
+
+    fn insert(&self, n: u64, steps: u64) {
+        self.entries.lock().unwrap().insert(n, steps);
+    }
+
+    fn hits(&self) -> u32 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+// -------------------------------------------------------------------------
+// 4. `RwLock`: Many Readers or One Writer
+// -------------------------------------------------------------------------
+// Lookups (the common case in a warm cache) take a shared `read` lock and
+// can run in parallel; inserts take the exclusive `write` lock. A read guard
+// can't be *upgraded*: asking for `write` while holding `read` waits for all
+// readers to leave, including yourself.
+
+struct RwLockMemo {
+    entries: RwLock<HashMap<u64, u64>>,
+    hits: AtomicU32,
+}
+
+impl RwLockMemo {
+    fn new() -> Self {
+        RwLockMemo {
+            entries: RwLock::new(HashMap::new()),
+            hits: AtomicU32::new(0),
+        }
+    }
+
+    // MISUSE: "upgrades" by taking `write` while the `read` guard is alive.
+    fn insert_if_missing_holding(&self, n: u64, steps: u64) {
+        let entries = self.entries.read().unwrap();
+        if !entries.contains_key(&n) {
+            self.entries.write().unwrap().insert(n, steps); // Waits for `entries`
+        }
+    }
+}
+
+impl Memo for RwLockMemo {
+    fn get(&self, n: u64) -> Option<u64> {
+        let steps = self.entries.read().unwrap().get(&n).copied();
+        if steps.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+        steps
+    }
+
+    fn insert(&self, n: u64, steps: u64) {
+        self.entries.write().unwrap().insert(n, steps);
+    }
+
+    fn hits(&self) -> u32 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+// Runs `f` on another thread and reports whether it finished in time. A
+// deadlocked thread is abandoned; it ends when the process exits.
+fn finishes_within(timeout: Duration, f: impl FnOnce() + Send + 'static) -> bool {
+    let (done_tx, done_rx) = mpsc::channel();
+    thread::spawn(move || {
+        f();
+        let _ = done_tx.send(());
+    });
+    done_rx.recv_timeout(timeout).is_ok()
+}
+
+fn main() {
+    println!("--- Interior Mutability: A Decision Guide ---");
+
+    let expected_27 = 111; // Collatz steps for 27, a famously long one
+
+    // -------------------------------------------------------------------------
+    // 1. `Cell`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. `Cell` ---");
+
+    let cell_memo = CellMemo::new(10_000);
+    assert_eq!(collatz_steps(&cell_memo, 27), expected_27);
+    let cold = cell_memo.hits();
+    assert_eq!(collatz_steps(&cell_memo, 27), expected_27); // Now a single hit
+    println!(
+        "steps(27) = {}, hits: {} then {}",
+        expected_27,
+        cold,
+        cell_memo.hits()
+    );
+    assert_eq!(cell_memo.hits(), cold + 1);
+
+    // Misuse is a compile error. No `&T` into a `Cell`, so no mutating the
+    // contents in place, and `get` needs `T: Copy`:
+    /*
+    let list: Cell<Vec<u64>> = Cell::new(Vec::new());
+    list.get().push(1);
+    // error[E0599]: the method `get` exists for struct `Cell<Vec<u64>>`, but its trait bounds were not satisfied
+    */
+    // (`let mut v = list.take(); v.push(1); list.set(v);` works, but at that
+    // point a `RefCell` says what you mean.)
+    //
+    // And a `Cell` is `Send` but not `Sync`: no two threads may touch it.
+    /*
+    thread::scope(|s| {
+        s.spawn(|| collatz_steps(&cell_memo, 97));
+    });
+    // error[E0277]: `Cell<Option<u64>>` cannot be shared between threads safely
+    */
+
+    // -------------------------------------------------------------------------
+    // 2. `RefCell`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. `RefCell` ---");
+
+    let refcell_memo = RefCellMemo::new();
+    assert_eq!(collatz_steps(&refcell_memo, 27), expected_27);
+    assert_eq!(collatz_steps(&refcell_memo, 54), expected_27 + 1); // 54 -> 27: one miss, one hit
+    assert_eq!(refcell_memo.hits(), 1);
+
+    // Misuse: a borrow held across the re-entrant call panics, with
+    // "RefCell already mutably borrowed" (printed to stderr).
+    let misused = RefCellMemo::new();
+    let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        misused.get_or_compute_holding(6, || 1 + collatz_steps(&misused, 3))
+    }));
+    println!(
+        "Re-entrant call while borrowed: panicked = {}",
+        outcome.is_err()
+    );
+    assert!(outcome.is_err());
+
+    // `try_borrow`/`try_borrow_mut` return a `Result` instead of panicking,
+    // for code that can do something sensible when the value is busy.
+    let guard = refcell_memo.entries.borrow_mut();
+    assert!(refcell_memo.entries.try_borrow().is_err());
+    drop(guard);
+    assert!(refcell_memo.entries.try_borrow().is_ok());
+
+    // Also `!Sync`, exactly like `Cell`:
+    /*
+    thread::scope(|s| {
+        s.spawn(|| collatz_steps(&refcell_memo, 97));
+    });
+    // error[E0277]: `RefCell<HashMap<u64, u64>>` cannot be shared between threads safely
+    */
+
+    // -------------------------------------------------------------------------
+    // 3. `Mutex`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. `Mutex` ---");
+
+    // Shared by several threads, each filling in different numbers.
+    let mutex_memo = MutexMemo::new();
+    thread::scope(|s| {
+        for t in 0..4 {
+            let memo = &mutex_memo;
+            s.spawn(move || {
+                for n in (1 + t..1_000).step_by(4) {
+                    collatz_steps(memo, n);
+                }
+            });
+        }
+    });
+    assert_eq!(collatz_steps(&mutex_memo, 27), expected_27);
+    println!(
+        "4 threads, 999 numbers: {} cached values, {} hits",
+        mutex_memo.entries.lock().unwrap().len(),
+        mutex_memo.hits()
+    );
+
+    // Misuse: the same held-across-`compute` mistake deadlocks.
+    let misused = Arc::new(MutexMemo::new());
+    let finished = finishes_within(Duration::from_millis(300), move || {
+        misused.get_or_compute_holding(6, || 1 + collatz_steps(&*misused, 3));
+    });
+    println!("Re-entrant call while locked: finished = {}", finished);
+    assert!(!finished);
+
+    // -------------------------------------------------------------------------
+    // 4. `RwLock`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. `RwLock` ---");
+
+    let rwlock_memo = RwLockMemo::new();
+    for n in 1..1_000 {
+        collatz_steps(&rwlock_memo, n);
+    }
+    // Warm cache: now every lookup is a read, and readers share the lock.
+    let before = rwlock_memo.hits();
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for n in 1..1_000 {
+                    collatz_steps(&rwlock_memo, n);
+                }
+            });
+        }
+    });
+    assert_eq!(rwlock_memo.hits() - before, 4 * 999);
+
+    // Two read guards at once is fine; a write during a read is not.
+    let reader_a = rwlock_memo.entries.read().unwrap();
+    let reader_b = rwlock_memo.entries.read().unwrap();
+    assert_eq!(reader_a.len(), reader_b.len());
+    let blocked = matches!(
+        rwlock_memo.entries.try_write(),
+        Err(TryLockError::WouldBlock)
+    );
+    assert!(blocked);
+    drop((reader_a, reader_b));
+
+    // Misuse: the read-then-write "upgrade" deadlocks.
+    let misused = Arc::new(RwLockMemo::new());
+    let finished = finishes_within(Duration::from_millis(300), move || {
+        misused.insert_if_missing_holding(1, 0);
+    });
+    println!("Write while holding a read guard: finished = {}", finished);
+    assert!(!finished);
+    // Fix: drop the read guard first, then re-check under the write lock
+    // (another thread may have inserted in between), or just use
+    // `entries.write().unwrap().entry(n).or_insert(steps)`.
+
+    // -------------------------------------------------------------------------
+    // 5. All Four Agree
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 5. All Four Agree ---");
+
+    let memos: (CellMemo, RefCellMemo, MutexMemo, RwLockMemo) = (
+        CellMemo::new(100),
+        RefCellMemo::new(),
+        MutexMemo::new(),
+        RwLockMemo::new(),
+    );
+    for n in 1..=200 {
+        let steps = collatz_steps(&memos.0, n);
+        assert_eq!(collatz_steps(&memos.1, n), steps);
+        assert_eq!(collatz_steps(&memos.2, n), steps);
+        assert_eq!(collatz_steps(&memos.3, n), steps);
+    }
+    println!(
+        "Hits for n = 1..=200: Cell {}, RefCell {}, Mutex {}, RwLock {}",
+        memos.0.hits(),
+        memos.1.hits(),
+        memos.2.hits(),
+        memos.3.hits()
+    );
+    assert_eq!(memos.1.hits(), memos.2.hits());
+    assert_eq!(memos.2.hits(), memos.3.hits());
+    // The `Cell` version only has slots for `n < 100`.
+    assert_eq!(memos.0.get(150), None);
+    assert_eq!(memos.1.get(150), Some(15));
+
+    println!("\n--- End of Interior Mutability Guide Examples ---");
+}
@@ -0,0 +1,284 @@
+// This file implements the `Future` trait by hand, to show what `async` and
+// `.await` turn into. `Delay` is a timer future: the first `poll` starts a
+// background thread that sleeps and then calls the task's `Waker`, and every
+// `poll` until then returns `Poll::Pending`. We then write the state machine
+// that an `async fn` compiles to, run everything with a ten-line executor of
+// our own, and finally await the same futures under tokio.
+// tokio is an optional dependency behind the `ecosystem` feature:
+//     cargo run --features ecosystem --bin 59-implementing-future
+// (16-asynchronous.rs introduces `async`/`.await` and tokio itself.)
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use fmt::human_duration;
+
+// -------------------------------------------------------------------------
+// The `Future` Trait
+// -------------------------------------------------------------------------
+// pub trait Future {
+//     type Output;
+//     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>;
+// }
+// - `poll` must not block. It returns `Poll::Ready(value)` when done, or
+//   `Poll::Pending` when it's still waiting for something.
+// - Before returning `Pending`, it must arrange for `cx.waker().wake()` to be
+//   called once progress is possible; the executor only polls again after
+//   that. A future that returns `Pending` without doing so is never polled
+//   again, and hangs.
+// - `Pin<&mut Self>` promises the future won't move between polls (the
+//   futures `async` generates may point into themselves). Types with no such
+//   self-references are `Unpin`, and can ignore it, as `Delay` does.
+
+// -------------------------------------------------------------------------
+// 1. `Delay`: A Hand-Written Timer Future
+// -------------------------------------------------------------------------
+
+// State shared between the future and its timer thread.
+#[derive(Default)]
+struct TimerState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+struct Delay {
+    duration: Duration,
+    state: Arc<Mutex<TimerState>>,
+    started: bool,
+    polls: u32, // For the lesson: how often the executor asked
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        Delay {
+            duration,
+            state: Arc::new(Mutex::new(TimerState::default())),
+            started: false,
+            polls: 0,
+        }
+    }
+
+    // Futures are lazy: nothing happens until the first `poll`, so that's
+    // where the timer thread starts.
+    fn start_timer(&mut self) {
+        let state = Arc::clone(&self.state);
+        let duration = self.duration;
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = state.lock().unwrap();
+            state.completed = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake(); // "Poll me again"
+            }
+        });
+        self.started = true;
+    }
+}
+
+impl Future for Delay {
+    type Output = u32; // The number of polls it took, to print below
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        // `Delay` is `Unpin` (no self-references), so we may take `&mut Self`.
+        let this = self.get_mut();
+        this.polls += 1;
+        if !this.started {
+            this.start_timer();
+        }
+        let mut state = this.state.lock().unwrap();
+        if state.completed {
+            return Poll::Ready(this.polls);
+        }
+        // Store the waker of the task polling us *now*: a future can be moved
+        // to another task between polls, and the old waker would wake the
+        // wrong one. `will_wake` skips the clone when it's unchanged.
+        match &state.waker {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            _ => state.waker = Some(cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
+
+// -------------------------------------------------------------------------
+// 2. What an `async fn` Desugars To
+// -------------------------------------------------------------------------
+// async fn wait_then_double(n: u32, ms: u64) -> u32 {
+//     let polls = Delay::new(Duration::from_millis(ms)).await;
+//     println!("  waited ({} polls)", polls);
+//     n * 2
+// }
+// The compiler turns this into an anonymous type implementing `Future`: an
+// enum with one variant per `.await` point, holding the locals that live
+// across it. Written out by hand, it's roughly:
+
+enum WaitThenDouble {
+    Start { n: u32, ms: u64 },
+    Waiting { n: u32, delay: Delay }, // Suspended at the `.await`
+    Done,
+}
+
+fn wait_then_double(n: u32, ms: u64) -> WaitThenDouble {
+    WaitThenDouble::Start { n, ms } // Calling it runs nothing yet
+}
+
+impl Future for WaitThenDouble {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        let this = self.get_mut();
+        loop {
+            match this {
+                // Code before the first `.await`, then start awaiting.
+                WaitThenDouble::Start { n, ms } => {
+                    let delay = Delay::new(Duration::from_millis(*ms));
+                    *this = WaitThenDouble::Waiting { n: *n, delay };
+                }
+                // `.await` = poll the inner future; if it's pending, so are we.
+                WaitThenDouble::Waiting { n, delay } => {
+                    let polls = match Pin::new(delay).poll(cx) {
+                        Poll::Ready(polls) => polls,
+                        Poll::Pending => return Poll::Pending,
+                    };
+                    // Code after the `.await`, up to the `return`.
+                    println!("  waited ({} polls)", polls);
+                    let result = *n * 2;
+                    *this = WaitThenDouble::Done;
+                    return Poll::Ready(result);
+                }
+                WaitThenDouble::Done => panic!("`async fn` resumed after completion"),
+            }
+        }
+    }
+}
+
+// The real `async fn`, for comparison. Same behaviour, generated state machine.
+async fn wait_then_double_async(n: u32, ms: u64) -> u32 {
+    let polls = Delay::new(Duration::from_millis(ms)).await;
+    println!("  waited ({} polls)", polls);
+    n * 2
+}
+
+// -------------------------------------------------------------------------
+// 3. A Minimal Executor
+// -------------------------------------------------------------------------
+// Runs one future to completion on the current thread: poll it, and if it's
+// pending, park the thread until its waker unparks it. `Wake` turns any
+// `Arc<impl Wake>` into a `Waker`.
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// Returns the output and how many times the future was polled.
+fn block_on<F: Future>(future: F) -> (F::Output, u32) {
+    let mut future = std::pin::pin!(future); // Pinned on the stack
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut polls = 0;
+    loop {
+        polls += 1;
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return (output, polls);
+        }
+        thread::park(); // May wake spuriously; then we just poll again
+    }
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    println!("--- Implementing Future by Hand ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Polling `Delay` Directly
+    // -------------------------------------------------------------------------
+    // No executor at all: poll once with a waker that does nothing, to see
+    // the `Pending` result and the lazily started timer.
+
+    println!("\n--- 1. Polling `Delay` by Hand ---");
+
+    let mut delay = Delay::new(Duration::from_millis(20));
+    assert!(!delay.started); // Creating it did nothing
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(Pin::new(&mut delay).poll(&mut cx), Poll::Pending);
+    assert!(delay.started); // The first poll started the timer
+    thread::sleep(Duration::from_millis(50)); // Blocking is fine here: not in a future
+    assert_eq!(Pin::new(&mut delay).poll(&mut cx), Poll::Ready(2));
+    println!("Pending, then Ready after the timer fired");
+
+    // -------------------------------------------------------------------------
+    // 2. The Desugared State Machine
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. Hand-Written vs Generated State Machine ---");
+
+    // `block_on` can't run inside tokio's runtime thread (it would block
+    // it), so these run on their own thread.
+    let (manual, generated) = thread::spawn(|| {
+        println!("Hand-written:");
+        let manual = block_on(wait_then_double(21, 30));
+        println!("`async fn`:");
+        let generated = block_on(wait_then_double_async(21, 30));
+        (manual, generated)
+    })
+    .join()
+    .unwrap();
+    println!("Results: {:?} and {:?} (output, polls)", manual, generated);
+    assert_eq!(manual.0, 42);
+    assert_eq!(generated.0, 42);
+    // Polled once to start, and once more after the single wake-up (a
+    // spurious wake-up from `park` would add a poll, but not change the result).
+    assert!(manual.1 >= 2);
+    assert!(generated.1 >= 2);
+
+    // -------------------------------------------------------------------------
+    // 3. Awaiting Under tokio
+    // -------------------------------------------------------------------------
+    // tokio is just another executor: it polls our futures and reacts to our
+    // `Waker` calls. It knows nothing about `Delay`'s thread.
+
+    println!("\n--- 3. Awaiting Under tokio ---");
+
+    let start = Instant::now();
+    let polls = Delay::new(Duration::from_millis(50)).await;
+    println!(
+        "Delay(50ms) finished after {}, {} polls",
+        human_duration(start.elapsed()),
+        polls
+    );
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    assert_eq!(polls, 2);
+
+    assert_eq!(wait_then_double(5, 10).await, 10); // The hand-written one too
+
+    // Concurrency comes from the executor polling several futures in turn:
+    // three 100ms delays awaited together take about 100ms, not 300ms.
+    let start = Instant::now();
+    let (a, b, c) = tokio::join!(
+        Delay::new(Duration::from_millis(100)),
+        wait_then_double(1, 100),
+        wait_then_double_async(2, 100),
+    );
+    let elapsed = start.elapsed();
+    println!("join! of three 100ms futures: {}", human_duration(elapsed));
+    assert_eq!((b, c), (2, 4));
+    assert!(a >= 2);
+    assert!(elapsed < Duration::from_millis(250));
+
+    // `tokio::time::sleep` is the production version of `Delay`: instead of a
+    // thread per timer, one timer wheel in the runtime wakes all of them.
+    tokio::time::sleep(Duration::from_millis(1)).await;
+
+    println!("\n--- End of Implementing Future Examples ---");
+}
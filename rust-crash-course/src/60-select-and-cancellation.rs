@@ -0,0 +1,306 @@
+// This file covers racing futures with `tokio::select!` and what happens to
+// the ones that lose: they are *dropped*, which cancels them at whatever
+// `.await` they were suspended on. That gives cheap timeouts and shutdowns,
+// and one classic bug: state that was half-updated when the `.await` hit.
+// `CancellationToken` (from `tokio-util`) turns "please stop" into something
+// every task can `select!` on and clean up after.
+//
+// The runtime starts with time *paused*: sleeps don't really wait, the clock
+// jumps ahead whenever every task is idle. So the timings below are exact,
+// and the whole file runs in milliseconds. tokio and tokio-util are optional
+// dependencies behind the `ecosystem` feature:
+//     cargo run --features ecosystem --bin 60-select-and-cancellation
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::time::{Instant, sleep};
+use tokio_util::sync::CancellationToken;
+
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use fmt::human_duration;
+
+// Pretends to ask `server`, which takes `latency` to answer.
+async fn fetch(server: &str, latency: Duration) -> String {
+    sleep(latency).await;
+    format!("reply from {}", server)
+}
+
+// Records when it's dropped, to show a losing branch being cancelled.
+struct OnDrop<'a> {
+    name: &'static str,
+    log: &'a Mutex<Vec<String>>,
+}
+
+impl Drop for OnDrop<'_> {
+    fn drop(&mut self) {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} dropped", self.name));
+    }
+}
+
+async fn fetch_logged(server: &'static str, latency: Duration, log: &Mutex<Vec<String>>) -> String {
+    let _guard = OnDrop { name: server, log };
+    let reply = fetch(server, latency).await;
+    log.lock().unwrap().push(format!("{} finished", server));
+    reply
+}
+
+// -------------------------------------------------------------------------
+// Cancellation-Unsafe vs Cancellation-Safe
+// -------------------------------------------------------------------------
+// Both move one job from `pending` to `done`, with some slow work in between.
+
+struct Queues {
+    pending: Vec<u32>,
+    done: Vec<u32>,
+}
+
+// UNSAFE: takes the job out, *then* awaits. If the future is dropped during
+// the `sleep`, the job is in neither queue: it's gone.
+async fn process_next_unsafe(queues: &Mutex<Queues>) {
+    let Some(job) = queues.lock().unwrap().pending.pop() else {
+        return;
+    };
+    sleep(Duration::from_millis(100)).await; // Cancelled here = job lost
+    queues.lock().unwrap().done.push(job);
+}
+
+// SAFE: looks at the job, awaits, and only then moves it, with no `.await`
+// between the take and the push. Dropped during the `sleep`, nothing has
+// changed yet; the job is simply still pending.
+async fn process_next_safe(queues: &Mutex<Queues>) {
+    let Some(&job) = queues.lock().unwrap().pending.last() else {
+        return;
+    };
+    sleep(Duration::from_millis(100)).await; // Cancelled here = no effect
+    let mut queues = queues.lock().unwrap();
+    if queues.pending.last() == Some(&job) {
+        queues.pending.pop();
+        queues.done.push(job);
+    }
+}
+
+// A worker that stops when its token is cancelled. Unlike a dropped future,
+// it gets to run its own shutdown code (here it just reports; a real one
+// might flush or save its progress). Returns how many items it processed.
+async fn worker(id: u32, token: CancellationToken, log: Arc<Mutex<Vec<String>>>) -> u32 {
+    let mut processed = 0;
+    loop {
+        tokio::select! {
+            // Checked first, so a cancelled worker doesn't start new work.
+            biased;
+            _ = token.cancelled() => {
+                log.lock().unwrap().push(format!("worker {} stopped after {}", id, processed));
+                return processed;
+            }
+            _ = sleep(Duration::from_millis(30 * id as u64)) => {
+                processed += 1; // One item of work
+            }
+        }
+    }
+}
+
+#[tokio::main(flavor = "current_thread", start_paused = true)]
+async fn main() {
+    println!("--- select! and Cancellation ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Racing Two Futures
+    // -------------------------------------------------------------------------
+    // `select!` polls all branches together; the first to finish runs its
+    // handler, and the other branches' futures are dropped on the spot.
+
+    println!("\n--- 1. Racing Two Futures ---");
+
+    let log = Mutex::new(Vec::new());
+    let start = Instant::now();
+    let reply = tokio::select! {
+        reply = fetch_logged("primary", Duration::from_millis(300), &log) => reply,
+        reply = fetch_logged("mirror", Duration::from_millis(100), &log) => reply,
+    };
+    println!("{} after {}", reply, human_duration(start.elapsed()));
+    println!("Log: {:?}", log.lock().unwrap());
+    assert_eq!(reply, "reply from mirror");
+    assert_eq!(start.elapsed(), Duration::from_millis(100)); // Exact: time is paused
+    // The primary never finished: it was dropped (cancelled) mid-`sleep`.
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["mirror finished", "mirror dropped", "primary dropped"]
+    );
+
+    // A timeout is a race against a `sleep`...
+    let slow = tokio::select! {
+        reply = fetch("slow server", Duration::from_secs(5)) => Some(reply),
+        _ = sleep(Duration::from_secs(1)) => None,
+    };
+    assert_eq!(slow, None);
+    // ...which `tokio::time::timeout` packages up.
+    let result = tokio::time::timeout(
+        Duration::from_secs(1),
+        fetch("slow server", Duration::from_secs(5)),
+    )
+    .await;
+    assert!(result.is_err()); // `Err(Elapsed)`
+
+    // -------------------------------------------------------------------------
+    // 2. `select!` in a Loop
+    // -------------------------------------------------------------------------
+    // Each pass creates the branch futures anew. For `recv()` that's fine;
+    // for a deadline it's a bug: a fresh `sleep` restarts the countdown.
+
+    println!("\n--- 2. `select!` in a Loop ---");
+
+    // A sender that produces a message every 50ms, forever (until dropped).
+    let (tx, mut rx) = mpsc::channel::<u32>(8);
+    let producer = tokio::spawn(async move {
+        for n in 0.. {
+            sleep(Duration::from_millis(50)).await;
+            if tx.send(n).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    // BUG: "give up after 200ms" with a new `sleep(200ms)` on every pass.
+    // A message every 50ms resets it each time, so it never fires; we stop
+    // after 10 messages to show that.
+    let start = Instant::now();
+    let mut received = 0;
+    loop {
+        tokio::select! {
+            Some(_) = rx.recv() => {
+                received += 1;
+                if received == 10 {
+                    break;
+                }
+            }
+            _ = sleep(Duration::from_millis(200)) => break,
+        }
+    }
+    println!(
+        "Fresh sleep each pass: {} messages, {}",
+        received,
+        human_duration(start.elapsed())
+    );
+    assert_eq!(received, 10); // The "200ms" deadline never fired
+    assert!(start.elapsed() > Duration::from_millis(200));
+
+    // FIX: create the deadline once, pin it, and poll it by `&mut`, so every
+    // pass waits on the same timer.
+    let start = Instant::now();
+    let deadline = sleep(Duration::from_millis(200));
+    tokio::pin!(deadline); // `Sleep` isn't `Unpin`; polling by `&mut` needs a pin
+    let mut received = 0;
+    loop {
+        tokio::select! {
+            Some(_) = rx.recv() => received += 1,
+            _ = &mut deadline => break,
+        }
+    }
+    println!(
+        "One pinned deadline: {} messages, {}",
+        received,
+        human_duration(start.elapsed())
+    );
+    assert_eq!(start.elapsed(), Duration::from_millis(200));
+    assert!(received <= 4); // What arrived within 200ms
+    drop(rx);
+    producer.await.unwrap(); // Its next `send` fails, so it stops
+
+    // -------------------------------------------------------------------------
+    // 3. Graceful Shutdown with `CancellationToken`
+    // -------------------------------------------------------------------------
+    // Dropping a future cancels it instantly, with no chance to clean up.
+    // A `CancellationToken` instead *asks*: each task `select!`s on
+    // `token.cancelled()` and decides how to finish. `child_token()` gives a
+    // token that's cancelled with its parent (but can also be cancelled alone).
+
+    println!("\n--- 3. Graceful Shutdown with `CancellationToken` ---");
+
+    let shutdown = CancellationToken::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let workers: Vec<_> = (1..=3)
+        .map(|id| tokio::spawn(worker(id, shutdown.child_token(), Arc::clone(&log))))
+        .collect();
+
+    sleep(Duration::from_millis(200)).await; // Let them work
+    shutdown.cancel(); // Cancels every child token too
+
+    let mut counts = Vec::new();
+    for handle in workers {
+        counts.push(handle.await.unwrap()); // Each returned normally
+    }
+    println!("Processed per worker: {:?}", counts);
+    for line in log.lock().unwrap().iter() {
+        println!("  {}", line);
+    }
+    // 200ms of 30ms, 60ms and 90ms items.
+    assert_eq!(counts, [6, 3, 2]);
+    assert!(shutdown.is_cancelled());
+
+    // A child can be cancelled without touching the parent or its siblings.
+    let parent = CancellationToken::new();
+    let child = parent.child_token();
+    let sibling = parent.child_token();
+    child.cancel();
+    assert!(child.is_cancelled());
+    assert!(!parent.is_cancelled() && !sibling.is_cancelled());
+
+    // -------------------------------------------------------------------------
+    // 4. Pitfall: State Cancelled Mid-Update
+    // -------------------------------------------------------------------------
+    // A future can be dropped at any `.await`. Whatever it changed before that
+    // point stays changed, and whatever it meant to do after never happens.
+
+    println!("\n--- 4. Cancelled at an `.await` ---");
+
+    let queues = Mutex::new(Queues {
+        pending: vec![1, 2, 3],
+        done: Vec::new(),
+    });
+    // The work takes 100ms; a 50ms timeout cancels it halfway.
+    let _ = tokio::time::timeout(Duration::from_millis(50), process_next_unsafe(&queues)).await;
+    {
+        let q = queues.lock().unwrap();
+        println!("Unsafe: pending {:?}, done {:?}", q.pending, q.done);
+        assert_eq!(q.pending, [1, 2]);
+        assert_eq!(q.done, []); // Job 3 is lost
+    }
+
+    let queues = Mutex::new(Queues {
+        pending: vec![1, 2, 3],
+        done: Vec::new(),
+    });
+    let _ = tokio::time::timeout(Duration::from_millis(50), process_next_safe(&queues)).await;
+    {
+        let q = queues.lock().unwrap();
+        println!(
+            "Safe (cancelled): pending {:?}, done {:?}",
+            q.pending, q.done
+        );
+        assert_eq!(q.pending, [1, 2, 3]); // Untouched: can be retried
+    }
+    process_next_safe(&queues).await; // Not cancelled this time
+    let q = queues.lock().unwrap();
+    println!(
+        "Safe (completed): pending {:?}, done {:?}",
+        q.pending, q.done
+    );
+    assert_eq!(
+        (q.pending.as_slice(), q.done.as_slice()),
+        (&[1, 2][..], &[3][..])
+    );
+
+    // The same question applies to every future used in a `select!` loop:
+    // is it "cancel safe"? tokio documents it per method. `mpsc::Receiver::recv`
+    // is (a message is either returned or left in the channel), while e.g.
+    // `AsyncReadExt::read_exact` is not (bytes already read are lost).
+
+    println!("\n--- End of select! and Cancellation Examples ---");
+}
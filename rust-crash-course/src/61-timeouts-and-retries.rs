@@ -0,0 +1,400 @@
+// This file makes the `fetch_data_async1` function from the async lesson
+// (16-asynchronous.rs) robust against slow and flaky servers:
+// - a *timeout* bounds how long one attempt may take;
+// - a *retry* loop tries again after a transient failure;
+// - *exponential backoff* waits longer after each failure (100ms, 200ms,
+//   400ms, ...), so a struggling server isn't hammered.
+// `retry_with_backoff` works with any closure that returns a future, and the
+// examples check both ways it can end: success, and running out of attempts.
+//
+// As in 60-select-and-cancellation.rs, the runtime starts with time paused,
+// so every delay below is exact and the file runs instantly. tokio is an
+// optional dependency behind the `ecosystem` feature:
+//     cargo run --features ecosystem --bin 61-timeouts-and-retries
+
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::time::{Instant, sleep, timeout};
+
+// Not `mod fmt`: this file implements `std::fmt::Display`, so `fmt` means `std::fmt`.
+#[path = "lessons/fmt.rs"]
+mod human_fmt;
+
+use human_fmt::human_duration;
+
+// A copy of `fetch_data_async1` from 16-asynchronous.rs: takes 2 seconds.
+async fn fetch_data_async(id: u32) -> String {
+    println!("[Task {}] Fetching data asynchronously...", id);
+    // Simulate a non-blocking I/O operation (e.g., network request)
+    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    println!("[Task {}] Data fetched asynchronously!", id);
+    format!("Asynchronous Data from Task {}", id)
+}
+
+// -------------------------------------------------------------------------
+// Errors
+// -------------------------------------------------------------------------
+// Retrying only helps with *transient* failures. A missing record will be
+// just as missing on the next attempt, so `NotFound` is returned at once.
+// `retry_with_backoff` asks the error which kind it is through this trait.
+
+trait Retryable {
+    fn is_transient(&self) -> bool;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FetchError {
+    Timeout(Duration),
+    Unavailable,
+    NotFound(u32),
+}
+
+impl Retryable for FetchError {
+    fn is_transient(&self) -> bool {
+        match self {
+            FetchError::Timeout(_) | FetchError::Unavailable => true,
+            FetchError::NotFound(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::Timeout(limit) => write!(f, "timed out after {}", human_duration(*limit)),
+            FetchError::Unavailable => write!(f, "service unavailable"),
+            FetchError::NotFound(id) => write!(f, "no record with id {}", id),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+// How a retry loop ended without a result.
+#[derive(Debug, PartialEq)]
+enum RetryError<E> {
+    // Every attempt failed with a transient error; `last` is the final one.
+    Exhausted { attempts: u32, last: E },
+    // An attempt failed with an error that retrying can't fix.
+    Permanent { attempt: u32, error: E },
+}
+
+impl<E: fmt::Display> fmt::Display for RetryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RetryError::Exhausted { attempts, last } => {
+                write!(f, "gave up after {} attempts: {}", attempts, last)
+            }
+            RetryError::Permanent { attempt, error } => {
+                write!(f, "attempt {} failed permanently: {}", attempt, error)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RetryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RetryError::Exhausted { last, .. } => Some(last),
+            RetryError::Permanent { error, .. } => Some(error),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------
+// 1. Timeouts
+// -------------------------------------------------------------------------
+// `tokio::time::timeout(limit, future)` returns `Err(Elapsed)` if `future`
+// hasn't finished within `limit`, and drops (cancels) it.
+
+async fn fetch_with_timeout(id: u32, limit: Duration) -> Result<String, FetchError> {
+    timeout(limit, fetch_data_async(id))
+        .await
+        .map_err(|_elapsed| FetchError::Timeout(limit))
+}
+
+// -------------------------------------------------------------------------
+// 2. Backoff Policy
+// -------------------------------------------------------------------------
+// The delay before retry `n` (counting from 1) is
+// `initial_delay * multiplier^(n - 1)`, capped at `max_delay`.
+// Production code usually adds random *jitter* to each delay as well, so
+// that many clients that failed together don't all retry together.
+
+#[derive(Debug, Clone)]
+struct RetryPolicy {
+    max_attempts: u32,
+    initial_delay: Duration,
+    multiplier: u32,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    fn new(max_attempts: u32) -> Self {
+        assert!(max_attempts >= 1, "at least one attempt is needed");
+        RetryPolicy {
+            max_attempts,
+            initial_delay: Duration::from_millis(100),
+            multiplier: 2,
+            max_delay: Duration::from_secs(10),
+        }
+    }
+
+    fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    // Delay before retry number `retry` (1 = the first retry).
+    fn delay_before(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(retry - 1);
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+// -------------------------------------------------------------------------
+// 3. `retry_with_backoff`
+// -------------------------------------------------------------------------
+// `f` is called once per attempt, with the attempt number, and returns a new
+// future each time. (A future can only be awaited once, so retrying needs a
+// way to *make* futures, not a single future.)
+
+async fn retry_with_backoff<T, E, F, Fut>(
+    mut f: F,
+    policy: &RetryPolicy,
+) -> Result<T, RetryError<E>>
+where
+    E: Retryable + fmt::Display,
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut attempt = 1;
+    loop {
+        match f(attempt).await {
+            Ok(value) => return Ok(value),
+            Err(error) if !error.is_transient() => {
+                return Err(RetryError::Permanent { attempt, error });
+            }
+            Err(error) if attempt == policy.max_attempts => {
+                return Err(RetryError::Exhausted {
+                    attempts: attempt,
+                    last: error,
+                });
+            }
+            Err(error) => {
+                let delay = policy.delay_before(attempt);
+                println!(
+                    "  attempt {} failed ({}), retrying in {}",
+                    attempt,
+                    error,
+                    human_duration(delay)
+                );
+                sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+// A fake server that fails its first `failures` requests, then succeeds.
+// `Cell` is enough: everything runs on one thread (58-interior-mutability-guide.rs).
+struct FlakyServer {
+    failures_left: Cell<u32>,
+    calls: Cell<u32>,
+}
+
+impl FlakyServer {
+    fn failing(failures: u32) -> Self {
+        FlakyServer {
+            failures_left: Cell::new(failures),
+            calls: Cell::new(0),
+        }
+    }
+
+    async fn get(&self, id: u32) -> Result<String, FetchError> {
+        self.calls.set(self.calls.get() + 1);
+        sleep(Duration::from_millis(10)).await; // Network round trip
+        if id == 404 {
+            return Err(FetchError::NotFound(id));
+        }
+        match self.failures_left.get() {
+            0 => Ok(format!("record {}", id)),
+            n => {
+                self.failures_left.set(n - 1);
+                Err(FetchError::Unavailable)
+            }
+        }
+    }
+}
+
+#[tokio::main(flavor = "current_thread", start_paused = true)]
+async fn main() {
+    println!("--- Timeouts, Retries and Backoff ---");
+
+    // -------------------------------------------------------------------------
+    // 1. A Timeout Around `fetch_data_async`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 1. Timeouts ---");
+
+    let start = Instant::now();
+    let result = fetch_with_timeout(1, Duration::from_secs(1)).await;
+    println!(
+        "1s limit: {:?} after {}",
+        result,
+        human_duration(start.elapsed())
+    );
+    assert_eq!(result, Err(FetchError::Timeout(Duration::from_secs(1))));
+    assert_eq!(start.elapsed(), Duration::from_secs(1)); // Gave up on time
+
+    let start = Instant::now();
+    let result = fetch_with_timeout(2, Duration::from_secs(3)).await;
+    println!(
+        "3s limit: {:?} after {}",
+        result,
+        human_duration(start.elapsed())
+    );
+    assert_eq!(result.as_deref(), Ok("Asynchronous Data from Task 2"));
+    assert_eq!(start.elapsed(), Duration::from_secs(2)); // Didn't wait for the limit
+
+    // -------------------------------------------------------------------------
+    // 2. The Backoff Schedule
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. The Backoff Schedule ---");
+
+    let policy = RetryPolicy::new(8).max_delay(Duration::from_secs(5));
+    let delays: Vec<Duration> = (1..policy.max_attempts)
+        .map(|r| policy.delay_before(r))
+        .collect();
+    let shown: Vec<String> = delays.iter().map(|&d| human_duration(d)).collect();
+    println!("Delays: {}", shown.join(", "));
+    let millis: Vec<u128> = delays.iter().map(|d| d.as_millis()).collect();
+    assert_eq!(millis, [100, 200, 400, 800, 1600, 3200, 5000]); // Capped at 5s
+
+    // No overflow, however many retries: `saturating_*` clamps instead.
+    assert_eq!(policy.delay_before(200), Duration::from_secs(5));
+
+    // -------------------------------------------------------------------------
+    // 3. Success After Retries
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. Success After Retries ---");
+
+    let server = FlakyServer::failing(2);
+    let policy = RetryPolicy::new(4);
+    let start = Instant::now();
+    let result = retry_with_backoff(|_attempt| server.get(7), &policy).await;
+    println!(
+        "Result: {:?} after {}",
+        result,
+        human_duration(start.elapsed())
+    );
+    assert_eq!(result, Ok("record 7".to_string()));
+    assert_eq!(server.calls.get(), 3);
+    // Three 10ms requests, plus 100ms and 200ms of backoff.
+    assert_eq!(start.elapsed(), Duration::from_millis(330));
+
+    // -------------------------------------------------------------------------
+    // 4. Running Out of Attempts
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Exhaustion ---");
+
+    let server = FlakyServer::failing(u32::MAX); // Never recovers
+    let start = Instant::now();
+    let result = retry_with_backoff(|_attempt| server.get(7), &policy).await;
+    let error = result.unwrap_err();
+    println!("Error: {} after {}", error, human_duration(start.elapsed()));
+    assert_eq!(
+        error,
+        RetryError::Exhausted {
+            attempts: 4,
+            last: FetchError::Unavailable,
+        }
+    );
+    assert_eq!(server.calls.get(), 4); // Exactly `max_attempts`, no more
+    assert_eq!(
+        start.elapsed(),
+        Duration::from_millis(4 * 10 + 100 + 200 + 400)
+    );
+    assert!(error.source().is_some()); // The last `FetchError`, for reporting
+
+    // A permanent error isn't retried at all.
+    let server = FlakyServer::failing(0);
+    let result = retry_with_backoff(|_attempt| server.get(404), &policy).await;
+    println!("Error: {}", result.as_ref().unwrap_err());
+    assert_eq!(
+        result,
+        Err(RetryError::Permanent {
+            attempt: 1,
+            error: FetchError::NotFound(404),
+        })
+    );
+    assert_eq!(server.calls.get(), 1);
+
+    // -------------------------------------------------------------------------
+    // 5. Timeouts and Retries Together
+    // -------------------------------------------------------------------------
+    // A per-attempt timeout inside the retry loop: a hung attempt counts as a
+    // transient failure. Here the limit grows with the attempt number, so the
+    // 2-second `fetch_data_async` only fits on the third try.
+
+    println!("\n--- 5. Timeouts and Retries Together ---");
+
+    let start = Instant::now();
+    let result = retry_with_backoff(
+        |attempt| fetch_with_timeout(3, Duration::from_millis(800) * attempt),
+        &policy,
+    )
+    .await;
+    println!(
+        "Result: {:?} after {}",
+        result,
+        human_duration(start.elapsed())
+    );
+    assert_eq!(result.as_deref(), Ok("Asynchronous Data from Task 3"));
+    // 0.8s (timed out) + 0.1s + 1.6s (timed out) + 0.2s + 2s (success)
+    assert_eq!(
+        start.elapsed(),
+        Duration::from_millis(800 + 100 + 1600 + 200 + 2000)
+    );
+
+    // An overall deadline around the whole retry loop bounds the total time,
+    // whatever the policy says.
+    let start = Instant::now();
+    let slow_server = FlakyServer::failing(u32::MAX);
+    let result = timeout(
+        Duration::from_millis(250),
+        retry_with_backoff(|_attempt| slow_server.get(1), &RetryPolicy::new(100)),
+    )
+    .await;
+    assert!(result.is_err()); // `Elapsed`: the loop was cancelled mid-backoff
+    assert_eq!(start.elapsed(), Duration::from_millis(250));
+    println!(
+        "Overall deadline: stopped after {} calls",
+        slow_server.calls.get()
+    );
+
+    // Without backoff, retries follow each other as fast as the server can
+    // fail: 5 calls in 50ms, where the default policy spreads 5 over 1.5s.
+    let eager = RetryPolicy::new(5).initial_delay(Duration::ZERO);
+    let busy_server = FlakyServer::failing(u32::MAX);
+    let start = Instant::now();
+    let _ = retry_with_backoff(|_attempt| busy_server.get(1), &eager).await;
+    assert_eq!(busy_server.calls.get(), 5);
+    assert_eq!(start.elapsed(), Duration::from_millis(50));
+
+    println!("\n--- End of Timeouts, Retries and Backoff Examples ---");
+}
@@ -0,0 +1,278 @@
+// This file covers waiting for *several* futures or tasks at once, instead of
+// awaiting them one by one as the async lesson (16-asynchronous.rs) does:
+// - `tokio::join!` runs a fixed set of futures concurrently and waits for all;
+// - `tokio::try_join!` does the same for fallible futures, but returns at
+//   the first error and cancels the rest;
+// - `JoinSet` holds a *dynamic* group of spawned tasks, hands back results in
+//   completion order, and reports tasks that panicked or were aborted.
+//
+// As in 60-select-and-cancellation.rs, the runtime starts with time paused,
+// so every duration below is exact. tokio is an optional dependency behind
+// the `ecosystem` feature:
+//     cargo run --features ecosystem --bin 62-join-and-joinset
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::task::JoinSet;
+use tokio::time::{Instant, sleep};
+
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use fmt::human_duration;
+
+// The shape of `background_task` from 16-asynchronous.rs, in milliseconds.
+async fn background_task(name: &str, delay_ms: u64) -> String {
+    sleep(Duration::from_millis(delay_ms)).await;
+    format!("Result from {}", name)
+}
+
+// A fallible step that logs when it finishes, so we can see which ones
+// `try_join!` cancelled.
+async fn step(
+    name: &'static str,
+    delay_ms: u64,
+    fail: bool,
+    log: &Mutex<Vec<&'static str>>,
+) -> Result<&'static str, String> {
+    sleep(Duration::from_millis(delay_ms)).await;
+    log.lock().unwrap().push(name);
+    if fail {
+        Err(format!("{} failed", name))
+    } else {
+        Ok(name)
+    }
+}
+
+// A task for the `JoinSet` examples: "fetch" a page and return the links
+// found on it. Page 13 is broken and makes the task panic.
+async fn crawl(page: u32) -> (u32, Vec<u32>) {
+    sleep(Duration::from_millis(10 * (page % 4 + 1) as u64)).await;
+    if page == 13 {
+        panic!("page {} is malformed", page);
+    }
+    // Each page links to two pages with higher numbers, up to 20.
+    let links = [page * 2, page * 2 + 1]
+        .into_iter()
+        .filter(|&p| p <= 20)
+        .collect();
+    (page, links)
+}
+
+#[tokio::main(flavor = "current_thread", start_paused = true)]
+async fn main() {
+    println!("--- join!, try_join! and JoinSet ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Awaiting One by One vs `join!`
+    // -------------------------------------------------------------------------
+    // A future does nothing until polled. `a.await; b.await;` polls `b` only
+    // after `a` is done, so the two run one after the other. `join!` polls
+    // them all, and finishes when the slowest one does.
+
+    println!("\n--- 1. One by One vs `join!` ---");
+
+    let start = Instant::now();
+    let a = background_task("A", 300).await;
+    let b = background_task("B", 100).await;
+    let c = background_task("C", 200).await;
+    println!("One by one: {}", human_duration(start.elapsed()));
+    assert_eq!(start.elapsed(), Duration::from_millis(600)); // The sum
+
+    let start = Instant::now();
+    let (a2, b2, c2) = tokio::join!(
+        background_task("A", 300),
+        background_task("B", 100),
+        background_task("C", 200),
+    );
+    println!("join!:      {}", human_duration(start.elapsed()));
+    assert_eq!(start.elapsed(), Duration::from_millis(300)); // The maximum
+    assert_eq!((a, b, c), (a2, b2, c2)); // Same results, in argument order
+
+    // `join!` runs everything on the *current* task: concurrently, but not in
+    // parallel. Spawned tasks (section 3) can use several threads.
+
+    // -------------------------------------------------------------------------
+    // 2. `try_join!`: Stop at the First Error
+    // -------------------------------------------------------------------------
+    // With `join!`, one failing future still waits for all the others. With
+    // `try_join!`, the first `Err` is returned at once and the futures still
+    // running are dropped, i.e. cancelled.
+
+    println!("\n--- 2. `try_join!` ---");
+
+    let log = Mutex::new(Vec::new());
+    let start = Instant::now();
+    let all_ok = tokio::try_join!(
+        step("load config", 50, false, &log),
+        step("open database", 80, false, &log),
+    );
+    assert_eq!(all_ok, Ok(("load config", "open database")));
+    assert_eq!(start.elapsed(), Duration::from_millis(80));
+
+    log.lock().unwrap().clear();
+    let start = Instant::now();
+    let result = tokio::try_join!(
+        step("load config", 50, true, &log), // Fails first
+        step("open database", 200, false, &log),
+        step("warm cache", 300, false, &log),
+    );
+    println!("{:?} after {}", result, human_duration(start.elapsed()));
+    println!("Steps that finished: {:?}", log.lock().unwrap());
+    assert_eq!(result, Err("load config failed".to_string()));
+    assert_eq!(start.elapsed(), Duration::from_millis(50)); // Didn't wait
+    assert_eq!(*log.lock().unwrap(), ["load config"]); // The others were cancelled
+
+    // With `join!`, the same three take 300ms and return all three results.
+    log.lock().unwrap().clear();
+    let start = Instant::now();
+    let (first, second, third) = tokio::join!(
+        step("load config", 50, true, &log),
+        step("open database", 200, false, &log),
+        step("warm cache", 300, false, &log),
+    );
+    assert!(first.is_err() && second.is_ok() && third.is_ok());
+    assert_eq!(start.elapsed(), Duration::from_millis(300));
+
+    // -------------------------------------------------------------------------
+    // 3. `JoinSet`: A Dynamic Group of Tasks
+    // -------------------------------------------------------------------------
+    // `join!` needs the futures written out in the source. A `JoinSet` takes
+    // any number of tasks at runtime (`spawn`), and `join_next()` returns the
+    // next one to *finish* (not the next one spawned), or `None` when empty.
+
+    println!("\n--- 3. `JoinSet` ---");
+
+    let mut set = JoinSet::new();
+    for (name, delay) in [("slow", 300), ("fast", 100), ("medium", 200)] {
+        set.spawn(async move { (name, background_task(name, delay).await) });
+    }
+    let mut order = Vec::new();
+    while let Some(result) = set.join_next().await {
+        let (name, _output) = result.unwrap(); // `Err` only if the task panicked or was aborted
+        order.push(name);
+    }
+    println!("Completion order: {:?}", order);
+    assert_eq!(order, ["fast", "medium", "slow"]);
+
+    // -------------------------------------------------------------------------
+    // 4. Growing the Set, and Panics in Tasks
+    // -------------------------------------------------------------------------
+    // Tasks can be added while draining: a crawler spawns a task per link
+    // found, and the set going empty means the crawl is done.
+    // A panic inside a spawned task doesn't crash the program: the runtime
+    // catches it, and `join_next` returns a `JoinError` with `is_panic()`.
+    // The other tasks keep running.
+
+    println!("\n--- 4. Growing the Set, and Panics ---");
+
+    let start = Instant::now();
+    let mut set = JoinSet::new();
+    let mut seen = vec![1];
+    set.spawn(crawl(1));
+    let mut crawled = Vec::new();
+    let mut failures = Vec::new();
+    while let Some(result) = set.join_next().await {
+        match result {
+            Ok((page, links)) => {
+                crawled.push(page);
+                for link in links {
+                    if !seen.contains(&link) {
+                        seen.push(link);
+                        set.spawn(crawl(link));
+                    }
+                }
+            }
+            // The payload is whatever `panic!` was given: a `String` when
+            // it had format arguments, a `&'static str` otherwise.
+            Err(error) if error.is_panic() => {
+                let payload = error.into_panic();
+                let message = payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .unwrap_or_else(|| "<non-string panic>".to_string());
+                failures.push(message);
+            }
+            Err(error) => panic!("task failed: {}", error),
+        }
+    }
+    crawled.sort();
+    println!(
+        "Crawled {} pages in {}; failures: {:?}",
+        crawled.len(),
+        human_duration(start.elapsed()),
+        failures
+    );
+    // Pages 1..=20, except 13 (panicked) and 26/27 (its links, never found).
+    let expected: Vec<u32> = (1..=20).filter(|&p| p != 13).collect();
+    assert_eq!(crawled, expected);
+    assert_eq!(failures, ["page 13 is malformed"]);
+
+    // To treat a panic as fatal instead, re-raise it in the current task:
+    //     Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+    // (The "page 13" message above was also printed by the default panic
+    // hook, at the moment the task panicked.)
+
+    // -------------------------------------------------------------------------
+    // 5. Aborting and Dropping
+    // -------------------------------------------------------------------------
+    // `abort_all()` cancels every task still in the set; their results come
+    // back as `JoinError`s with `is_cancelled() == true`. Dropping a `JoinSet`
+    // aborts its tasks too, so a group can't outlive the code that owns it:
+    // that's the "structured" in structured concurrency.
+
+    println!("\n--- 5. Aborting ---");
+
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    let mut set = JoinSet::new();
+    for delay in [50, 100, 5_000, 10_000] {
+        let finished = Arc::clone(&finished);
+        set.spawn(async move {
+            sleep(Duration::from_millis(delay)).await;
+            finished.lock().unwrap().push(delay);
+        });
+    }
+    sleep(Duration::from_millis(150)).await;
+    set.abort_all();
+    let mut cancelled = 0;
+    while let Some(result) = set.join_next().await {
+        if let Err(error) = result {
+            assert!(error.is_cancelled());
+            cancelled += 1;
+        }
+    }
+    println!(
+        "Finished before abort: {:?}, cancelled: {}",
+        finished.lock().unwrap(),
+        cancelled
+    );
+    assert_eq!(*finished.lock().unwrap(), [50, 100]);
+    assert_eq!(cancelled, 2);
+
+    {
+        let finished = Arc::clone(&finished);
+        let mut scoped = JoinSet::new();
+        scoped.spawn(async move {
+            sleep(Duration::from_millis(100)).await;
+            finished.lock().unwrap().push(1);
+        });
+    } // `scoped` dropped here: its task is aborted
+    sleep(Duration::from_millis(500)).await;
+    assert_eq!(finished.lock().unwrap().len(), 2); // Never ran to completion
+
+    // `join_all` collects every result at once, in spawn order, and
+    // re-raises a task's panic in the caller.
+    let mut set = JoinSet::new();
+    for n in 1..=3u64 {
+        set.spawn(async move {
+            sleep(Duration::from_millis(100 / n)).await;
+            n * n
+        });
+    }
+    let mut squares = set.join_all().await;
+    squares.sort();
+    assert_eq!(squares, [1, 4, 9]);
+
+    println!("\n--- End of join!, try_join! and JoinSet Examples ---");
+}
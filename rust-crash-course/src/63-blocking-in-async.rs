@@ -0,0 +1,254 @@
+// This file shows what happens when async code calls something that
+// *blocks*: `std::thread::sleep`, a synchronous file or network call, or just
+// a long CPU-bound loop. An `.await` hands the thread back to the runtime;
+// a blocking call keeps it, so every other task scheduled on that thread
+// stops until the call returns. We make the stall visible with a "ticker"
+// task that should run every 10ms, then fix it with `spawn_blocking` and
+// `block_in_place`. (`lessons/async_compat.rs` wraps `spawn_blocking` in a
+// `run_blocking` helper that re-raises panics, used in section 10 of
+// 16-asynchronous.rs; this file compares the options side by side.)
+//
+// Unlike the other async lessons, time is *not* paused here: a blocked
+// thread is a real-time problem, so the numbers vary a little between runs.
+// tokio is an optional dependency behind the `ecosystem` feature:
+//     cargo run --features ecosystem --bin 63-blocking-in-async
+
+use std::hint::black_box;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tokio::runtime::{Builder, Runtime};
+use tokio::time::sleep;
+
+#[path = "lessons/fmt.rs"]
+mod fmt;
+
+use fmt::human_duration;
+
+const TICK: Duration = Duration::from_millis(10);
+const BLOCKING_CALL: Duration = Duration::from_millis(300);
+const CHECKSUM_ROUNDS: u64 = 30_000_000; // About 0.2s in a debug build
+
+// A task that wakes up every `TICK` and records the longest gap between two
+// wake-ups. On a healthy runtime that's about `TICK`; a longer gap means the
+// ticker wanted to run and couldn't.
+async fn ticker(stop: Arc<AtomicBool>) -> (u32, Duration) {
+    let mut ticks = 0;
+    let mut longest_gap = Duration::ZERO;
+    let mut last = Instant::now();
+    while !stop.load(Ordering::Relaxed) {
+        sleep(TICK).await;
+        ticks += 1;
+        longest_gap = longest_gap.max(last.elapsed());
+        last = Instant::now();
+    }
+    (ticks, longest_gap)
+}
+
+// Runs `work` with the ticker going in the background, and reports how long
+// the work took and the ticker's longest gap meanwhile.
+async fn with_ticker<F: Future>(work: F) -> (F::Output, Duration, Duration) {
+    let stop = Arc::new(AtomicBool::new(false));
+    let ticker = tokio::spawn(ticker(Arc::clone(&stop)));
+    sleep(TICK * 3).await; // Let it tick a few times first
+    let start = Instant::now();
+    let output = work.await;
+    let took = start.elapsed();
+    sleep(TICK * 3).await; // And a few times after
+    stop.store(true, Ordering::Relaxed);
+    let (_ticks, longest_gap) = ticker.await.unwrap();
+    (output, took, longest_gap)
+}
+
+// CPU-bound work with no `.await` in it: blocking, just like a sleep.
+fn checksum(rounds: u64) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for i in 0..rounds {
+        hash ^= black_box(i);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+// A runtime with a single thread, so there's nowhere else for the ticker to
+// run. (`#[tokio::main(flavor = "current_thread")]` builds the same.)
+fn current_thread_runtime() -> Runtime {
+    Builder::new_current_thread().enable_time().build().unwrap()
+}
+
+fn main() {
+    println!("--- Blocking Work Inside Async ---");
+
+    // -------------------------------------------------------------------------
+    // 1. A Blocking Call Stalls Every Task on Its Thread
+    // -------------------------------------------------------------------------
+    // Both versions wait 300ms. `tokio::time::sleep` suspends the task and
+    // lets the ticker run; `std::thread::sleep` holds the thread for 300ms,
+    // and the ticker misses every tick in between.
+
+    println!("\n--- 1. Starving the Runtime ---");
+
+    let runtime = current_thread_runtime();
+
+    let ((), took, gap) = runtime.block_on(with_ticker(async { sleep(BLOCKING_CALL).await }));
+    println!(
+        "tokio::time::sleep:  took {:>8}, longest tick gap {:>8}",
+        human_duration(took),
+        human_duration(gap)
+    );
+    assert!(gap < BLOCKING_CALL / 2);
+
+    let ((), took, gap) = runtime.block_on(with_ticker(async {
+        thread::sleep(BLOCKING_CALL); // WRONG inside async code
+    }));
+    println!(
+        "std::thread::sleep:  took {:>8}, longest tick gap {:>8}",
+        human_duration(took),
+        human_duration(gap)
+    );
+    assert!(gap >= BLOCKING_CALL); // Not a single tick during the call
+
+    // CPU-bound work does the same: there's no `.await` to stop at. Whatever
+    // it took, the ticker was frozen at least that long.
+    let (sum, took, gap) = runtime.block_on(with_ticker(async { checksum(CHECKSUM_ROUNDS) }));
+    println!(
+        "CPU-bound loop:      took {:>8}, longest tick gap {:>8}",
+        human_duration(took),
+        human_duration(gap)
+    );
+    assert!(gap >= took);
+    black_box(sum);
+
+    // Typical result:
+    //     tokio::time::sleep:  took   300.9ms, longest tick gap    11.0ms
+    //     std::thread::sleep:  took   300.1ms, longest tick gap   310.2ms
+    //     CPU-bound loop:      took   262.4ms, longest tick gap   272.6ms
+    // On a real server the "ticker" is every other request: they all wait.
+
+    // -------------------------------------------------------------------------
+    // 2. `spawn_blocking`: Move the Work to Another Thread
+    // -------------------------------------------------------------------------
+    // `tokio::task::spawn_blocking` runs a closure on a separate pool of
+    // threads meant for blocking work, and returns a `JoinHandle` to `.await`.
+    // The async side just waits; its thread is free for other tasks. It
+    // works on any runtime, including the single-threaded one.
+
+    println!("\n--- 2. `spawn_blocking` ---");
+
+    let (result, took, gap) = runtime.block_on(with_ticker(async {
+        tokio::task::spawn_blocking(|| {
+            thread::sleep(BLOCKING_CALL);
+            "read from a synchronous API"
+        })
+        .await
+        .unwrap() // `Err` only if the closure panicked
+    }));
+    println!(
+        "spawn_blocking(sleep):    took {:>8}, longest tick gap {:>8}",
+        human_duration(took),
+        human_duration(gap)
+    );
+    assert_eq!(result, "read from a synchronous API");
+    assert!(took >= BLOCKING_CALL);
+    assert!(gap < BLOCKING_CALL / 2); // The ticker kept going
+
+    let (sum_elsewhere, took, gap) = runtime.block_on(with_ticker(async {
+        tokio::task::spawn_blocking(|| checksum(CHECKSUM_ROUNDS))
+            .await
+            .unwrap()
+    }));
+    println!(
+        "spawn_blocking(checksum): took {:>8}, longest tick gap {:>8}",
+        human_duration(took),
+        human_duration(gap)
+    );
+    assert_eq!(sum_elsewhere, sum);
+    assert!(gap < took.max(BLOCKING_CALL) / 2);
+
+    // The closure must be `Send + 'static` (it runs on another thread), so
+    // it takes owned data, not references into the async function:
+    /*
+    let data = vec![1u64, 2, 3];
+    let total = tokio::task::spawn_blocking(|| data.iter().sum::<u64>());
+    // error[E0373]: closure may outlive the current function, but it borrows `data`, which is owned by the current function
+    */
+    let data: Vec<u64> = (1..=3).collect();
+    let total = runtime.block_on(async {
+        tokio::task::spawn_blocking(move || data.iter().sum::<u64>()) // `move` it in
+            .await
+            .unwrap()
+    });
+    assert_eq!(total, 6);
+
+    // Once started, a blocking closure can't be cancelled: aborting its
+    // handle, or dropping the runtime, doesn't interrupt it. (Dropping the
+    // runtime waits for it to finish; `Runtime::shutdown_timeout` doesn't.)
+    drop(runtime);
+
+    // -------------------------------------------------------------------------
+    // 3. `block_in_place`: Block Right Here, on a Multi-Threaded Runtime
+    // -------------------------------------------------------------------------
+    // `tokio::task::block_in_place` runs the closure on the *current* thread,
+    // but first hands this worker's other tasks to another worker. No new
+    // thread, no `Send + 'static` bound, so it can borrow local data.
+    // It needs a multi-threaded runtime: on a current-thread one there's no
+    // other worker to hand over to, and it panics.
+
+    println!("\n--- 3. `block_in_place` ---");
+
+    let runtime = Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_time()
+        .build()
+        .unwrap();
+
+    let (count, took, gap) = runtime.block_on(async {
+        // `block_on`'s own future runs outside the worker pool, so run the
+        // example as a spawned task, on a worker, like server code would.
+        tokio::spawn(with_ticker(async {
+            let words = ["borrowed", "not", "moved"];
+            tokio::task::block_in_place(|| {
+                thread::sleep(BLOCKING_CALL);
+                words.iter().map(|w| w.len()).sum::<usize>() // Borrows `words`
+            })
+        }))
+        .await
+        .unwrap()
+    });
+    println!(
+        "block_in_place(sleep): took {:>8}, longest tick gap {:>8}",
+        human_duration(took),
+        human_duration(gap)
+    );
+    assert_eq!(count, 16);
+    assert!(took >= BLOCKING_CALL);
+    assert!(gap < BLOCKING_CALL / 2);
+    drop(runtime);
+
+    // The same call on the single-threaded runtime panics.
+    let runtime = current_thread_runtime();
+    let result = runtime
+        .block_on(async { tokio::spawn(async { tokio::task::block_in_place(|| ()) }).await });
+    assert!(result.unwrap_err().is_panic());
+    println!("block_in_place on a current-thread runtime: panicked (see message above)");
+
+    // -------------------------------------------------------------------------
+    // 4. Which One to Use
+    // -------------------------------------------------------------------------
+    // - A blocking call with no async version (sync file I/O, a C library, a
+    //   password hash): `spawn_blocking`. The blocking pool grows up to 512
+    //   threads by default (`Builder::max_blocking_threads`).
+    // - Blocking briefly in code that can't easily become `'static`, on a
+    //   multi-threaded runtime: `block_in_place`.
+    // - Lots of CPU-bound work: a dedicated pool (e.g. the `rayon` crate),
+    //   sending results back over a `tokio::sync::oneshot` channel, so heavy
+    //   jobs can't use up the blocking pool.
+    // - A long async loop that rarely awaits: `tokio::task::yield_now().await`
+    //   every so often lets other tasks run.
+    // A handy rule of thumb: no more than 10-100 microseconds between two
+    // `.await`s.
+
+    println!("\n--- End of Blocking Work Inside Async Examples ---");
+}
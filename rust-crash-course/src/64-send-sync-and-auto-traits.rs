@@ -0,0 +1,354 @@
+// This file covers the two marker traits behind Rust's thread safety:
+// - `Send`: a value of this type can be *moved* to another thread.
+// - `Sync`: a value of this type can be *shared* between threads, i.e. `&T`
+//   can be sent (`T: Sync` exactly when `&T: Send`).
+// They have no methods. They're "auto traits": the compiler implements them
+// for a struct or enum whenever all of its fields have them, so a single
+// `Rc` field makes the whole type non-`Send`. `thread::spawn` and
+// `tokio::spawn` require `Send`, which is how data races become compile
+// errors. 57-shared-state-arc-mutex.rs shows the types; this file shows the
+// rules behind them.
+
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Compile-time checks: these calls only compile if `T` has the trait.
+// Being `const fn`, they can also run in a `const` item, which is
+// evaluated while compiling.
+const fn assert_send<T: Send>() {}
+const fn assert_sync<T: Sync>() {}
+
+// -------------------------------------------------------------------------
+// Auto Traits Follow the Fields
+// -------------------------------------------------------------------------
+
+// Every field is `Send + Sync`, so `Config` is too, with nothing written.
+#[allow(dead_code)]
+struct Config {
+    name: String,
+    retries: u32,
+    tags: Vec<&'static str>,
+}
+
+// One `Rc` field, and `Session` is neither `Send` nor `Sync`.
+struct Session {
+    id: u32,
+    cache: Rc<Vec<u8>>,
+}
+
+// The thread-safe version: `Arc` instead of `Rc`.
+struct SharedSession {
+    id: u32,
+    cache: Arc<Vec<u8>>,
+}
+
+// A raw pointer is neither `Send` nor `Sync`: the compiler can't know what
+// it points to or who else uses it. A type wrapping one has to *promise*
+// it's safe, with an `unsafe impl`.
+struct Buffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl Buffer {
+    fn new(len: usize) -> Self {
+        let boxed: Box<[u8]> = vec![0; len].into_boxed_slice();
+        Buffer {
+            ptr: Box::into_raw(boxed) as *mut u8,
+            len,
+        }
+    }
+
+    fn fill(&mut self, byte: u8) {
+        // SAFETY: `ptr` points to `len` bytes that only this `Buffer` owns.
+        unsafe { std::ptr::write_bytes(self.ptr, byte, self.len) };
+    }
+
+    fn sum(&self) -> u32 {
+        // SAFETY: as above; `&self` means no one is writing.
+        let bytes = unsafe { std::slice::from_raw_parts(self.ptr, self.len) };
+        bytes.iter().map(|&b| b as u32).sum()
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` and `len` came from the `Box<[u8]>` made in `new`.
+        unsafe {
+            drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+                self.ptr, self.len,
+            )));
+        }
+    }
+}
+
+// SAFETY: `Buffer` owns its bytes exclusively, like a `Box<[u8]>` does, so
+// moving it to another thread moves that ownership along. Nothing else
+// holds the pointer. Mutation needs `&mut self`, so sharing `&Buffer`
+// between threads only allows reads.
+unsafe impl Send for Buffer {}
+unsafe impl Sync for Buffer {}
+
+// Going the other way: a type that must stay on the thread that made it
+// (say, a handle to a thread-local resource). `impl !Send` isn't stable
+// Rust, so add a field that isn't `Send` and costs nothing:
+/*
+impl !Send for ThreadBound {}
+// error[E0658]: negative trait bounds are not fully implemented; use marker types for now
+*/
+#[allow(dead_code)]
+struct ThreadBound {
+    id: u32,
+    _not_send: PhantomData<*const ()>, // Zero-sized; removes `Send` and `Sync`
+}
+
+// And a type that may move between threads, but not be shared: `Cell` is
+// `Send` but not `Sync`.
+#[allow(dead_code)]
+struct NotShared {
+    id: u32,
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+// Evaluated at compile time: if any line here were wrong, the file
+// wouldn't build.
+const _: () = {
+    assert_send::<Config>();
+    assert_sync::<Config>();
+    assert_send::<SharedSession>();
+    assert_sync::<SharedSession>();
+    assert_send::<Buffer>();
+    assert_sync::<Buffer>();
+    assert_send::<NotShared>();
+    // The lines that would fail, for reference:
+    // assert_send::<Session>();      // `Rc<Vec<u8>>` cannot be sent
+    // assert_send::<ThreadBound>();  // `*const ()` cannot be sent
+    // assert_sync::<NotShared>();    // `Cell<()>` cannot be shared
+};
+
+fn session_size(session: Session) -> usize {
+    session.id as usize + session.cache.len()
+}
+
+fn shared_session_size(session: SharedSession) -> usize {
+    session.id as usize + session.cache.len()
+}
+
+// Compile-time check for futures: only accepts ones that are `Send`, like
+// `tokio::spawn` on a multi-threaded runtime.
+fn require_send<F: Future + Send>(future: F) -> F {
+    future
+}
+
+async fn tick() {}
+
+fn main() {
+    println!("--- Send, Sync, and Auto Traits ---");
+
+    // -------------------------------------------------------------------------
+    // 1. Which Types Are `Send` and `Sync`
+    // -------------------------------------------------------------------------
+    // | Type                | Send              | Sync              |
+    // |---------------------|-------------------|-------------------|
+    // | i32, String, Vec<T> | yes (if T is)     | yes (if T is)     |
+    // | &T                  | if T: Sync        | if T: Sync        |
+    // | Rc<T>               | no                | no                |
+    // | Cell<T>, RefCell<T> | if T: Send        | no                |
+    // | Arc<T>              | if T: Send + Sync | if T: Send + Sync |
+    // | Mutex<T>            | if T: Send        | if T: Send        |
+    // | RwLock<T>           | if T: Send        | if T: Send + Sync |
+    // | MutexGuard<'_, T>   | no                | if T: Sync        |
+    // | *const T, *mut T    | no                | no                |
+    // `Rc` and `Cell` are out because they update shared state without
+    // atomics; a `MutexGuard` must unlock on the thread that locked it; raw
+    // pointers are out because the compiler can't tell what they point to.
+
+    println!("\n--- 1. Which Types Are Send and Sync ---");
+
+    assert_send::<Vec<String>>();
+    assert_sync::<Vec<String>>();
+    // A `Cell` can be moved to another thread, but not shared:
+    assert_send::<Cell<i32>>();
+    /*
+    assert_sync::<Cell<i32>>();
+    // error[E0277]: `Cell<i32>` cannot be shared between threads safely
+    */
+    // (This and the other commented-out errors in sections 2, 3 and 5 are
+    // checked as compile-fail cases in tests/compile_fail/; see
+    // 28-compiler-errors.rs.)
+    assert_send::<Arc<Mutex<Vec<i32>>>>();
+    assert_sync::<Arc<Mutex<Vec<i32>>>>();
+    println!("Checked at compile time: nothing to print.");
+
+    // -------------------------------------------------------------------------
+    // 2. Why `Rc<T>` Is Not `Send`
+    // -------------------------------------------------------------------------
+    // Every `Rc::clone` and drop updates a count with a plain, non-atomic
+    // `+= 1` / `-= 1`. If two clones lived on different threads, two updates
+    // at once could lose one: the count ends too high (a leak) or hits zero
+    // early (a use-after-free). Sending *one* `Rc` would be fine, but the
+    // type can't know whether clones exist, so it's never `Send`.
+
+    println!("\n--- 2. Why `Rc<T>` Is Not `Send` ---");
+
+    let session = Session {
+        id: 7,
+        cache: Rc::new(vec![1, 2, 3]),
+    };
+    /*
+    thread::spawn(move || session_size(session));
+    // error[E0277]: `Rc<Vec<u8>>` cannot be sent between threads safely
+    // note: required because it appears within the type `Session`
+    */
+    // The note shows the auto trait at work: `Session` never mentions
+    // `Send`, but it lost it through its `cache` field.
+    let cache_for_later = Rc::clone(&session.cache);
+    assert_eq!(session_size(session), 10); // Fine on this thread
+    assert_eq!(Rc::strong_count(&cache_for_later), 1);
+
+    // Other ways to hit the same error:
+    /*
+    let counter = Cell::new(0);
+    let counter_ref = &counter;
+    thread::scope(|s| {
+        s.spawn(|| counter_ref.set(1));
+    });
+    // error[E0277]: `Cell<i32>` cannot be shared between threads safely
+    // note: required for `&Cell<i32>` to implement `Send`
+    */
+    // `&T` is `Send` only if `T` is `Sync`: that's the definition of `Sync`.
+
+    // -------------------------------------------------------------------------
+    // 3. `Arc<Mutex<T>>` Restores `Send + Sync`
+    // -------------------------------------------------------------------------
+    // `Arc` fixes the count (atomic operations), and `Mutex` fixes mutation
+    // (one thread at a time). Each covers one half:
+    // - `Arc<T>` is `Send + Sync` only if `T: Send + Sync`;
+    // - `Mutex<T>` is `Sync` if `T: Send`, even when `T` isn't `Sync`.
+
+    println!("\n--- 3. `Arc<Mutex<T>>` Restores `Send + Sync` ---");
+
+    let session = SharedSession {
+        id: 7,
+        cache: Arc::new(vec![1, 2, 3]),
+    };
+    let result = thread::spawn(move || shared_session_size(session))
+        .join()
+        .unwrap();
+    assert_eq!(result, 10);
+
+    /*
+    let counter = Arc::new(RefCell::new(0));
+    let c = Arc::clone(&counter);
+    thread::spawn(move || *c.borrow_mut() += 1);
+    // error[E0277]: `RefCell<i32>` cannot be shared between threads safely
+    // note: required for `Arc<RefCell<i32>>` to implement `Send`
+    */
+    let counter = Arc::new(Mutex::new(0));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    *counter.lock().unwrap() += 1;
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!(
+        "Arc<Mutex<i32>> across 4 threads: {}",
+        counter.lock().unwrap()
+    );
+    assert_eq!(*counter.lock().unwrap(), 4000);
+
+    // `Mutex<Cell<T>>` is `Sync`: the lock makes the `Cell` safe to share.
+    assert_sync::<Mutex<Cell<i32>>>();
+    assert_sync::<Mutex<RefCell<Vec<i32>>>>();
+    // But a `Mutex` can't rescue a value that isn't even `Send`: an `Rc`
+    // inside could still have clones outside the lock.
+    /*
+    let m = Arc::new(Mutex::new(Rc::new(1)));
+    thread::spawn(move || **m.lock().unwrap());
+    // error[E0277]: `Rc<i32>` cannot be sent between threads safely
+    // note: required for `std::sync::Mutex<Rc<i32>>` to implement `Sync`
+    // note: required for `Arc<std::sync::Mutex<Rc<i32>>>` to implement `Send`
+    */
+
+    // -------------------------------------------------------------------------
+    // 4. `unsafe impl Send`: Promising It Yourself
+    // -------------------------------------------------------------------------
+    // `Buffer` holds a raw pointer, so the compiler drops `Send`/`Sync`, and
+    // our `unsafe impl`s above put them back. Nothing checks that promise:
+    // getting it wrong is undefined behavior, not a compile error. Only write
+    // one when the type really has exclusive ownership (or its own locking).
+
+    println!("\n--- 4. `unsafe impl Send` ---");
+
+    let mut buffer = Buffer::new(1024);
+    buffer.fill(1);
+    let buffer = Arc::new(buffer); // `Arc<Buffer>` is `Send`: `Buffer` is `Send + Sync`
+    let sums: Vec<u32> = (0..3)
+        .map(|_| {
+            let buffer = Arc::clone(&buffer);
+            thread::spawn(move || buffer.sum())
+        })
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    println!("Sums read from three threads: {:?}", sums);
+    assert_eq!(sums, [1024, 1024, 1024]);
+
+    // -------------------------------------------------------------------------
+    // 5. `Send` Futures: What an `async` Block Holds Across `.await`
+    // -------------------------------------------------------------------------
+    // An `async` block is a struct too (see 59-implementing-future.rs), and
+    // its fields are the variables alive at an `.await`. So an `Rc` held
+    // across an `.await` makes the future non-`Send`, and `tokio::spawn` on
+    // a multi-threaded runtime (which may resume it on another thread)
+    // rejects it:
+    /*
+    tokio::spawn(async {
+        let names = Rc::new(vec!["a"]);
+        tick().await;
+        println!("{}", names.len());
+    });
+    // error: future cannot be sent between threads safely
+    // note: future is not `Send` as this value is used across an await
+    */
+    // A `std::sync::MutexGuard` held across an `.await` gives the same error,
+    // for `MutexGuard<'_, i32>`: the lock must be released on the thread
+    // that took it. (Both are checked by e0277_rc_across_await.rs and
+    // e0277_mutex_guard_across_await.rs, where this file's `require_send`
+    // stands in for `tokio::spawn`.)
+
+    println!("\n--- 5. `Send` Futures ---");
+
+    // Fix: make sure the non-`Send` value is gone before the `.await`.
+    let future = require_send(async {
+        let count = {
+            let names = Rc::new(vec!["a", "b"]);
+            names.len()
+        }; // `names` dropped here
+        tick().await;
+        count
+    });
+    // Or keep `Send` types only: `Arc`, and a guard dropped before awaiting.
+    let shared = Mutex::new(0);
+    let other = require_send(async {
+        *shared.lock().unwrap() += 1; // Guard dropped at the `;`
+        tick().await;
+    });
+    // `require_send` already proved it; we just drop them unpolled.
+    drop((future, other));
+    println!("Both futures are `Send`: checked at compile time.");
+    // (`tokio::spawn` requires `Send` on every runtime. `block_on` and
+    // `tokio::task::spawn_local` don't: they never move the future to
+    // another thread.)
+
+    println!("\n--- End of Send, Sync, and Auto Traits Examples ---");
+}
@@ -0,0 +1,329 @@
+// This file compares the two ways a trait can leave a type open:
+// - an *associated type*: `trait Container { type Item; }`. Each implementing
+//   type picks the `Item` once, so there's one impl per type;
+// - a *generic type parameter*: `trait Container<T> { }`. A type can
+//   implement `Container<u32>`, `Container<String>`, ... as separate traits.
+// We rebuild the `Counter` iterator from 09-iterator.rs both ways, and see
+// what each choice does to callers, to type inference, and to which impls
+// the coherence rules allow.
+
+use std::ops::Add;
+
+// -------------------------------------------------------------------------
+// 1. The Same Trait, Two Ways
+// -------------------------------------------------------------------------
+
+// Associated type: "a container has *an* item type".
+trait Container {
+    type Item;
+    fn get(&self, index: usize) -> Option<&Self::Item>;
+    fn first(&self) -> Option<&Self::Item> {
+        self.get(0)
+    }
+}
+
+// Generic parameter: "a container can be a container *of T*, for some Ts".
+trait ContainerOf<T> {
+    fn get(&self, index: usize) -> Option<&T>;
+    fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+}
+
+struct Shelf {
+    books: Vec<String>,
+}
+
+impl Container for Shelf {
+    type Item = String;
+    fn get(&self, index: usize) -> Option<&String> {
+        self.books.get(index)
+    }
+}
+
+impl ContainerOf<String> for Shelf {
+    fn get(&self, index: usize) -> Option<&String> {
+        self.books.get(index)
+    }
+}
+
+// Generic code needs one type parameter for the first design...
+fn first_of<C: Container>(container: &C) -> Option<&C::Item> {
+    container.first()
+}
+
+// ...and two for the second: `T` is part of the trait's name.
+fn first_of_generic<T, C: ContainerOf<T>>(container: &C) -> Option<&T> {
+    container.first()
+}
+
+// -------------------------------------------------------------------------
+// 2. `Counter`, Both Ways
+// -------------------------------------------------------------------------
+// Our own copies of the iterator trait, so both designs can be compared
+// side by side. The standard `Iterator` uses the first one.
+
+trait MyIterator {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+
+    // A default method that mentions `Self::Item` without knowing it.
+    fn collect_vec(mut self) -> Vec<Self::Item>
+    where
+        Self: Sized,
+    {
+        let mut items = Vec::new();
+        while let Some(item) = self.next() {
+            items.push(item);
+        }
+        items
+    }
+}
+
+trait GenericIterator<T> {
+    fn next(&mut self) -> Option<T>;
+
+    fn collect_vec(mut self) -> Vec<T>
+    where
+        Self: Sized,
+    {
+        let mut items = Vec::new();
+        while let Some(item) = self.next() {
+            items.push(item);
+        }
+        items
+    }
+}
+
+// Counts 1 to 5, as in 09-iterator.rs.
+struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    fn new() -> Counter {
+        Counter { count: 0 }
+    }
+
+    fn step(&mut self) -> Option<u32> {
+        if self.count < 5 {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+impl MyIterator for Counter {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> {
+        self.step()
+    }
+}
+
+impl GenericIterator<u32> for Counter {
+    fn next(&mut self) -> Option<u32> {
+        self.step()
+    }
+}
+
+// With a generic trait, nothing stops a *second* impl for the same type.
+// Here `Counter` also yields its numbers as words.
+impl GenericIterator<String> for Counter {
+    fn next(&mut self) -> Option<String> {
+        let words = ["one", "two", "three", "four", "five"];
+        self.step().map(|n| words[n as usize - 1].to_string())
+    }
+}
+
+// The associated type can't do that: `Item` is chosen once per type.
+/*
+impl MyIterator for Counter {
+    type Item = String;
+    fn next(&mut self) -> Option<String> { ... }
+}
+// error[E0119]: conflicting implementations of trait `MyIterator` for type `Counter`
+*/
+
+// Counting items works for any `MyIterator`, whatever its `Item`.
+fn count_items<I: MyIterator>(mut iter: I) -> usize {
+    let mut count = 0;
+    while iter.next().is_some() {
+        count += 1;
+    }
+    count
+}
+
+// The generic version needs `T` as well, and callers may have to name it.
+fn count_items_generic<T, I: GenericIterator<T>>(mut iter: I) -> usize {
+    let mut count = 0;
+    while iter.next().is_some() {
+        count += 1;
+    }
+    count
+}
+
+// Constraining the item: `Item = u32` in the bound, vs `GenericIterator<u32>`.
+fn total<I: MyIterator<Item = u32>>(iter: I) -> u32 {
+    iter.collect_vec().into_iter().sum()
+}
+
+fn total_generic<I: GenericIterator<u32>>(iter: I) -> u32 {
+    iter.collect_vec().into_iter().sum()
+}
+
+// -------------------------------------------------------------------------
+// 3. Where Several Impls Are the Point
+// -------------------------------------------------------------------------
+// `Add<Rhs>` is generic over the right-hand side, because one type can
+// sensibly be added to several others. But its `Output` is an associated
+// type: once you know both operand types, the result type is fixed.
+// Rule of thumb: *inputs* are generic parameters, *outputs* are associated
+// types.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centimeters(f64);
+
+impl Add for Meters {
+    // `Add<Meters>`: `Rhs` defaults to `Self`
+    type Output = Meters;
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0 + other.0)
+    }
+}
+
+impl Add<Centimeters> for Meters {
+    type Output = Meters;
+    fn add(self, other: Centimeters) -> Meters {
+        Meters(self.0 + other.0 / 100.0)
+    }
+}
+
+// -------------------------------------------------------------------------
+// 4. Coherence: What Other Crates May Implement
+// -------------------------------------------------------------------------
+// The orphan rule (see 12-traits.rs) allows `impl ForeignTrait for
+// ForeignType` only when a *local* type appears in the right place. A
+// generic parameter is such a place: `impl From<Meters> for f64` is allowed,
+// because `Meters` is ours, even though `From` and `f64` are not.
+
+impl From<Meters> for f64 {
+    fn from(meters: Meters) -> f64 {
+        meters.0
+    }
+}
+
+// An associated type is not such a place: it's part of the impl's body, not
+// of what's being implemented. `Iterator` and `Vec` are both foreign:
+/*
+impl Iterator for Vec<Meters> {
+    type Item = Meters;
+    fn next(&mut self) -> Option<Meters> { self.pop() }
+}
+// error[E0117]: only traits defined in the current crate can be implemented for types defined outside of the crate
+*/
+
+fn main() {
+    println!("--- Associated Types vs Generic Type Parameters ---");
+
+    println!("\n--- 1. The Same Trait, Two Ways ---");
+
+    let shelf = Shelf {
+        books: vec!["Dune".to_string(), "Emma".to_string()],
+    };
+    // Both designs read the same at the call site while there's one impl.
+    assert_eq!(first_of(&shelf).map(String::as_str), Some("Dune"));
+    assert_eq!(first_of_generic(&shelf).map(String::as_str), Some("Dune"));
+    assert_eq!(Container::get(&shelf, 1).map(String::as_str), Some("Emma"));
+    println!("First book, either way: {:?}", first_of(&shelf));
+    // (`Shelf` has two methods named `get`, one per trait, so `shelf.get(1)`
+    // would be ambiguous: we name the trait. Real code rarely implements both.)
+
+    // -------------------------------------------------------------------------
+    // 2. `Counter`, Both Ways
+    // -------------------------------------------------------------------------
+    // With the associated type, `Counter` has exactly one item type, so the
+    // compiler always knows what `next()` returns. (The calls below name the
+    // trait only because `Counter` implements both traits' `next`.)
+
+    println!("\n--- 2. `Counter`, Both Ways ---");
+
+    let mut counter = Counter::new();
+    let first = MyIterator::next(&mut counter); // Always `Option<u32>`
+    assert_eq!(first, Some(1));
+    assert_eq!(count_items(Counter::new()), 5);
+    assert_eq!(total(Counter::new()), 15);
+    println!(
+        "MyIterator:       {:?}",
+        MyIterator::collect_vec(Counter::new())
+    );
+
+    // With the generic trait and two impls, `next()` could return either, so
+    // every call site must say which one it means:
+    /*
+    let mut counter = Counter::new();
+    let next = GenericIterator::next(&mut counter);
+    // error[E0283]: type annotations needed for `Option<_>`
+    // note: multiple `impl`s satisfying `Counter: GenericIterator<_>` found
+
+    count_items_generic(Counter::new());
+    // error[E0283]: type annotations needed
+    */
+    let mut counter = Counter::new();
+    let as_number: Option<u32> = GenericIterator::next(&mut counter); // Via the type...
+    let as_word = GenericIterator::<String>::next(&mut counter); // ...or the trait
+    assert_eq!(as_number, Some(1));
+    assert_eq!(as_word.as_deref(), Some("two"));
+    assert_eq!(count_items_generic::<u32, _>(Counter::new()), 5);
+    assert_eq!(total_generic(Counter::new()), 15); // The bound names `u32`: fine
+    let words: Vec<String> = GenericIterator::collect_vec(Counter::new());
+    println!("GenericIterator:  {:?}", words);
+    assert_eq!(words, ["one", "two", "three", "four", "five"]);
+
+    // This is why `Iterator` uses an associated type: an iterator yields one
+    // kind of item, and `for x in iter`, `.map()`, `.sum()` all rely on the
+    // compiler knowing which without being told.
+
+    // -------------------------------------------------------------------------
+    // 3. Where Several Impls Are the Point
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 3. `Add<Rhs>` with `type Output` ---");
+
+    let length = Meters(2.0) + Meters(1.5);
+    let longer = length + Centimeters(50.0); // A second `Add` impl
+    println!("{:?} + 50cm = {:?}", length, longer);
+    assert_eq!(length, Meters(3.5));
+    assert_eq!(longer, Meters(4.0));
+    // Other std traits with this shape: `From<T>` (one impl per source
+    // type), `PartialEq<Rhs>`, `AsRef<T>`, `Index<Idx>` (`type Output`).
+
+    // -------------------------------------------------------------------------
+    // 4. Coherence
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Coherence ---");
+
+    let raw: f64 = Meters(12.5).into(); // Our `From<Meters> for f64`
+    assert_eq!(raw, 12.5);
+    println!("f64::from(Meters(12.5)) = {}", raw);
+
+    // -------------------------------------------------------------------------
+    // Summary
+    // -------------------------------------------------------------------------
+    // | Question                                  | Associated type | Generic param   |
+    // |-------------------------------------------|-----------------|-----------------|
+    // | Impls of the trait per type               | one             | one per `T`     |
+    // | Callers must name the type?               | never           | if ambiguous    |
+    // | Extra type parameter in generic functions | no              | yes (`T`)       |
+    // | Local type as parameter beats orphan rule | no              | yes             |
+    // | Typical std examples                      | Iterator, Deref | From, Add<Rhs>  |
+    // Pick an associated type when the implementing type determines the
+    // answer, and a generic parameter when callers choose it.
+
+    println!("\n--- End of Associated Types vs Generics Examples ---");
+}
@@ -0,0 +1,337 @@
+// This file covers the `Default` trait: a type's "empty" or "standard"
+// value, from `T::default()`. We derive it, write it by hand when the
+// derived values are wrong, and combine it with struct update syntax
+// (`..Default::default()`) to build configuration structs where callers
+// only spell out what they change, without writing a builder. Finally, the
+// std APIs that lean on `Default`, starting with `Option::unwrap_or_default`
+// from the options lesson (08-options.rs).
+
+use std::collections::HashMap;
+use std::ops::AddAssign;
+use std::time::Duration;
+
+// -------------------------------------------------------------------------
+// 1. Deriving `Default`
+// -------------------------------------------------------------------------
+// `#[derive(Default)]` sets every field to *its* default: 0, 0.0, false,
+// "", empty collections, `None`. It compiles only if every field type
+// implements `Default`.
+
+#[derive(Debug, Default, PartialEq)]
+struct Stats {
+    requests: u64,
+    errors: u32,
+    mean_latency_ms: f64,
+    last_error: Option<String>,
+    per_route: HashMap<String, u64>,
+}
+
+// Enums pick a variant with `#[default]` (a unit variant).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+}
+/*
+#[derive(Default)]
+enum Level { Low, High }
+// error[E0665]: `#[derive(Default)]` on enum with no `#[default]`
+*/
+
+// -------------------------------------------------------------------------
+// 2. Implementing `Default` by Hand
+// -------------------------------------------------------------------------
+// Derived defaults are "zero" values. A server listening on port 0 with 0
+// workers is not a sensible default, so `ServerConfig` writes its own.
+
+#[derive(Debug, Clone, PartialEq)]
+struct ServerConfig {
+    host: String,
+    port: u16,
+    workers: usize,
+    timeout: Duration,
+    log_level: LogLevel,
+    tls: Option<TlsConfig>,
+    allowed_origins: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct TlsConfig {
+    cert_path: String,
+    key_path: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+            workers: 4,
+            timeout: Duration::from_secs(30),
+            log_level: LogLevel::default(), // `Info`
+            tls: None,
+            allowed_origins: Vec::new(),
+        }
+    }
+}
+
+// Derive has one more catch for generic types: it adds a `T: Default`
+// bound, even when no `T` is ever created.
+#[derive(Debug, Default)]
+struct DerivedCache<T> {
+    items: Vec<T>, // An empty `Vec<T>` needs no `T`...
+}
+
+// A token type with no sensible default value.
+#[derive(Debug, Clone, PartialEq)]
+struct SessionToken(String);
+
+/*
+let cache: DerivedCache<SessionToken> = DerivedCache::default();
+// error[E0277]: the trait bound `SessionToken: Default` is not satisfied
+// note: required for `DerivedCache<SessionToken>` to implement `Default`
+*/
+
+// ...so write the impl without the bound.
+#[derive(Debug)]
+struct Cache<T> {
+    items: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> Default for Cache<T> {
+    fn default() -> Self {
+        Cache {
+            items: Vec::new(),
+            capacity: 16,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------
+// 4. Nested Configuration Without a Builder
+// -------------------------------------------------------------------------
+// When every part of a configuration has a `Default`, callers can override
+// any field at any depth with struct update syntax, and the compiler still
+// checks field names and types. (A builder adds validation or a fluent
+// API, at the cost of one method per field: see 61-timeouts-and-retries.rs
+// for `RetryPolicy`, which is one.)
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct AppConfig {
+    server: ServerConfig,
+    database: DatabaseConfig,
+    feature_flags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DatabaseConfig {
+    url: String,
+    pool_size: u32,
+    statement_timeout: Option<Duration>,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        DatabaseConfig {
+            url: "postgres://localhost/app".to_string(),
+            pool_size: 10,
+            statement_timeout: None,
+        }
+    }
+}
+
+fn parse_level(name: &str) -> Option<LogLevel> {
+    match name {
+        "error" => Some(LogLevel::Error),
+        "warn" => Some(LogLevel::Warn),
+        "info" => Some(LogLevel::Info),
+        "debug" => Some(LogLevel::Debug),
+        _ => None,
+    }
+}
+
+// Generic code can ask for "a zero of whatever type this is".
+fn sum_all<T: Default + AddAssign + Copy>(values: &[T]) -> T {
+    let mut total = T::default();
+    for &value in values {
+        total += value;
+    }
+    total
+}
+
+fn main() {
+    println!("--- Default and Struct Update Syntax ---");
+
+    println!("\n--- 1. Deriving `Default` ---");
+
+    let stats = Stats::default();
+    println!("{:?}", stats);
+    assert_eq!(stats.requests, 0);
+    assert_eq!(stats.mean_latency_ms, 0.0);
+    assert_eq!(stats.last_error, None);
+    assert!(stats.per_route.is_empty());
+    assert_eq!(LogLevel::default(), LogLevel::Info);
+
+    // Three ways to ask for it; type inference picks the impl.
+    let a: Stats = Default::default();
+    let b = Stats::default();
+    let c = <Stats as Default>::default();
+    assert!(a == b && b == c);
+
+    println!("\n--- 2. Implementing `Default` by Hand ---");
+
+    let config = ServerConfig::default();
+    println!("{:#?}", config);
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.workers, 4);
+
+    let cache: Cache<SessionToken> = Cache::default(); // No `SessionToken::default()` needed
+    assert!(cache.items.is_empty());
+    assert_eq!(cache.capacity, 16);
+    let derived: DerivedCache<u32> = DerivedCache::default(); // Fine: `u32: Default`
+    assert!(derived.items.is_empty());
+
+    // -------------------------------------------------------------------------
+    // 3. Struct Update Syntax: `..Default::default()`
+    // -------------------------------------------------------------------------
+    // `Struct { a: x, ..base }` takes the listed fields from the literal and
+    // every other field from `base`, which can be any value of the same type.
+    // With `..Default::default()`, you write only what differs.
+
+    println!("\n--- 3. Struct Update Syntax ---");
+
+    let production = ServerConfig {
+        host: "0.0.0.0".to_string(),
+        workers: 16,
+        tls: Some(TlsConfig {
+            cert_path: "/etc/app/cert.pem".to_string(),
+            key_path: "/etc/app/key.pem".to_string(),
+        }),
+        ..Default::default()
+    };
+    assert_eq!(production.port, 8080); // From the default
+    assert_eq!(production.workers, 16); // Overridden
+    println!(
+        "Production: {}:{} with {} workers, TLS: {}",
+        production.host,
+        production.port,
+        production.workers,
+        production.tls.is_some()
+    );
+
+    // The base can be another config, e.g. staging = production with changes.
+    let staging = ServerConfig {
+        port: 9090,
+        log_level: LogLevel::Debug,
+        ..production.clone()
+    };
+    assert_eq!(staging.workers, 16);
+    assert_eq!(staging.tls, production.tls);
+
+    // `..base` *moves* the fields it takes. Without the `.clone()` above,
+    // `production`'s `String` and `Option<TlsConfig>` fields would move into
+    // `staging`, and `production` couldn't be used as a whole any more:
+    /*
+    let staging = ServerConfig { port: 9090, ..production };
+    println!("{:?}", production);
+    // error[E0382]: borrow of partially moved value: `production`
+    */
+    // (04-structures.rs shows the same with `..user3`.)
+
+    // Note that `..Default::default()` builds a *complete* default value
+    // first and then discards the overridden fields, so an expensive
+    // `default()` is paid in full each time.
+
+    // -------------------------------------------------------------------------
+    // 4. Nested Configuration Without a Builder
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Nested Configuration ---");
+
+    let app = AppConfig {
+        server: ServerConfig {
+            port: 3000,
+            ..Default::default()
+        },
+        database: DatabaseConfig {
+            pool_size: 50,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    println!(
+        "Server port {}, DB pool {}, DB url {}",
+        app.server.port, app.database.pool_size, app.database.url
+    );
+    assert_eq!(app.server.port, 3000);
+    assert_eq!(app.server.host, "127.0.0.1");
+    assert_eq!(app.database.pool_size, 50);
+    assert_eq!(app.database.url, "postgres://localhost/app");
+    assert!(app.feature_flags.is_empty());
+
+    // In tests, this keeps fixtures short: set only what the test is about.
+    let config_for_test = AppConfig {
+        feature_flags: vec!["new-checkout".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(config_for_test.server, ServerConfig::default());
+
+    // A library that wants to add fields later can mark its config
+    // `#[non_exhaustive]`. Other crates then can't write a struct literal
+    // for it at all, even with `..Default::default()`: they start from
+    // `Config::default()` and assign fields, or use a builder.
+
+    // -------------------------------------------------------------------------
+    // 5. std APIs Built on `Default`
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 5. std APIs Built on `Default` ---");
+
+    // `Option::unwrap_or_default` (08-options.rs): the value, or `T::default()`.
+    assert_eq!(parse_level("error").unwrap_or_default(), LogLevel::Error);
+    assert_eq!(parse_level("loud").unwrap_or_default(), LogLevel::Info); // Our `#[default]`
+    let parsed: Result<u32, _> = "not a number".parse::<u32>();
+    assert_eq!(parsed.unwrap_or_default(), 0); // `Result` has it too
+
+    // `HashMap::entry(..).or_default()`: insert the default on first use.
+    let mut hits: HashMap<&str, u32> = HashMap::new();
+    for route in ["/", "/about", "/", "/"] {
+        *hits.entry(route).or_default() += 1;
+    }
+    assert_eq!(hits["/"], 3);
+    let mut by_level: HashMap<LogLevel, Vec<&str>> = HashMap::new();
+    by_level
+        .entry(LogLevel::Warn)
+        .or_default()
+        .push("disk 90% full");
+    assert_eq!(by_level[&LogLevel::Warn], ["disk 90% full"]);
+
+    // `std::mem::take`: move a value out of a `&mut`, leaving the default.
+    let mut stats = Stats {
+        requests: 120,
+        errors: 3,
+        ..Default::default()
+    };
+    let snapshot = std::mem::take(&mut stats); // Report and reset in one step
+    assert_eq!(snapshot.requests, 120);
+    assert_eq!(stats, Stats::default());
+
+    // Generic code: `T::default()` as the starting value.
+    assert_eq!(sum_all(&[1, 2, 3]), 6);
+    assert_eq!(sum_all(&[0.5, 0.25]), 0.75);
+    assert_eq!(sum_all::<u8>(&[]), 0);
+
+    // Tuples, arrays and references to slices implement it too.
+    let (count, name, flag): (u32, String, bool) = Default::default();
+    assert_eq!((count, name.as_str(), flag), (0, "", false));
+    assert_eq!(<[i32; 3]>::default(), [0, 0, 0]);
+    assert!(<&[u8]>::default().is_empty());
+
+    println!("Defaults checked.");
+
+    println!("\n--- End of Default and Struct Update Examples ---");
+}
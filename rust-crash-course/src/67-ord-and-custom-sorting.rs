@@ -0,0 +1,383 @@
+// This file covers ordering your own types and sorting by custom rules:
+// - deriving `PartialOrd`/`Ord`, and implementing them by hand for a
+//   semantic-versioning `Version` where the derived order is wrong;
+// - keeping `PartialEq`, `Eq`, `PartialOrd` and `Ord` consistent, and what
+//   breaks (a `BTreeSet` losing values) when they aren't;
+// - `sort`, `sort_by_key`, `sort_by`, `Reverse`, `min_by`/`max_by_key`;
+// - the float pitfalls: `f64` has only a partial order.
+// 23-ordering-and-floats.rs goes deeper on floats, and
+// 52-sorting-and-searching.rs implements the sorting algorithms themselves.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeSet;
+use std::fmt;
+
+// -------------------------------------------------------------------------
+// 1. Derived Ordering: Field by Field
+// -------------------------------------------------------------------------
+// `#[derive(PartialOrd, Ord)]` compares the fields in declaration order,
+// like words in a dictionary: the first field that differs decides. For
+// enums, earlier variants are smaller.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct SimpleVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+// -------------------------------------------------------------------------
+// 2. `Version`: Implementing `Ord` by Hand
+// -------------------------------------------------------------------------
+// Semantic versioning adds two parts the derive gets wrong:
+// - a pre-release tag: `1.0.0-alpha < 1.0.0`. With `pre: Option<String>`,
+//   derive would put `None` (the release) *before* `Some("alpha")`;
+// - build metadata: `1.0.0+build.5` and `1.0.0+build.7` have the *same*
+//   precedence. Derive would compare it like any other field.
+
+#[derive(Debug, Clone)]
+struct Version {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    pre: Option<String>,   // "alpha", "rc.1", ...
+    build: Option<String>, // Ignored when comparing
+}
+
+impl Version {
+    // Parses "MAJOR.MINOR.PATCH[-PRE][+BUILD]".
+    fn parse(text: &str) -> Option<Version> {
+        let (rest, build) = match text.split_once('+') {
+            Some((rest, build)) => (rest, Some(build.to_string())),
+            None => (text, None),
+        };
+        let (core, pre) = match rest.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (rest, None),
+        };
+        let mut numbers = core.split('.').map(|part| part.parse::<u32>().ok());
+        let version = Version {
+            major: numbers.next()??,
+            minor: numbers.next()??,
+            patch: numbers.next()??,
+            pre,
+            build,
+        };
+        numbers.next().is_none().then_some(version) // Exactly three numbers
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+        if let Some(build) = &self.build {
+            write!(f, "+{}", build)?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Tuples compare field by field, like the derive.
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater, // A release beats its pre-releases
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b), // Simplified: the spec compares dot-separated parts
+            })
+        // `build` is deliberately not compared.
+    }
+}
+
+// The other three must agree with `cmp`, so they're written in terms of it.
+// Deriving `PartialEq` would compare `build` too, and then two versions
+// could be "neither less, nor greater, nor equal".
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+fn v(text: &str) -> Version {
+    Version::parse(text).expect("valid version")
+}
+
+// -------------------------------------------------------------------------
+// 3. Inconsistent Ordering: A Bug the Compiler Can't See
+// -------------------------------------------------------------------------
+// `Ord` and `Eq` are "trust me" traits: nothing checks that they agree.
+// Here `cmp` looks only at `score`, while the derived `PartialEq` compares
+// both fields.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Player {
+    name: String,
+    score: u32,
+}
+
+impl Ord for Player {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score.cmp(&other.score) // BUG: `name` ignored, but `==` uses it
+    }
+}
+
+impl PartialOrd for Player {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn player(name: &str, score: u32) -> Player {
+    Player {
+        name: name.to_string(),
+        score,
+    }
+}
+
+fn main() {
+    println!("--- Ord, PartialOrd and Custom Sorting ---");
+
+    println!("\n--- 1. Derived Ordering ---");
+
+    let old = SimpleVersion {
+        major: 1,
+        minor: 9,
+        patch: 3,
+    };
+    let new = SimpleVersion {
+        major: 1,
+        minor: 10,
+        patch: 0,
+    };
+    assert!(old < new); // `minor` decides: 9 < 10
+    assert_eq!(old.max(new), new); // `Ord` gives `max`, `min` and `clamp`
+    assert!(Priority::Low < Priority::Medium && Priority::Medium < Priority::High);
+    // Comparing the *strings* gets this wrong: '9' > '1' at the third byte.
+    assert!("1.9.3" > "1.10.0");
+    println!("1.9.3 < 1.10.0 as versions, but not as strings");
+
+    // -------------------------------------------------------------------------
+    // 2. `Version` with Pre-Releases and Build Metadata
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 2. A Hand-Written `Ord` ---");
+
+    assert!(v("1.0.0-alpha") < v("1.0.0")); // Derive would say the opposite
+    assert!(v("1.0.0-alpha") < v("1.0.0-beta"));
+    assert!(v("1.0.0") < v("1.0.1-alpha"));
+    assert_eq!(v("1.0.0+build.5"), v("1.0.0+build.7")); // Same precedence
+    assert_eq!(v("2.1.0").cmp(&v("2.1.0+ci")), Ordering::Equal); // Agrees with `==`
+    assert!(Version::parse("1.0").is_none());
+    assert!(Version::parse("1.0.0.0").is_none());
+
+    let mut releases: Vec<Version> = [
+        "1.10.0",
+        "1.2.0",
+        "2.0.0-rc.1",
+        "1.2.0-beta",
+        "2.0.0",
+        "1.9.3",
+    ]
+    .into_iter()
+    .map(v)
+    .collect();
+    releases.sort(); // Uses our `Ord`
+    let sorted: Vec<String> = releases.iter().map(Version::to_string).collect();
+    println!("Sorted: {}", sorted.join(" < "));
+    assert_eq!(
+        sorted,
+        [
+            "1.2.0-beta",
+            "1.2.0",
+            "1.9.3",
+            "1.10.0",
+            "2.0.0-rc.1",
+            "2.0.0"
+        ]
+    );
+
+    // "Latest stable": filter, then `max` (needs `Ord`).
+    let latest_stable = releases.iter().filter(|r| r.pre.is_none()).max();
+    assert_eq!(
+        latest_stable.map(Version::to_string).as_deref(),
+        Some("2.0.0")
+    );
+
+    // `Ordering` has combinators for building comparisons:
+    assert_eq!(Ordering::Less.reverse(), Ordering::Greater);
+    assert_eq!(Ordering::Equal.then(Ordering::Less), Ordering::Less); // First non-`Equal` wins
+    assert_eq!(Ordering::Greater.then(Ordering::Less), Ordering::Greater);
+    assert_eq!(7.clamp(1, 5), 5);
+
+    // -------------------------------------------------------------------------
+    // 3. Inconsistent `Ord` and `Eq`
+    // -------------------------------------------------------------------------
+    // A `BTreeSet` finds and deduplicates with `cmp` alone, so two *different*
+    // players with the same score count as the same element.
+
+    println!("\n--- 3. Inconsistent `Ord` and `Eq` ---");
+
+    let alice = player("Alice", 90);
+    let bob = player("Bob", 90);
+    assert_ne!(alice, bob); // `==` says different...
+    assert_eq!(alice.cmp(&bob), Ordering::Equal); // ...`cmp` says equal
+
+    let mut leaderboard = BTreeSet::new();
+    assert!(leaderboard.insert(alice.clone()));
+    let inserted = leaderboard.insert(bob.clone());
+    println!(
+        "Inserted Bob: {}; set: {:?}",
+        inserted,
+        leaderboard.iter().map(|p| &p.name).collect::<Vec<_>>()
+    );
+    assert!(!inserted); // Bob was silently dropped
+    assert!(leaderboard.contains(&bob)); // And yet "contains" him: it finds Alice
+    // The fix is to compare every field `==` compares, e.g.
+    // `self.score.cmp(&other.score).then_with(|| self.name.cmp(&other.name))`.
+    // Or keep `Player` unordered and sort by key instead (section 4).
+
+    // -------------------------------------------------------------------------
+    // 4. Sorting by Key and by Comparator
+    // -------------------------------------------------------------------------
+
+    println!("\n--- 4. Sorting by Key and by Comparator ---");
+
+    let roster = vec![
+        player("Dana", 70),
+        player("Ana", 90),
+        player("Cleo", 85),
+        player("Ben", 90),
+        player("Eve", 85),
+    ];
+
+    // `sort_by_key`: sort by something extracted from each element.
+    let mut by_score = roster.clone();
+    by_score.sort_by_key(|p| p.score);
+    let names: Vec<&str> = by_score.iter().map(|p| p.name.as_str()).collect();
+    // `sort` and friends are *stable*: equal keys keep their original order
+    // (Ana before Ben, Cleo before Eve). `sort_unstable*` doesn't promise that.
+    assert_eq!(names, ["Dana", "Cleo", "Eve", "Ana", "Ben"]);
+
+    // `Reverse` flips a key's order: highest score first, still stable.
+    let mut ranking = roster.clone();
+    ranking.sort_by_key(|p| Reverse(p.score));
+    let names: Vec<&str> = ranking.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["Ana", "Ben", "Cleo", "Eve", "Dana"]);
+
+    // The key closure must return an owned value; it can't borrow the element:
+    /*
+    ranking.sort_by_key(|p| &p.name);
+    // error: lifetime may not live long enough
+    */
+    // Clone the key (`p.name.clone()`), or use `sort_by`, which gets both
+    // elements and returns their `Ordering` directly.
+
+    // `sort_by`: score descending, then name ascending, without cloning.
+    let mut leaderboard = roster.clone();
+    leaderboard.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+    let names: Vec<&str> = leaderboard.iter().map(|p| p.name.as_str()).collect();
+    println!("Leaderboard: {:?}", names);
+    assert_eq!(names, ["Ana", "Ben", "Cleo", "Eve", "Dana"]);
+
+    // The same as a tuple key: tuples compare field by field, and `Reverse`
+    // can wrap just one of them.
+    let mut by_tuple = roster.clone();
+    by_tuple.sort_by_key(|p| (Reverse(p.score), p.name.clone()));
+    assert_eq!(by_tuple, leaderboard);
+
+    // `sort_by_cached_key` computes each key once, for keys that are costly,
+    // like parsing: here, strings sorted as versions.
+    let mut tags = vec!["v1.10.0", "v1.2.0", "v1.9.3"];
+    tags.sort_by_cached_key(|tag| v(&tag[1..]));
+    assert_eq!(tags, ["v1.2.0", "v1.9.3", "v1.10.0"]);
+
+    // -------------------------------------------------------------------------
+    // 5. `min`/`max` by Key and by Comparator
+    // -------------------------------------------------------------------------
+    // On ties, `max*` return the *last* of the maximal elements and `min*`
+    // the *first* of the minimal ones.
+
+    println!("\n--- 5. min_by, max_by_key ---");
+
+    let top = roster.iter().max_by_key(|p| p.score).unwrap();
+    let bottom = roster.iter().min_by_key(|p| p.score).unwrap();
+    assert_eq!(top.name, "Ben"); // Ana and Ben tie at 90: the last wins
+    assert_eq!(bottom.name, "Dana");
+    let first_top = roster.iter().min_by_key(|p| Reverse(p.score)).unwrap();
+    assert_eq!(first_top.name, "Ana"); // For the *first* maximum, flip and take `min`
+
+    // `min_by` takes a comparator: shortest name, ties broken alphabetically.
+    let shortest = roster
+        .iter()
+        .min_by(|a, b| a.name.len().cmp(&b.name.len()).then(a.name.cmp(&b.name)))
+        .unwrap();
+    assert_eq!(shortest.name, "Ana");
+    println!(
+        "Top: {}, bottom: {}, shortest name: {}",
+        top.name, bottom.name, shortest.name
+    );
+
+    // -------------------------------------------------------------------------
+    // 6. Floats: Only a Partial Order
+    // -------------------------------------------------------------------------
+    // `f64` is `PartialOrd` but not `Ord`: NaN compares as neither less,
+    // equal nor greater than anything. So the `Ord`-based methods refuse it:
+    /*
+    let mut times = vec![1.5, 0.2];
+    times.sort();
+    let fastest = times.iter().min();
+    // error[E0277]: the trait bound `f64: Ord` is not satisfied
+    */
+
+    println!("\n--- 6. Floats ---");
+
+    let mut times = vec![2.5, f64::NAN, 0.75, 1.0, -0.0, 0.0];
+    // `total_cmp` is a total order on all floats: -NaN < -inf < ... < -0.0
+    // < 0.0 < ... < inf < NaN.
+    times.sort_by(f64::total_cmp);
+    println!("Sorted with total_cmp: {:?}", times);
+    assert_eq!(times[..5], [-0.0, 0.0, 0.75, 1.0, 2.5]);
+    assert!(times[5].is_nan()); // NaN sorts last
+    assert!(times[0].is_sign_negative()); // And -0.0 before 0.0
+
+    // `min_by`/`max_by` with `partial_cmp(..).unwrap()` panic on NaN; with
+    // `total_cmp` they don't. Filtering the NaNs out first is often clearer.
+    let fastest = times
+        .iter()
+        .filter(|t| !t.is_nan())
+        .copied()
+        .min_by(f64::total_cmp);
+    assert_eq!(fastest, Some(-0.0));
+    let slowest = times.iter().copied().max_by(f64::total_cmp).unwrap();
+    assert!(slowest.is_nan()); // Unfiltered, NaN is the "largest"
+
+    // For a key that's a float, `sort_by` with a comparator on that field:
+    let mut laps: Vec<(&str, f64)> = vec![("Ana", 61.2), ("Ben", 59.8), ("Cleo", 60.5)];
+    laps.sort_by(|a, b| a.1.total_cmp(&b.1));
+    assert_eq!(laps[0].0, "Ben");
+    // (`sort_by_key(|lap| lap.1)` doesn't compile: the key must be `Ord`.
+    // 23-ordering-and-floats.rs builds a `NotNan` wrapper that is.)
+
+    println!("\n--- End of Ord, PartialOrd and Custom Sorting Examples ---");
+}
@@ -0,0 +1,51 @@
+// A counting global allocator, for measuring how much a piece of code allocates.
+
+#![allow(dead_code)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Forwards to the system allocator and counts every `alloc` call.
+pub struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+// What a piece of code allocated. `bytes` adds up every request, including
+// the ones that were freed again (a growing `Vec` counts each buffer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+}
+
+// Runs `f` and returns its result together with what it allocated. Only
+// meaningful when `CountingAllocator` is the global allocator.
+pub fn measure_allocations<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let count_before = ALLOCATIONS.load(Ordering::SeqCst);
+    let bytes_before = ALLOCATED_BYTES.load(Ordering::SeqCst);
+    let result = f();
+    let allocations = Allocations {
+        count: ALLOCATIONS.load(Ordering::SeqCst) - count_before,
+        bytes: ALLOCATED_BYTES.load(Ordering::SeqCst) - bytes_before,
+    };
+    (result, allocations)
+}
+
+// Just the number of allocations.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let (result, allocations) = measure_allocations(f);
+    (result, allocations.count)
+}
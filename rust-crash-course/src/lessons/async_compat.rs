@@ -0,0 +1,19 @@
+// `run_blocking`, which runs CPU-heavy or blocking work off the async worker threads.
+
+#![allow(dead_code)]
+
+// Runs `work` on tokio's blocking pool. A panic inside `work` is re-raised
+// in the caller, just as if the closure had been called directly.
+pub async fn run_blocking<F, T>(work: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(work).await {
+        Ok(value) => value,
+        Err(join_error) if join_error.is_panic() => {
+            std::panic::resume_unwind(join_error.into_panic())
+        }
+        Err(join_error) => panic!("Blocking task was cancelled: {}", join_error),
+    }
+}
@@ -0,0 +1,37 @@
+// `CleanupGuard`, which removes a temporary file when dropped, even if its task was cancelled.
+
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Cancelling a task drops its future at the pending `.await`, which drops
+// its locals, so cleanup in `Drop` runs whether the task finished or not.
+pub struct CleanupGuard {
+    path: PathBuf,
+    cleaned_up: Arc<AtomicBool>, // Lets the caller observe that cleanup happened
+}
+
+impl CleanupGuard {
+    // The guard owns removing `path`; `cleaned_up` is set once it has.
+    pub fn new(path: impl Into<PathBuf>, cleaned_up: Arc<AtomicBool>) -> Self {
+        CleanupGuard {
+            path: path.into(),
+            cleaned_up,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for CleanupGuard {
+    fn drop(&mut self) {
+        // `drop` cannot be `async`, so use the quick, blocking std call here.
+        // The file may already be gone, which is fine.
+        let _ = std::fs::remove_file(&self.path);
+        self.cleaned_up.store(true, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,51 @@
+// `map_concurrent`, which maps an async function over items with a cap on how many run at once.
+
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+// Applies `f` to every item with at most `limit` calls running at once,
+// returning the results in the same order as `items`. A `limit` of 0 is
+// treated as 1. A panic in one of the calls is re-raised here.
+pub async fn map_concurrent<T, R, F, Fut>(items: Vec<T>, limit: usize, f: F) -> Vec<R>
+where
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = R> + Send + 'static,
+    R: Send + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(limit.max(1)));
+    let mut set = JoinSet::new();
+    for (index, item) in items.into_iter().enumerate() {
+        // Waits here while `limit` tasks are already running.
+        let permit = Arc::clone(&semaphore)
+            .acquire_owned()
+            .await
+            .expect("Semaphore closed");
+        let future = f(item);
+        set.spawn(async move {
+            let result = future.await;
+            drop(permit); // Free the slot for the next item
+            (index, result)
+        });
+    }
+
+    let mut indexed_results = Vec::new();
+    while let Some(joined) = set.join_next().await {
+        match joined {
+            Ok(indexed) => indexed_results.push(indexed),
+            Err(join_error) if join_error.is_panic() => {
+                std::panic::resume_unwind(join_error.into_panic())
+            }
+            Err(join_error) => panic!("map_concurrent task was cancelled: {}", join_error),
+        }
+    }
+    indexed_results.sort_by_key(|(index, _)| *index); // Restore input order
+    indexed_results
+        .into_iter()
+        .map(|(_, result)| result)
+        .collect()
+}
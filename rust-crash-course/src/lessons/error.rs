@@ -0,0 +1,67 @@
+// `MultiError`, which reports every failure from a batch of work instead of only the first.
+
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+
+// Each failure is kept as its message, so errors of different types (a
+// task's own error, a `JoinError`, ...) can be collected together.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MultiError {
+    errors: Vec<String>,
+}
+
+impl MultiError {
+    pub fn new() -> Self {
+        MultiError::default()
+    }
+
+    pub fn push(&mut self, error: impl fmt::Display) {
+        self.errors.push(error.to_string());
+    }
+
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    // Sorts the messages, so the report doesn't depend on the order in which
+    // concurrent work happened to fail.
+    pub fn sorted(mut self) -> Self {
+        self.errors.sort();
+        self
+    }
+
+    // `Ok(())` if nothing failed, so a caller can finish with `?`.
+    pub fn into_result(self) -> Result<(), MultiError> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl FromIterator<String> for MultiError {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        MultiError {
+            errors: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for MultiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} task(s) failed:", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "\n  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for MultiError {}
@@ -0,0 +1,59 @@
+// Extension traits adding a few helpers to `Option` and `Result`.
+
+#![allow(dead_code)]
+
+use std::fmt::Display;
+
+pub trait OptionExt<T> {
+    // Runs `f` if the option is `None`, then returns the option unchanged.
+    // Handy for noting a missing value in the middle of a chain.
+    fn tap_none(self, f: impl FnOnce()) -> Self;
+
+    // Runs `f` on the value if there is one, then returns the option unchanged.
+    fn tap_some(self, f: impl FnOnce(&T)) -> Self;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn tap_none(self, f: impl FnOnce()) -> Self {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+
+    fn tap_some(self, f: impl FnOnce(&T)) -> Self {
+        if let Some(value) = &self {
+            f(value);
+        }
+        self
+    }
+}
+
+pub trait ResultExt<T, E> {
+    // Prints the error to stderr as "[error] <context>: <error>" and turns the
+    // result into an `Option`. For failures that are worth reporting but not
+    // worth stopping for.
+    fn log_err(self, context: &str) -> Option<T>;
+
+    // Runs `f` on the error if there is one, then returns the result unchanged.
+    fn tap_err(self, f: impl FnOnce(&E)) -> Self;
+}
+
+impl<T, E: Display> ResultExt<T, E> for Result<T, E> {
+    fn log_err(self, context: &str) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(err) => {
+                eprintln!("[error] {}: {}", context, err);
+                None
+            }
+        }
+    }
+
+    fn tap_err(self, f: impl FnOnce(&E)) -> Self {
+        if let Err(err) = &self {
+            f(err);
+        }
+        self
+    }
+}
@@ -0,0 +1,119 @@
+// Human-readable durations and byte sizes ("1.3 s", "2.4 MiB"), and parsing them back.
+
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+// Largest first; a value is shown in the largest unit it reaches.
+const DURATION_UNITS: [(&str, u128); 4] = [
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("µs", 1_000),
+    ("ns", 1),
+];
+
+const BYTE_UNITS: [(&str, u128); 5] = [
+    ("TiB", 1 << 40),
+    ("GiB", 1 << 30),
+    ("MiB", 1 << 20),
+    ("KiB", 1 << 10),
+    ("B", 1),
+];
+
+// Shows `value` in the largest unit it reaches, rounded to one decimal place
+// (and without a trailing ".0"). Rounding happens before the unit is picked,
+// so 999.96 ms is "1 s", not "1000 ms".
+fn with_unit(value: u128, units: &[(&str, u128)]) -> String {
+    for &(unit, size) in units {
+        let tenths = (value * 10 + size / 2) / size;
+        if tenths >= 10 || size == 1 {
+            return if tenths.is_multiple_of(10) {
+                format!("{} {}", tenths / 10, unit)
+            } else {
+                format!("{}.{} {}", tenths / 10, tenths % 10, unit)
+            };
+        }
+    }
+    unreachable!("the last unit has size 1")
+}
+
+// `human_duration(Duration::from_millis(1_250))` is "1.3 s".
+pub fn human_duration(duration: Duration) -> String {
+    with_unit(duration.as_nanos(), &DURATION_UNITS)
+}
+
+// Binary units, as the allocator counts them: `human_bytes(2_500_000)` is
+// "2.4 MiB".
+pub fn human_bytes(bytes: usize) -> String {
+    with_unit(bytes as u128, &BYTE_UNITS)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseDurationError {
+    Empty,
+    InvalidNumber(String),
+    MissingUnit(String),
+    UnknownUnit(String),
+    OutOfRange(String),
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseDurationError::Empty => write!(f, "empty duration"),
+            ParseDurationError::InvalidNumber(s) => write!(f, "'{}' is not a number", s),
+            ParseDurationError::MissingUnit(s) => {
+                write!(f, "'{}' needs a unit (ns, µs, ms, s, m, h)", s)
+            }
+            ParseDurationError::UnknownUnit(unit) => write!(f, "unknown unit '{}'", unit),
+            ParseDurationError::OutOfRange(s) => write!(f, "'{}' is too long", s),
+        }
+    }
+}
+
+impl Error for ParseDurationError {}
+
+// Parses a number with a unit: "250ms", "1.3 s", "5us", "2m". The number is
+// read as a decimal, not through `f64`, so "0.1s" is exactly 100 ms.
+pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+    let unit_start = trimmed
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(unit_start);
+    let nanos_per_unit: u128 = match unit.trim_start() {
+        "ns" => 1,
+        "us" | "µs" => 1_000,
+        "ms" => 1_000_000,
+        "s" => 1_000_000_000,
+        "m" => 60 * 1_000_000_000,
+        "h" => 60 * 60 * 1_000_000_000,
+        "" => return Err(ParseDurationError::MissingUnit(s.to_string())),
+        other => return Err(ParseDurationError::UnknownUnit(other.to_string())),
+    };
+
+    let invalid = || ParseDurationError::InvalidNumber(s.to_string());
+    let out_of_range = || ParseDurationError::OutOfRange(s.to_string());
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() || fraction.contains('.') || fraction.len() > 9 {
+        return Err(invalid()); // Nine decimals already reach below a nanosecond
+    }
+    let whole: u128 = whole.parse().map_err(|_| out_of_range())?;
+    let fraction_nanos = if fraction.is_empty() {
+        0
+    } else {
+        let digits: u128 = fraction.parse().map_err(|_| invalid())?;
+        digits * nanos_per_unit / 10u128.pow(fraction.len() as u32)
+    };
+    let nanos = whole
+        .checked_mul(nanos_per_unit)
+        .and_then(|n| n.checked_add(fraction_nanos))
+        .ok_or_else(out_of_range)?;
+    let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| out_of_range())?;
+    Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
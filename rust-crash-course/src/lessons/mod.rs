@@ -0,0 +1,45 @@
+// Modules loaded from files by `33-modules-across-files.rs`.
+//
+// The lesson file declares `mod lessons;`, so the compiler looks for either
+// `src/lessons.rs` or this file, `src/lessons/mod.rs`. Each `mod` declaration
+// below in turn names a file or directory next to this one.
+//
+// The other files in this directory are deliberately not declared here.
+// Each is a helper that a lesson includes on its own, so it only compiles
+// the files it uses:
+//
+//   #[path = "lessons/table.rs"]
+//   mod table;
+//
+// A lesson rarely uses everything in a helper, so each helper file starts
+// with `#![allow(dead_code)]`. tests/lesson_helpers.rs includes them the
+// same way and tests them. The lesson in parentheses explains the helper:
+// - `table.rs`: text tables (39-custom-formatting.rs).
+// - `ext.rs`: extension traits for `Option` and `Result`
+//   (50-option-result-combinators.rs).
+// - `alloc.rs`: a counting global allocator. The including lesson installs
+//   it with `#[global_allocator]` (18-zero-copy-parsing.rs).
+// - `async_compat.rs`: `run_blocking`, which moves CPU-heavy work to tokio's
+//   blocking pool (16-asynchronous.rs, section 10). Needs the `ecosystem`
+//   feature.
+// - `async_guard.rs`: `CleanupGuard`, which removes a temp file when dropped,
+//   even by a cancelled task (16-asynchronous.rs, section 9).
+// - `async_iter.rs`: `map_concurrent`, an async map with a concurrency limit
+//   (16-asynchronous.rs, section 11). Needs the `ecosystem` feature.
+// - `error.rs`: `MultiError`, which collects every failure from a batch of
+//   tasks (16-asynchronous.rs, section 3.1).
+// - `fmt.rs`: human-readable durations and byte sizes, for the lessons that
+//   print timings and allocation reports.
+// - `money.rs`: a fixed-point money type stored as integer cents
+//   (26-floating-point-and-money.rs).
+// - `safety.rs`: lookups that return an error instead of panicking
+//   (07-rust_data_structures.rs).
+// - `strings.rs`: string slicing that can't split a character
+//   (07-rust_data_structures.rs). `grapheme_count` needs the `ecosystem`
+//   feature.
+// - `testing.rs`: a drop tracker for asserting drop order (13-pointers.rs).
+// - `text.rs`: character classification that understands accented letters
+//   (27-char-classification.rs). With the `ecosystem` feature, `base_letter`
+//   also decomposes letters its table doesn't list.
+
+pub mod modules_demo;
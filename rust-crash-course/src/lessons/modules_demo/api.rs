@@ -0,0 +1,86 @@
+// API layer: the only type callers use to work with notes.
+//
+// It reaches its sibling module through the parent (`super::storage`) and
+// shows the other ways to write a path to the same items.
+
+use std::fmt;
+
+use super::storage::{NoteId, NoteStore}; // Relative path: parent, then sibling
+use crate::lessons::modules_demo::MAX_TITLE_LEN; // Absolute path from the crate root
+
+#[derive(Debug, PartialEq)]
+pub enum ApiError {
+    EmptyTitle,
+    TitleTooLong(usize),
+    NotFound(NoteId),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApiError::EmptyTitle => write!(f, "a note needs a title"),
+            ApiError::TitleTooLong(len) => write!(
+                f,
+                "title is {} characters, the limit is {}",
+                len, MAX_TITLE_LEN
+            ),
+            ApiError::NotFound(id) => write!(f, "no note with id {}", id.value()),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+#[derive(Debug, Default)]
+pub struct NoteApi {
+    store: NoteStore, // Private field of a `pub(super)` type: fine, it never leaks out
+}
+
+impl NoteApi {
+    pub fn new() -> Self {
+        NoteApi::default()
+    }
+
+    pub fn create(&mut self, title: &str, body: &str) -> Result<NoteId, ApiError> {
+        self::validate_title(title)?; // `self::` is the current module; usually left out
+        Ok(self.store.insert(title, body))
+    }
+
+    pub fn title(&self, id: NoteId) -> Result<&str, ApiError> {
+        self.store
+            .get(id)
+            .map(|note| note.title.as_str())
+            .ok_or(ApiError::NotFound(id))
+    }
+
+    pub fn render(&self, id: NoteId) -> Result<String, ApiError> {
+        let note = self.store.get(id).ok_or(ApiError::NotFound(id))?;
+        Ok(format!(
+            "#{} {}\n{}",
+            note.id.value(),
+            note.title,
+            note.body
+        ))
+    }
+
+    pub fn delete(&mut self, id: NoteId) -> Result<(), ApiError> {
+        self.store
+            .remove(id)
+            .map(|_| ())
+            .ok_or(ApiError::NotFound(id))
+    }
+
+    pub fn count(&self) -> usize {
+        self.store.len()
+    }
+}
+
+// Private helper: not even the parent module can call it.
+fn validate_title(title: &str) -> Result<(), ApiError> {
+    let len = title.trim().chars().count();
+    match len {
+        0 => Err(ApiError::EmptyTitle),
+        len if len > MAX_TITLE_LEN => Err(ApiError::TitleTooLong(len)),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,23 @@
+// The root of the `modules_demo` module: a tiny note-taking service.
+//
+// This file decides what the outside world sees. The submodules themselves are
+// private (`mod`, not `pub mod`), so callers can't write
+// `modules_demo::storage::...`; instead, the few items they need are
+// re-exported with `pub use`. The file layout can then change without
+// breaking anyone who uses this module.
+
+mod api; // Loaded from `modules_demo/api.rs`
+mod storage; // Loaded from `modules_demo/storage.rs`
+
+// Re-exports: the public face of the module.
+pub use api::{ApiError, NoteApi};
+pub use storage::NoteId;
+
+// Visible anywhere in this crate, but not part of the module's public API.
+pub(crate) const MAX_TITLE_LEN: usize = 32;
+
+// Private to `modules_demo` and its children: `api` and `storage` can call it
+// (children see everything their ancestors define), but the lesson file can't.
+fn normalize_title(title: &str) -> String {
+    title.split_whitespace().collect::<Vec<_>>().join(" ")
+}
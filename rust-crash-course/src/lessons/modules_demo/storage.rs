@@ -0,0 +1,57 @@
+// Storage layer: owns the notes and hands out ids.
+//
+// `NoteId` is public and re-exported by the parent module. Its field is
+// private, so only code in this file can create one: callers can hold and
+// compare ids but never invent them.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteId(u32);
+
+impl NoteId {
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Note {
+    pub(super) id: NoteId,
+    pub(super) title: String,
+    pub(super) body: String,
+}
+
+// `pub(super)`: usable by the parent (`modules_demo`) and its other children
+// (`api`), but not outside `modules_demo`.
+#[derive(Debug, Default)]
+pub(super) struct NoteStore {
+    notes: Vec<Note>, // Private: only this file touches the Vec directly
+    next_id: u32,
+}
+
+impl NoteStore {
+    pub(super) fn insert(&mut self, title: &str, body: &str) -> NoteId {
+        self.next_id += 1;
+        let id = NoteId(self.next_id);
+        self.notes.push(Note {
+            id,
+            // `super::` goes up one level, to `modules_demo`, whose private
+            // function is visible here because `storage` is its child.
+            title: super::normalize_title(title),
+            body: body.to_string(),
+        });
+        id
+    }
+
+    pub(super) fn get(&self, id: NoteId) -> Option<&Note> {
+        self.notes.iter().find(|note| note.id == id)
+    }
+
+    pub(super) fn remove(&mut self, id: NoteId) -> Option<Note> {
+        let index = self.notes.iter().position(|note| note.id == id)?;
+        Some(self.notes.remove(index))
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.notes.len()
+    }
+}
@@ -0,0 +1,197 @@
+// A fixed-point `Money` type that stores amounts as whole cents in an `i64`.
+
+#![allow(dead_code)]
+
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// Amounts are stored as a whole number of cents, so adding prices is exact.
+// Arithmetic that could overflow has `checked_*` versions; the operators
+// panic on overflow like the integer types they wrap (in debug builds).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Money {
+    cents: i64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MoneyError {
+    Empty,
+    InvalidNumber(String),
+    TooManyDecimals(String),
+    NotFinite,
+    OutOfRange,
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoneyError::Empty => write!(f, "empty amount"),
+            MoneyError::InvalidNumber(s) => write!(f, "'{}' is not a number", s),
+            MoneyError::TooManyDecimals(s) => write!(f, "'{}' has more than 2 decimal places", s),
+            MoneyError::NotFinite => write!(f, "amount is NaN or infinite"),
+            MoneyError::OutOfRange => write!(f, "amount is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for MoneyError {}
+
+impl Money {
+    pub const ZERO: Money = Money { cents: 0 };
+
+    pub fn from_cents(cents: i64) -> Money {
+        Money { cents }
+    }
+
+    // `Money::new(150, 75)` is $150.75. The amount is never negative; negate
+    // it for one that is: `-Money::new(0, 50)` is -$0.50. (A signed `dollars`
+    // couldn't express that amount, because `-0` is just `0`.)
+    pub fn new(dollars: u32, cents: u8) -> Money {
+        assert!(cents < 100, "cents must be below 100, got {}", cents);
+        Money {
+            cents: dollars as i64 * 100 + cents as i64,
+        }
+    }
+
+    pub fn cents(self) -> i64 {
+        self.cents
+    }
+
+    // Converting *from* `f64` rounds to the nearest cent. Use it at the edges
+    // (e.g. data from an API that sends floats), never for calculations.
+    pub fn from_f64(amount: f64) -> Result<Money, MoneyError> {
+        if !amount.is_finite() {
+            return Err(MoneyError::NotFinite);
+        }
+        let cents = (amount * 100.0).round();
+        // `i64::MAX as f64` rounds up to 2^63, one past `i64::MAX`, so that
+        // bound is exclusive; `as i64` would silently saturate it. `i64::MIN`
+        // is -2^63 exactly, so that one is inclusive.
+        if cents < i64::MIN as f64 || cents >= i64::MAX as f64 {
+            return Err(MoneyError::OutOfRange);
+        }
+        Ok(Money {
+            cents: cents as i64,
+        })
+    }
+
+    // Converting *to* `f64` is for display or statistics only.
+    pub fn to_f64(self) -> f64 {
+        self.cents as f64 / 100.0
+    }
+
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.cents.checked_add(other.cents).map(Money::from_cents)
+    }
+
+    pub fn checked_mul(self, quantity: i64) -> Option<Money> {
+        self.cents.checked_mul(quantity).map(Money::from_cents)
+    }
+
+    // Splits an amount into `parts` shares that differ by at most one cent and
+    // always add back up to the original (no cent lost to rounding).
+    pub fn split(self, parts: u32) -> Vec<Money> {
+        assert!(parts > 0, "cannot split into zero parts");
+        let parts = parts as i64;
+        let base = self.cents.div_euclid(parts);
+        let remainder = self.cents.rem_euclid(parts);
+        (0..parts)
+            .map(|i| Money::from_cents(base + if i < remainder { 1 } else { 0 }))
+            .collect()
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.cents < 0 { "-" } else { "" };
+        let abs = self.cents.unsigned_abs(); // `abs()` would overflow for i64::MIN
+        write!(f, "{}${}.{:02}", sign, abs / 100, abs % 100)
+    }
+}
+
+// Parses "150.75", "-3.5", "$20" exactly, without going through `f64`.
+impl FromStr for Money {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Money, MoneyError> {
+        let trimmed = s.trim();
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let rest = rest.strip_prefix('$').unwrap_or(rest);
+        if rest.is_empty() {
+            return Err(MoneyError::Empty);
+        }
+        // A '.' needs digits on both sides: "1." is rejected like ".5".
+        let (whole, fraction) = match rest.split_once('.') {
+            Some((_, "")) => return Err(MoneyError::InvalidNumber(s.to_string())),
+            Some(parts) => parts,
+            None => (rest, ""),
+        };
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+            return Err(MoneyError::InvalidNumber(s.to_string()));
+        }
+        if fraction.len() > 2 {
+            return Err(MoneyError::TooManyDecimals(s.to_string()));
+        }
+        let dollars: i64 = whole.parse().map_err(|_| MoneyError::OutOfRange)?;
+        let mut cents: i64 = if fraction.is_empty() {
+            0
+        } else {
+            fraction
+                .parse()
+                .map_err(|_| MoneyError::InvalidNumber(s.to_string()))?
+        };
+        if fraction.len() == 1 {
+            cents *= 10; // "3.5" means 50 cents, not 5
+        }
+        let total = dollars
+            .checked_mul(100)
+            .and_then(|c| c.checked_add(cents))
+            .ok_or(MoneyError::OutOfRange)?;
+        Ok(Money::from_cents(if negative { -total } else { total }))
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money::from_cents(self.cents + other.cents)
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money::from_cents(self.cents - other.cents)
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money::from_cents(-self.cents)
+    }
+}
+
+// Price times quantity.
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, quantity: i64) -> Money {
+        Money::from_cents(self.cents * quantity)
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
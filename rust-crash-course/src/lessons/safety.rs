@@ -0,0 +1,44 @@
+// Lookups that return a `LookupError` instead of panicking on a missing index or key.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+
+#[derive(Debug, PartialEq)]
+pub enum LookupError {
+    IndexOutOfBounds { index: usize, len: usize },
+    KeyNotFound(String),
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LookupError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds for length {}", index, len)
+            }
+            LookupError::KeyNotFound(key) => write!(f, "key {} not found", key),
+        }
+    }
+}
+
+impl Error for LookupError {}
+
+pub fn get_or_err<T>(slice: &[T], index: usize) -> Result<&T, LookupError> {
+    slice.get(index).ok_or(LookupError::IndexOutOfBounds {
+        index,
+        len: slice.len(),
+    })
+}
+
+// The error keeps the key's `Debug` text, so it can be reported after the
+// map is gone.
+pub fn get_key_or_err<'a, K, V>(map: &'a HashMap<K, V>, key: &K) -> Result<&'a V, LookupError>
+where
+    K: Hash + Eq + fmt::Debug,
+{
+    map.get(key)
+        .ok_or_else(|| LookupError::KeyNotFound(format!("{:?}", key)))
+}
@@ -0,0 +1,45 @@
+// String slicing that works in characters, so it can't split a multibyte character.
+
+#![allow(dead_code)]
+
+use std::ops::Range;
+
+// Converts a character position into a byte offset (or `s.len()` if past the end).
+fn char_to_byte_offset(s: &str, char_pos: usize) -> usize {
+    s.char_indices()
+        .nth(char_pos)
+        .map_or(s.len(), |(byte_pos, _)| byte_pos)
+}
+
+// Slices by *character* positions, so it can never split a multibyte character.
+// An end past the last character is clamped; a reversed range gives "".
+pub fn slice_chars(s: &str, range: Range<usize>) -> &str {
+    let start = char_to_byte_offset(s, range.start);
+    let end = char_to_byte_offset(s, range.end.max(range.start));
+    &s[start..end]
+}
+
+// Keeps at most `max_bytes` bytes, dropping a partially included character.
+// (The standard library's `s.floor_char_boundary(max_bytes)` finds the same
+// cut; 42-string-internals.rs uses it to also keep accents and emoji
+// modifiers with their base character.)
+pub fn truncate_at_char_boundary(s: &str, max_bytes: usize) -> &str {
+    if max_bytes >= s.len() {
+        return s;
+    }
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1; // Index 0 is always a boundary, so this loop terminates
+    }
+    &s[..end]
+}
+
+// Counts what a reader sees as characters (extended grapheme clusters): an
+// emoji with a skin-tone modifier is two `char`s but one grapheme. The
+// standard library doesn't segment graphemes; `unicode-segmentation` does.
+#[cfg(feature = "ecosystem")]
+pub fn grapheme_count(s: &str) -> usize {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    s.graphemes(true).count()
+}
@@ -0,0 +1,100 @@
+// A small text-table printer.
+
+#![allow(dead_code)]
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    headers: Vec<String>,
+    align: Vec<Align>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<H: fmt::Display>(headers: impl IntoIterator<Item = H>) -> Table {
+        let headers: Vec<String> = headers.into_iter().map(|h| h.to_string()).collect();
+        let align = vec![Align::Left; headers.len()];
+        Table {
+            headers,
+            align,
+            rows: Vec::new(),
+        }
+    }
+
+    // Sets the alignment of one column (columns start at 0).
+    pub fn align(mut self, column: usize, align: Align) -> Table {
+        if let Some(slot) = self.align.get_mut(column) {
+            *slot = align;
+        }
+        self
+    }
+
+    // Adds a row. Any `Display` values work; missing cells are left empty and
+    // extra cells are ignored.
+    pub fn row<C: fmt::Display>(&mut self, cells: impl IntoIterator<Item = C>) -> &mut Table {
+        let mut cells: Vec<String> = cells.into_iter().map(|c| c.to_string()).collect();
+        cells.resize(self.headers.len(), String::new());
+        self.rows.push(cells);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    // Column widths in characters (not bytes, so "é" counts as one).
+    fn widths(&self) -> Vec<usize> {
+        (0..self.headers.len())
+            .map(|col| {
+                std::iter::once(&self.headers[col])
+                    .chain(self.rows.iter().map(|row| &row[col]))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let widths = self.widths();
+        let separator: String = widths
+            .iter()
+            .map(|&w| format!("+{}", "-".repeat(w + 2)))
+            .collect::<String>()
+            + "+";
+
+        let write_row = |f: &mut fmt::Formatter, cells: &[String], align: &[Align]| {
+            for ((cell, &width), &align) in cells.iter().zip(&widths).zip(align) {
+                // The width comes from a variable: `width$` names it.
+                match align {
+                    Align::Left => write!(f, "| {:<width$} ", cell, width = width)?,
+                    Align::Right => write!(f, "| {:>width$} ", cell, width = width)?,
+                    Align::Center => write!(f, "| {:^width$} ", cell, width = width)?,
+                }
+            }
+            writeln!(f, "|")
+        };
+
+        writeln!(f, "{}", separator)?;
+        write_row(f, &self.headers, &self.align)?;
+        writeln!(f, "{}", separator)?;
+        for row in &self.rows {
+            write_row(f, row, &self.align)?;
+        }
+        write!(f, "{}", separator)
+    }
+}
@@ -0,0 +1,54 @@
+// A drop tracker that turns "dropped here" comments into assertions on drop order.
+
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct DropTracker {
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+pub struct Tracked {
+    tag: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl DropTracker {
+    pub fn new() -> DropTracker {
+        DropTracker {
+            log: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    // Creates a value that records `tag` in this tracker's log when dropped.
+    pub fn track(&self, tag: &'static str) -> Tracked {
+        Tracked {
+            tag,
+            log: Rc::clone(&self.log),
+        }
+    }
+
+    // Returns a copy of the tags dropped so far, in drop order.
+    pub fn dropped(&self) -> Vec<&'static str> {
+        self.log.borrow().clone()
+    }
+
+    // Like `dropped`, but also clears the log, so the next assertion only
+    // sees what was dropped after this call.
+    pub fn take(&self) -> Vec<&'static str> {
+        std::mem::take(&mut *self.log.borrow_mut())
+    }
+}
+
+impl Tracked {
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.tag);
+    }
+}
@@ -0,0 +1,115 @@
+// Character classification (vowels, consonants, ...) that understands accented letters.
+
+#![allow(dead_code)]
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Vowel,
+    Consonant,
+    Digit,
+    Punctuation,
+    Whitespace,
+    Other, // Symbols, emoji, letters from non-Latin scripts, ...
+}
+
+// Maps an accented Latin letter to its lowercase base letter ('É' -> 'e').
+// Letters without an accent are just lowercased. Only the letters listed
+// are known; 'ø', 'ł' and 'ı' are here because Unicode doesn't decompose
+// them, so even the NFD version of `base_letter` needs this table for them.
+fn fold_letter(c: char) -> char {
+    let lower = c.to_lowercase().next().unwrap_or(c);
+    match lower {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => 'e',
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => 'i',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ç' | 'ć' | 'č' => 'c',
+        'ñ' | 'ń' | 'ň' => 'n',
+        'ś' | 'š' | 'ş' => 's',
+        'ź' | 'ż' | 'ž' => 'z',
+        'ł' => 'l',
+        'ř' => 'r',
+        'ť' => 't',
+        'ď' => 'd',
+        other => other,
+    }
+}
+
+// Without normalization, the fold table is all there is.
+#[cfg(not(feature = "ecosystem"))]
+pub fn base_letter(c: char) -> char {
+    fold_letter(c)
+}
+
+// NFD splits a precomposed letter into its base and combining marks
+// ('ế' -> 'e', U+0302, U+0301), so the first character of the decomposition
+// is the base letter, for every accented letter Unicode knows. Letters that
+// don't decompose come back unchanged and go through the fold table.
+#[cfg(feature = "ecosystem")]
+pub fn base_letter(c: char) -> char {
+    use unicode_normalization::char::decompose_canonical;
+
+    let mut base = None;
+    decompose_canonical(c, |part| {
+        base.get_or_insert(part);
+    });
+    fold_letter(base.unwrap_or(c))
+}
+
+// Removes the accents from a whole string: "Crème brûlée" -> "Creme brulee".
+// It also handles accents that are already separate ("e\u{301}"), which a
+// per-`char` function like `base_letter` sees only as a lone mark.
+#[cfg(feature = "ecosystem")]
+pub fn strip_accents(s: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+    use unicode_normalization::char::is_combining_mark;
+
+    s.nfd().filter(|&c| !is_combining_mark(c)).collect()
+}
+
+pub fn is_latin_letter(c: char) -> bool {
+    base_letter(c).is_ascii_lowercase()
+}
+
+pub fn is_vowel(c: char) -> bool {
+    matches!(base_letter(c), 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+// 'y' is treated as a consonant here, as in "yes" (in "rhythm" it acts as a vowel).
+pub fn is_consonant(c: char) -> bool {
+    is_latin_letter(c) && !is_vowel(c)
+}
+
+// `is_ascii_digit` only accepts '0'..='9'; `is_numeric` would also accept
+// '²', '½' or Arabic-Indic '٣'. Pick the one that matches what you parse.
+pub fn is_digit(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+// The standard library only has an ASCII punctuation check, so a few common
+// Unicode marks are listed explicitly.
+pub fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(
+            c,
+            '¡' | '¿' | '«' | '»' | '“' | '”' | '‘' | '’' | '…' | '–' | '—' | '、' | '。'
+        )
+}
+
+pub fn classify(c: char) -> CharClass {
+    if is_vowel(c) {
+        CharClass::Vowel
+    } else if is_consonant(c) {
+        CharClass::Consonant
+    } else if is_digit(c) {
+        CharClass::Digit
+    } else if is_punctuation(c) {
+        CharClass::Punctuation
+    } else if c.is_whitespace() {
+        CharClass::Whitespace
+    } else {
+        CharClass::Other
+    }
+}
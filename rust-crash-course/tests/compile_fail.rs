@@ -0,0 +1,16 @@
+// The lessons show code that does *not* compile in comments, next to the
+// error it produces. This harness keeps a copy of those examples as real
+// files in tests/compile_fail/, and checks with `trybuild` that each one
+// still fails, with the error recorded in its `.stderr` file.
+//
+//     cargo test --test compile_fail
+//
+// After adding a case (or when a new compiler changes a message), write the
+// expected output with `TRYBUILD=overwrite cargo test --test compile_fail`
+// and review the `.stderr` diff like any other change.
+
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
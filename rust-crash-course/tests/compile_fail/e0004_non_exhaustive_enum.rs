@@ -0,0 +1,17 @@
+// 20-api-design-sealed-traits.rs, section 2: `#[non_exhaustive]` only
+// applies in *other* crates, so this uses a non-exhaustive enum from std,
+// `io::ErrorKind`, the way a downstream crate sees `LessonError`.
+
+use std::io::ErrorKind;
+
+fn describe(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::NotFound => "not found",
+        ErrorKind::PermissionDenied => "permission denied",
+        ErrorKind::Other => "other",
+    }
+}
+
+fn main() {
+    println!("{}", describe(ErrorKind::NotFound));
+}
@@ -0,0 +1,9 @@
+// 28-compiler-errors.rs, E0106; taught in 11-lifetimes.rs.
+
+fn longer(a: &str, b: &str) -> &str {
+    if a.len() >= b.len() { a } else { b }
+}
+
+fn main() {
+    println!("{}", longer("borrow", "checker"));
+}
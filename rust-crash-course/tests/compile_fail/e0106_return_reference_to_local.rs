@@ -0,0 +1,12 @@
+// 11-lifetimes.rs, section 0: `dangle` would return a reference to its own
+// local. With no reference parameter, there is no lifetime for the result
+// to borrow from.
+
+fn dangle() -> &i32 {
+    let x = 5;
+    &x
+}
+
+fn main() {
+    println!("{}", dangle());
+}
@@ -0,0 +1,11 @@
+// 11-lifetimes.rs, section 4: a struct that holds a reference needs a
+// lifetime parameter.
+
+struct BadExcerpt {
+    part: &str,
+}
+
+fn main() {
+    let excerpt = BadExcerpt { part: "Call me Ishmael." };
+    println!("{}", excerpt.part);
+}
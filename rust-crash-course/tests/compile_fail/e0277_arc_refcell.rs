@@ -0,0 +1,12 @@
+// 64-send-sync-and-auto-traits.rs, section 3: `Arc<T>` is `Send` only if
+// `T` is `Sync`, and `RefCell` isn't.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::thread;
+
+fn main() {
+    let counter = Arc::new(RefCell::new(0));
+    let c = Arc::clone(&counter);
+    thread::spawn(move || *c.borrow_mut() += 1);
+}
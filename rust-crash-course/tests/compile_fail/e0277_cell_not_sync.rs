@@ -0,0 +1,10 @@
+// 64-send-sync-and-auto-traits.rs, section 1: a `Cell` is `Send` but not
+// `Sync`.
+
+use std::cell::Cell;
+
+const fn assert_sync<T: Sync>() {}
+
+fn main() {
+    assert_sync::<Cell<i32>>();
+}
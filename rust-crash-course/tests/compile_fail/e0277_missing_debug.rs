@@ -0,0 +1,10 @@
+// 28-compiler-errors.rs, E0277; taught in 12-traits.rs.
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    println!("{:?}", Point { x: 1, y: 2 });
+}
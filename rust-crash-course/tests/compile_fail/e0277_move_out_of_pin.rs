@@ -0,0 +1,24 @@
+// 19-self-referential-structs.rs, section 5: `PhantomPinned` makes a type
+// `!Unpin`, so safe code can't get a `&mut` out of its `Pin` to move it.
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+struct PinnedTitle {
+    len: usize,
+    _pinned: PhantomPinned,
+}
+
+fn new(len: usize) -> Pin<Box<PinnedTitle>> {
+    Box::pin(PinnedTitle {
+        len,
+        _pinned: PhantomPinned,
+    })
+}
+
+fn main() {
+    let mut a = new(1);
+    let mut b = new(2);
+    std::mem::swap(a.as_mut().get_mut(), b.as_mut().get_mut());
+    println!("{} {}", a.len, b.len);
+}
@@ -0,0 +1,19 @@
+// 64-send-sync-and-auto-traits.rs, section 5: a `std::sync::MutexGuard`
+// held across an `.await` makes the future non-`Send`.
+
+use std::sync::Mutex;
+
+fn require_send<F: Future + Send>(future: F) -> F {
+    future
+}
+
+async fn tick() {}
+
+fn main() {
+    let shared = Mutex::new(0);
+    require_send(async {
+        let mut guard = shared.lock().unwrap();
+        tick().await;
+        *guard += 1;
+    });
+}
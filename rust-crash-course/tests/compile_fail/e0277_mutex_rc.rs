@@ -0,0 +1,11 @@
+// 64-send-sync-and-auto-traits.rs, section 3: a `Mutex` makes its contents
+// `Sync` only if they are `Send`, and `Rc` isn't.
+
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+fn main() {
+    let m = Arc::new(Mutex::new(Rc::new(1)));
+    thread::spawn(move || **m.lock().unwrap());
+}
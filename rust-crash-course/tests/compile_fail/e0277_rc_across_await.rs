@@ -0,0 +1,19 @@
+// 64-send-sync-and-auto-traits.rs, section 5: an `Rc` held across an
+// `.await` makes the future non-`Send`. `require_send` stands in for
+// `tokio::spawn`, which has the same `Send` bound.
+
+use std::rc::Rc;
+
+fn require_send<F: Future + Send>(future: F) -> F {
+    future
+}
+
+async fn tick() {}
+
+fn main() {
+    require_send(async {
+        let names = Rc::new(vec!["a"]);
+        tick().await;
+        println!("{}", names.len());
+    });
+}
@@ -0,0 +1,22 @@
+// 64-send-sync-and-auto-traits.rs, section 2: `Session` loses `Send`
+// through its `Rc` field.
+
+use std::rc::Rc;
+use std::thread;
+
+struct Session {
+    id: u32,
+    cache: Rc<Vec<u8>>,
+}
+
+fn session_size(session: Session) -> usize {
+    session.id as usize + session.cache.len()
+}
+
+fn main() {
+    let session = Session {
+        id: 7,
+        cache: Rc::new(vec![1, 2, 3]),
+    };
+    thread::spawn(move || session_size(session));
+}
@@ -0,0 +1,37 @@
+// 20-api-design-sealed-traits.rs, section 1: implementing `Shape` outside
+// `shapes` needs its supertrait `Sealed`, which can't be implemented there
+// (see e0603_sealed_trait.rs).
+
+mod shapes {
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    pub trait Shape: sealed::Sealed {
+        fn area(&self) -> f64;
+    }
+
+    pub struct Square(pub f64);
+
+    impl sealed::Sealed for Square {}
+
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            self.0 * self.0
+        }
+    }
+}
+
+use shapes::Shape;
+
+struct Triangle;
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
+
+fn main() {
+    println!("{} {}", shapes::Square(2.0).area(), Triangle.area());
+}
@@ -0,0 +1,13 @@
+// 64-send-sync-and-auto-traits.rs, section 2: `&T` is `Send` only if `T`
+// is `Sync`.
+
+use std::cell::Cell;
+use std::thread;
+
+fn main() {
+    let counter = Cell::new(0);
+    let counter_ref = &counter;
+    thread::scope(|s| {
+        s.spawn(|| counter_ref.set(1));
+    });
+}
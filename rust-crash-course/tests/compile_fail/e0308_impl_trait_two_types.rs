@@ -0,0 +1,43 @@
+// 12-traits.rs, sections 8 and 12: a function returning `impl Summary`
+// must return one concrete type, not a `NewsArticle` on one branch and a
+// `Tweet` on the other.
+
+trait Summary {
+    fn summarize(&self) -> String;
+}
+
+struct NewsArticle {
+    headline: String,
+}
+
+struct Tweet {
+    username: String,
+}
+
+impl Summary for NewsArticle {
+    fn summarize(&self) -> String {
+        self.headline.clone()
+    }
+}
+
+impl Summary for Tweet {
+    fn summarize(&self) -> String {
+        self.username.clone()
+    }
+}
+
+fn returns_summarizable_error(switch: bool) -> impl Summary {
+    if switch {
+        NewsArticle {
+            headline: String::from("Headline"),
+        }
+    } else {
+        Tweet {
+            username: String::from("user"),
+        }
+    }
+}
+
+fn main() {
+    println!("{}", returns_summarizable_error(true).summarize());
+}
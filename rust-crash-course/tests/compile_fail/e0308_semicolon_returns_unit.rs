@@ -0,0 +1,10 @@
+// 03-functions.rs, section 3: the `;` turns the last expression into a
+// statement, so the body returns `()` instead of `i32`.
+
+fn example_error_function(x: i32) -> i32 {
+    x + 1;
+}
+
+fn main() {
+    println!("{}", example_error_function(1));
+}
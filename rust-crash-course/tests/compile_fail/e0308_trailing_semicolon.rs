@@ -0,0 +1,9 @@
+// 28-compiler-errors.rs, E0308; taught in 03-functions.rs.
+
+fn double(x: i32) -> i32 {
+    x * 2;
+}
+
+fn main() {
+    println!("{}", double(21));
+}
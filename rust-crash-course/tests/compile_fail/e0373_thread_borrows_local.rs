@@ -0,0 +1,9 @@
+// 34-threads.rs, section 1: a spawned thread's closure must be `'static`.
+
+use std::thread;
+
+fn main() {
+    let name = String::from("Ferris");
+    let greeter = thread::spawn(|| println!("Hello, {}", name));
+    greeter.join().unwrap();
+}
@@ -0,0 +1,12 @@
+// 02-ownership_borrowing.rs, section 13: a `String` passed by value moves
+// into the function, so the caller can't use it afterwards.
+
+fn take_ownership(s: String) {
+    println!("Function got ownership: {}", s);
+}
+
+fn main() {
+    let another_string = String::from("taking ownership");
+    take_ownership(another_string);
+    println!("another_string after function call: {}", another_string);
+}
@@ -0,0 +1,8 @@
+// 28-compiler-errors.rs, E0382; taught in 02-ownership_borrowing.rs.
+
+fn main() {
+    let name = String::from("Ferris");
+    let greeting = name; // `name` moved here
+    println!("{}", name);
+    println!("{}", greeting);
+}
@@ -0,0 +1,9 @@
+// 13-pointers.rs, section 1: only one `&mut` to `y` at a time.
+
+fn main() {
+    let mut y = 20;
+    let r_mut = &mut y;
+    let another_r_mut = &mut y;
+    *r_mut = 30;
+    println!("Another mutable reference (another_r_mut): {}", another_r_mut);
+}
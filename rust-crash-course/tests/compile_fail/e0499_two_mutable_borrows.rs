@@ -0,0 +1,9 @@
+// 28-compiler-errors.rs, E0499; taught in 02-ownership_borrowing.rs and
+// 24-slices-and-split-borrows.rs.
+
+fn main() {
+    let mut scores = vec![1, 2];
+    let first = &mut scores[0];
+    let second = &mut scores[1];
+    *first += *second;
+}
@@ -0,0 +1,10 @@
+// 02-ownership_borrowing.rs, section 18: no `&mut` while a shared reference
+// into the same `Vec` is still used.
+
+fn main() {
+    let mut data2 = vec![10, 20, 30];
+    let immutable_ref1 = &data2[0];
+    let mutable_ref2 = &mut data2;
+    println!("Immutable ref: {}", immutable_ref1);
+    mutable_ref2.push(40);
+}
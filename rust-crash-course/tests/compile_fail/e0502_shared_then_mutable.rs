@@ -0,0 +1,8 @@
+// 28-compiler-errors.rs, E0502; taught in 02-ownership_borrowing.rs.
+
+fn main() {
+    let mut items = vec![10, 20];
+    let first = &items[0];
+    items.push(30);
+    println!("{}", first);
+}
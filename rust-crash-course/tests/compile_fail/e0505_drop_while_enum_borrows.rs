@@ -0,0 +1,14 @@
+// 11-lifetimes.rs, section 4: `greeting` can't be dropped while
+// `text_message` still borrows it.
+
+#[derive(Debug)]
+enum Message<'a> {
+    Text(&'a str),
+}
+
+fn main() {
+    let greeting = String::from("Hello, enum!");
+    let text_message = Message::Text(&greeting);
+    drop(greeting);
+    println!("Message: {:?}", text_message);
+}
@@ -0,0 +1,28 @@
+// 11-lifetimes.rs, section 5: a reference returned by a `Person` method
+// borrows `name_scope`, so `name_scope` can't be dropped while it's used.
+
+struct Person<'a> {
+    first_name: &'a str,
+    last_name: &'a str,
+}
+
+impl<'a> Person<'a> {
+    fn new(first: &'a str, last: &'a str) -> Self {
+        Person {
+            first_name: first,
+            last_name: last,
+        }
+    }
+
+    fn get_first_name_ref(&self) -> &'a str {
+        self.first_name
+    }
+}
+
+fn main() {
+    let name_scope = String::from("Alice");
+    let person = Person::new(&name_scope, "Smith");
+    let first_name_ref = person.get_first_name_ref();
+    drop(name_scope);
+    println!("{} {}", first_name_ref, person.last_name);
+}
@@ -0,0 +1,17 @@
+// 19-self-referential-structs.rs, section 1: a struct can't borrow from its
+// own field, because no lifetime means "as long as the struct itself".
+
+struct Document<'a> {
+    text: String,
+    title: &'a str,
+}
+
+fn load(text: String) -> Document<'static> {
+    let title = text.lines().next().unwrap_or("");
+    Document { text, title }
+}
+
+fn main() {
+    let document = load(String::from("Rust Book\nChapter 1"));
+    println!("{} ({} bytes)", document.title, document.text.len());
+}
@@ -0,0 +1,9 @@
+// 13-pointers.rs, section 1: a `&` reference can't be used to change the
+// value it points to.
+
+fn main() {
+    let x = 10;
+    let r1 = &x;
+    *r1 = 20;
+    println!("{}", x);
+}
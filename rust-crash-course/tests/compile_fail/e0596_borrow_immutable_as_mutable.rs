@@ -0,0 +1,11 @@
+// 02-ownership_borrowing.rs, section 15: `&mut` needs a variable declared
+// with `mut`.
+
+fn modify_string(s: &mut String) {
+    s.push_str(" (modified)");
+}
+
+fn main() {
+    let immutable_string = String::from("not changeable");
+    modify_string(&mut immutable_string);
+}
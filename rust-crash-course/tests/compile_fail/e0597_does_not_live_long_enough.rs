@@ -0,0 +1,10 @@
+// 28-compiler-errors.rs, E0597; taught in 11-lifetimes.rs.
+
+fn main() {
+    let longest;
+    {
+        let local = String::from("inner");
+        longest = &local;
+    }
+    println!("{}", longest);
+}
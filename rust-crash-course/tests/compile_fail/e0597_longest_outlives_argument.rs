@@ -0,0 +1,16 @@
+// 11-lifetimes.rs, section 3: the result of `longest` may be `s2`, so it
+// can't be used after `s2` goes out of scope.
+
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() > y.len() { x } else { y }
+}
+
+fn main() {
+    let s1 = String::from("longer string");
+    let result_dangling;
+    {
+        let s2 = String::from("short");
+        result_dangling = longest(&s1, &s2);
+    }
+    println!("The result is {}", result_dangling);
+}
@@ -0,0 +1,18 @@
+// 11-lifetimes.rs, section 4: `ImportantExcerpt` can't outlive the string
+// its `part` points into.
+
+#[derive(Debug)]
+struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+fn main() {
+    let excerpt_holder;
+    {
+        let chapter_intro = String::from("Introduction to borrowing.");
+        excerpt_holder = ImportantExcerpt {
+            part: &chapter_intro,
+        };
+    }
+    println!("Excerpt holder still valid: {:?}", excerpt_holder);
+}
@@ -0,0 +1,17 @@
+// 15-package-crate-module-path.rs, section 3: items in a module are private
+// unless marked `pub`.
+
+mod greetings {
+    fn english() {
+        println!("Hello!");
+    }
+
+    pub fn greet_all() {
+        english();
+    }
+}
+
+fn main() {
+    greetings::greet_all();
+    greetings::english();
+}
@@ -0,0 +1,38 @@
+// 20-api-design-sealed-traits.rs, section 1: outside `shapes`, nobody can
+// implement `Sealed`, because it lives in a private module.
+
+mod shapes {
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    pub trait Shape: sealed::Sealed {
+        fn area(&self) -> f64;
+    }
+
+    pub struct Square(pub f64);
+
+    impl sealed::Sealed for Square {}
+
+    impl Shape for Square {
+        fn area(&self) -> f64 {
+            self.0 * self.0
+        }
+    }
+}
+
+use shapes::Shape;
+
+struct Triangle;
+
+impl Shape for Triangle {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
+
+impl shapes::sealed::Sealed for Triangle {}
+
+fn main() {
+    println!("{} {}", shapes::Square(2.0).area(), Triangle.area());
+}
@@ -0,0 +1,15 @@
+// 13-pointers.rs, `MyBox`: `*` only works on a type that implements
+// `Deref`, and `MyBox` doesn't yet.
+
+struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+fn main() {
+    let my_val = MyBox::new(String::from("Hello from MyBox!"));
+    println!("Trying to dereference: {}", *my_val);
+}
@@ -0,0 +1,28 @@
+// 15-package-crate-module-path.rs, section 3: a method without `pub` can
+// only be called from inside its module.
+
+mod calculator {
+    pub struct BasicCalculator {
+        pub value: f64,
+    }
+
+    impl BasicCalculator {
+        pub fn new(start_value: f64) -> BasicCalculator {
+            BasicCalculator { value: start_value }
+        }
+
+        fn subtract(&mut self, num: f64) {
+            self.value -= num;
+        }
+
+        pub fn perform_subtraction(&mut self, num: f64) {
+            self.subtract(num);
+        }
+    }
+}
+
+fn main() {
+    let mut calc = calculator::BasicCalculator::new(10.0);
+    calc.perform_subtraction(2.0);
+    calc.subtract(1.0);
+}
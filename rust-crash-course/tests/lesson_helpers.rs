@@ -0,0 +1,484 @@
+// The shared helpers in src/lessons/ are included into lesson files with
+// `#[path]`, and most lessons are run on their own with rustc, so nothing
+// else checks them on every build. This test includes the same files the
+// same way and checks each helper against the cases the lessons rely on.
+//
+//     cargo test --test lesson_helpers
+
+#[cfg(feature = "ecosystem")]
+#[path = "../src/lessons/async_compat.rs"]
+mod async_compat;
+#[path = "../src/lessons/async_guard.rs"]
+mod async_guard;
+#[cfg(feature = "ecosystem")]
+#[path = "../src/lessons/async_iter.rs"]
+mod async_iter;
+#[path = "../src/lessons/error.rs"]
+mod error;
+#[path = "../src/lessons/fmt.rs"]
+mod fmt;
+#[path = "../src/lessons/money.rs"]
+mod money;
+#[path = "../src/lessons/safety.rs"]
+mod safety;
+#[path = "../src/lessons/strings.rs"]
+mod strings;
+#[path = "../src/lessons/testing.rs"]
+mod testing;
+#[path = "../src/lessons/text.rs"]
+mod text;
+
+use std::time::Duration;
+
+#[test]
+fn human_duration_picks_the_largest_unit() {
+    use fmt::human_duration;
+
+    assert_eq!(human_duration(Duration::ZERO), "0 ns");
+    assert_eq!(human_duration(Duration::from_nanos(940)), "940 ns");
+    assert_eq!(human_duration(Duration::from_nanos(1_500)), "1.5 µs");
+    assert_eq!(human_duration(Duration::from_millis(250)), "250 ms");
+    assert_eq!(human_duration(Duration::from_millis(1_250)), "1.3 s");
+    assert_eq!(human_duration(Duration::from_secs(90)), "90 s");
+    // Rounding can carry into the next unit.
+    assert_eq!(human_duration(Duration::from_nanos(999)), "1 µs");
+    assert_eq!(human_duration(Duration::from_micros(999_960)), "1 s");
+}
+
+#[test]
+fn human_bytes_uses_binary_units() {
+    use fmt::human_bytes;
+
+    assert_eq!(human_bytes(0), "0 B");
+    assert_eq!(human_bytes(900), "900 B");
+    assert_eq!(human_bytes(1_023), "1 KiB"); // 0.999 KiB, rounded
+    assert_eq!(human_bytes(1_024), "1 KiB");
+    assert_eq!(human_bytes(1_536), "1.5 KiB");
+    assert_eq!(human_bytes(2_500_000), "2.4 MiB");
+    assert_eq!(human_bytes(3 << 30), "3 GiB");
+}
+
+#[test]
+fn parse_duration_reads_what_human_duration_writes() {
+    use fmt::{ParseDurationError, human_duration, parse_duration};
+
+    assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+    assert_eq!(parse_duration(" 1.3 s "), Ok(Duration::from_millis(1_300)));
+    assert_eq!(parse_duration("0.1s"), Ok(Duration::from_millis(100))); // Exact
+    assert_eq!(parse_duration("5us"), parse_duration("5 µs"));
+    assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+    assert_eq!(parse_duration("1.5h"), Ok(Duration::from_secs(5_400)));
+
+    // Round trip: every value `human_duration` shows exactly parses back.
+    for duration in [
+        Duration::ZERO,
+        Duration::from_nanos(42),
+        Duration::from_micros(1_500),
+        Duration::from_millis(250),
+        Duration::from_millis(1_300),
+        Duration::from_secs(3_600),
+    ] {
+        assert_eq!(parse_duration(&human_duration(duration)), Ok(duration));
+    }
+
+    assert_eq!(parse_duration(""), Err(ParseDurationError::Empty));
+    assert_eq!(
+        parse_duration("250"),
+        Err(ParseDurationError::MissingUnit(String::from("250")))
+    );
+    assert_eq!(
+        parse_duration("3 days"),
+        Err(ParseDurationError::UnknownUnit(String::from("days")))
+    );
+    assert_eq!(
+        parse_duration(".5s"),
+        Err(ParseDurationError::InvalidNumber(String::from(".5s")))
+    );
+    assert_eq!(
+        parse_duration("1.2.3s"),
+        Err(ParseDurationError::InvalidNumber(String::from("1.2.3s")))
+    );
+    assert!(matches!(
+        parse_duration("99999999999999999999999h"),
+        Err(ParseDurationError::OutOfRange(_))
+    ));
+}
+
+#[test]
+fn multi_error_reports_every_failure() {
+    use error::MultiError;
+
+    let mut errors = MultiError::new();
+    assert!(errors.is_empty());
+    assert_eq!(errors.clone().into_result(), Ok(()));
+
+    errors.push("queue is down");
+    errors.push(format_args!("{} is down", "cache"));
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.errors(), ["queue is down", "cache is down"]);
+
+    // Sorting makes the order in which the failures arrived irrelevant.
+    let reversed: MultiError = ["cache is down", "queue is down"]
+        .into_iter()
+        .rev()
+        .map(String::from)
+        .collect();
+    assert_eq!(errors.clone().sorted(), reversed.sorted());
+
+    let report = errors.sorted().into_result().unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "2 task(s) failed:\n  - cache is down\n  - queue is down"
+    );
+    let boxed: Box<dyn std::error::Error> = Box::new(report); // Works with `?`
+    assert!(boxed.source().is_none());
+}
+
+#[cfg(feature = "ecosystem")]
+#[tokio::test]
+async fn run_blocking_returns_the_result_and_reraises_panics() {
+    use async_compat::run_blocking;
+
+    let worker = std::thread::current().id();
+    let (answer, ran_on) = run_blocking(|| (6 * 7, std::thread::current().id())).await;
+    assert_eq!(answer, 42);
+    assert_ne!(ran_on, worker); // On the blocking pool, not the async worker
+
+    // The panic comes out of the `.await`, with its original payload; here
+    // the awaiting task is spawned so the test can inspect its `JoinError`.
+    let panicking = tokio::spawn(run_blocking(|| -> u64 { panic!("hash input was empty") }));
+    let join_error = panicking.await.expect_err("the panic propagates");
+    assert!(join_error.is_panic());
+    assert_eq!(
+        join_error.into_panic().downcast_ref::<&str>(),
+        Some(&"hash input was empty")
+    );
+}
+
+// Paused time: the simulated requests finish in a fixed order, so the peak
+// is exact rather than "at most `limit`".
+#[cfg(feature = "ecosystem")]
+#[tokio::test(start_paused = true)]
+async fn map_concurrent_respects_the_limit_and_keeps_order() {
+    use async_iter::map_concurrent;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::time::Instant;
+
+    // (limit, expected peak, expected total time in ms)
+    for (limit, expected_peak, expected_ms) in [(3, 3, 50), (1, 1, 150), (0, 1, 150), (10, 5, 50)] {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let high_water_mark = Arc::new(AtomicUsize::new(0));
+        let started = Instant::now();
+
+        let doubled = map_concurrent((1..=5).collect(), limit, |n: u64| {
+            let in_flight = Arc::clone(&in_flight);
+            let high_water_mark = Arc::clone(&high_water_mark);
+            async move {
+                let running = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                high_water_mark.fetch_max(running, Ordering::SeqCst);
+                // Later items finish sooner (50ms down to 10ms), so completion
+                // order isn't input order.
+                tokio::time::sleep(Duration::from_millis(60 - 10 * n)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                n * 2
+            }
+        })
+        .await;
+
+        assert_eq!(doubled, [2, 4, 6, 8, 10], "limit {}", limit);
+        assert_eq!(
+            high_water_mark.load(Ordering::SeqCst),
+            expected_peak,
+            "limit {}",
+            limit
+        );
+        assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            started.elapsed(),
+            Duration::from_millis(expected_ms),
+            "limit {}",
+            limit
+        );
+    }
+}
+
+#[test]
+fn cleanup_guard_removes_its_file_when_dropped() {
+    use async_guard::CleanupGuard;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let path = std::env::temp_dir().join(format!("cleanup_guard_{}.tmp", std::process::id()));
+    std::fs::write(&path, "scratch").expect("writing the temp file");
+    let cleaned_up = Arc::new(AtomicBool::new(false));
+
+    let guard = CleanupGuard::new(&path, Arc::clone(&cleaned_up));
+    assert_eq!(guard.path(), path);
+    assert!(path.exists());
+    drop(guard);
+    assert!(cleaned_up.load(Ordering::SeqCst));
+    assert!(!path.exists());
+
+    // A file that's already gone is not an error.
+    let cleaned_up = Arc::new(AtomicBool::new(false));
+    drop(CleanupGuard::new(&path, Arc::clone(&cleaned_up)));
+    assert!(cleaned_up.load(Ordering::SeqCst));
+}
+
+// Aborting a task drops its future at the pending `.await`, guard included.
+#[cfg(feature = "ecosystem")]
+#[tokio::test]
+async fn cleanup_guard_runs_when_its_task_is_aborted() {
+    use async_guard::CleanupGuard;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tokio::sync::{Semaphore, oneshot};
+
+    let path = std::env::temp_dir().join(format!("cleanup_guard_abort_{}.tmp", std::process::id()));
+    let cleaned_up = Arc::new(AtomicBool::new(false));
+    let semaphore = Arc::new(Semaphore::new(1));
+    let (ready_tx, ready_rx) = oneshot::channel();
+
+    let task = tokio::spawn({
+        let path = path.clone();
+        let cleaned_up = Arc::clone(&cleaned_up);
+        let semaphore = Arc::clone(&semaphore);
+        async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+            std::fs::write(&path, "work in progress").expect("writing the temp file");
+            let _guard = CleanupGuard::new(path, cleaned_up);
+            ready_tx.send(()).expect("test is waiting");
+            std::future::pending::<()>().await; // Never finishes on its own
+        }
+    });
+
+    ready_rx.await.expect("task reached its await");
+    assert!(path.exists());
+    assert_eq!(semaphore.available_permits(), 0);
+    assert!(!cleaned_up.load(Ordering::SeqCst));
+
+    task.abort();
+    assert!(task.await.expect_err("task was aborted").is_cancelled());
+    assert!(cleaned_up.load(Ordering::SeqCst));
+    assert!(!path.exists());
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
+#[test]
+fn slice_chars_counts_characters_not_bytes() {
+    use strings::slice_chars;
+
+    let russian = "Здравствуйте"; // Two bytes per letter
+    assert_eq!(slice_chars(russian, 0..1), "З");
+    assert_eq!(slice_chars(russian, 0..5), "Здрав");
+    assert_eq!(slice_chars(russian, 6..100), "твуйте"); // End clamped
+    assert_eq!(slice_chars(russian, 20..30), ""); // Start past the end
+    #[allow(clippy::reversed_empty_ranges)] // Reversed on purpose
+    let reversed = slice_chars(russian, 3..1);
+    assert_eq!(reversed, "");
+
+    let emoji = "héllo 👋🏽!";
+    assert_eq!(slice_chars(emoji, 1..2), "é");
+    assert_eq!(slice_chars(emoji, 6..7), "👋"); // Without its skin tone
+    assert_eq!(slice_chars(emoji, 6..8), "👋🏽");
+}
+
+#[test]
+fn truncate_at_char_boundary_never_splits_a_character() {
+    use strings::truncate_at_char_boundary;
+
+    let russian = "Здравствуйте";
+    assert_eq!(truncate_at_char_boundary(russian, 5), "Зд"); // 5 bytes would split 'р'
+    assert_eq!(truncate_at_char_boundary(russian, 6), "Здр");
+    assert_eq!(truncate_at_char_boundary(russian, 1), "");
+    assert_eq!(truncate_at_char_boundary("short", 100), "short");
+
+    let emoji = "héllo 👋🏽!";
+    assert_eq!(truncate_at_char_boundary(emoji, 9), "héllo "); // Inside '👋'
+    assert_eq!(truncate_at_char_boundary(emoji, 11), "héllo 👋");
+    // Agrees with the standard library's version at every cut.
+    for max_bytes in 0..=emoji.len() {
+        assert_eq!(
+            truncate_at_char_boundary(emoji, max_bytes),
+            &emoji[..emoji.floor_char_boundary(max_bytes)]
+        );
+    }
+}
+
+#[cfg(feature = "ecosystem")]
+#[test]
+fn grapheme_count_counts_what_a_reader_sees() {
+    use strings::grapheme_count;
+
+    assert_eq!(grapheme_count("héllo 👋🏽!"), 8); // '👋🏽' is two chars, one grapheme
+    assert_eq!(grapheme_count("e\u{301}"), 1); // 'e' + combining acute accent
+    assert_eq!(grapheme_count("Здравствуйте"), 12);
+    assert_eq!(grapheme_count(""), 0);
+}
+
+#[test]
+fn lookups_return_errors_instead_of_panicking() {
+    use safety::{LookupError, get_key_or_err, get_or_err};
+    use std::collections::HashMap;
+
+    let scores = [90, 85, 77];
+    assert_eq!(get_or_err(&scores, 0), Ok(&90));
+    assert_eq!(
+        get_or_err(&scores, 3),
+        Err(LookupError::IndexOutOfBounds { index: 3, len: 3 })
+    );
+    assert_eq!(
+        get_or_err::<i32>(&[], 0),
+        Err(LookupError::IndexOutOfBounds { index: 0, len: 0 })
+    );
+
+    let ages = HashMap::from([("Alice", 30)]);
+    assert_eq!(get_key_or_err(&ages, &"Alice"), Ok(&30));
+    let missing = get_key_or_err(&ages, &"Bob").unwrap_err();
+    assert_eq!(missing, LookupError::KeyNotFound(String::from("\"Bob\"")));
+    assert_eq!(missing.to_string(), "key \"Bob\" not found");
+    assert_eq!(
+        get_or_err(&scores, 5).unwrap_err().to_string(),
+        "index 5 is out of bounds for length 3"
+    );
+}
+
+#[test]
+fn drop_tracker_records_drops_in_order() {
+    use testing::DropTracker;
+
+    let tracker = DropTracker::new();
+    let kept = tracker.track("kept");
+    {
+        let _first = tracker.track("first");
+        let _second = tracker.track("second");
+        assert!(tracker.dropped().is_empty());
+    }
+    assert_eq!(tracker.dropped(), ["second", "first"]);
+    assert_eq!(tracker.take(), ["second", "first"]);
+    assert!(tracker.dropped().is_empty()); // `take` cleared the log
+
+    assert_eq!(kept.tag(), "kept");
+    drop(kept);
+    assert_eq!(tracker.take(), ["kept"]);
+
+    std::mem::forget(tracker.track("forgotten")); // Never dropped, never logged
+    assert!(tracker.dropped().is_empty());
+}
+
+#[test]
+fn money_is_exact_to_the_cent() {
+    use money::{Money, MoneyError};
+
+    assert_eq!(Money::new(150, 75).cents(), 15_075);
+    assert_eq!(Money::new(0, 50).to_string(), "$0.50");
+    // Negative amounts, including ones under a dollar, come from negation.
+    assert_eq!((-Money::new(0, 50)).cents(), -50);
+    assert_eq!((-Money::new(0, 50)).to_string(), "-$0.50");
+    assert_eq!((-Money::new(3, 5)).to_string(), "-$3.05");
+    assert_eq!(
+        Money::from_cents(i64::MIN).to_string(),
+        "-$92233720368547758.08"
+    );
+
+    // Display and parsing round-trip.
+    for cents in [0, 5, 50, -50, 15_075, -350, 1_000_000] {
+        let money = Money::from_cents(cents);
+        assert_eq!(money.to_string().parse::<Money>(), Ok(money));
+    }
+    assert_eq!("-$0.05".parse::<Money>(), Ok(-Money::new(0, 5)));
+    assert_eq!(
+        "1.999".parse::<Money>(),
+        Err(MoneyError::TooManyDecimals(String::from("1.999")))
+    );
+    for missing_digits in ["1.", ".5", "$.", "-3."] {
+        assert_eq!(
+            missing_digits.parse::<Money>(),
+            Err(MoneyError::InvalidNumber(String::from(missing_digits)))
+        );
+    }
+
+    let shares = Money::new(100, 0).split(3);
+    assert_eq!(
+        shares,
+        [
+            Money::from_cents(3_334),
+            Money::from_cents(3_333),
+            Money::from_cents(3_333)
+        ]
+    );
+    assert_eq!(shares.into_iter().sum::<Money>(), Money::new(100, 0));
+    assert_eq!(
+        Money::new(19, 99) * 3 - Money::new(0, 97),
+        Money::new(59, 0)
+    );
+}
+
+// `i64::MAX as f64` is 2^63, which doesn't fit in an `i64`; it must be
+// rejected rather than saturated to `i64::MAX` by `as`.
+#[test]
+fn money_from_f64_rejects_amounts_past_i64() {
+    use money::{Money, MoneyError};
+
+    let two_pow_63 = i64::MAX as f64; // Rounded up
+    assert_eq!(two_pow_63, 2f64.powi(63));
+    assert_eq!(
+        Money::from_f64(two_pow_63 / 100.0), // Exactly 2^63 cents
+        Err(MoneyError::OutOfRange)
+    );
+    let largest_below = (1u64 << 63) - 1024; // The next f64 down from 2^63
+    assert_eq!(
+        Money::from_f64(largest_below as f64 / 100.0),
+        Ok(Money::from_cents(largest_below as i64))
+    );
+    assert_eq!(
+        Money::from_f64(-two_pow_63 / 100.0), // -2^63 cents fits
+        Ok(Money::from_cents(i64::MIN))
+    );
+    assert_eq!(Money::from_f64(-1e17), Err(MoneyError::OutOfRange));
+    assert_eq!(Money::from_f64(f64::NAN), Err(MoneyError::NotFinite));
+    assert_eq!(Money::from_f64(19.99), Ok(Money::new(19, 99)));
+}
+
+#[test]
+fn classify_understands_accented_letters() {
+    use text::{CharClass, base_letter, classify};
+
+    assert_eq!(base_letter('É'), 'e');
+    assert_eq!(base_letter('ø'), 'o'); // No decomposition: from the fold table
+    assert_eq!(base_letter('Q'), 'q');
+    assert_eq!(classify('a'), CharClass::Vowel);
+    assert_eq!(classify('Ç'), CharClass::Consonant);
+    assert_eq!(classify('y'), CharClass::Consonant);
+    assert_eq!(classify('7'), CharClass::Digit);
+    assert_eq!(classify('²'), CharClass::Other);
+    assert_eq!(classify('¿'), CharClass::Punctuation);
+    assert_eq!(classify('\u{a0}'), CharClass::Whitespace); // No-break space
+    assert_eq!(classify('ж'), CharClass::Other);
+    assert_eq!(classify('🦀'), CharClass::Other);
+}
+
+// Letters the fold table doesn't list are only known through NFD.
+#[cfg(not(feature = "ecosystem"))]
+#[test]
+fn base_letter_without_normalization_only_knows_the_table() {
+    use text::{CharClass, base_letter, classify};
+
+    assert_eq!(base_letter('ế'), 'ế');
+    assert_eq!(classify('ế'), CharClass::Other);
+}
+
+#[cfg(feature = "ecosystem")]
+#[test]
+fn base_letter_decomposes_with_normalization() {
+    use text::{CharClass, base_letter, classify, strip_accents};
+
+    assert_eq!(base_letter('ế'), 'e'); // Two accents
+    assert_eq!(base_letter('Ǹ'), 'n');
+    assert_eq!(base_letter('ı'), 'i'); // Still from the fold table
+    assert_eq!(classify('ế'), CharClass::Vowel);
+    assert_eq!(strip_accents("Crème brûlée"), "Creme brulee");
+    assert_eq!(strip_accents("e\u{301}lan"), "elan");
+    assert_eq!(strip_accents("Øresund"), "Øresund"); // 'Ø' doesn't decompose
+}
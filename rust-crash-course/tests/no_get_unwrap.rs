@@ -0,0 +1,208 @@
+// Lessons handle a missing key or index instead of calling `.unwrap()` on a
+// `.get(..)` (see lessons/safety.rs and section 5.l of
+// 07-rust_data_structures.rs). Clippy's `get_unwrap` lint checks that for
+// the cargo targets, but most lessons are built with plain rustc, so this
+// test looks for the pattern in every file under src/.
+//
+//     cargo test --test no_get_unwrap
+//
+// A demo that panics on purpose marks its statement with
+// `#[allow(clippy::get_unwrap)]`, the same marker clippy accepts.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MARKER: &str = "#[allow(clippy::get_unwrap)]";
+
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).unwrap_or_else(|e| panic!("reading {:?}: {}", dir, e));
+    for entry in entries {
+        let path = entry.expect("directory entry").path();
+        if path.is_dir() {
+            rust_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+// Replaces comments and the contents of string and char literals with
+// spaces (keeping newlines), so only code is left at the same offsets.
+fn code_only(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len());
+    let blank = |c: char| if c == '\n' { '\n' } else { ' ' };
+    let mut i = 0;
+    while i < chars.len() {
+        let rest = &chars[i..];
+        if rest.starts_with(&['/', '/']) {
+            while i < chars.len() && chars[i] != '\n' {
+                out.push(' ');
+                i += 1;
+            }
+        } else if rest.starts_with(&['/', '*']) {
+            let mut depth = 0;
+            while i < chars.len() {
+                if chars[i..].starts_with(&['/', '*']) {
+                    depth += 1;
+                    out.push_str("  ");
+                    i += 2;
+                } else if chars[i..].starts_with(&['*', '/']) {
+                    depth -= 1;
+                    out.push_str("  ");
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    out.push(blank(chars[i]));
+                    i += 1;
+                }
+            }
+        } else if rest[0] == 'r' && rest.get(1).is_some_and(|&c| c == '"' || c == '#') {
+            // Raw string: r"..." or r#"..."#, with no escapes.
+            let hashes = rest[1..].iter().take_while(|&&c| c == '#').count();
+            if rest.get(1 + hashes) != Some(&'"') {
+                out.push('r'); // An identifier such as `r#type`
+                i += 1;
+                continue;
+            }
+            let closing: Vec<char> = std::iter::once('"')
+                .chain(std::iter::repeat_n('#', hashes))
+                .collect();
+            out.push_str(&" ".repeat(2 + hashes));
+            i += 2 + hashes;
+            while i < chars.len() && !chars[i..].starts_with(&closing) {
+                out.push(blank(chars[i]));
+                i += 1;
+            }
+            out.push_str(&" ".repeat(closing.len()));
+            i += closing.len();
+        } else if rest[0] == '"' {
+            out.push(' ');
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    out.push(' ');
+                    i += 1;
+                }
+                if i < chars.len() {
+                    out.push(blank(chars[i]));
+                    i += 1;
+                }
+            }
+            out.push(' ');
+            i += 1;
+        } else if rest[0] == '\'' && (rest.get(1) == Some(&'\\') || rest.get(2) == Some(&'\'')) {
+            // A char literal such as '(' or '\n' (a lifetime has no closing
+            // quote). Skip the char after a backslash, then find the quote.
+            let mut len = if rest[1] == '\\' { 3 } else { 2 };
+            while len < rest.len() && rest[len] != '\'' {
+                len += 1; // '\u{301}'
+            }
+            len = (len + 1).min(rest.len());
+            out.push_str(&" ".repeat(len));
+            i += len;
+        } else {
+            out.push(rest[0]);
+            i += 1;
+        }
+    }
+    out
+}
+
+// Line numbers (1-based) of `.get(..).unwrap()` and `.get_mut(..).unwrap()`
+// calls, including ones that rustfmt split across lines, unless the
+// statement they're in carries the marker.
+fn unmarked_get_unwraps(source: &str) -> Vec<usize> {
+    let code = code_only(source);
+    let lines: Vec<&str> = code.lines().collect();
+    let line_of = |offset: usize| code[..offset].matches('\n').count();
+    let mut found = Vec::new();
+    for (pos, _) in code.match_indices(".unwrap()") {
+        let before = code[..pos].trim_end();
+        if !before.ends_with(')') {
+            continue;
+        }
+        let mut depth = 0;
+        let mut open = None;
+        for (i, c) in before.char_indices().rev() {
+            match c {
+                ')' => depth += 1,
+                '(' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        open = Some(i);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let Some(open) = open else { continue };
+        let callee = code[..open].trim_end();
+        if !(callee.ends_with(".get") || callee.ends_with(".get_mut")) {
+            continue;
+        }
+        // Walk up from the `.get` line to the start of the statement.
+        let mut line = line_of(open);
+        let marked = loop {
+            if lines[line].contains(MARKER) {
+                break true;
+            }
+            if line == 0 {
+                break false;
+            }
+            line -= 1;
+            let previous = lines[line].trim_end();
+            if previous.ends_with(';') || previous.ends_with('{') || previous.ends_with('}') {
+                break false;
+            }
+        };
+        if !marked {
+            found.push(line_of(pos) + 1);
+        }
+    }
+    found
+}
+
+#[test]
+fn the_scanner_finds_what_it_should() {
+    let flagged = "let a = v.get(0).unwrap();\nlet b = map\n    .get(&key)\n    .unwrap();\n";
+    assert_eq!(unmarked_get_unwraps(flagged), [1, 4]);
+
+    let ignored = concat!(
+        "// v.get(0).unwrap() in a comment\n",
+        "let s = \"v.get(0).unwrap()\";\n",
+        "let c = v.get(b')').copied().unwrap();\n",
+        "let d = v.first().unwrap();\n",
+        "#[allow(clippy::get_unwrap)]\n",
+        "let e = v.get(0).unwrap();\n",
+    );
+    assert_eq!(unmarked_get_unwraps(ignored), Vec::<usize>::new());
+}
+
+#[test]
+fn lessons_do_not_unwrap_lookups() {
+    let mut files = Vec::new();
+    rust_files(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src").as_path(),
+        &mut files,
+    );
+    files.sort();
+    assert!(files.len() > 50, "found only {} files", files.len());
+
+    let mut offenders = Vec::new();
+    for file in &files {
+        let source = fs::read_to_string(file).expect("lesson source is UTF-8");
+        for line in unmarked_get_unwraps(&source) {
+            offenders.push(format!("{}:{}", file.display(), line));
+        }
+    }
+    assert!(
+        offenders.is_empty(),
+        "`.get(..).unwrap()` outside a `{}` demo:\n{}",
+        MARKER,
+        offenders.join("\n")
+    );
+}